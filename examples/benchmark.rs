@@ -5,7 +5,7 @@ use std::time::Instant;
 const ITERATIONS: usize = 5;
 
 pub fn create_pathfind_map(width: usize, height: usize) -> BidiVec<u8> {
-    BidiVec::with_size_func(width, height, || rand::random::<u8>())
+    BidiVec::with_size_func(width, height, rand::random::<u8>)
 }
 
 pub fn create_uniform_map(width: usize, height: usize, value: u8) -> BidiVec<u8> {
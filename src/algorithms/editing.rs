@@ -12,15 +12,43 @@
 //!   or for whatever operation the calling code decides.
 //! - [`flood_fill()`]: Performs a flood-fill on the [`BidiViewMut`][crate::BidiViewMut], using a custom
 //!   comparison closure and a custom action for painting/filling.
+//! - [`flood_blend()`]: Like [`flood_fill()`], but blends the filled region with another view.
+//! - [`copy_nine_slice()`]: Copies a view over a rectangle of any size, stretching or tiling
+//!   its center and edges while keeping its corners intact.
+//! - [`tile_fill()`]: Fills a rectangle by repeating a pattern view.
+//! - [`fill_checkerboard()`] and [`fill_stripes()`]: Fill a rectangle with a checkerboard
+//!   or with stripes of two values.
+//! - [`stamp_symmetric()`]: Copies a view together with its mirrored copies, to edit
+//!   symmetric maps.
+//! - [`outline()`]: Finds the boundary items of the regions matching a predicate.
+//! - [`marching_squares()`]: Traces the contours of the regions matching a predicate as polylines.
+//! - [`replace()`] and [`replace_value()`]: Replace the items matching a predicate, or
+//!   equal to a value, in a rectangle.
+//! - [`histogram()`]: Counts the occurrences of each distinct value in a view.
+//! - [`fill()`] and [`count()`]: Fill a rectangle with a value, and count the items equal
+//!   to a value, operating on whole rows at once where possible.
+//! - [`draw_border()`]: Draws a frame of a value around the edges of a view.
+//! - `fill_random()`, `shuffle()` and `shuffle_rows()`: Randomize the contents of a view
+//!   (requires the `rand` feature).
+//! - `fill_noise()`: Fills a view with fractal noise, for example to generate heightmaps
+//!   (requires the `noise` feature).
 
 use crate::*;
-use std::{cmp::min, collections::VecDeque};
+use std::{
+    cmp::min,
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
 
 /// Copies a rectangle from a [`BidiView`][crate::BidiView] to a [`BidiViewMut`][crate::BidiViewMut].
 /// The type is required to be [`Copy`]; if the type is [`Clone`],  see
 /// the [`clone_over()`] function. If the type is neither [`Copy`] nor [`Clone`], see the
 /// [`blend()`] function.
 ///
+/// When both views lay out their rows contiguously in memory (see
+/// [`BidiView::row_slice`][crate::BidiView::row_slice]), as the data structures of this
+/// crate do, rows are copied in a single operation rather than item by item.
+///
 /// # Examples
 ///
 /// ```
@@ -61,19 +89,32 @@ where
     D: BidiViewMut<Output = S::Output>,
     S::Output: Copy + Sized,
 {
-    if from.x >= source.width()
-        || from.y >= source.height()
-        || to.0 >= dest.width()
-        || to.1 >= dest.height()
-    {
-        return Err(BidiError::OutOfBounds);
+    if !source.is_in_bounds(from.x, from.y) {
+        return Err(BidiError::out_of_bounds(from.x, from.y, source.size()));
     }
+    if !dest.is_in_bounds(to.0, to.1) {
+        return Err(BidiError::out_of_bounds(to.0, to.1, dest.size()));
+    }
+
+    let (dx_start, dx_end) = (to.0, min(to.0 + from.width, to.0 + source.width()));
+    let (sx_start, sx_end) = (from.x, dx_end - to.0 + from.x);
 
     for dy in to.1..min(to.1 + from.height, to.1 + source.height()) {
         let sy = dy - to.1 + from.y;
-        for dx in to.0..min(to.0 + from.width, to.0 + source.width()) {
+
+        // if both rows are contiguous in memory, copy them in a single operation
+        if let Some(src) = source.row_slice(sy).and_then(|r| r.get(sx_start..sx_end)) {
+            if let Some(dst) = dest
+                .row_slice_mut(dy)
+                .and_then(|r| r.get_mut(dx_start..dx_end))
+            {
+                dst.copy_from_slice(src);
+                continue;
+            }
+        }
+
+        for dx in dx_start..dx_end {
             let sx = dx - to.0 + from.x;
-            println!("copying {},{} to {},{}", sx, sy, dx, dy);
             dest[(dx, dy)] = source[(sx, sy)];
         }
     }
@@ -184,12 +225,11 @@ where
     D::Output: Sized,
     F: FnMut(&S::Output, &mut D::Output),
 {
-    if from.x >= source.width()
-        || from.y >= source.height()
-        || to.0 >= dest.width()
-        || to.1 >= dest.height()
-    {
-        return Err(BidiError::OutOfBounds);
+    if !source.is_in_bounds(from.x, from.y) {
+        return Err(BidiError::out_of_bounds(from.x, from.y, source.size()));
+    }
+    if !dest.is_in_bounds(to.0, to.1) {
+        return Err(BidiError::out_of_bounds(to.0, to.1, dest.size()));
     }
 
     for dy in to.1..min(to.1 + from.height, to.1 + source.height()) {
@@ -274,8 +314,8 @@ where
         Paint,
     }
 
-    if pos.0 >= dest.width() || pos.1 >= dest.height() {
-        return Err(BidiError::OutOfBounds);
+    if !dest.is_in_bounds(pos.0, pos.1) {
+        return Err(BidiError::out_of_bounds(pos.0, pos.1, dest.size()));
     }
 
     let (width, height) = (dest.width(), dest.height());
@@ -285,7 +325,7 @@ where
 
     let mut visited = BidiArray::with_elem(FloodFillState::Unvisited, width, height);
 
-    visited[(pos)] = FloodFillState::Paint;
+    visited[pos] = FloodFillState::Paint;
     queue.push_back(pos);
 
     while let Some(point) = queue.pop_front() {
@@ -314,3 +354,1324 @@ where
 
     Ok(visited.len())
 }
+
+/// Performs a flood-fill like operation (see [`flood_fill()`]), blending the
+/// filled region with the items of a source view using a custom blending
+/// function (see [`blend()`]).
+///
+/// The source view is aligned to the destination, so that each item of the
+/// destination is blended with the source item at the same coordinates; items
+/// of the filled region that are outside the bounds of the source are left
+/// untouched.
+///
+/// The `comparer` function has the same semantic as in [`flood_fill()`], while
+/// the `blender` function is a [`FnMut(&S::Output, &mut D::Output)`][FnMut] that
+/// takes the source item and the destination item to be altered.
+///
+/// Returns the number of elements that have been passed to the blender function.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiNeighbours};
+///
+/// // light levels of a map
+/// let mut map = bidivec!{
+///     [8, 8, 0, 8],
+///     [8, 8, 0, 8],
+///     [0, 0, 0, 8],
+/// };
+///
+/// // darkness to apply
+/// let shading = bidivec!{
+///     [2, 1, 1, 1],
+///     [1, 3, 1, 1],
+///     [1, 1, 1, 1],
+/// };
+///
+/// // darken the whole room around (0, 0)
+/// let count = editing::flood_blend(
+///     &shading,
+///     &mut map,
+///     (0, 0),
+///     BidiNeighbours::Adjacent,
+///     |_, val1, val2| val1 == val2,
+///     |dark, light| *light -= dark,
+/// )?;
+///
+/// assert_eq!(count, 4);
+/// assert_eq!(map, bidivec!{
+///     [6, 7, 0, 8],
+///     [7, 5, 0, 8],
+///     [0, 0, 0, 8],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn flood_blend<S, D, FC, FB>(
+    source: &S,
+    dest: &mut D,
+    pos: (usize, usize),
+    neighbouring: BidiNeighbours,
+    comparer: FC,
+    mut blender: FB,
+) -> Result<usize, BidiError>
+where
+    S: BidiView,
+    D: BidiViewMut,
+    D::Output: Sized,
+    FC: Fn(&D::Output, &D::Output, &D::Output) -> bool,
+    FB: FnMut(&S::Output, &mut D::Output),
+{
+    let mut count = 0;
+
+    flood_fill(dest, pos, neighbouring, comparer, |item, (x, y)| {
+        if let Some(src) = source.get(x, y) {
+            blender(src, item);
+            count += 1;
+        }
+    })?;
+
+    Ok(count)
+}
+
+/// How the center and the edges of the source are adapted to the destination
+/// size by [`copy_nine_slice()`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum NineSliceMode {
+    /// The center and the edges are stretched (using nearest-neighbour
+    /// sampling) to fill the destination.
+    Stretch,
+    /// The center and the edges are repeated to fill the destination.
+    Tile,
+}
+
+impl NineSliceMode {
+    // Maps a coordinate in a destination of size `dest_len` to the source
+    // coordinate, given the source size and the range of the center.
+    fn map_coord(
+        self,
+        d: usize,
+        dest_len: usize,
+        src_len: usize,
+        center_start: usize,
+        center_len: usize,
+    ) -> usize {
+        let left = min(center_start, dest_len);
+        let right = min(src_len - center_start - center_len, dest_len - left);
+
+        if d < left {
+            d
+        } else if d >= dest_len - right {
+            src_len - (dest_len - d)
+        } else {
+            let offset = d - left;
+            match self {
+                NineSliceMode::Tile => center_start + offset % center_len,
+                NineSliceMode::Stretch => {
+                    center_start + offset * center_len / (dest_len - left - right)
+                }
+            }
+        }
+    }
+}
+
+/// Copies a source view over a rectangle of a destination view using the
+/// "nine-slice" technique: the source is divided in nine parts by the
+/// `center` rectangle; the four corners are copied as they are, the edges
+/// are stretched or tiled along one axis and the center is stretched or
+/// tiled along both axes (according to `mode`), so that the whole `to`
+/// rectangle is covered.
+///
+/// This is typically used to draw panels and frames of arbitrary size on
+/// tile or character based grids.
+///
+/// If the `to` rectangle is smaller than the corners, the corners are
+/// clipped. If the `center` rectangle is empty (in a dimension where
+/// the destination needs it to be filled), [`BidiError::IncompatibleSize`]
+/// is returned.
+///
+/// The type is required to be [`Copy`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, editing::NineSliceMode, BidiRect};
+///
+/// let frame = bidivec!{
+///     ['+', '-', '+'],
+///     ['|', ' ', '|'],
+///     ['+', '-', '+'],
+/// };
+///
+/// let mut screen = bidivec!['.'; 6, 5];
+///
+/// editing::copy_nine_slice(
+///     &frame,
+///     &mut screen,
+///     &BidiRect::new(1, 1, 1, 1),
+///     &BidiRect::new(1, 1, 5, 4),
+///     NineSliceMode::Stretch,
+/// )?;
+///
+/// assert_eq!(screen, bidivec!{
+///     ['.', '.', '.', '.', '.', '.'],
+///     ['.', '+', '-', '-', '-', '+'],
+///     ['.', '|', ' ', ' ', ' ', '|'],
+///     ['.', '|', ' ', ' ', ' ', '|'],
+///     ['.', '+', '-', '-', '-', '+'],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn copy_nine_slice<S, D>(
+    source: &S,
+    dest: &mut D,
+    center: &BidiRect,
+    to: &BidiRect,
+    mode: NineSliceMode,
+) -> Result<(), BidiError>
+where
+    S: BidiView,
+    D: BidiViewMut<Output = S::Output>,
+    S::Output: Copy + Sized,
+{
+    let (src_width, src_height) = source.size();
+
+    if center.max_x() > src_width || center.max_y() > src_height {
+        return Err(BidiError::rect_out_of_bounds(
+            center,
+            (src_width, src_height),
+        ));
+    }
+    if to.max_x() > dest.width() || to.max_y() > dest.height() {
+        return Err(BidiError::rect_out_of_bounds(to, dest.size()));
+    }
+
+    let needs_center = |dest_len: usize, src_len: usize, center_len: usize| {
+        center_len == 0 && dest_len > src_len - center_len
+    };
+
+    if needs_center(to.width, src_width, center.width) {
+        return Err(BidiError::incompatible_size(src_width, to.width));
+    }
+    if needs_center(to.height, src_height, center.height) {
+        return Err(BidiError::incompatible_size(src_height, to.height));
+    }
+
+    for dy in 0..to.height {
+        let sy = mode.map_coord(dy, to.height, src_height, center.y, center.height);
+        for dx in 0..to.width {
+            let sx = mode.map_coord(dx, to.width, src_width, center.x, center.width);
+            dest[(to.x + dx, to.y + dy)] = source[(sx, sy)];
+        }
+    }
+
+    Ok(())
+}
+
+/// Fills a rectangle of a destination view by repeating a (usually small)
+/// pattern view over it.
+///
+/// The pattern is anchored to the origin of the destination view, so that
+/// adjacent fills using the same pattern line up seamlessly; the `offset`
+/// argument shifts the phase of the pattern (i.e. the item of the pattern
+/// written at destination `(x, y)` is the one at
+/// `((x + offset.0) % pattern.width(), (y + offset.1) % pattern.height())`).
+///
+/// The rectangle is clipped to the bounds of the destination. If the pattern
+/// is empty, [`BidiError::InvalidArgument`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiRect};
+///
+/// let pattern = bidivec!{
+///     ['/', ' '],
+///     [' ', '/'],
+/// };
+///
+/// let mut v = bidivec!['.'; 5, 3];
+///
+/// editing::tile_fill(&mut v, &BidiRect::new(1, 0, 10, 2), &pattern, (0, 0))?;
+///
+/// assert_eq!(v, bidivec!{
+///     ['.', ' ', '/', ' ', '/'],
+///     ['.', '/', ' ', '/', ' '],
+///     ['.', '.', '.', '.', '.'],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn tile_fill<D, S>(
+    dest: &mut D,
+    rect: &BidiRect,
+    pattern: &S,
+    offset: (usize, usize),
+) -> Result<(), BidiError>
+where
+    D: BidiViewMut,
+    S: BidiView<Output = D::Output>,
+    D::Output: Clone + Sized,
+{
+    let (pattern_width, pattern_height) = pattern.size();
+
+    if pattern_width == 0 || pattern_height == 0 {
+        return Err(BidiError::invalid_argument("pattern"));
+    }
+
+    let rect = rect.intersect(&dest.bounding_rect());
+
+    for y in rect.y_range() {
+        let py = (y + offset.1) % pattern_height;
+        for x in rect.x_range() {
+            let px = (x + offset.0) % pattern_width;
+            dest[(x, y)] = pattern[(px, py)].clone();
+        }
+    }
+
+    Ok(())
+}
+
+/// Fills a rectangle of a view with a checkerboard of two values, made of squares
+/// of `cell_size` items per side, e.g. as the background of debug visualizations.
+///
+/// As with [`tile_fill()`], the checkerboard is anchored to the origin of the view,
+/// with the square at the top-left corner filled with `a`, so that adjacent fills line
+/// up seamlessly. The rectangle is clipped to the bounds of the view. If `cell_size`
+/// is zero, [`BidiError::InvalidArgument`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiView};
+///
+/// let mut v = bidivec!['.'; 5, 3];
+/// let rect = v.bounding_rect();
+///
+/// editing::fill_checkerboard(&mut v, &rect, '#', ' ', 2)?;
+///
+/// assert_eq!(v, bidivec!{
+///     ['#', '#', ' ', ' ', '#'],
+///     ['#', '#', ' ', ' ', '#'],
+///     [' ', ' ', '#', '#', ' '],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn fill_checkerboard<V>(
+    view: &mut V,
+    rect: &BidiRect,
+    a: V::Output,
+    b: V::Output,
+    cell_size: usize,
+) -> Result<(), BidiError>
+where
+    V: BidiViewMut,
+    V::Output: Clone + Sized,
+{
+    if cell_size == 0 {
+        return Err(BidiError::invalid_argument("cell_size"));
+    }
+
+    let rect = rect.intersect(&view.bounding_rect());
+
+    for y in rect.y_range() {
+        let odd_row = (y / cell_size) % 2 == 1;
+        for x in rect.x_range() {
+            let odd_col = (x / cell_size) % 2 == 1;
+            view[(x, y)] = if odd_row == odd_col {
+                a.clone()
+            } else {
+                b.clone()
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// The directions of the stripes drawn by [`fill_stripes()`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum StripeDirection {
+    /// Horizontal stripes, made of whole rows.
+    Horizontal,
+    /// Vertical stripes, made of whole columns.
+    Vertical,
+    /// Diagonal stripes, going from the bottom-left to the top-right.
+    Diagonal,
+}
+
+/// Fills a rectangle of a view with stripes alternating two values, each
+/// `stripe_width` items wide, in the specified [`StripeDirection`].
+///
+/// As with [`tile_fill()`], the stripes are anchored to the origin of the view,
+/// with the first stripe filled with `a`, so that adjacent fills line up seamlessly.
+/// The rectangle is clipped to the bounds of the view. If `stripe_width` is zero,
+/// [`BidiError::InvalidArgument`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiView};
+/// use bidivec::editing::StripeDirection;
+///
+/// let mut v = bidivec!['.'; 5, 3];
+/// let rect = v.bounding_rect();
+///
+/// editing::fill_stripes(&mut v, &rect, '/', ' ', 1, StripeDirection::Diagonal)?;
+///
+/// assert_eq!(v, bidivec!{
+///     ['/', ' ', '/', ' ', '/'],
+///     [' ', '/', ' ', '/', ' '],
+///     ['/', ' ', '/', ' ', '/'],
+/// });
+///
+/// editing::fill_stripes(&mut v, &rect, '|', ' ', 2, StripeDirection::Vertical)?;
+///
+/// assert_eq!(v, bidivec!{
+///     ['|', '|', ' ', ' ', '|'],
+///     ['|', '|', ' ', ' ', '|'],
+///     ['|', '|', ' ', ' ', '|'],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn fill_stripes<V>(
+    view: &mut V,
+    rect: &BidiRect,
+    a: V::Output,
+    b: V::Output,
+    stripe_width: usize,
+    direction: StripeDirection,
+) -> Result<(), BidiError>
+where
+    V: BidiViewMut,
+    V::Output: Clone + Sized,
+{
+    if stripe_width == 0 {
+        return Err(BidiError::invalid_argument("stripe_width"));
+    }
+
+    let rect = rect.intersect(&view.bounding_rect());
+
+    for y in rect.y_range() {
+        for x in rect.x_range() {
+            let offset = match direction {
+                StripeDirection::Horizontal => y,
+                StripeDirection::Vertical => x,
+                StripeDirection::Diagonal => x + y,
+            };
+
+            view[(x, y)] = if (offset / stripe_width) % 2 == 0 {
+                a.clone()
+            } else {
+                b.clone()
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// The symmetries supported by [`stamp_symmetric()`], that is the mirrored copies
+/// of a source stamped over a view, about the center of the view.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Symmetry {
+    /// The source is mirrored over the vertical axis of the view (from left to right).
+    Horizontal,
+    /// The source is mirrored over the horizontal axis of the view (from top to bottom).
+    Vertical,
+    /// The source is mirrored over both axes, producing 4 copies.
+    FourFold,
+    /// The source is mirrored over both axes and both diagonals, producing 8 copies.
+    /// Requires a square view.
+    EightFold,
+}
+
+/// Clones a source view over a view at the specified coordinates, together with its
+/// mirrored copies according to the specified [`Symmetry`], as when editing maps that
+/// must be fair to players starting from opposite sides.
+///
+/// Where copies overlap (e.g. on the axes of the view), the source itself has the
+/// precedence. Returns [`BidiError::OutOfBounds`] if the source doesn't fit in the view
+/// at the specified coordinates, and [`BidiError::IncompatibleSize`] if the view isn't
+/// square with [`Symmetry::EightFold`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+/// use bidivec::editing::Symmetry;
+///
+/// let mut map = bidivec!['.'; 5, 4];
+///
+/// editing::stamp_symmetric(&mut map, &bidivec!['A', 'B'; 2], 0, 1, Symmetry::FourFold)?;
+///
+/// assert_eq!(map, bidivec!{
+///     ['.', '.', '.', '.', '.'],
+///     ['A', 'B', '.', 'B', 'A'],
+///     ['A', 'B', '.', 'B', 'A'],
+///     ['.', '.', '.', '.', '.'],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn stamp_symmetric<D, S>(
+    dest: &mut D,
+    source: &S,
+    x: usize,
+    y: usize,
+    symmetry: Symmetry,
+) -> Result<(), BidiError>
+where
+    D: BidiViewMut,
+    S: BidiView<Output = D::Output>,
+    D::Output: Clone + Sized,
+{
+    let (width, height) = dest.size();
+    let rect = BidiRect::new(x, y, source.width(), source.height());
+
+    if x.checked_add(rect.width).map_or(true, |end| end > width)
+        || y.checked_add(rect.height).map_or(true, |end| end > height)
+    {
+        return Err(BidiError::rect_out_of_bounds(&rect, dest.size()));
+    }
+    if symmetry == Symmetry::EightFold && width != height {
+        return Err(BidiError::incompatible_size(width, height));
+    }
+
+    let mut images = Vec::with_capacity(8);
+
+    for (sx, sy, item) in source.iter().with_coords() {
+        let (px, py) = (x + sx, y + sy);
+        let (mx, my) = (width - px - 1, height - py - 1);
+
+        images.clear();
+        match symmetry {
+            Symmetry::Horizontal => images.push((mx, py)),
+            Symmetry::Vertical => images.push((px, my)),
+            Symmetry::FourFold => images.extend_from_slice(&[(mx, py), (px, my), (mx, my)]),
+            Symmetry::EightFold => images.extend_from_slice(&[
+                (mx, py),
+                (px, my),
+                (mx, my),
+                (py, px),
+                (my, px),
+                (py, mx),
+                (my, mx),
+            ]),
+        }
+
+        for &image in images.iter() {
+            dest[image] = item.clone();
+        }
+    }
+
+    // the source is stamped last, to have the precedence over its copies
+    for (sx, sy, item) in source.iter().with_coords() {
+        dest[(x + sx, y + sy)] = item.clone();
+    }
+
+    Ok(())
+}
+
+/// Finds the outline of the regions of a view, that is the coordinates of
+/// all the items for which the `predicate` returns `true` and that have at
+/// least one neighbour (according to the `neighbouring` argument) for which
+/// the `predicate` returns `false`.
+///
+/// Items on the edges of the view are considered part of the outline too
+/// (as if the view was surrounded by items not satisfying the predicate).
+///
+/// The coordinates are returned by rows, from the top-left corner.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiNeighbours};
+///
+/// let v = bidivec!{
+///     [0, 0, 0, 0, 0],
+///     [0, 1, 1, 1, 0],
+///     [0, 1, 1, 1, 0],
+///     [0, 1, 1, 1, 0],
+///     [0, 0, 0, 0, 0],
+/// };
+///
+/// let outline = editing::outline(&v, BidiNeighbours::Adjacent, |n| *n == 1);
+///
+/// assert_eq!(outline.len(), 8);
+/// assert!(!outline.contains(&(2, 2)));
+/// ```
+pub fn outline<V, F>(view: &V, neighbouring: BidiNeighbours, predicate: F) -> Vec<(usize, usize)>
+where
+    V: BidiView,
+    V::Output: Sized,
+    F: Fn(&V::Output) -> bool,
+{
+    let (width, height) = view.size();
+    let mut result = Vec::new();
+    let mut neighbours = neighbouring.prealloc_vec();
+    let neighbours_count = neighbouring.max_count();
+
+    for y in 0..height {
+        for x in 0..width {
+            if !predicate(&view[(x, y)]) {
+                continue;
+            }
+
+            neighbours.clear();
+            neighbouring.generate_points_on(&mut neighbours, (x, y), width, height);
+
+            if neighbours.len() < neighbours_count
+                || neighbours.iter().any(|p| !predicate(&view[*p]))
+            {
+                result.push((x, y));
+            }
+        }
+    }
+
+    result
+}
+
+/// Traces the contours of the regions of a view for which the `predicate`
+/// returns `true`, using the marching squares algorithm.
+///
+/// Contours are returned as closed polylines in cell-edge space: a point
+/// `(x, y)` is the top-left corner of the item at `(x, y)`, so coordinates
+/// range from `(0, 0)` to `(width, height)` included. Only the vertices
+/// where the contour changes direction are returned, and the last point
+/// of each polyline implicitly connects to the first one. Each polyline
+/// starts from its top-left vertex.
+///
+/// Outer boundaries are returned in clockwise order and the boundaries of
+/// holes in counter-clockwise order (with the y axis pointing down), so that
+/// the region is always on the right side of the contour. Items touching
+/// only by a corner are considered disconnected, consistently with
+/// [`BidiNeighbours::Adjacent`].
+///
+/// This is useful, for example, to convert tile maps to collision polygons.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+///
+/// let v = bidivec!{
+///     [0, 0, 0, 0],
+///     [0, 1, 1, 0],
+///     [0, 1, 0, 0],
+/// };
+///
+/// let contours = editing::marching_squares(&v, |n| *n == 1);
+///
+/// assert_eq!(contours, vec![
+///     vec![(1, 1), (3, 1), (3, 2), (2, 2), (2, 3), (1, 3)],
+/// ]);
+///
+/// // a threshold can be expressed as a predicate
+/// let heights = bidivec!{
+///     [10, 200],
+///     [20, 150],
+/// };
+///
+/// let contours = editing::marching_squares(&heights, |h| *h > 100);
+///
+/// assert_eq!(contours, vec![
+///     vec![(1, 0), (2, 0), (2, 2), (1, 2)],
+/// ]);
+/// ```
+pub fn marching_squares<V, F>(view: &V, predicate: F) -> Vec<Vec<(usize, usize)>>
+where
+    V: BidiView,
+    V::Output: Sized,
+    F: Fn(&V::Output) -> bool,
+{
+    let (width, height) = view.size();
+    let inside = |x: usize, y: usize| predicate(&view[(x, y)]);
+
+    // Directed boundary edges, oriented so that the region is on their right.
+    let mut edges = Vec::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            if !inside(x, y) {
+                continue;
+            }
+
+            if y == 0 || !inside(x, y - 1) {
+                edges.push(((x, y), (x + 1, y)));
+            }
+            if x + 1 == width || !inside(x + 1, y) {
+                edges.push(((x + 1, y), (x + 1, y + 1)));
+            }
+            if y + 1 == height || !inside(x, y + 1) {
+                edges.push(((x + 1, y + 1), (x, y + 1)));
+            }
+            if x == 0 || !inside(x - 1, y) {
+                edges.push(((x, y + 1), (x, y)));
+            }
+        }
+    }
+
+    let mut outgoing: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (idx, (start, _)) in edges.iter().enumerate() {
+        outgoing.entry(*start).or_default().push(idx);
+    }
+
+    let direction = |(from, to): ((usize, usize), (usize, usize))| {
+        (
+            to.0 as isize - from.0 as isize,
+            to.1 as isize - from.1 as isize,
+        )
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut contours = Vec::new();
+
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+
+        let mut points = Vec::new();
+        let mut current = first;
+
+        loop {
+            used[current] = true;
+            points.push(edges[current].0);
+
+            let (dx, dy) = direction(edges[current]);
+            let candidates = &outgoing[&edges[current].1];
+
+            // On saddle points, turn right to keep diagonal items separated.
+            let next = candidates
+                .iter()
+                .copied()
+                .filter(|&e| !used[e])
+                .find(|&e| candidates.len() == 1 || direction(edges[e]) == (-dy, dx))
+                .or_else(|| candidates.iter().copied().find(|&e| !used[e]));
+
+            match next {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+
+        // Keep only the vertices where the contour changes direction.
+        let len = points.len();
+        let mut contour: Vec<(usize, usize)> = (0..len)
+            .filter(|&i| {
+                let prev = points[(i + len - 1) % len];
+                let next = points[(i + 1) % len];
+                direction((prev, points[i])) != direction((points[i], next))
+            })
+            .map(|i| points[i])
+            .collect();
+
+        // Start from the top-left vertex, for a predictable output.
+        if let Some(start) = (0..contour.len()).min_by_key(|&i| (contour[i].1, contour[i].0)) {
+            contour.rotate_left(start);
+        }
+
+        contours.push(contour);
+    }
+
+    contours
+}
+
+/// Replaces all the items in a rectangle of a view for which the `predicate`
+/// returns `true` with the value returned by the `replacement` closure, which
+/// receives the item being replaced. Returns the number of replaced items.
+///
+/// The rectangle is clipped to the bounds of the view; pass the view
+/// [`bounding_rect()`][BidiView::bounding_rect] to replace items in the
+/// whole view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiRect, BidiView};
+///
+/// let mut v = bidivec!{
+///     [1, 2, 1],
+///     [2, 1, 2],
+///     [1, 2, 1],
+/// };
+///
+/// let count = editing::replace(&mut v, &BidiRect::new(0, 0, 3, 2), |n| *n == 1, |_| 7);
+///
+/// assert_eq!(count, 3);
+/// assert_eq!(v, bidivec!{
+///     [7, 2, 7],
+///     [2, 7, 2],
+///     [1, 2, 1],
+/// });
+///
+/// let rect = v.bounding_rect();
+/// let count = editing::replace(&mut v, &rect, |n| *n == 2, |n| n * 10);
+///
+/// assert_eq!(count, 4);
+/// assert_eq!(v, bidivec!{
+///     [7, 20, 7],
+///     [20, 7, 20],
+///     [1, 20, 1],
+/// });
+/// ```
+pub fn replace<V, FP, FR>(
+    view: &mut V,
+    rect: &BidiRect,
+    predicate: FP,
+    mut replacement: FR,
+) -> usize
+where
+    V: BidiViewMut,
+    V::Output: Sized,
+    FP: Fn(&V::Output) -> bool,
+    FR: FnMut(&V::Output) -> V::Output,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+    let mut count = 0;
+
+    for y in rect.y_range() {
+        if let Some(row) = view.row_slice_mut(y) {
+            for item in row[rect.x_range()].iter_mut() {
+                if predicate(item) {
+                    *item = replacement(item);
+                    count += 1;
+                }
+            }
+            continue;
+        }
+
+        for x in rect.x_range() {
+            let item = &mut view[(x, y)];
+            if predicate(item) {
+                *item = replacement(item);
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Replaces all the items in a rectangle of a view equal to `old` with `new`,
+/// returning the number of replaced items. The rectangle is clipped to the bounds
+/// of the view.
+///
+/// This is the common "find and replace" of a tile; unlike [`replace()`] it works
+/// on whole rows at once where possible, comparing items by value.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiView};
+///
+/// let mut v = bidivec!{
+///     ['#', '.', '#'],
+///     ['.', '#', '.'],
+/// };
+///
+/// let rect = v.bounding_rect();
+/// let count = editing::replace_value(&mut v, &rect, '#', '~');
+///
+/// assert_eq!(count, 3);
+/// assert_eq!(v, bidivec!{
+///     ['~', '.', '~'],
+///     ['.', '~', '.'],
+/// });
+/// ```
+pub fn replace_value<V>(view: &mut V, rect: &BidiRect, old: V::Output, new: V::Output) -> usize
+where
+    V: BidiViewMut,
+    V::Output: Copy + PartialEq + Sized,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+    let mut count = 0;
+
+    for y in rect.y_range() {
+        if let Some(row) = view.row_slice_mut(y) {
+            for item in row[rect.x_range()].iter_mut().filter(|item| **item == old) {
+                *item = new;
+                count += 1;
+            }
+            continue;
+        }
+
+        for x in rect.x_range() {
+            let item = &mut view[(x, y)];
+            if *item == old {
+                *item = new;
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Counts the occurrences of each distinct value in a view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+///
+/// let v = bidivec!{
+///     ['a', 'b', 'a'],
+///     ['c', 'a', 'b'],
+/// };
+///
+/// let histogram = editing::histogram(&v);
+///
+/// assert_eq!(histogram.len(), 3);
+/// assert_eq!(histogram[&'a'], 3);
+/// assert_eq!(histogram[&'b'], 2);
+/// assert_eq!(histogram[&'c'], 1);
+/// ```
+pub fn histogram<V>(view: &V) -> HashMap<V::Output, usize>
+where
+    V: BidiView,
+    V::Output: Sized + Clone + Eq + Hash,
+{
+    histogram_in_rect(view, &view.bounding_rect())
+}
+
+/// Counts the occurrences of each distinct value in a rectangle of a view.
+/// The rectangle is clipped to the bounds of the view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiRect};
+///
+/// let v = bidivec!{
+///     ['a', 'b', 'a'],
+///     ['c', 'a', 'b'],
+/// };
+///
+/// let histogram = editing::histogram_in_rect(&v, &BidiRect::new(1, 0, 2, 2));
+///
+/// assert_eq!(histogram.len(), 2);
+/// assert_eq!(histogram[&'a'], 2);
+/// assert_eq!(histogram[&'b'], 2);
+/// ```
+pub fn histogram_in_rect<V>(view: &V, rect: &BidiRect) -> HashMap<V::Output, usize>
+where
+    V: BidiView,
+    V::Output: Sized + Clone + Eq + Hash,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+    let mut histogram = HashMap::new();
+
+    for y in rect.y_range() {
+        for x in rect.x_range() {
+            let item = &view[(x, y)];
+            if let Some(count) = histogram.get_mut(item) {
+                *count += 1;
+            } else {
+                histogram.insert(item.clone(), 1);
+            }
+        }
+    }
+
+    histogram
+}
+
+/// Fills a rectangle of a view with a value. The rectangle is clipped to the
+/// bounds of the view.
+///
+/// Rows which are contiguous in memory (see [`BidiViewMut::row_slice_mut`]) are
+/// filled as slices, which the compiler can vectorize, making this suitable to clear
+/// whole frames.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiRect, BidiView};
+///
+/// let mut v = bidivec![0u32; 4, 3];
+///
+/// editing::fill(&mut v, &BidiRect::new(1, 1, 10, 10), 7);
+///
+/// assert_eq!(v, bidivec!{
+///     [0, 0, 0, 0],
+///     [0, 7, 7, 7],
+///     [0, 7, 7, 7],
+/// });
+///
+/// let rect = v.bounding_rect();
+/// editing::fill(&mut v, &rect, 0);
+/// assert!(v.iter().all(|n| *n == 0));
+/// ```
+pub fn fill<V>(view: &mut V, rect: &BidiRect, value: V::Output)
+where
+    V: BidiViewMut,
+    V::Output: Copy + Sized,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+
+    for y in rect.y_range() {
+        if let Some(row) = view.row_slice_mut(y) {
+            for item in row[rect.x_range()].iter_mut() {
+                *item = value;
+            }
+            continue;
+        }
+
+        for x in rect.x_range() {
+            view[(x, y)] = value;
+        }
+    }
+}
+
+/// Fills the items within `thickness` items from the edges of a view with a value,
+/// drawing a frame around the whole view (e.g. to seal the edges of a map with walls).
+/// If the frame is thicker than half the view, the whole view is filled.
+///
+/// See [`Iter::on_view_border`][crate::bidiiter::Iter::on_view_border] to iterate
+/// over the same items.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+///
+/// let mut v = bidivec!['.'; 5, 4];
+///
+/// editing::draw_border(&mut v, 1, '#');
+///
+/// assert_eq!(v, bidivec!{
+///     ['#', '#', '#', '#', '#'],
+///     ['#', '.', '.', '.', '#'],
+///     ['#', '.', '.', '.', '#'],
+///     ['#', '#', '#', '#', '#'],
+/// });
+/// ```
+pub fn draw_border<V>(view: &mut V, thickness: usize, value: V::Output)
+where
+    V: BidiViewMut,
+    V::Output: Copy + Sized,
+{
+    let (width, height) = view.size();
+    let thickness_x = min(thickness, width);
+    let thickness_y = min(thickness, height);
+
+    fill(view, &BidiRect::new(0, 0, width, thickness_y), value);
+    fill(
+        view,
+        &BidiRect::new(0, height - thickness_y, width, thickness_y),
+        value,
+    );
+    fill(view, &BidiRect::new(0, 0, thickness_x, height), value);
+    fill(
+        view,
+        &BidiRect::new(width - thickness_x, 0, thickness_x, height),
+        value,
+    );
+}
+
+/// Counts the items of a view equal to a value.
+///
+/// Rows which are contiguous in memory (see [`BidiView::row_slice`]) are scanned
+/// as slices, which the compiler can vectorize.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+///
+/// let v = bidivec!{
+///     [1, 0, 1],
+///     [0, 1, 1],
+/// };
+///
+/// assert_eq!(editing::count(&v, &1), 4);
+/// assert_eq!(editing::count(&v, &2), 0);
+/// ```
+pub fn count<V>(view: &V, value: &V::Output) -> usize
+where
+    V: BidiView,
+    V::Output: PartialEq + Sized,
+{
+    count_in_rect(view, &view.bounding_rect(), value)
+}
+
+/// Counts the items in a rectangle of a view equal to a value. The rectangle is
+/// clipped to the bounds of the view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiRect};
+///
+/// let v = bidivec!{
+///     [1, 0, 1],
+///     [0, 1, 1],
+/// };
+///
+/// assert_eq!(editing::count_in_rect(&v, &BidiRect::new(1, 0, 2, 2), &1), 3);
+/// ```
+pub fn count_in_rect<V>(view: &V, rect: &BidiRect, value: &V::Output) -> usize
+where
+    V: BidiView,
+    V::Output: PartialEq + Sized,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+    let mut count = 0;
+
+    for y in rect.y_range() {
+        if let Some(row) = view.row_slice(y) {
+            count += row[rect.x_range()]
+                .iter()
+                .filter(|item| *item == value)
+                .count();
+            continue;
+        }
+
+        for x in rect.x_range() {
+            if view[(x, y)] == *value {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Fills a rectangle of a view with random values, sampled from the
+/// specified [`Distribution`][rand::distributions::Distribution] using
+/// the specified random number generator. The rectangle is clipped to the
+/// bounds of the view.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiRect, BidiView};
+/// use rand::distributions::{Distribution, Slice, Uniform};
+///
+/// let mut rng = rand::thread_rng();
+/// let mut v = bidivec![0; 4, 4];
+///
+/// editing::fill_random(&mut v, &BidiRect::new(0, 0, 4, 2), &mut rng, Uniform::new(1, 10));
+///
+/// assert!(v.iter().on_row(0).all(|n| (1..10).contains(n)));
+/// assert!(v.iter().on_row(3).all(|n| *n == 0));
+///
+/// let tiles = ['.', '#', '~'];
+/// let mut map = bidivec!['?'; 5, 5];
+/// let rect = map.bounding_rect();
+///
+/// editing::fill_random(&mut map, &rect, &mut rng, Slice::new(&tiles).unwrap().map(|c| *c));
+///
+/// assert!(map.iter().all(|c| tiles.contains(c)));
+/// ```
+#[cfg(feature = "rand")]
+pub fn fill_random<V, R, D>(view: &mut V, rect: &BidiRect, rng: &mut R, distribution: D)
+where
+    V: BidiViewMut,
+    V::Output: Sized,
+    R: rand::Rng + ?Sized,
+    D: rand::distributions::Distribution<V::Output>,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+
+    for y in rect.y_range() {
+        for x in rect.x_range() {
+            view[(x, y)] = distribution.sample(rng);
+        }
+    }
+}
+
+/// Shuffles all the items of a view, using the Fisher-Yates algorithm and
+/// the specified random number generator.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+///
+/// let mut v = bidivec!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// };
+///
+/// editing::shuffle(&mut v, &mut rand::thread_rng());
+///
+/// let mut items = v.into_vec();
+/// items.sort();
+/// assert_eq!(items, vec![1, 2, 3, 4, 5, 6]);
+/// ```
+#[cfg(feature = "rand")]
+pub fn shuffle<V, R>(view: &mut V, rng: &mut R)
+where
+    V: BidiViewMutIterable,
+    V::Output: Sized,
+    R: rand::Rng + ?Sized,
+{
+    let width = view.width();
+
+    for i in (1..view.width() * view.height()).rev() {
+        let j = rng.gen_range(0..=i);
+        swap_items(view, (i % width, i / width), (j % width, j / width));
+    }
+}
+
+/// Shuffles the items of each row of a view independently, using the
+/// Fisher-Yates algorithm and the specified random number generator.
+/// Items never move to a different row.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing};
+///
+/// let mut v = bidivec!{
+///     [1, 1, 1],
+///     [2, 2, 2],
+/// };
+///
+/// editing::shuffle_rows(&mut v, &mut rand::thread_rng());
+///
+/// assert_eq!(v, bidivec!{
+///     [1, 1, 1],
+///     [2, 2, 2],
+/// });
+/// ```
+#[cfg(feature = "rand")]
+pub fn shuffle_rows<V, R>(view: &mut V, rng: &mut R)
+where
+    V: BidiViewMutIterable,
+    V::Output: Sized,
+    R: rand::Rng + ?Sized,
+{
+    for y in 0..view.height() {
+        for i in (1..view.width()).rev() {
+            let j = rng.gen_range(0..=i);
+            swap_items(view, (i, y), (j, y));
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+fn swap_items<V>(view: &mut V, a: (usize, usize), b: (usize, usize))
+where
+    V: BidiViewMutIterable,
+    V::Output: Sized,
+{
+    if a != b {
+        let pa: *mut V::Output = &mut view[a];
+        let pb: *mut V::Output = &mut view[b];
+        // Safety: BidiViewMutIterable guarantees that different coordinates
+        // refer to different items, so the two pointers never alias.
+        unsafe { std::ptr::swap(pa, pb) };
+    }
+}
+
+/// The parameters used by [`fill_noise()`] to evaluate a noise function
+/// over a view, summing multiple octaves of the noise (fractal noise).
+///
+/// Requires the `noise` feature.
+#[cfg(feature = "noise")]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NoiseParams {
+    /// The factor the item coordinates are multiplied by, before being
+    /// passed to the noise function. Smaller values produce smoother results.
+    pub scale: f64,
+    /// The offset added to the scaled coordinates, to sample a different
+    /// region of the noise function.
+    pub offset: (f64, f64),
+    /// The number of octaves to sum. Values lower than 1 are treated as 1.
+    pub octaves: u32,
+    /// The factor the amplitude is multiplied by at each octave.
+    pub persistence: f64,
+    /// The factor the frequency is multiplied by at each octave.
+    pub lacunarity: f64,
+}
+
+#[cfg(feature = "noise")]
+impl Default for NoiseParams {
+    /// Returns parameters for a single octave of noise, sampled with
+    /// a scale of `0.1` and no offset.
+    fn default() -> Self {
+        Self {
+            scale: 0.1,
+            offset: (0.0, 0.0),
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+        }
+    }
+}
+
+#[cfg(feature = "noise")]
+impl NoiseParams {
+    /// Evaluates the noise function at the specified item coordinates,
+    /// summing all the octaves. The result is normalized by the sum of the
+    /// amplitudes, so that it's in the same range as the noise function.
+    pub fn sample<N>(&self, noise: &N, x: usize, y: usize) -> f64
+    where
+        N: Fn(f64, f64) -> f64,
+    {
+        let x = x as f64 * self.scale + self.offset.0;
+        let y = y as f64 * self.scale + self.offset.1;
+        let (mut value, mut total) = (0.0, 0.0);
+        let (mut amplitude, mut frequency) = (1.0, 1.0);
+
+        for _ in 0..self.octaves.max(1) {
+            value += noise(x * frequency, y * frequency) * amplitude;
+            total += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+
+        if total != 0.0 {
+            value / total
+        } else {
+            value
+        }
+    }
+}
+
+/// Fills a rectangle of a view with the values of a noise function,
+/// evaluated according to the specified [`NoiseParams`]. The noise function
+/// is any `Fn(f64, f64) -> f64` closure, so that noise generators from
+/// other crates (e.g. `noise`) can be easily adapted. The rectangle is
+/// clipped to the bounds of the view.
+///
+/// Requires the `noise` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, editing::NoiseParams, BidiRect};
+///
+/// let mut heightmap = bidivec![0.0; 8, 8];
+/// let params = NoiseParams {
+///     octaves: 3,
+///     ..NoiseParams::default()
+/// };
+///
+/// editing::fill_noise(&mut heightmap, &BidiRect::new(0, 0, 8, 8), &params, |x, y| {
+///     (x.sin() * y.cos() + 1.0) / 2.0
+/// });
+///
+/// assert!(heightmap.iter().all(|h| (0.0..=1.0).contains(h)));
+/// ```
+#[cfg(feature = "noise")]
+pub fn fill_noise<V, N>(view: &mut V, rect: &BidiRect, params: &NoiseParams, noise: N)
+where
+    V: BidiViewMut<Output = f64>,
+    N: Fn(f64, f64) -> f64,
+{
+    let rect = rect.intersect(&view.bounding_rect());
+
+    for y in rect.y_range() {
+        for x in rect.x_range() {
+            view[(x, y)] = params.sample(&noise, x, y);
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod editing;
 pub mod pathfinding;
+#[cfg(feature = "synthesis")]
+pub mod synthesis;
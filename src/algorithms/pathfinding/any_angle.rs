@@ -0,0 +1,145 @@
+use super::*;
+
+fn distance(a: (usize, usize), b: (usize, usize)) -> f64 {
+    let dx = a.0 as f64 - b.0 as f64;
+    let dy = a.1 as f64 - b.1 as f64;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Finds a path between `start` and `dest` in the given `view` whose segments are not
+/// restricted to the directions of the grid (an any-angle path), using the Theta*
+/// algorithm. The locations for which `is_blocking` returns true can't be crossed.
+///
+/// `is_blocking` is a closure like `fn(elem: &T, pos: (usize, usize)) -> bool`.
+///
+/// The search moves between bordering locations like A*, but whenever a location
+/// is in [line of sight][line_of_sight] of the parent of the location being expanded,
+/// the parent is connected to it directly; as a result the path found is made of the
+/// few straight segments that an agent would follow in open terrain, without the
+/// zigzags of grid paths (even [smoothed][smooth] ones). Segments can't pass through
+/// corners shared with blocking locations.
+///
+/// The cost of the path is its Euclidean length; the path is not guaranteed to be
+/// the shortest any-angle path, but it's usually very close to it.
+///
+/// Returns the path as the list of its vertices (starting with `start` and ending
+/// with `dest`), or [`None`] if `dest` can't be reached; returns
+/// [`BidiError::OutOfBounds`] if `start` or `dest` are outside of the view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::bidivec;
+/// use bidivec::pathfinding;
+///
+/// let map = bidivec!{
+///     ['.', '.', '.', '.', '.', '.', '.'],
+///     ['.', '.', '.', '#', '.', '.', '.'],
+///     ['.', '.', '.', '#', '.', '.', '.'],
+///     ['.', '.', '.', '#', '.', '.', '.'],
+///     ['.', '.', '.', '.', '.', '.', '.'],
+/// };
+///
+/// let path = pathfinding::pathfind_to_dest_any_angle(&map, (0, 3), (6, 1), |t, _| *t == '#')?
+///     .unwrap();
+///
+/// // the path goes around the wall, from below
+/// assert_eq!(path.coords(), &[(0, 3), (4, 4), (6, 1)]);
+/// assert!(path.cost() < 8.0);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_dest_any_angle<T, V, FB>(
+    view: &V,
+    start: (usize, usize),
+    dest: (usize, usize),
+    is_blocking: FB,
+) -> Result<Option<BidiPath<f64>>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    FB: Fn(&T, (usize, usize)) -> bool,
+{
+    let (width, height) = view.size();
+    if !view.is_in_bounds(start.0, start.1) || !view.is_in_bounds(dest.0, dest.1) {
+        let (x, y) = if view.is_in_bounds(start.0, start.1) {
+            dest
+        } else {
+            start
+        };
+        return Err(BidiError::out_of_bounds(x, y, (width, height)));
+    }
+
+    if is_blocking(&view[dest], dest) {
+        return Ok(None);
+    }
+
+    let visible = |a, b| line_of_sight(view, a, b, &is_blocking);
+
+    let mut costs = BidiArray::<Option<f64>>::with_elem(None, width, height);
+    let mut parents = BidiArray::with_elem(None, width, height);
+    let mut closed = BidiArray::with_elem(false, width, height);
+    let mut queue = BinaryHeap::new();
+    let mut neighbours = BidiNeighbours::Bordering.prealloc_vec();
+
+    costs[start] = Some(0.0);
+    parents[start] = Some(start);
+    queue.push(Adjacency {
+        estimated_cost: distance(start, dest),
+        actual_cost: 0.0,
+        position: start,
+        origin: start,
+    });
+
+    while let Some(Adjacency { position, .. }) = queue.pop() {
+        if closed[position] {
+            continue;
+        }
+
+        if position == dest {
+            let mut coords = vec![dest];
+            let mut pos = dest;
+
+            while let Some(parent) = parents[pos].filter(|parent| *parent != pos) {
+                coords.push(parent);
+                pos = parent;
+            }
+
+            coords.reverse();
+            return Ok(costs[dest].map(|cost| BidiPath::new(coords, cost)));
+        }
+
+        closed[position] = true;
+
+        let parent = parents[position].unwrap_or(position);
+        let cost = costs[position].unwrap_or_default();
+        let parent_cost = costs[parent].unwrap_or_default();
+
+        BidiNeighbours::Bordering.generate_points_on(&mut neighbours, position, width, height);
+
+        while let Some(neighbour) = neighbours.pop() {
+            if closed[neighbour] || is_blocking(&view[neighbour], neighbour) {
+                continue;
+            }
+
+            let (new_parent, new_cost) = if visible(parent, neighbour) {
+                (parent, parent_cost + distance(parent, neighbour))
+            } else if visible(position, neighbour) {
+                (position, cost + distance(position, neighbour))
+            } else {
+                continue;
+            };
+
+            if costs[neighbour].map_or(true, |old_cost| new_cost < old_cost) {
+                costs[neighbour] = Some(new_cost);
+                parents[neighbour] = Some(new_parent);
+                queue.push(Adjacency {
+                    estimated_cost: new_cost + distance(neighbour, dest),
+                    actual_cost: new_cost,
+                    position: neighbour,
+                    origin: new_parent,
+                });
+            }
+        }
+    }
+
+    Ok(None)
+}
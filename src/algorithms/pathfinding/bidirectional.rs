@@ -0,0 +1,262 @@
+use super::*;
+
+/// One of the two searches of a bidirectional search.
+struct Frontier<C: PathFindCost> {
+    tiles: BidiArray<PathFindDataTile<C>>,
+    queue: BinaryHeap<Adjacency<C>>,
+}
+
+impl<C: PathFindCost> Frontier<C> {
+    fn new((width, height): (usize, usize), start: (usize, usize)) -> Self {
+        let mut tiles = BidiArray::<PathFindDataTile<C>>::with_size_default(width, height);
+        tiles[start].origin = Some(start);
+        tiles[start].cost = Some(C::default());
+
+        let mut queue = BinaryHeap::new();
+        queue.push(Adjacency {
+            estimated_cost: C::default(),
+            actual_cost: C::default(),
+            position: start,
+            origin: start,
+        });
+
+        Self { tiles, queue }
+    }
+
+    /// Returns the smallest estimate of the locations still to be expanded,
+    /// discarding the outdated entries of the queue.
+    fn peek(&mut self) -> Option<C> {
+        while let Some(top) = self.queue.peek() {
+            if self.tiles[top.position].cost == Some(top.actual_cost) {
+                return Some(top.estimated_cost);
+            }
+            self.queue.pop();
+        }
+        None
+    }
+
+    /// Expands the best location of the queue, updating `best` with the
+    /// paths that meet the locations reached by the `other` search.
+    fn expand<FN, FH>(
+        &mut self,
+        other: &Self,
+        neighbours_func: &mut FN,
+        heuristic: FH,
+        neighbours: &mut Moves<C>,
+        best: &mut Option<(C, (usize, usize))>,
+    ) where
+        FN: FnMut((usize, usize), &mut Moves<C>),
+        FH: Fn((usize, usize)) -> C,
+    {
+        let adjacency = match self.queue.pop() {
+            Some(adjacency) => adjacency,
+            None => return,
+        };
+        let (pos, cur_cost) = (adjacency.position, adjacency.actual_cost);
+
+        neighbours.clear();
+        neighbours_func(pos, neighbours);
+
+        for &(neighbour, cost) in neighbours.iter() {
+            if !self.tiles.valid_coords(neighbour.0, neighbour.1) {
+                continue;
+            }
+
+            let actual_cost = match cost.normalize().and_then(|c| cur_cost.add_cost(c)) {
+                Some(c) => c,
+                None => continue,
+            };
+
+            let tile = &mut self.tiles[neighbour];
+            if tile.cost.map_or(false, |old_cost| actual_cost >= old_cost) {
+                continue;
+            }
+
+            tile.cost = Some(actual_cost);
+            tile.origin = Some(pos);
+
+            self.queue.push(Adjacency {
+                estimated_cost: {
+                    let estimate = heuristic(neighbour).normalize().unwrap_or_default();
+                    actual_cost.add_cost(estimate).unwrap_or(actual_cost)
+                },
+                actual_cost,
+                position: neighbour,
+                origin: pos,
+            });
+
+            if let Some(total) = other.tiles[neighbour]
+                .cost
+                .and_then(|c| actual_cost.add_cost(c))
+            {
+                if best.map_or(true, |(best_cost, _)| total < best_cost) {
+                    *best = Some((total, neighbour));
+                }
+            }
+        }
+    }
+}
+
+/// Sets the `in_shortest_path` flag of the locations of the path ending at `pos`.
+fn mark_path<C: PathFindCost>(
+    tiles: &mut BidiArray<PathFindDataTile<C>>,
+    pos: (usize, usize),
+    value: bool,
+) {
+    let mut pos = Some(pos);
+    while let Some(p) = pos {
+        tiles[p].in_shortest_path = value;
+        pos = tiles[p].origin.filter(|origin| *origin != p);
+    }
+}
+
+/// Finds the shortest path between `start` and `dest` in the given `view`, like
+/// [`pathfind_to_dest_heuristic`], but running two A* searches at the same time:
+/// one from `start` towards `dest` and one from `dest` towards `start` (with the
+/// movements reversed), until they meet in the middle.
+///
+/// On large maps (especially those with long corridors and dead ends) this usually
+/// explores fewer locations than a single search; it works best with an informative
+/// `heuristic` (see the [`heuristics`] module), which must be admissible and consistent
+/// for the path to be the shortest one.
+///
+/// See [`pathfind_to_dest_heuristic`] for details on `cost_func` and `heuristic`; note
+/// that `heuristic` is also called to estimate the cost from `start` to the locations
+/// explored by the backwards search.
+///
+/// The tiles of the result contain the data of the search from `start`, with the
+/// locations of the shortest path (if any) completed, so that [`PathFindData::path`]
+/// can be used to retrieve it.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiVec, BidiNeighbours};
+/// use bidivec::pathfinding::{self, heuristics, PathFindDataResult};
+///
+/// // a maze-like map with long walls
+/// let map = BidiVec::with_size_func_xy(40, 40, |x, y| x % 8 == 4 && (y + x / 8 * 13) % 40 > 5);
+///
+/// let res = pathfinding::pathfind_to_dest_bidirectional(
+///     &map,
+///     (0, 0),
+///     (39, 39),
+///     BidiNeighbours::Adjacent,
+///     |_, _, wall, _| if *wall { None } else { Some(1u32) },
+///     heuristics::manhattan(1),
+/// )?;
+///
+/// let path = res.path().unwrap();
+/// assert_eq!(path.start(), Some((0, 0)));
+/// assert_eq!(path.end(), Some((39, 39)));
+/// assert!(matches!(res.result, PathFindDataResult::ShortestPathFound(c) if c == path.cost()));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_dest_bidirectional<T, V, FC, FH, C>(
+    view: &V,
+    start: (usize, usize),
+    dest: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+    heuristic: FH,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    if !view.is_in_bounds(start.0, start.1) || !view.is_in_bounds(dest.0, dest.1) {
+        let (x, y) = if view.is_in_bounds(start.0, start.1) {
+            dest
+        } else {
+            start
+        };
+        return Err(BidiError::out_of_bounds(x, y, view.size()));
+    }
+
+    let mut forward = Frontier::new(view.size(), start);
+    let mut backward = Frontier::new(view.size(), dest);
+    let mut forward_moves = grid_neighbours(view, neighbouring, &cost_func);
+    let mut backward_moves = grid_neighbours(view, neighbouring, |from, from_pos, to, to_pos| {
+        cost_func(to, to_pos, from, from_pos)
+    });
+
+    let mut best = if start == dest {
+        Some((C::default(), start))
+    } else {
+        None
+    };
+    let mut neighbours = Vec::new();
+
+    // Each search gives a lower bound to the cost of the paths not found yet:
+    // the search stops when the best path found is not worse than either of them.
+    while let (Some(forward_bound), Some(backward_bound)) = (forward.peek(), backward.peek()) {
+        if let Some((best_cost, _)) = best {
+            if forward_bound >= best_cost || backward_bound >= best_cost {
+                break;
+            }
+        }
+
+        if forward.queue.len() <= backward.queue.len() {
+            forward.expand(
+                &backward,
+                &mut forward_moves,
+                |pos| heuristic(pos, dest),
+                &mut neighbours,
+                &mut best,
+            );
+        } else {
+            backward.expand(
+                &forward,
+                &mut backward_moves,
+                |pos| heuristic(start, pos),
+                &mut neighbours,
+                &mut best,
+            );
+        }
+    }
+
+    let mut data = PathFindData {
+        result: PathFindDataResult::PathNotFound,
+        tiles: forward.tiles,
+        destination: None,
+    };
+
+    if let Some((total_cost, meeting)) = best {
+        mark_path(&mut data.tiles, meeting, true);
+
+        // The path from the meeting location to `dest` follows the backward search;
+        // with zero cost movements it might cross the path from `start`, in which
+        // case the path from `start` to the crossing location is kept.
+        let mut pos = meeting;
+        while pos != dest {
+            let next = backward.tiles[pos]
+                .origin
+                .expect("backward search not reaching dest");
+
+            if !data.tiles[next].in_shortest_path {
+                let cost = data.tiles[pos].cost.and_then(|c| {
+                    cost_func(&view[pos], pos, &view[next], next)
+                        .and_then(C::normalize)
+                        .and_then(|step| c.add_cost(step))
+                });
+
+                data.tiles[next].origin = Some(pos);
+                data.tiles[next].cost = cost;
+            }
+
+            pos = next;
+        }
+
+        mark_path(&mut data.tiles, meeting, false);
+        mark_path(&mut data.tiles, dest, true);
+
+        // the cost of the last tile is the total cost, even with rounding errors
+        data.tiles[dest].cost = Some(total_cost);
+        data.result = PathFindDataResult::ShortestPathFound(total_cost);
+        data.destination = Some(dest);
+    }
+
+    Ok(data)
+}
@@ -0,0 +1,216 @@
+use super::*;
+
+#[derive(Clone, Debug, Default)]
+struct CostGridTile<C: PathFindCost> {
+    cost: Option<C>,
+    origin: Option<(usize, usize)>,
+    finalized: bool,
+}
+
+/// The building block of best-first searches over a bidimensional map: a grid
+/// holding, for every location, the best cost found so far and the location it
+/// was reached from, together with the queue of the locations still to be explored.
+///
+/// This is the machinery used by the pathfinders of this module, exposed so that
+/// custom searches (e.g. the propagation of an influence map, or searches with
+/// unusual movement rules) can be written without reimplementing it. A search
+/// [`relax`][BidiCostGrid::relax]es its starting locations, then repeatedly
+/// [`pop`][BidiCostGrid::pop]s the cheapest location not yet finalized and relaxes
+/// its neighbours, until there are no locations left to explore.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::bidivec;
+/// use bidivec::pathfinding::BidiCostGrid;
+///
+/// let map = bidivec!{
+///     ['.', '.', '.', '.', '.'],
+///     ['.', '#', '#', '#', '.'],
+///     ['.', '.', '.', '#', '.'],
+/// };
+///
+/// // distances from the nearest of two sources
+/// let mut grid = BidiCostGrid::<u32>::for_view(&map);
+/// grid.relax(0, 0, 0);
+/// grid.relax(4, 2, 0);
+///
+/// while let Some(((x, y), cost)) = grid.pop() {
+///     let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+///
+///     for &(nx, ny) in neighbours.iter() {
+///         if map.get(nx, ny) == Some(&'.') {
+///             grid.relax_from(nx, ny, cost + 1, (x, y));
+///         }
+///     }
+/// }
+///
+/// assert_eq!(grid.cost(2, 2), Some(4));
+/// assert_eq!(grid.cost(3, 0), Some(3));
+/// assert_eq!(grid.cost(1, 1), None);
+/// assert_eq!(grid.finalized().count(), 11);
+///
+/// let path = grid.path_to((2, 0)).unwrap();
+/// assert_eq!(path.coords(), &[(0, 0), (1, 0), (2, 0)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BidiCostGrid<C: PathFindCost> {
+    tiles: BidiArray<CostGridTile<C>>,
+    open: BinaryHeap<Adjacency<C>>,
+    finalized: Vec<(usize, usize)>,
+}
+
+impl<C: PathFindCost> BidiCostGrid<C> {
+    /// Creates a new cost grid of the specified size, with no location reached.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            tiles: BidiArray::with_size_default(width, height),
+            open: BinaryHeap::new(),
+            finalized: Vec::new(),
+        }
+    }
+
+    /// Creates a new cost grid of the same size as the specified view, with no
+    /// location reached.
+    pub fn for_view<V: BidiView + ?Sized>(view: &V) -> Self {
+        Self::new(view.width(), view.height())
+    }
+
+    /// Returns the width of the cost grid.
+    pub fn width(&self) -> usize {
+        self.tiles.width()
+    }
+
+    /// Returns the height of the cost grid.
+    pub fn height(&self) -> usize {
+        self.tiles.height()
+    }
+
+    /// Records that the location at (x, y) coordinates can be reached with the
+    /// specified total `cost`, without a previous location (e.g. because it's the
+    /// start of the search). See [`relax_from`][BidiCostGrid::relax_from].
+    pub fn relax(&mut self, x: usize, y: usize, cost: C) -> bool {
+        self.relax_core(x, y, cost, None)
+    }
+
+    /// Records that the location at (x, y) coordinates can be reached with the
+    /// specified total `cost`, coming from the location `from`. If the cost is
+    /// lower than the best one found so far, the location is queued to be
+    /// explored and true is returned.
+    ///
+    /// False is returned, and nothing is done, if the location is out of bounds,
+    /// if it has already been finalized, if the cost isn't lower than the best one
+    /// found so far or if the cost is invalid (see [`PathFindCost::normalize`]).
+    pub fn relax_from(&mut self, x: usize, y: usize, cost: C, from: (usize, usize)) -> bool {
+        self.relax_core(x, y, cost, Some(from))
+    }
+
+    fn relax_core(&mut self, x: usize, y: usize, cost: C, from: Option<(usize, usize)>) -> bool {
+        let cost = match cost.normalize() {
+            Some(cost) => cost,
+            None => return false,
+        };
+
+        match self.tiles.get_mut(x, y) {
+            Some(tile) if !tile.finalized && tile.cost.map_or(true, |old| cost < old) => {
+                tile.cost = Some(cost);
+                tile.origin = from;
+                self.open.push(Adjacency {
+                    estimated_cost: cost,
+                    actual_cost: cost,
+                    position: (x, y),
+                    origin: from.unwrap_or((x, y)),
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Finalizes and returns the location with the lowest cost among the ones
+    /// queued and not yet finalized, together with its cost, or [`None`] if
+    /// there are no locations left to explore.
+    ///
+    /// The cost of a finalized location is the lowest possible, as long as all
+    /// the movement costs are non-negative.
+    pub fn pop(&mut self) -> Option<((usize, usize), C)> {
+        while let Some(adjacency) = self.open.pop() {
+            let tile = &mut self.tiles[adjacency.position];
+
+            if !tile.finalized && tile.cost == Some(adjacency.actual_cost) {
+                tile.finalized = true;
+                self.finalized.push(adjacency.position);
+                return Some((adjacency.position, adjacency.actual_cost));
+            }
+        }
+
+        None
+    }
+
+    /// Returns true if there are no locations left to explore.
+    pub fn is_exhausted(&self) -> bool {
+        self.open
+            .iter()
+            .all(|adjacency| self.tiles[adjacency.position].finalized)
+    }
+
+    /// Returns the best cost found so far for the location at (x, y) coordinates,
+    /// or [`None`] if the location hasn't been reached (or is out of bounds).
+    pub fn cost(&self, x: usize, y: usize) -> Option<C> {
+        self.tiles.get(x, y).and_then(|tile| tile.cost)
+    }
+
+    /// Returns the location the location at (x, y) coordinates has been reached
+    /// from with the best cost found so far, or [`None`] if the location hasn't
+    /// been reached, has been reached without a previous location (see
+    /// [`relax`][BidiCostGrid::relax]) or is out of bounds.
+    pub fn origin(&self, x: usize, y: usize) -> Option<(usize, usize)> {
+        self.tiles.get(x, y).and_then(|tile| tile.origin)
+    }
+
+    /// Returns true if the location at (x, y) coordinates has been finalized
+    /// (see [`pop`][BidiCostGrid::pop]).
+    pub fn is_finalized(&self, x: usize, y: usize) -> bool {
+        self.tiles.get(x, y).map_or(false, |tile| tile.finalized)
+    }
+
+    /// Returns an iterator over the finalized locations and their costs, in the
+    /// order they have been finalized (that is, by increasing cost).
+    pub fn finalized(&self) -> impl Iterator<Item = ((usize, usize), C)> + '_ {
+        self.finalized.iter().map(move |&pos| {
+            let cost = self.tiles[pos].cost.unwrap_or_default();
+            (pos, cost)
+        })
+    }
+
+    /// Returns the path to the finalized location at `pos`, following the
+    /// previous locations back to a location reached without one, or [`None`]
+    /// if the location hasn't been finalized.
+    pub fn path_to(&self, pos: (usize, usize)) -> Option<BidiPath<C>> {
+        if !self.is_finalized(pos.0, pos.1) {
+            return None;
+        }
+
+        let cost = self.tiles[pos].cost?;
+        let mut coords = vec![pos];
+        let mut pos = pos;
+
+        while let Some(origin) = self.tiles[pos].origin.filter(|origin| *origin != pos) {
+            coords.push(origin);
+            pos = origin;
+        }
+
+        coords.reverse();
+        Some(BidiPath::new(coords, cost))
+    }
+
+    /// Forgets all the costs and the queued locations, so that the cost grid
+    /// can be reused for another search.
+    pub fn clear(&mut self) {
+        for tile in self.tiles.iter_mut() {
+            *tile = CostGridTile::default();
+        }
+        self.open.clear();
+        self.finalized.clear();
+    }
+}
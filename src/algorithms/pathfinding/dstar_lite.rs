@@ -0,0 +1,370 @@
+use super::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct QueueEntry<C: PathFindCost> {
+    key: (C, C),
+    position: (usize, usize),
+}
+
+impl<C: PathFindCost> Eq for QueueEntry<C> {}
+
+impl<C: PathFindCost> Ord for QueueEntry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .key
+            .partial_cmp(&self.key)
+            .expect("non-normalized value in pathfinding")
+    }
+}
+
+impl<C: PathFindCost> PartialOrd for QueueEntry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Adds two optional costs, where [`None`] stands for an infinite cost.
+fn add<C: PathFindCost>(a: Option<C>, b: Option<C>) -> Option<C> {
+    a?.add_cost(b?)
+}
+
+/// Returns the minimum of two optional costs, where [`None`] stands for an infinite cost.
+fn min_cost<C: PathFindCost>(a: Option<C>, b: Option<C>) -> Option<C> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b < a { b } else { a }),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// An incremental pathfinding search, using the D* Lite algorithm.
+///
+/// Unlike the other functions of this module, which perform a whole search every
+/// time they are called, a [`DStarLite`] keeps its search state alive, so that
+/// when the cost of some locations changes (see [`update_cell`][DStarLite::update_cell])
+/// or the start location moves along the path (see [`set_start`][DStarLite::set_start]),
+/// the shortest path can be repaired much faster than with a new search. This is
+/// well suited to agents moving on maps that change a few locations at a time.
+///
+/// Costs are associated to locations: moving to a location costs the cost of the
+/// location being entered, and locations with a [`None`] cost can't be entered.
+///
+/// The heuristic must never overestimate the actual cost (see the [`heuristics`]
+/// module); use `|_, _| 0` to perform an uninformed search.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{heuristics, DStarLite};
+///
+/// let map = bidivec!{
+///     ['.', '.', '.', '.'],
+///     ['.', '#', '#', '.'],
+///     ['.', '.', '.', '.'],
+/// };
+///
+/// let mut search = DStarLite::new(
+///     &map,
+///     (0, 1),
+///     (3, 1),
+///     BidiNeighbours::Adjacent,
+///     |tile, _| if *tile == '#' { None } else { Some(1u32) },
+///     heuristics::manhattan(1),
+/// )?;
+///
+/// assert_eq!(search.cost(), Some(5));
+///
+/// // block the top and bottom rows, and open a passage in the middle
+/// search.update_cell(1, 0, None)?;
+/// search.update_cell(2, 2, None)?;
+/// search.update_cell(1, 1, Some(1))?;
+/// search.update_cell(2, 1, Some(1))?;
+///
+/// let path = search.path().unwrap();
+/// assert_eq!(path.coords(), &[(0, 1), (1, 1), (2, 1), (3, 1)]);
+///
+/// // the agent moves along the path
+/// search.set_start((1, 1))?;
+/// assert_eq!(search.cost(), Some(2));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub struct DStarLite<C, FH>
+where
+    C: PathFindCost,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    start: (usize, usize),
+    last_start: (usize, usize),
+    goal: (usize, usize),
+    neighbouring: BidiNeighbours,
+    heuristic: FH,
+    costs: BidiArray<Option<C>>,
+    g: BidiArray<Option<C>>,
+    rhs: BidiArray<Option<C>>,
+    queued: BidiArray<Option<(C, C)>>,
+    queue: BinaryHeap<QueueEntry<C>>,
+    km: C,
+    neighbours: Vec<(usize, usize)>,
+    vertex_neighbours: Vec<(usize, usize)>,
+}
+
+impl<C, FH> DStarLite<C, FH>
+where
+    C: PathFindCost,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    /// Creates a new incremental search from `start` to `goal` over the given `view`.
+    ///
+    /// `cost_func` is a closure like `fn(elem: &T, pos: (usize, usize)) -> Option<C>`,
+    /// returning the cost of entering each location of the view (or [`None`] if the
+    /// location can't be entered); it's called once per location, as the search then
+    /// keeps its own copy of the costs.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if `start` or `goal` are outside of the view.
+    pub fn new<T, V, FC>(
+        view: &V,
+        start: (usize, usize),
+        goal: (usize, usize),
+        neighbouring: BidiNeighbours,
+        cost_func: FC,
+        heuristic: FH,
+    ) -> Result<Self, BidiError>
+    where
+        V: BidiView<Output = T> + Sized,
+        FC: Fn(&T, (usize, usize)) -> Option<C>,
+    {
+        let (width, height) = view.size();
+
+        if !view.is_in_bounds(start.0, start.1) || !view.is_in_bounds(goal.0, goal.1) {
+            let (x, y) = if view.is_in_bounds(start.0, start.1) {
+                goal
+            } else {
+                start
+            };
+            return Err(BidiError::out_of_bounds(x, y, (width, height)));
+        }
+
+        let mut this = Self {
+            start,
+            last_start: start,
+            goal,
+            neighbouring,
+            heuristic,
+            costs: BidiArray::with_size_func_xy(width, height, |x, y| {
+                cost_func(&view[(x, y)], (x, y)).and_then(C::normalize)
+            }),
+            g: BidiArray::with_elem(None, width, height),
+            rhs: BidiArray::with_elem(None, width, height),
+            queued: BidiArray::with_elem(None, width, height),
+            queue: BinaryHeap::new(),
+            km: C::default(),
+            neighbours: neighbouring.prealloc_vec(),
+            vertex_neighbours: neighbouring.prealloc_vec(),
+        };
+
+        this.rhs[goal] = Some(C::default());
+        this.enqueue(goal);
+
+        Ok(this)
+    }
+
+    /// Returns the current start location.
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+
+    /// Returns the goal location.
+    pub fn goal(&self) -> (usize, usize) {
+        self.goal
+    }
+
+    /// Changes the cost of entering the location at `(x, y)`; use [`None`] to
+    /// make the location impassable. The path is repaired lazily, the next time
+    /// it's requested.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if the location is outside of the map.
+    pub fn update_cell(&mut self, x: usize, y: usize, cost: Option<C>) -> Result<(), BidiError> {
+        if !self.costs.valid_coords(x, y) {
+            return Err(BidiError::out_of_bounds(x, y, self.costs.size()));
+        }
+
+        let cost = cost.and_then(C::normalize);
+        if self.costs[(x, y)] == cost {
+            return Ok(());
+        }
+
+        self.costs[(x, y)] = cost;
+
+        // the cost of all the movements towards (x, y) changed
+        let (width, height) = self.costs.size();
+        let mut neighbours = std::mem::take(&mut self.neighbours);
+        self.neighbouring
+            .generate_points_on(&mut neighbours, (x, y), width, height);
+
+        while let Some(neighbour) = neighbours.pop() {
+            self.update_vertex(neighbour);
+        }
+
+        self.neighbours = neighbours;
+        Ok(())
+    }
+
+    /// Moves the start location (e.g. because the agent following the path moved).
+    /// The path is repaired lazily, the next time it's requested.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if the location is outside of the map.
+    pub fn set_start(&mut self, start: (usize, usize)) -> Result<(), BidiError> {
+        if !self.costs.valid_coords(start.0, start.1) {
+            return Err(BidiError::out_of_bounds(
+                start.0,
+                start.1,
+                self.costs.size(),
+            ));
+        }
+
+        self.start = start;
+        Ok(())
+    }
+
+    /// Returns the cost of the shortest path from the start location to the goal,
+    /// or [`None`] if the goal can't be reached.
+    pub fn cost(&mut self) -> Option<C> {
+        self.compute_shortest_path();
+        self.g[self.start]
+    }
+
+    /// Returns the shortest path from the start location to the goal (both included),
+    /// or [`None`] if the goal can't be reached.
+    pub fn path(&mut self) -> Option<BidiPath<C>> {
+        self.compute_shortest_path();
+        let cost = self.g[self.start]?;
+
+        let (width, height) = self.costs.size();
+        let mut path = vec![self.start];
+        let mut pos = self.start;
+        let mut neighbours = self.neighbouring.prealloc_vec();
+
+        while pos != self.goal && path.len() <= width * height {
+            self.neighbouring
+                .generate_points_on(&mut neighbours, pos, width, height);
+
+            let mut best = None;
+            let mut best_cost = None;
+
+            while let Some(neighbour) = neighbours.pop() {
+                let cost = add(self.costs[neighbour], self.g[neighbour]);
+                if cost.is_some() && min_cost(cost, best_cost) != best_cost {
+                    best = Some(neighbour);
+                    best_cost = cost;
+                }
+            }
+
+            pos = best?;
+            path.push(pos);
+        }
+
+        Some(BidiPath::new(path, cost))
+    }
+
+    fn key(&self, pos: (usize, usize)) -> Option<(C, C)> {
+        let min = min_cost(self.g[pos], self.rhs[pos])?;
+        let estimate = (self.heuristic)(self.start, pos)
+            .normalize()
+            .unwrap_or_default();
+        let primary = min
+            .add_cost(estimate)
+            .and_then(|k| k.add_cost(self.km))
+            .unwrap_or(min);
+        Some((primary, min))
+    }
+
+    fn enqueue(&mut self, pos: (usize, usize)) {
+        let key = self.key(pos);
+        self.queued[pos] = key;
+
+        if let Some(key) = key {
+            self.queue.push(QueueEntry { key, position: pos });
+        }
+    }
+
+    fn update_vertex(&mut self, pos: (usize, usize)) {
+        if pos != self.goal {
+            let (width, height) = self.costs.size();
+            self.neighbouring
+                .generate_points_on(&mut self.vertex_neighbours, pos, width, height);
+
+            let mut rhs = None;
+            while let Some(neighbour) = self.vertex_neighbours.pop() {
+                rhs = min_cost(rhs, add(self.costs[neighbour], self.g[neighbour]));
+            }
+            self.rhs[pos] = rhs;
+        }
+
+        if self.g[pos] != self.rhs[pos] {
+            self.enqueue(pos);
+        } else {
+            self.queued[pos] = None;
+        }
+    }
+
+    fn compute_shortest_path(&mut self) {
+        if self.start != self.last_start {
+            let estimate = (self.heuristic)(self.last_start, self.start)
+                .normalize()
+                .unwrap_or_default();
+            self.km = self.km.add_cost(estimate).unwrap_or(self.km);
+            self.last_start = self.start;
+        }
+
+        let (width, height) = self.costs.size();
+        let mut neighbours = std::mem::take(&mut self.neighbours);
+
+        while let Some(top) = self.queue.peek() {
+            let (top_key, pos) = (top.key, top.position);
+
+            // skip entries that have been removed or re-inserted with a different key
+            if self.queued[pos] != Some(top_key) {
+                self.queue.pop();
+                continue;
+            }
+
+            let start_key = self.key(self.start);
+            let start_consistent = self.g[self.start] == self.rhs[self.start];
+            if start_consistent && start_key.map_or(false, |k| top_key >= k) {
+                break;
+            }
+
+            self.queue.pop();
+            self.queued[pos] = None;
+
+            let new_key = self.key(pos);
+            if new_key.map_or(false, |k| top_key < k) {
+                self.enqueue(pos);
+                continue;
+            }
+
+            let overconsistent = match (self.g[pos], self.rhs[pos]) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(g), Some(rhs)) => g > rhs,
+            };
+
+            if overconsistent {
+                self.g[pos] = self.rhs[pos];
+            } else {
+                self.g[pos] = None;
+                self.update_vertex(pos);
+            }
+
+            self.neighbouring
+                .generate_points_on(&mut neighbours, pos, width, height);
+
+            while let Some(neighbour) = neighbours.pop() {
+                self.update_vertex(neighbour);
+            }
+        }
+
+        self.neighbours = neighbours;
+    }
+}
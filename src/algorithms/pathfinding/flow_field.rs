@@ -0,0 +1,114 @@
+use super::*;
+
+/// A single location of a flow field, as computed by [`flow_field`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowFieldTile<C: PathFindCost> {
+    /// The cost to reach the nearest target from this location, or [`None`]
+    /// if no target can be reached.
+    pub distance: Option<C>,
+    /// The location to move to, to reach the nearest target along the
+    /// shortest path, or [`None`] if this location is a target or if no
+    /// target can be reached.
+    pub next: Option<(usize, usize)>,
+    /// The direction (i.e. the offset of [`next`][FlowFieldTile::next]) to
+    /// move in, to reach the nearest target, or `(0, 0)` if this location is a
+    /// target or if no target can be reached.
+    pub direction: (isize, isize),
+}
+
+impl<C: PathFindCost> Default for FlowFieldTile<C> {
+    fn default() -> Self {
+        Self {
+            distance: None,
+            next: None,
+            direction: (0, 0),
+        }
+    }
+}
+
+/// Computes a flow field (also known as Djikstra map) over the given `view`,
+/// that is, for every location, the direction to move in and the cost to reach
+/// the nearest of the `targets`, using the given `cost_func` to evaluate the
+/// cost of a given movement.
+///
+/// This allows moving any number of agents towards a shared set of goals with
+/// a single search, as every agent simply follows the direction of the tile it's on.
+///
+/// Under the hood, this uses the Djikstra algorithm, starting from all the targets
+/// and following the movements backwards.
+///
+/// `cost_func` is a closure like:
+/// `fn(from_elem: &T, from_pos: (usize, usize), to_elem: &T, to_pos: (usize, usize)) -> Option<C>`.
+///
+/// It should return the cost of a movement between `from_pos` to `to_pos` (the elements
+/// are also passed for convenience) and should return [`None`] if no such path exists, or
+/// the cost C if it does.
+///
+/// Returns [`BidiError::OutOfBounds`] if any of the targets is outside of the view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding;
+///
+/// let map = bidivec!{
+///     ['.', '.', '.', '.', '.'],
+///     ['.', '#', '#', '#', '.'],
+///     ['X', '.', '.', '.', 'X'],
+/// };
+///
+/// let field = pathfinding::flow_field(
+///     &map,
+///     &[(0, 2), (4, 2)],
+///     BidiNeighbours::Adjacent,
+///     |from, _, to, _| if *from == '#' || *to == '#' { None } else { Some(1u32) },
+/// )?;
+///
+/// assert_eq!(field[(1, 2)].direction, (-1, 0));
+/// assert_eq!(field[(3, 2)].direction, (1, 0));
+/// assert_eq!(field[(4, 0)].next, Some((4, 1)));
+/// assert_eq!(field[(2, 0)].distance, Some(4));
+/// assert_eq!(field[(0, 2)].distance, Some(0));
+/// assert_eq!(field[(0, 2)].next, None);
+/// assert_eq!(field[(1, 1)].distance, None);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn flow_field<T, V, FC, C>(
+    view: &V,
+    targets: &[(usize, usize)],
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> Result<BidiArray<FlowFieldTile<C>>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    let data = pathfind_core(
+        view.size(),
+        targets,
+        None,
+        grid_neighbours(view, neighbouring, |from, from_pos, to, to_pos| {
+            cost_func(to, to_pos, from, from_pos)
+        }),
+        |_| C::default(),
+        &mut PathFindLimits::new(),
+    )?;
+
+    let mut field = BidiArray::with_size_default(view.width(), view.height());
+
+    for (x, y, tile) in data.tiles.iter().with_coords() {
+        let next = tile.origin.filter(|origin| *origin != (x, y));
+
+        field[(x, y)] = FlowFieldTile {
+            distance: tile.cost,
+            next,
+            direction: next.map_or((0, 0), |(nx, ny)| {
+                (nx as isize - x as isize, ny as isize - y as isize)
+            }),
+        };
+    }
+
+    Ok(field)
+}
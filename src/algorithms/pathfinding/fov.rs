@@ -0,0 +1,176 @@
+use super::*;
+
+/// The transformations mapping the first octant to each of the eight octants.
+const OCTANTS: [(isize, isize, isize, isize); 8] = [
+    (1, 0, 0, 1),
+    (0, 1, 1, 0),
+    (0, -1, 1, 0),
+    (-1, 0, 0, 1),
+    (-1, 0, 0, -1),
+    (0, -1, -1, 0),
+    (0, 1, -1, 0),
+    (1, 0, 0, -1),
+];
+
+struct Shadowcaster<'a, V, FO>
+where
+    V: BidiView,
+{
+    view: &'a V,
+    origin: (isize, isize),
+    radius: isize,
+    is_opaque: FO,
+    visible: BidiVec<bool>,
+}
+
+impl<'a, T, V, FO> Shadowcaster<'a, V, FO>
+where
+    V: BidiView<Output = T>,
+    FO: Fn(&T, (usize, usize)) -> bool,
+{
+    fn position(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        if x >= 0 && y >= 0 && (x as usize) < self.view.width() && (y as usize) < self.view.height()
+        {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Scans the rows of an octant starting from `row`, between the `start` and `end`
+    /// slopes, recursing on the parts of the following rows that are not in shadow.
+    fn cast_light(
+        &mut self,
+        row: isize,
+        mut start: f64,
+        end: f64,
+        (xx, xy, yx, yy): (isize, isize, isize, isize),
+    ) {
+        if start < end {
+            return;
+        }
+
+        let radius_sq = self.radius * self.radius;
+        let mut next_start = start;
+
+        for distance in row..=self.radius {
+            let mut blocked = false;
+            let dy = -distance;
+
+            for dx in -distance..=0 {
+                let left_slope = (dx as f64 - 0.5) / (dy as f64 + 0.5);
+                let right_slope = (dx as f64 + 0.5) / (dy as f64 - 0.5);
+
+                if start < right_slope {
+                    continue;
+                } else if end > left_slope {
+                    break;
+                }
+
+                let x = self.origin.0 + dx * xx + dy * xy;
+                let y = self.origin.1 + dx * yx + dy * yy;
+                let pos = self.position(x, y);
+
+                if let Some(pos) = pos {
+                    if dx * dx + dy * dy <= radius_sq {
+                        self.visible[pos] = true;
+                    }
+                }
+
+                // locations outside of the view block the sight
+                let opaque = pos.map_or(true, |pos| (self.is_opaque)(&self.view[pos], pos));
+
+                if blocked {
+                    if opaque {
+                        next_start = right_slope;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && distance < self.radius {
+                    blocked = true;
+                    self.cast_light(distance + 1, start, left_slope, (xx, xy, yx, yy));
+                    next_start = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
+
+/// Computes the field of view from `origin`: the locations of the `view` that can be
+/// seen from `origin` within a circle of the given `radius`, using recursive
+/// shadowcasting.
+///
+/// `is_opaque` is a closure like `fn(elem: &T, pos: (usize, usize)) -> bool`, which
+/// should return true for the locations that block the sight. Opaque locations are
+/// visible themselves (e.g. the walls of a room are visible from inside the room),
+/// but hide the locations behind them.
+///
+/// Returns [`BidiError::OutOfBounds`] if `origin` is outside of the view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::bidivec;
+/// use bidivec::pathfinding;
+///
+/// let map = bidivec!{
+///     ['.', '.', '.', '.', '.'],
+///     ['.', '.', '.', '.', '.'],
+///     ['.', '.', '#', '.', '.'],
+///     ['.', '.', '.', '.', '.'],
+///     ['.', '.', '@', '.', '.'],
+/// };
+///
+/// let visible = pathfinding::fov(&map, (2, 4), 10, |tile, _| *tile == '#')?;
+///
+/// assert!(visible[(2, 2)]);
+/// assert!(!visible[(2, 1)]);
+/// assert!(!visible[(2, 0)]);
+/// assert!(visible[(0, 0)]);
+/// assert!(visible[(4, 0)]);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn fov<T, V, FO>(
+    view: &V,
+    origin: (usize, usize),
+    radius: usize,
+    is_opaque: FO,
+) -> Result<BidiVec<bool>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    FO: Fn(&T, (usize, usize)) -> bool,
+{
+    let (width, height) = view.size();
+
+    if !view.is_in_bounds(origin.0, origin.1) {
+        return Err(BidiError::out_of_bounds(
+            origin.0,
+            origin.1,
+            (width, height),
+        ));
+    }
+
+    // no location is farther than this from the origin
+    let radius = radius.min(width + height);
+
+    let mut caster = Shadowcaster {
+        view,
+        origin: (origin.0 as isize, origin.1 as isize),
+        radius: radius as isize,
+        is_opaque,
+        visible: BidiVec::with_elem(false, width, height),
+    };
+
+    caster.visible[origin] = true;
+
+    for octant in OCTANTS.iter() {
+        caster.cast_light(1, 1.0, 0.0, *octant);
+    }
+
+    Ok(caster.visible)
+}
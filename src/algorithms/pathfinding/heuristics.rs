@@ -0,0 +1,285 @@
+//! Ready made heuristics for the A* algorithm (see
+//! [`pathfind_to_dest_heuristic`][super::pathfind_to_dest_heuristic]), and helpers
+//! to check their admissibility.
+//!
+//! An heuristic is admissible if it never overestimates the actual cost to reach the
+//! destination; admissible heuristics guarantee that the path found by A* is the
+//! shortest one. Which heuristic is admissible depends on the allowed movements and on
+//! their costs:
+//! - [`manhattan`] is admissible when moving between [adjacent][crate::BidiNeighbours::Adjacent]
+//!   tiles, if every step costs at least `cost_per_step`.
+//! - [`chebyshev`] is admissible when moving between [bordering][crate::BidiNeighbours::Bordering]
+//!   tiles, if every step (including diagonal ones) costs at least `cost_per_step`.
+//! - [`octile`] is admissible when moving between bordering tiles, if every straight step
+//!   costs at least `straight_cost` and every diagonal step costs at least `diagonal_cost`.
+//! - [`hex`] is admissible when moving between [hex][crate::BidiNeighbours::Hex] tiles
+//!   with the same layout, if every step costs at least `cost_per_step`.
+//! - [`euclidean`] is admissible in more cases, as long as every step costs at least
+//!   `cost_per_step` times the distance between the centers of the tiles; it is however
+//!   less informed (and thus slower) than the others.
+//!
+//! If in doubt, [`find_inadmissible`] can be used to check an heuristic against a map.
+//!
+//! Heuristics can be made greedier using [`weighted`], trading the optimality of the path
+//! for speed (weighted A*), and can be adapted to maps wrapping around their edges using
+//! [`wrapping`].
+//!
+//! # Examples
+//!
+//! ```
+//! use bidivec::{bidivec, BidiNeighbours};
+//! use bidivec::pathfinding::{self, heuristics, PathFindDataResult};
+//!
+//! let map = bidivec![1u32; 20, 20];
+//!
+//! let astar = pathfinding::pathfind_to_dest_heuristic(
+//!     &map,
+//!     (0, 0),
+//!     (19, 10),
+//!     BidiNeighbours::Adjacent,
+//!     |_, _, to, _| Some(*to),
+//!     heuristics::manhattan(1),
+//! )?;
+//!
+//! assert!(matches!(astar.result, PathFindDataResult::ShortestPathFound(29)));
+//!
+//! // weighted A*: the path is only guaranteed to cost at most 1.5 times the
+//! // cost of the shortest one, but far fewer tiles are explored to find it
+//! let weighted = pathfinding::pathfind_to_dest_heuristic(
+//!     &map,
+//!     (0, 0),
+//!     (19, 10),
+//!     BidiNeighbours::Adjacent,
+//!     |_, _, to, _| Some(*to),
+//!     heuristics::weighted(heuristics::manhattan(1), 1.5),
+//! )?;
+//!
+//! let cost = weighted.path().unwrap().cost();
+//! assert!(cost >= 29 && cost as f64 <= 29.0 * 1.5);
+//!
+//! let explored = |res: &pathfinding::PathFindData<u32>| {
+//!     res.tiles.iter().filter(|tile| tile.cost.is_some()).count()
+//! };
+//! assert!(explored(&weighted) * 2 < explored(&astar));
+//! # Ok::<(), bidivec::BidiError>(())
+//! ```
+use super::*;
+
+/// A trait for the [`PathFindCost`] types that can be converted from and to [`f64`],
+/// so that they can be used with the heuristics of this module.
+///
+/// It's implemented for all the types [`PathFindCost`] is implemented for by the crate.
+pub trait PathFindHeuristicCost: PathFindCost {
+    /// Converts an estimate to the cost type. Implementations for integer types
+    /// round down, so that an admissible estimate stays admissible.
+    fn from_estimate(estimate: f64) -> Self;
+
+    /// Converts the cost to an [`f64`].
+    fn to_estimate(self) -> f64;
+}
+
+macro_rules! impl_heuristic_cost {
+    ($($t:ty),*) => {
+        $(impl PathFindHeuristicCost for $t {
+            fn from_estimate(estimate: f64) -> Self {
+                estimate as $t
+            }
+
+            fn to_estimate(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_heuristic_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+fn distances(from: (usize, usize), to: (usize, usize)) -> (f64, f64) {
+    let dx = if from.0 > to.0 {
+        from.0 - to.0
+    } else {
+        to.0 - from.0
+    };
+    let dy = if from.1 > to.1 {
+        from.1 - to.1
+    } else {
+        to.1 - from.1
+    };
+    (dx as f64, dy as f64)
+}
+
+/// An heuristic returning the Manhattan distance (the sum of the horizontal and
+/// vertical distances) multiplied by `cost_per_step`.
+pub fn manhattan<C>(cost_per_step: C) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindHeuristicCost,
+{
+    let cost_per_step = cost_per_step.to_estimate();
+    move |from, to| {
+        let (dx, dy) = distances(from, to);
+        C::from_estimate((dx + dy) * cost_per_step)
+    }
+}
+
+/// An heuristic returning the Chebyshev distance (the maximum between the
+/// horizontal and vertical distances) multiplied by `cost_per_step`.
+pub fn chebyshev<C>(cost_per_step: C) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindHeuristicCost,
+{
+    let cost_per_step = cost_per_step.to_estimate();
+    move |from, to| {
+        let (dx, dy) = distances(from, to);
+        C::from_estimate(dx.max(dy) * cost_per_step)
+    }
+}
+
+/// An heuristic returning the octile distance, that is the cost of reaching the
+/// destination moving diagonally as much as possible and then straight, where
+/// straight steps cost `straight_cost` and diagonal steps cost `diagonal_cost`.
+pub fn octile<C>(straight_cost: C, diagonal_cost: C) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindHeuristicCost,
+{
+    let (straight_cost, diagonal_cost) = (straight_cost.to_estimate(), diagonal_cost.to_estimate());
+    move |from, to| {
+        let (dx, dy) = distances(from, to);
+        let (min, max) = (dx.min(dy), dx.max(dy));
+        C::from_estimate(min * diagonal_cost + (max - min) * straight_cost)
+    }
+}
+
+/// An heuristic returning the Euclidean distance multiplied by `cost_per_step`.
+pub fn euclidean<C>(cost_per_step: C) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindHeuristicCost,
+{
+    let cost_per_step = cost_per_step.to_estimate();
+    move |from, to| {
+        let (dx, dy) = distances(from, to);
+        C::from_estimate((dx * dx + dy * dy).sqrt() * cost_per_step)
+    }
+}
+
+/// An heuristic returning the distance between two hexes of a hex map with the given
+/// `layout` (see [`BidiHexLayout::distance`]) multiplied by `cost_per_step`.
+pub fn hex<C>(
+    layout: BidiHexLayout,
+    cost_per_step: C,
+) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindHeuristicCost,
+{
+    let cost_per_step = cost_per_step.to_estimate();
+    move |from, to| C::from_estimate(layout.distance(from, to) as f64 * cost_per_step)
+}
+
+/// Multiplies the estimates of an heuristic by `weight`.
+///
+/// Weights greater than 1 make the search greedier (weighted A*): fewer tiles are
+/// explored, and if the heuristic is admissible the cost of the path found is at
+/// most `weight` times the cost of the shortest path.
+pub fn weighted<C, FH>(heuristic: FH, weight: f64) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindHeuristicCost,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    move |from, to| C::from_estimate(heuristic(from, to).to_estimate() * weight)
+}
+
+/// Adapts an heuristic to a map of the given `size` wrapping around the edges specified
+/// by `wrap` (see [`wrapping_neighbours`][super::wrapping_neighbours]): along each wrapping
+/// axis, the distance is measured going around the map if that's shorter.
+///
+/// The heuristic must only depend on the distances along the two axes, like all the
+/// heuristics of this module.
+pub fn wrapping<C, FH>(
+    heuristic: FH,
+    size: (usize, usize),
+    wrap: PathFindWrap,
+) -> impl Fn((usize, usize), (usize, usize)) -> C
+where
+    C: PathFindCost,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    move |from, to| {
+        // the copy of `to` nearest to `from` along each axis, as the distance
+        // between the two locations going the other way around the map
+        let fold = |from: usize, to: usize, size: usize| {
+            let distance = if from > to { from - to } else { to - from };
+            (from, from + min(distance, size - distance))
+        };
+
+        let (from_x, to_x) = if wrap.horizontal() {
+            fold(from.0, to.0, size.0)
+        } else {
+            (from.0, to.0)
+        };
+        let (from_y, to_y) = if wrap.vertical() {
+            fold(from.1, to.1, size.1)
+        } else {
+            (from.1, to.1)
+        };
+
+        heuristic((from_x, from_y), (to_x, to_y))
+    }
+}
+
+/// Checks an heuristic against a map, returning the coordinates of all the tiles
+/// from which the heuristic overestimates the actual cost of reaching `dest`,
+/// in no particular order. Tiles from which `dest` can't be reached are ignored.
+///
+/// An empty result means that the heuristic is admissible for this map and
+/// destination. This is meant to be used in tests and debug code, as it explores
+/// the whole map.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::heuristics;
+///
+/// let map = bidivec![1u32; 10, 10];
+/// let cost = |_: &u32, _, to: &u32, _| Some(*to);
+///
+/// // with diagonal movements, Manhattan distance overestimates
+/// let overestimating = heuristics::find_inadmissible(
+///     &map, (5, 5), BidiNeighbours::Bordering, cost, heuristics::manhattan(1))?;
+/// assert!(!overestimating.is_empty());
+///
+/// let overestimating = heuristics::find_inadmissible(
+///     &map, (5, 5), BidiNeighbours::Bordering, cost, heuristics::chebyshev(1))?;
+/// assert!(overestimating.is_empty());
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn find_inadmissible<T, V, FC, FH, C>(
+    view: &V,
+    dest: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+    heuristic: FH,
+) -> Result<Vec<(usize, usize)>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    // Exploring from the destination with reversed movements gives
+    // the actual cost of reaching the destination from every tile.
+    let data = pathfind_to_whole(view, dest, neighbouring, |from, from_pos, to, to_pos| {
+        cost_func(to, to_pos, from, from_pos)
+    })?;
+
+    Ok(data
+        .tiles
+        .iter()
+        .with_coords()
+        .filter_map(
+            |(x, y, tile)| match (tile.cost, heuristic((x, y), dest).normalize()) {
+                (Some(cost), Some(estimate)) if estimate > cost => Some((x, y)),
+                _ => None,
+            },
+        )
+        .collect())
+}
@@ -0,0 +1,467 @@
+use super::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+struct NodeEntry<C: PathFindCost> {
+    cost: C,
+    node: usize,
+}
+
+impl<C: PathFindCost> Eq for NodeEntry<C> {}
+
+impl<C: PathFindCost> Ord for NodeEntry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .expect("non-normalized value in pathfinding")
+    }
+}
+
+impl<C: PathFindCost> PartialOrd for NodeEntry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A hierarchical pathfinder, using the HPA* algorithm to answer long-distance
+/// queries much faster than a search over every single location.
+///
+/// The map is partitioned into square clusters; when the pathfinder is created, the
+/// entrances between neighbouring clusters are found and the paths between the
+/// entrances of each cluster are precomputed, building an abstract graph that is
+/// much smaller than the map. Queries search this graph and then refine the result
+/// to a path over the locations of the map.
+///
+/// Costs are associated to locations: moving to a location costs the cost of the
+/// location being entered, and locations with a [`None`] cost can't be entered.
+///
+/// With [`BidiNeighbours::Bordering`], diagonal moves are used inside clusters, but
+/// transitions between clusters are only placed across their straight edges: a path
+/// that can only cross from a cluster to another diagonally (e.g. through a gap at
+/// the corner of four clusters) is not found.
+///
+/// The paths found are valid but only near-optimal: their cost is usually within a
+/// few percent of the cost of the shortest path. Larger clusters make the abstract
+/// graph smaller (and queries faster) at the price of slower precomputation.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiVec, BidiNeighbours};
+/// use bidivec::pathfinding::HierarchicalPathfinder;
+///
+/// // a 64x64 map with a wall in the middle, open at the bottom
+/// let map = BidiVec::with_size_func_xy(64, 64, |x, y| x == 32 && y < 60);
+///
+/// let pathfinder = HierarchicalPathfinder::new(
+///     &map,
+///     BidiNeighbours::Adjacent,
+///     8,
+///     |wall, _| if *wall { None } else { Some(1u32) },
+/// )?;
+///
+/// let path = pathfinder.find_path((0, 0), (63, 0))?.unwrap();
+///
+/// assert_eq!(path.start(), Some((0, 0)));
+/// assert_eq!(path.end(), Some((63, 0)));
+/// assert_eq!(path.cost() as usize, path.len() - 1);
+/// assert!(path.iter_coords().all(|p| !map[p]));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub struct HierarchicalPathfinder<C: PathFindCost> {
+    costs: BidiArray<Option<C>>,
+    neighbouring: BidiNeighbours,
+    cluster_size: usize,
+    nodes: Vec<(usize, usize)>,
+    node_indices: HashMap<(usize, usize), usize>,
+    edges: Vec<Vec<(usize, C)>>,
+}
+
+impl<C: PathFindCost> HierarchicalPathfinder<C> {
+    /// Creates a new hierarchical pathfinder over the given `view`, using
+    /// clusters of `cluster_size` x `cluster_size` locations.
+    ///
+    /// `cost_func` is a closure like `fn(elem: &T, pos: (usize, usize)) -> Option<C>`,
+    /// returning the cost of entering each location of the view (or [`None`] if the
+    /// location can't be entered); it's called once per location, as the pathfinder
+    /// keeps its own copy of the costs.
+    ///
+    /// Returns [`BidiError::InvalidArgument`] if `cluster_size` is zero.
+    pub fn new<T, V, FC>(
+        view: &V,
+        neighbouring: BidiNeighbours,
+        cluster_size: usize,
+        cost_func: FC,
+    ) -> Result<Self, BidiError>
+    where
+        V: BidiView<Output = T> + Sized,
+        FC: Fn(&T, (usize, usize)) -> Option<C>,
+    {
+        if cluster_size == 0 {
+            return Err(BidiError::invalid_argument("cluster_size"));
+        }
+
+        let mut this = Self {
+            costs: BidiArray::with_size_func_xy(view.width(), view.height(), |x, y| {
+                cost_func(&view[(x, y)], (x, y)).and_then(C::normalize)
+            }),
+            neighbouring,
+            cluster_size,
+            nodes: Vec::new(),
+            node_indices: HashMap::new(),
+            edges: Vec::new(),
+        };
+
+        this.build_entrances();
+        this.build_intra_edges();
+
+        Ok(this)
+    }
+
+    /// Returns the number of nodes of the abstract graph (that is, the number
+    /// of locations at the entrances between clusters).
+    pub fn abstract_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Finds a path from `start` to `goal`, or [`None`] if `goal` can't be reached.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if `start` or `goal` are outside of the map.
+    pub fn find_path(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Result<Option<BidiPath<C>>, BidiError> {
+        if !self.costs.valid_coords(start.0, start.1) || !self.costs.valid_coords(goal.0, goal.1) {
+            let (x, y) = if self.costs.valid_coords(start.0, start.1) {
+                goal
+            } else {
+                start
+            };
+            return Err(BidiError::out_of_bounds(x, y, self.costs.size()));
+        }
+
+        if start == goal {
+            return Ok(Some(BidiPath::new(vec![start], C::default())));
+        }
+
+        // The start and goal are temporarily added to the abstract graph,
+        // connected to the nodes of their clusters.
+        let (start_node, goal_node) = (self.nodes.len(), self.nodes.len() + 1);
+        let position = |node: usize| match node {
+            n if n == start_node => start,
+            n if n == goal_node => goal,
+            n => self.nodes[n],
+        };
+
+        let start_search = self.cluster_search(start, false);
+        let goal_search = self.cluster_search(goal, true);
+
+        let start_edges = self
+            .cluster_nodes(start)
+            .filter_map(|n| Some((n, start_search.cost_to(self.nodes[n])?)))
+            .chain(start_search.cost_to(goal).map(|cost| (goal_node, cost)))
+            .collect::<Vec<_>>();
+
+        let mut goal_edges = HashMap::new();
+        for n in self.cluster_nodes(goal) {
+            if let Some(cost) = goal_search.cost_to(self.nodes[n]) {
+                goal_edges.insert(n, cost);
+            }
+        }
+
+        // Djikstra over the abstract graph
+        let mut costs = vec![None; self.nodes.len() + 2];
+        let mut origins = vec![None; self.nodes.len() + 2];
+        let mut queue = BinaryHeap::new();
+
+        costs[start_node] = Some(C::default());
+        queue.push(NodeEntry {
+            cost: C::default(),
+            node: start_node,
+        });
+
+        while let Some(NodeEntry { cost, node }) = queue.pop() {
+            if costs[node].map_or(false, |c| cost > c) {
+                continue;
+            }
+
+            if node == goal_node {
+                break;
+            }
+
+            let edges = if node == start_node {
+                &start_edges[..]
+            } else {
+                &self.edges[node][..]
+            };
+            let goal_edge = goal_edges.get(&node).map(|c| (goal_node, *c));
+
+            for &(next, edge_cost) in edges.iter().chain(goal_edge.iter()) {
+                let next_cost = match cost.add_cost(edge_cost) {
+                    Some(c) => c,
+                    None => continue,
+                };
+
+                if costs[next].map_or(true, |c| next_cost < c) {
+                    costs[next] = Some(next_cost);
+                    origins[next] = Some(node);
+                    queue.push(NodeEntry {
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+
+        let total_cost = match costs[goal_node] {
+            Some(cost) => cost,
+            None => return Ok(None),
+        };
+
+        let mut abstract_path = vec![goal_node];
+        while let Some(origin) = origins[*abstract_path.last().unwrap()] {
+            abstract_path.push(origin);
+        }
+        abstract_path.reverse();
+
+        // Refinement of each abstract step to a path over the map
+        let mut path = vec![start];
+
+        for step in abstract_path.windows(2) {
+            let (from, to) = (position(step[0]), position(step[1]));
+
+            if self.cluster_of(from) != self.cluster_of(to) {
+                // a transition between two clusters
+                path.push(to);
+            } else {
+                let search = self.cluster_search(from, false);
+                let mut segment = search.path_to(to);
+                segment.pop();
+                segment.reverse();
+                path.extend(segment);
+            }
+        }
+
+        Ok(Some(BidiPath::new(path, total_cost)))
+    }
+
+    fn cluster_of(&self, pos: (usize, usize)) -> (usize, usize) {
+        (pos.0 / self.cluster_size, pos.1 / self.cluster_size)
+    }
+
+    fn cluster_rect(&self, pos: (usize, usize)) -> BidiRect {
+        let (cx, cy) = self.cluster_of(pos);
+        let (x, y) = (cx * self.cluster_size, cy * self.cluster_size);
+
+        BidiRect::new(
+            x,
+            y,
+            self.cluster_size.min(self.costs.width() - x),
+            self.cluster_size.min(self.costs.height() - y),
+        )
+    }
+
+    fn cluster_nodes(&self, pos: (usize, usize)) -> impl Iterator<Item = usize> + '_ {
+        let rect = self.cluster_rect(pos);
+        rect.y_range().flat_map(move |y| {
+            rect.x_range()
+                .filter_map(move |x| self.node_indices.get(&(x, y)).copied())
+        })
+    }
+
+    fn add_node(&mut self, pos: (usize, usize)) -> usize {
+        if let Some(node) = self.node_indices.get(&pos) {
+            return *node;
+        }
+
+        self.nodes.push(pos);
+        self.edges.push(Vec::new());
+        self.node_indices.insert(pos, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    fn add_transition(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let (cost_a, cost_b) = match (self.costs[a], self.costs[b]) {
+            (Some(cost_a), Some(cost_b)) => (cost_a, cost_b),
+            _ => return,
+        };
+
+        let (node_a, node_b) = (self.add_node(a), self.add_node(b));
+        self.edges[node_a].push((node_b, cost_b));
+        self.edges[node_b].push((node_a, cost_a));
+    }
+
+    fn build_entrances(&mut self) {
+        let (width, height) = self.costs.size();
+        let size = self.cluster_size;
+
+        // vertical borders, between (x, y) and (x + 1, y)
+        for x in (size - 1..width.saturating_sub(1)).step_by(size) {
+            for cy in (0..height).step_by(size) {
+                let ys = (cy..(cy + size).min(height)).collect::<Vec<_>>();
+                for (a, b) in self.entrance_runs(&ys, |y| ((x, y), (x + 1, y))) {
+                    self.add_transition(a, b);
+                }
+            }
+        }
+
+        // horizontal borders, between (x, y) and (x, y + 1)
+        for y in (size - 1..height.saturating_sub(1)).step_by(size) {
+            for cx in (0..width).step_by(size) {
+                let xs = (cx..(cx + size).min(width)).collect::<Vec<_>>();
+                for (a, b) in self.entrance_runs(&xs, |x| ((x, y), (x, y + 1))) {
+                    self.add_transition(a, b);
+                }
+            }
+        }
+    }
+
+    /// Finds the runs of passable pairs of locations along a border, returning
+    /// the pairs where transitions must be placed: one in the middle of short
+    /// runs, two at the ends of long runs.
+    fn entrance_runs<F>(&self, coords: &[usize], pair: F) -> Vec<((usize, usize), (usize, usize))>
+    where
+        F: Fn(usize) -> ((usize, usize), (usize, usize)),
+    {
+        const LONG_RUN: usize = 6;
+
+        let passable = |i: usize| {
+            let (a, b) = pair(coords[i]);
+            self.costs[a].is_some() && self.costs[b].is_some()
+        };
+
+        let mut transitions = Vec::new();
+        let mut i = 0;
+
+        while i < coords.len() {
+            if !passable(i) {
+                i += 1;
+                continue;
+            }
+
+            let begin = i;
+            while i < coords.len() && passable(i) {
+                i += 1;
+            }
+            let end = i - 1;
+
+            if end - begin + 1 >= LONG_RUN {
+                transitions.push(pair(coords[begin]));
+                transitions.push(pair(coords[end]));
+            } else {
+                transitions.push(pair(coords[(begin + end) / 2]));
+            }
+        }
+
+        transitions
+    }
+
+    fn build_intra_edges(&mut self) {
+        for node in 0..self.nodes.len() {
+            let pos = self.nodes[node];
+            let search = self.cluster_search(pos, false);
+
+            let edges = self
+                .cluster_nodes(pos)
+                .filter(|other| *other != node)
+                .filter_map(|other| Some((other, search.cost_to(self.nodes[other])?)))
+                .collect::<Vec<_>>();
+
+            self.edges[node].extend(edges);
+        }
+    }
+
+    /// Performs a search restricted to the cluster of `from`. If `reverse` is
+    /// true, the costs found are the costs of reaching `from` from each location,
+    /// rather than the costs of reaching each location from `from`.
+    fn cluster_search(&self, from: (usize, usize), reverse: bool) -> ClusterSearch<C> {
+        let rect = self.cluster_rect(from);
+        let local = |pos: (usize, usize)| (pos.0 - rect.x, pos.1 - rect.y);
+        let mut tiles =
+            BidiArray::<PathFindDataTile<C>>::with_size_default(rect.width, rect.height);
+        let mut queue = BinaryHeap::new();
+        let mut neighbours = self.neighbouring.prealloc_vec();
+
+        tiles[local(from)].cost = Some(C::default());
+        queue.push(Adjacency {
+            estimated_cost: C::default(),
+            actual_cost: C::default(),
+            position: from,
+            origin: from,
+        });
+
+        while let Some(adjacency) = queue.pop() {
+            let pos = adjacency.position;
+            if tiles[local(pos)]
+                .cost
+                .map_or(false, |c| adjacency.actual_cost > c)
+            {
+                continue;
+            }
+
+            self.neighbouring.generate_points_on(
+                &mut neighbours,
+                (pos.0 - rect.x, pos.1 - rect.y),
+                rect.width,
+                rect.height,
+            );
+
+            while let Some((nx, ny)) = neighbours.pop() {
+                let neighbour = (nx + rect.x, ny + rect.y);
+                let step = if reverse {
+                    self.costs[neighbour].and(self.costs[pos])
+                } else {
+                    self.costs[neighbour]
+                };
+
+                let cost = match step.and_then(|s| adjacency.actual_cost.add_cost(s)) {
+                    Some(cost) => cost,
+                    None => continue,
+                };
+
+                if tiles[(nx, ny)].cost.map_or(true, |c| cost < c) {
+                    tiles[(nx, ny)].cost = Some(cost);
+                    tiles[(nx, ny)].origin = Some(pos);
+                    queue.push(Adjacency {
+                        estimated_cost: cost,
+                        actual_cost: cost,
+                        position: neighbour,
+                        origin: pos,
+                    });
+                }
+            }
+        }
+
+        ClusterSearch { rect, tiles }
+    }
+}
+
+struct ClusterSearch<C: PathFindCost> {
+    rect: BidiRect,
+    tiles: BidiArray<PathFindDataTile<C>>,
+}
+
+impl<C: PathFindCost> ClusterSearch<C> {
+    fn cost_to(&self, pos: (usize, usize)) -> Option<C> {
+        if self.rect.contains(pos.0, pos.1) {
+            self.tiles[(pos.0 - self.rect.x, pos.1 - self.rect.y)].cost
+        } else {
+            None
+        }
+    }
+
+    /// Returns the path from `pos` back to the origin of the search.
+    fn path_to(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        let mut path = vec![pos];
+        let mut pos = pos;
+
+        while let Some(origin) = self.tiles[(pos.0 - self.rect.x, pos.1 - self.rect.y)].origin {
+            path.push(origin);
+            pos = origin;
+        }
+
+        path
+    }
+}
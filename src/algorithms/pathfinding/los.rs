@@ -0,0 +1,56 @@
+use super::*;
+
+/// Returns true if there is a line of sight between the locations `a` and `b`
+/// of the `view`, that is if no location covered by the segment between their
+/// centers (see [`BidiLine`]) blocks the sight.
+///
+/// `is_blocking` is a closure like `fn(elem: &T, pos: (usize, usize)) -> bool`,
+/// which should return true for the locations that block the sight. The two
+/// endpoints are never checked, so that (for example) a wall can be seen. When
+/// the segment passes exactly through a corner, a blocking location on either
+/// side of the corner blocks the sight.
+///
+/// Returns false if `a` or `b` are outside of the view.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::bidivec;
+/// use bidivec::pathfinding;
+///
+/// let map = bidivec!{
+///     ['.', '.', '.', '.'],
+///     ['.', '#', '.', '.'],
+///     ['.', '.', '.', '.'],
+/// };
+///
+/// let blocking = |tile: &char, _| *tile == '#';
+///
+/// assert!(pathfinding::line_of_sight(&map, (0, 0), (3, 0), blocking));
+/// assert!(!pathfinding::line_of_sight(&map, (0, 1), (3, 1), blocking));
+/// assert!(!pathfinding::line_of_sight(&map, (0, 0), (2, 2), blocking));
+/// assert!(pathfinding::line_of_sight(&map, (0, 0), (1, 1), blocking));
+///
+/// // remove redundant waypoints from a path
+/// let path = [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2)];
+/// let smoothed = pathfinding::smooth(&path, |a, b| pathfinding::line_of_sight(&map, a, b, blocking));
+/// assert_eq!(smoothed, vec![(0, 0), (3, 0), (3, 2)]);
+/// ```
+pub fn line_of_sight<T, V, FB>(
+    view: &V,
+    a: (usize, usize),
+    b: (usize, usize),
+    is_blocking: FB,
+) -> bool
+where
+    V: BidiView<Output = T> + Sized,
+    FB: Fn(&T, (usize, usize)) -> bool,
+{
+    if !view.is_in_bounds(a.0, a.1) || !view.is_in_bounds(b.0, b.1) {
+        return false;
+    }
+
+    BidiLine::new(a, b)
+        .filter(|pos| *pos != a && *pos != b)
+        .all(|pos| !is_blocking(&view[pos], pos))
+}
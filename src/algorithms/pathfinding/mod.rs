@@ -0,0 +1,1237 @@
+//! This module provides functionality to find the shortest path between two
+//! points on a 2D map represented by a [`BidiView`][crate::BidiView], using Djikstra algorithm
+//! on single source ([`pathfind_to_whole`]), multiple destinations and either Djikstra or A* for
+//! singe-source, single-destination path findings ([`pathfind_to_dest`] and
+//! [`pathfind_to_dest_heuristic`]).
+//!
+//! Parameters of the path are customizable, for example diagonal movement might
+//! or might not be allowed (using [`BidiNeighbours`][crate::BidiNeighbours]); movement,
+//! however, might happen only between adjacent or bordering tiles, that is,
+//! from a tile to the 4 or 8 tiles directly surrounding it. For other kinds of
+//! movement (hexagonal grids, wrapping maps, portals, etc.) the possible movements
+//! can be generated by a custom closure, using [`pathfind_to_dest_custom`] and
+//! [`pathfind_to_whole_custom`]; [`wrapping_neighbours`] generates the movements
+//! of maps wrapping around their edges.
+//!
+//! The cost of travelling from one tile to another, if possible at all, is
+//! customizable and can be expressed with any unsigned integer, with a floating
+//! point number, or even custom types (whatever sense it might make). See
+//! the [`PathFindCost`] trait for further details.
+//!
+//! To get started, start from the functions: [`pathfind_to_whole`], [`pathfind_to_dest`]
+//! and [`pathfind_to_dest_heuristic`].
+//!
+//! On large maps, [`pathfind_to_dest_bidirectional`] searches from both ends of the path at
+//! the same time, which usually explores fewer locations.
+//!
+//! To restrict the exploration (e.g. to the movement range of a unit, or to a maximum
+//! number of visited locations), [`pathfind_to_dest_limited`] and [`pathfind_to_whole_limited`]
+//! accept [`PathFindLimits`]; [`reachable`] directly computes the movement range from a
+//! location. Long searches can be run a few locations at a time (e.g. one batch per frame)
+//! with [`PathFindSearch`].
+//!
+//! To find the nearest of multiple destinations (or starts), [`pathfind_to_nearest`] and
+//! [`pathfind_from_nearest`] perform a single search instead of one per destination.
+//!
+//! To move many agents towards a shared set of goals, [`flow_field`] computes the best
+//! direction to move in from every location with a single search.
+//!
+//! On maps that change a few locations at a time, [`DStarLite`] keeps the state of
+//! the search alive, to repair the path incrementally instead of searching again.
+//! On large maps, [`HierarchicalPathfinder`] answers long-distance queries much faster,
+//! by precomputing paths between clusters of locations.
+//!
+//! Paths are returned as [`BidiPath`] values, offering the locations and directions of
+//! their steps; they can be post-processed with [`smooth`], to remove redundant waypoints.
+//! For navigation over open terrain, [`pathfind_to_dest_any_angle`] directly finds paths
+//! made of straight segments in any direction (using Theta*).
+//!
+//! The locations visible from a point of view (e.g. for roguelike visibility) can be
+//! computed with [`fov`], while [`line_of_sight`] checks the visibility between two locations.
+//!
+//! Ready made heuristics for A* (and helpers to check their admissibility) are
+//! provided by the [`heuristics`] module.
+//!
+//! Custom best-first searches (e.g. the propagation of an influence map) can be built on
+//! [`BidiCostGrid`], which holds the best costs found so far and the queue of the locations
+//! to explore.
+//!
+//! # Examples
+//!
+//! ```
+//! use bidivec::*;
+//! use bidivec::pathfinding::*;
+//!
+//! // The width of the map
+//! const WIDTH: usize = 9;
+//!
+//! // The map
+//! const DATA: &[u8] = b"\
+//!      ##########\
+//!      ##  S#   #\
+//!      ## ### # #\
+//!      ##     # #\
+//!      ## ### # #\
+//!      ##   # #D#\
+//!      ##########\
+//! ";
+//!
+//! pub fn main() -> Result<(), BidiError> {
+//!     // Load the map in a BidiSlice
+//!     let map = BidiSlice::new(DATA, WIDTH)?;
+//!
+//!     // Find the source point
+//!     let start = map.iter().with_coords().find_map(|(x, y, t)| {
+//!         if *t == b'S' {
+//!             Some((x, y))
+//!         } else {
+//!             None
+//!         }
+//!     }).unwrap();
+//!
+//!     // Find the destination point
+//!     let dest = map.iter().with_coords().find_map(|(x, y, t)| {
+//!         if *t == b'D' {
+//!             Some((x, y))
+//!         } else {
+//!             None
+//!         }
+//!     }).unwrap();
+//!
+//!     // Find the shortest path
+//!     let res = pathfind_to_dest(
+//!         &map,
+//!         start,
+//!         dest,
+//!         BidiNeighbours::Adjacent,
+//!         |_, _, to, _| if *to == b'#' {
+//!             None
+//!         } else {
+//!             Some(1u32)
+//!         }
+//!     ).unwrap();
+//!
+//!     // Print the result of the path
+//!     if let PathFindDataResult::ShortestPathFound(cost) = res.result {
+//!         for y in 0..map.height() {
+//!             for x in 0..map.width() {
+//!                 if map[(x, y)] == b' ' && res.tiles[(x, y)].in_shortest_path {
+//!                     print!(".");
+//!                 } else {
+//!                     print!("{}", map[(x, y)] as char)
+//!                 }
+//!             }
+//!             println!();
+//!         }
+//!
+//!         println!("Path cost: {}", cost);
+//!     } else {
+//!         println!("Path not found 🤷");
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! This will print:
+//!
+//! ```text
+//! $ cargo run --quiet
+//! ##########
+//! ##**S#***#
+//! ##*###*#*#
+//! ##*****#*#
+//! ## ### #*#
+//! ##   # #D#
+//! ##########
+//! Path cost: 16
+//! ```
+
+use crate::*;
+use std::cmp::*;
+use std::collections::BinaryHeap;
+use std::num::FpCategory;
+use std::ops::Add;
+
+mod any_angle;
+mod bidirectional;
+mod cost_grid;
+mod dstar_lite;
+mod flow_field;
+mod fov;
+pub mod heuristics;
+mod hierarchical;
+mod los;
+mod path;
+mod search;
+mod smoothing;
+mod wrapping;
+
+pub use self::any_angle::pathfind_to_dest_any_angle;
+pub use self::bidirectional::pathfind_to_dest_bidirectional;
+pub use self::cost_grid::BidiCostGrid;
+pub use self::dstar_lite::DStarLite;
+pub use self::flow_field::{flow_field, FlowFieldTile};
+pub use self::fov::fov;
+pub use self::hierarchical::HierarchicalPathfinder;
+pub use self::los::line_of_sight;
+pub use self::path::BidiPath;
+pub use self::search::{PathFindSearch, PathFindStatus};
+pub use self::smoothing::smooth;
+pub use self::wrapping::{wrapping_neighbours, PathFindWrap};
+
+/// A trait that is implemented by types that can be used to represent the cost in the
+/// pathfinding algorithms. An implementation is offered out of the box for all the
+/// primitive integer types and for [`f32`] and [`f64`], so that, for example, terrains
+/// with fractional movement costs can be expressed directly. Usage of one of the
+/// unsigned integer types is recommended, however.
+///
+/// Integer costs never overflow: movements that would make the total cost of a path
+/// overflow are treated as impossible (see [`add_cost`][PathFindCost::add_cost]), and
+/// negative costs of signed types are treated as impossible movements.
+///
+/// Types implementing this trait must implement at least [`PartialEq`], [`PartialOrd`],
+/// [`Copy`], [`Clone`], [`Default`] and [`Add<Output = Self>`][Add].
+///
+/// Types that implement [`Ord`] and [`Eq`] and do not have negative values are free to
+/// use an empty implementation.
+///
+/// Types that are not [`Ord`] should provide a [`normalize`][PathFindCost::normalize] method
+/// that takes a value and returns [`None`] if that value would break [`Ord`].
+/// For example, for floating point types, infinites and NaNs are normalized as [`None`]
+/// (negative zero is treated as zero).
+///
+/// # Panics
+/// If [`normalize`][PathFindCost::normalize] fails to normalize a value, the
+/// pathfinding algorithm may panic or enter an infinite loop. This can never
+/// happen with unsigned types correctly implementing [`Ord`].
+pub trait PathFindCost:
+    PartialEq + PartialOrd + Copy + Clone + Default + Add<Output = Self>
+{
+    /// Types implementing [`PathFindCost`] that do not have full ordering or that
+    /// are signed, should implement `normalize` so that those values that are either
+    /// negative or uncomparable, are either normalized to some value or to None.
+    ///
+    /// Implementors should handle all the edge cases of the types they are implementing
+    /// the trait for.
+    ///
+    /// # Examples
+    ///
+    /// This is how `normalize` is internally implemented for floating point types.
+    ///
+    /// ```ignore
+    /// // This is how `normalize` is internally implemented for f32.
+    /// // This is provided as a reference, don't copy/paste this into your code!
+    /// impl PathFindCost for f32 {
+    ///     fn normalize(self) -> Option<Self> {
+    ///         match self.classify() {
+    ///             // Default infinites and NaNs to None (that is,
+    ///             // no path is available).
+    ///             FpCategory::Infinite | FpCategory::Nan => None,
+    ///             // Handle zero separately, so that negative zero is
+    ///             // handled well.
+    ///             FpCategory::Zero => Some(0f32),
+    ///             // Do not allow negative results: if negative it's
+    ///             // unreachable
+    ///             _ if self.is_sign_negative() => None,
+    ///             // Other floating point numbers should be fine (at worst
+    ///             // imprecise).
+    ///             _ => Some(self),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn normalize(self) -> Option<Self> {
+        Some(self)
+    }
+
+    /// Adds two costs, returning [`None`] if the result can't be represented
+    /// (e.g. on integer overflow). Movements whose total cost overflows are
+    /// treated as impossible by the pathfinding algorithms.
+    ///
+    /// The default implementation uses [`Add`], so types that can overflow
+    /// should implement this method using checked arithmetic.
+    fn add_cost(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+macro_rules! impl_unsigned_cost {
+    ($($t:ty),*) => {
+        $(impl PathFindCost for $t {
+            fn add_cost(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_signed_cost {
+    ($($t:ty),*) => {
+        $(impl PathFindCost for $t {
+            fn normalize(self) -> Option<Self> {
+                // Do not allow negative costs: if negative it's unreachable
+                if self < 0 {
+                    None
+                } else {
+                    Some(self)
+                }
+            }
+
+            fn add_cost(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+        })*
+    };
+}
+
+impl_unsigned_cost!(u8, u16, u32, u64, u128, usize);
+impl_signed_cost!(i8, i16, i32, i64, i128, isize);
+impl PathFindCost for f32 {
+    fn normalize(self) -> Option<Self> {
+        match self.classify() {
+            // Default infinites and NaNs to None (that is,
+            // no path is available).
+            FpCategory::Infinite | FpCategory::Nan => None,
+            // Handle zero separately, so that negative zero is
+            // handled well.
+            FpCategory::Zero => Some(0f32),
+            // Do not allow negative results: if negative it's
+            // unreachable
+            _ if self.is_sign_negative() => None,
+            // Other floating point numbers should be fine (at worst
+            // imprecise).
+            _ => Some(self),
+        }
+    }
+}
+impl PathFindCost for f64 {
+    fn normalize(self) -> Option<Self> {
+        match self.classify() {
+            FpCategory::Infinite | FpCategory::Nan => None,
+            FpCategory::Zero => Some(0f64),
+            _ if self.is_sign_negative() => None,
+            _ => Some(self),
+        }
+    }
+}
+
+/// A single location in the result data of a pathfinding operation.
+#[derive(Default)]
+pub struct PathFindDataTile<C: PathFindCost> {
+    /// The previous location in the path if the path to this
+    /// tile was calculated, [`None`] otherwise.
+    pub origin: Option<(usize, usize)>,
+    /// The cost to reach this location if the path to this
+    /// tile was calculated, [`None`] otherwise.
+    pub cost: Option<C>,
+    /// True if this tile is part of the optimal path (valid
+    /// only for those pathfindings that lead to a single
+    /// destination).
+    pub in_shortest_path: bool,
+}
+
+/// The result type of the pathfinding operation
+pub enum PathFindDataResult<C: PathFindCost> {
+    /// The pathfinding was towards multiple destinations
+    MultipleDestinations,
+    /// The pathfinding was towards a single destination and
+    /// a path was found (the cost is included in this enum).
+    ShortestPathFound(C),
+    /// The pathfinding was towards a single destination, and
+    /// no valid path was found.
+    PathNotFound,
+}
+
+/// The resulting data from a pathfinding run
+pub struct PathFindData<C: PathFindCost> {
+    /// The result of the run
+    pub result: PathFindDataResult<C>,
+    /// Data for the possible tiles
+    pub tiles: BidiArray<PathFindDataTile<C>>,
+    /// The destination reached by the shortest path, if a path was found
+    /// (useful when searching for the nearest of multiple destinations).
+    pub destination: Option<(usize, usize)>,
+}
+
+impl<C: PathFindCost> PathFindData<C> {
+    /// Returns the shortest path found, from the start to the destination, or [`None`]
+    /// if the pathfinding was towards multiple destinations or no path was found.
+    pub fn path(&self) -> Option<BidiPath<C>> {
+        self.path_to(self.destination?)
+    }
+
+    /// Returns the path from the start to the location at `pos`, or [`None`] if
+    /// the path to that location wasn't calculated (or `pos` is out of bounds).
+    ///
+    /// This is mostly useful with the results of [`pathfind_to_whole`], where the
+    /// paths to all the reachable locations are calculated.
+    pub fn path_to(&self, pos: (usize, usize)) -> Option<BidiPath<C>> {
+        let cost = self.tiles.get(pos.0, pos.1)?.cost?;
+        let mut coords = vec![pos];
+        let mut pos = pos;
+
+        while let Some(origin) = self.tiles[pos].origin.filter(|origin| *origin != pos) {
+            coords.push(origin);
+            pos = origin;
+        }
+
+        coords.reverse();
+        Some(BidiPath::new(coords, cost))
+    }
+}
+
+/// The closure called when visiting a location, see [`PathFindLimits::with_on_visit`].
+type VisitFn<'a, C> = dyn FnMut((usize, usize), C) -> bool + 'a;
+
+/// Limits to the exploration done by a pathfinding search, used by
+/// [`pathfind_to_dest_limited`] and [`pathfind_to_whole_limited`].
+///
+/// By default no limit is set; limits are added with [`with_max_cost`][PathFindLimits::with_max_cost]
+/// and [`with_on_visit`][PathFindLimits::with_on_visit].
+pub struct PathFindLimits<'a, C: PathFindCost> {
+    max_cost: Option<C>,
+    on_visit: Option<Box<VisitFn<'a, C>>>,
+}
+
+impl<'a, C: PathFindCost> Default for PathFindLimits<'a, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, C: PathFindCost> PathFindLimits<'a, C> {
+    /// Creates a new [`PathFindLimits`] which does not limit the search.
+    pub fn new() -> Self {
+        Self {
+            max_cost: None,
+            on_visit: None,
+        }
+    }
+
+    /// Limits the search to the locations that can be reached with a cost lower
+    /// than or equal to `max_cost` (e.g. the movement points of a unit); the
+    /// locations beyond the budget are not explored at all.
+    ///
+    /// A negative budget (or a NaN one, for floating point costs) leaves only the
+    /// starting location reachable.
+    pub fn with_max_cost(mut self, max_cost: C) -> Self {
+        // not normalized: an invalid budget must not mean "no limit", and since
+        // movement costs are never negative, comparisons with it always fail
+        self.max_cost = Some(max_cost);
+        self
+    }
+
+    /// Sets a closure like `fn(pos: (usize, usize), cost: C) -> bool` that is called
+    /// every time the shortest path to a location is found, in order of increasing cost
+    /// (if no heuristic is used). The search stops as soon as the closure returns false,
+    /// leaving the locations not yet visited unexplored.
+    pub fn with_on_visit<F>(mut self, on_visit: F) -> Self
+    where
+        F: FnMut((usize, usize), C) -> bool + 'a,
+    {
+        self.on_visit = Some(Box::new(on_visit));
+        self
+    }
+
+    fn allows(&self, cost: C) -> bool {
+        self.max_cost.map_or(true, |max_cost| cost <= max_cost)
+    }
+
+    fn visit(&mut self, pos: (usize, usize), cost: C) -> bool {
+        match &mut self.on_visit {
+            Some(on_visit) => on_visit(pos, cost),
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq)]
+struct Adjacency<C: PathFindCost> {
+    pub estimated_cost: C,
+    pub actual_cost: C,
+    pub position: (usize, usize),
+    pub origin: (usize, usize),
+}
+impl<C: PathFindCost> Eq for Adjacency<C> {}
+
+impl<C: PathFindCost> Ord for Adjacency<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimated_cost
+            .partial_cmp(&self.estimated_cost)
+            .expect("non-normalized value in pathfinding")
+    }
+}
+
+impl<C: PathFindCost> PartialOrd for Adjacency<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The list of movements possible from a location, with their costs.
+type Moves<C> = Vec<((usize, usize), C)>;
+
+/// The state of a Djikstra or A* search, that can be advanced a few locations
+/// at a time (see [`PathFindSearch`]).
+struct SearchCore<C: PathFindCost, FN, FH> {
+    rect: BidiRect,
+    dest_mask: Option<BidiArray<bool>>,
+    adiacent: BinaryHeap<Adjacency<C>>,
+    data: PathFindData<C>,
+    neighbours_func: FN,
+    heuristic: FH,
+    neighbours: Moves<C>,
+    finished: bool,
+}
+
+impl<C, FN, FH> SearchCore<C, FN, FH>
+where
+    C: PathFindCost,
+    FN: FnMut((usize, usize), &mut Moves<C>),
+    FH: Fn((usize, usize)) -> C,
+{
+    fn new(
+        (width, height): (usize, usize),
+        starts: &[(usize, usize)],
+        dests: Option<&[(usize, usize)]>,
+        neighbours_func: FN,
+        heuristic: FH,
+    ) -> Result<Self, BidiError> {
+        let rect = BidiRect::new(0, 0, width, height);
+        if let Some(start) = starts.iter().find(|s| !rect.contains(s.0, s.1)) {
+            return Err(BidiError::out_of_bounds(start.0, start.1, (width, height)));
+        }
+
+        let mut dest_mask = None;
+
+        if let Some(dests) = dests {
+            let mut mask = BidiArray::with_elem(false, width, height);
+
+            for d in dests.iter() {
+                if !rect.contains(d.0, d.1) {
+                    return Err(BidiError::out_of_bounds(d.0, d.1, (width, height)));
+                }
+                mask[*d] = true;
+            }
+
+            dest_mask = Some(mask);
+        }
+
+        let mut adiacent = BinaryHeap::<Adjacency<C>>::new();
+
+        for &start in starts.iter() {
+            adiacent.push(Adjacency {
+                estimated_cost: C::default(),
+                actual_cost: C::default(),
+                position: start,
+                origin: start,
+            });
+        }
+
+        Ok(Self {
+            rect,
+            dest_mask,
+            adiacent,
+            data: PathFindData {
+                result: if dests.is_some() {
+                    PathFindDataResult::PathNotFound
+                } else {
+                    PathFindDataResult::MultipleDestinations
+                },
+                tiles: BidiArray::with_size_default(width, height),
+                destination: None,
+            },
+            neighbours_func,
+            heuristic,
+            neighbours: Vec::new(),
+            finished: false,
+        })
+    }
+
+    /// Explores up to `max_visits` locations, returning true if the search is finished.
+    fn step(&mut self, max_visits: usize, limits: &mut PathFindLimits<'_, C>) -> bool {
+        let mut visits = 0;
+
+        while !self.finished && visits < max_visits {
+            let adjacency = match self.adiacent.pop() {
+                Some(adjacency) => adjacency,
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            };
+
+            let cur_cost = {
+                let cur_tile = &mut self.data.tiles[adjacency.position];
+
+                if let Some(cost) = cur_tile.cost {
+                    if adjacency.actual_cost >= cost {
+                        continue;
+                    }
+                }
+
+                cur_tile.origin = Some(adjacency.origin);
+                cur_tile.cost = Some(adjacency.actual_cost);
+                adjacency.actual_cost
+            };
+
+            visits += 1;
+
+            if let Some(mask) = &self.dest_mask {
+                if mask[adjacency.position] {
+                    self.data.result = PathFindDataResult::ShortestPathFound(cur_cost);
+                    self.data.destination = Some(adjacency.position);
+                    limits.visit(adjacency.position, cur_cost);
+                    self.finished = true;
+                    break;
+                }
+            }
+
+            if !limits.visit(adjacency.position, cur_cost) {
+                self.finished = true;
+                break;
+            }
+
+            self.neighbours.clear();
+            (self.neighbours_func)(adjacency.position, &mut self.neighbours);
+
+            for &(neighbour, cost) in self.neighbours.iter() {
+                if !self.rect.contains(neighbour.0, neighbour.1) {
+                    continue;
+                }
+
+                if let Some(cost) = cost.normalize() {
+                    if match self.data.tiles[neighbour].cost {
+                        None => true,
+                        Some(old_cost) => cost < old_cost,
+                    } {
+                        // an overflowing cost (or one over the budget) is treated as an impassable movement
+                        let actual_cost = match cur_cost.add_cost(cost) {
+                            Some(c) if limits.allows(c) => c,
+                            _ => continue,
+                        };
+
+                        self.adiacent.push(Adjacency {
+                            estimated_cost: {
+                                let estimate =
+                                    (self.heuristic)(neighbour).normalize().unwrap_or_default();
+                                actual_cost.add_cost(estimate).unwrap_or(actual_cost)
+                            },
+                            actual_cost,
+                            position: neighbour,
+                            origin: adjacency.position,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.finished
+    }
+
+    /// Ends the search, marking the locations of the shortest path (if found).
+    fn finish(mut self) -> PathFindData<C> {
+        if let PathFindDataResult::ShortestPathFound(_) = self.data.result {
+            let mut pos = self.data.destination;
+
+            while let Some(p) = pos {
+                self.data.tiles[p].in_shortest_path = true;
+                pos = self.data.tiles[p].origin.filter(|origin| *origin != p);
+            }
+        }
+
+        self.data
+    }
+}
+
+fn pathfind_core<FN, FH, C>(
+    size: (usize, usize),
+    starts: &[(usize, usize)],
+    dests: Option<&[(usize, usize)]>,
+    neighbours_func: FN,
+    heuristic: FH,
+    limits: &mut PathFindLimits<'_, C>,
+) -> Result<PathFindData<C>, BidiError>
+where
+    C: PathFindCost,
+    FN: FnMut((usize, usize), &mut Moves<C>),
+    FH: Fn((usize, usize)) -> C,
+{
+    let mut search = SearchCore::new(size, starts, dests, neighbours_func, heuristic)?;
+    search.step(std::usize::MAX, limits);
+    Ok(search.finish())
+}
+
+/// Adapts a grid-based movement (with a `neighbouring` and a `cost_func`)
+/// to the neighbours function used by [`pathfind_core`].
+fn grid_neighbours<'a, T, V, FC, C>(
+    view: &'a V,
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> impl FnMut((usize, usize), &mut Moves<C>) + 'a
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C> + 'a,
+{
+    let mut points = neighbouring.prealloc_vec();
+    let (width, height) = view.size();
+
+    move |pos, neighbours| {
+        let from = &view[pos];
+        neighbouring.generate_points_on(&mut points, pos, width, height);
+
+        while let Some(neighbour) = points.pop() {
+            if let Some(cost) = cost_func(from, pos, &view[neighbour], neighbour) {
+                neighbours.push((neighbour, cost));
+            }
+        }
+    }
+}
+
+/// Adapts a user provided neighbours function to the one used by [`pathfind_core`].
+fn custom_neighbours<FN, I, C>(neighbours_func: FN) -> impl FnMut((usize, usize), &mut Moves<C>)
+where
+    FN: Fn((usize, usize)) -> I,
+    I: IntoIterator<Item = ((usize, usize), C)>,
+{
+    move |pos, neighbours| neighbours.extend(neighbours_func(pos))
+}
+
+/// Finds the shortest path between `start` and `dest` in the given
+/// `view` using the given `cost_func` to evaluate the cost of a
+/// given movement.
+///
+/// Under the hood, this uses the Djikstra algorithm.
+///
+/// `cost_func` is a closure like:
+/// `fn(from_elem: &T, from_pos: (usize, usize), to_elem: &T, to_pos: (usize, usize)) -> Option<C>`.
+///
+/// It should return the cost of a movement between `from_pos` to `to_pos` (the elements
+/// are also passed for convenience) and should return [`None`] if no such path exists, or
+/// the cost C if it does.
+pub fn pathfind_to_dest<T, V, FC, C>(
+    view: &V,
+    start: (usize, usize),
+    dest: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        Some(&[dest]),
+        grid_neighbours(view, neighbouring, cost_func),
+        |_| C::default(),
+        &mut PathFindLimits::new(),
+    )
+}
+
+/// Finds the shortest path between `start` and `dest` in the given
+/// `view` using the given `cost_func` to evaluate the cost of a
+/// given movement and an `heuristic` to estimate what is the best
+/// direction to go.
+///
+/// Under the hood, this uses the A* algorithm.
+///
+/// `cost_func` is a closure like:
+/// `fn(from_elem: &T, from_pos: (usize, usize), to_elem: &T, to_pos: (usize, usize)) -> Option<C>`.
+///
+/// It should return the cost of a movement between `from_pos` to `to_pos` (the elements
+/// are also passed for convenience) and should return [`None`] if no such path exists, or
+/// the cost C if it does.
+///
+/// `heuristic` is a closure like:
+/// `fn(from_pos: (usize, usize), to_pos: (usize, usize)) -> C`.
+///
+/// It should return the estimated cost of a movement between `from_pos` to `to_pos`.
+///
+/// The heuristic does not need, obviously, to provide and accurate estimate.
+///
+/// Underestimations have a performance impact, but they don't impact the ability to
+/// find a solution (in fact, `pathfind_to_dest` simply uses zero as the heuristic).
+/// Overestimations will improve runtime performances, but the result will not be the
+/// optimal one.
+///
+/// The [`heuristics`] module provides common heuristics (such as [`heuristics::manhattan`]),
+/// a way to weight them ([`heuristics::weighted`]) and a way to check them for
+/// admissibility ([`heuristics::find_inadmissible`]).
+pub fn pathfind_to_dest_heuristic<T, V, FC, FH, C>(
+    view: &V,
+    start: (usize, usize),
+    dest: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+    heuristic: FH,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        Some(&[dest]),
+        grid_neighbours(view, neighbouring, cost_func),
+        |pos| heuristic(pos, dest),
+        &mut PathFindLimits::new(),
+    )
+}
+
+/// Finds the shortest path between `start` and every other element in
+/// the given `view` using the given `cost_func` to evaluate the cost of
+/// a given movement.
+///
+/// Under the hood, this uses the Djikstra algorithm.
+///
+/// `cost_func` is a closure like:
+/// `fn(from_elem: &T, from_pos: (usize, usize), to_elem: &T, to_pos: (usize, usize)) -> Option<C>`.
+///
+/// It should return the cost of a movement between `from_pos` to `to_pos` (the elements
+/// are also passed for convenience) and should return [`None`] if no such path exists, or
+/// the cost C if it does.
+pub fn pathfind_to_whole<T, V, FC, C>(
+    view: &V,
+    start: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        None,
+        grid_neighbours(view, neighbouring, cost_func),
+        |_| C::default(),
+        &mut PathFindLimits::new(),
+    )
+}
+
+/// Like [`pathfind_to_dest_heuristic`], but the exploration is restricted by the
+/// given [`PathFindLimits`]: if `dest` can't be reached within the limits, the result
+/// is [`PathFindDataResult::PathNotFound`].
+///
+/// Use `|_, _| C::default()` as the `heuristic` to use the Djikstra algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{self, heuristics, PathFindDataResult, PathFindLimits};
+///
+/// let map = bidivec![1u32; 20, 20];
+///
+/// // is (15, 15) reachable within 8 movement points?
+/// let res = pathfinding::pathfind_to_dest_limited(
+///     &map,
+///     (10, 10),
+///     (15, 15),
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| Some(*to),
+///     heuristics::manhattan(1),
+///     &mut PathFindLimits::new().with_max_cost(8),
+/// )?;
+///
+/// assert!(matches!(res.result, PathFindDataResult::PathNotFound));
+///
+/// // give up after visiting 50 locations
+/// let mut visited = 0;
+/// let res = pathfinding::pathfind_to_dest_limited(
+///     &map,
+///     (0, 0),
+///     (19, 19),
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| Some(*to),
+///     |_, _| 0,
+///     &mut PathFindLimits::new().with_on_visit(|_, _| {
+///         visited += 1;
+///         visited < 50
+///     }),
+/// )?;
+///
+/// assert!(matches!(res.result, PathFindDataResult::PathNotFound));
+/// assert_eq!(visited, 50);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_dest_limited<T, V, FC, FH, C>(
+    view: &V,
+    start: (usize, usize),
+    dest: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+    heuristic: FH,
+    limits: &mut PathFindLimits<'_, C>,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        Some(&[dest]),
+        grid_neighbours(view, neighbouring, cost_func),
+        |pos| heuristic(pos, dest),
+        limits,
+    )
+}
+
+/// Like [`pathfind_to_whole`], but the exploration is restricted by the given
+/// [`PathFindLimits`]: the locations that can't be reached within the limits have
+/// no cost in the result.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{self, PathFindLimits};
+///
+/// let map = bidivec![1u32; 20, 20];
+///
+/// let res = pathfinding::pathfind_to_whole_limited(
+///     &map,
+///     (10, 10),
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| Some(*to),
+///     &mut PathFindLimits::new().with_max_cost(2),
+/// )?;
+///
+/// // the start, 4 locations at cost 1 and 8 locations at cost 2
+/// assert_eq!(res.tiles.iter().filter(|t| t.cost.is_some()).count(), 13);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_whole_limited<T, V, FC, C>(
+    view: &V,
+    start: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+    limits: &mut PathFindLimits<'_, C>,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        None,
+        grid_neighbours(view, neighbouring, cost_func),
+        |_| C::default(),
+        limits,
+    )
+}
+
+/// Computes the movement range from `start`: the cost of reaching each location of
+/// the `view` within `budget`, or [`None`] for the locations that can't be reached
+/// within it. Locations beyond the budget are never explored, so this is much faster
+/// than [`pathfind_to_whole`] on large maps.
+///
+/// See [`pathfind_to_dest`] for details on `cost_func`; use [`pathfind_to_whole_limited`]
+/// if the paths to the reachable locations are needed too.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding;
+///
+/// let map = bidivec!{
+///     [1, 1, 1, 1],
+///     [1, 3, 1, 1],
+///     [1, 1, 1, 1],
+/// };
+///
+/// let range = pathfinding::reachable(
+///     &map,
+///     (0, 0),
+///     2u32,
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| Some(*to),
+/// )?;
+///
+/// assert_eq!(range, bidivec!{
+///     [Some(0), Some(1), Some(2), None],
+///     [Some(1), None, None, None],
+///     [Some(2), None, None, None],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn reachable<T, V, FC, C>(
+    view: &V,
+    start: (usize, usize),
+    budget: C,
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> Result<BidiVec<Option<C>>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    let data = pathfind_to_whole_limited(
+        view,
+        start,
+        neighbouring,
+        cost_func,
+        &mut PathFindLimits::new().with_max_cost(budget),
+    )?;
+
+    Ok(BidiVec::with_size_func_xy(
+        view.width(),
+        view.height(),
+        |x, y| data.tiles[(x, y)].cost,
+    ))
+}
+
+/// Finds the shortest path between `start` and the nearest of the `dests` in the
+/// given `view`, using the given `cost_func` to evaluate the cost of a given movement.
+/// The destination that has been reached is returned in
+/// [`PathFindData::destination`].
+///
+/// Under the hood, this uses a single run of the Djikstra algorithm, which is much
+/// faster than searching a path to each destination.
+///
+/// See [`pathfind_to_dest`] for details on `cost_func`.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{self, PathFindDataResult};
+///
+/// let map = bidivec!{
+///     ['$', '.', '.', '.', '.', '$'],
+///     ['#', '#', '.', '#', '.', '.'],
+///     ['.', '.', '@', '#', '.', '$'],
+/// };
+///
+/// let res = pathfinding::pathfind_to_nearest(
+///     &map,
+///     (2, 2),
+///     &[(0, 0), (5, 0), (5, 2)],
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| if *to == '#' { None } else { Some(1u32) },
+/// )?;
+///
+/// assert!(matches!(res.result, PathFindDataResult::ShortestPathFound(4)));
+/// assert_eq!(res.destination, Some((0, 0)));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_nearest<T, V, FC, C>(
+    view: &V,
+    start: (usize, usize),
+    dests: &[(usize, usize)],
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        Some(dests),
+        grid_neighbours(view, neighbouring, cost_func),
+        |_| C::default(),
+        &mut PathFindLimits::new(),
+    )
+}
+
+/// Finds the shortest path between the nearest of the `starts` and `dest` in the
+/// given `view`, using the given `cost_func` to evaluate the cost of a given movement.
+/// The start the path begins from is the location in the shortest path whose
+/// [`origin`][PathFindDataTile::origin] is the location itself.
+///
+/// Under the hood, this uses a single run of the Djikstra algorithm, which is much
+/// faster than searching a path from each start.
+///
+/// See [`pathfind_to_dest`] for details on `cost_func`.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{self, PathFindDataResult};
+///
+/// let map = bidivec![1u32; 8, 3];
+///
+/// // which of the guards is the nearest to the alarm at (5, 1)?
+/// let res = pathfinding::pathfind_from_nearest(
+///     &map,
+///     &[(0, 0), (1, 2), (2, 1)],
+///     (5, 1),
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| Some(*to),
+/// )?;
+///
+/// assert!(matches!(res.result, PathFindDataResult::ShortestPathFound(3)));
+/// assert!(res.tiles[(2, 1)].in_shortest_path);
+/// assert_eq!(res.tiles[(2, 1)].origin, Some((2, 1)));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_from_nearest<T, V, FC, C>(
+    view: &V,
+    starts: &[(usize, usize)],
+    dest: (usize, usize),
+    neighbouring: BidiNeighbours,
+    cost_func: FC,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C>,
+{
+    pathfind_core(
+        view.size(),
+        starts,
+        Some(&[dest]),
+        grid_neighbours(view, neighbouring, cost_func),
+        |_| C::default(),
+        &mut PathFindLimits::new(),
+    )
+}
+
+/// Finds the shortest path between `start` and `dest` in the given `view`,
+/// using a custom `neighbours_func` to generate the possible movements from
+/// each location, and an optional `heuristic` (pass `|_, _| C::default()` or
+/// see the [`heuristics`] module).
+///
+/// Under the hood, this uses the A* algorithm (or the Djikstra algorithm if the
+/// heuristic always returns zero).
+///
+/// `neighbours_func` is a closure like:
+/// `fn(from_pos: (usize, usize)) -> impl IntoIterator<Item = ((usize, usize), C)>`.
+///
+/// It should return all the locations reachable with a single movement from `from_pos`,
+/// together with the cost of each movement. This allows movements that don't
+/// follow the grid adjacency, such as on hexagonal grids, on maps wrapping around
+/// their edges, or through teleporters and portals. Locations outside of the
+/// view are ignored. The view itself is only used to determine the size of the map.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiView};
+/// use bidivec::pathfinding::{self, PathFindDataResult};
+///
+/// // 'o' are two linked portals
+/// let map = bidivec!{
+///     ['S', '.', '#', '.', '.', '.'],
+///     ['.', 'o', '#', '.', 'o', '.'],
+///     ['.', '.', '#', '.', '.', 'D'],
+/// };
+///
+/// let res = pathfinding::pathfind_to_dest_custom(
+///     &map,
+///     (0, 0),
+///     (5, 2),
+///     |(x, y)| {
+///         let mut moves = Vec::new();
+///         for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)].iter() {
+///             let (nx, ny) = (x as isize + dx, y as isize + dy);
+///             match map.get_signed(nx, ny) {
+///                 Some('#') | None => (),
+///                 Some(_) => moves.push(((nx as usize, ny as usize), 1u32)),
+///             }
+///         }
+///         match (x, y) {
+///             (1, 1) => moves.push(((4, 1), 1)),
+///             (4, 1) => moves.push(((1, 1), 1)),
+///             _ => (),
+///         }
+///         moves
+///     },
+///     |_, _| 0,
+/// )?;
+///
+/// assert!(matches!(res.result, PathFindDataResult::ShortestPathFound(5)));
+/// assert!(res.tiles[(4, 1)].in_shortest_path);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_dest_custom<V, FN, I, FH, C>(
+    view: &V,
+    start: (usize, usize),
+    dest: (usize, usize),
+    neighbours_func: FN,
+    heuristic: FH,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView + ?Sized,
+    C: PathFindCost,
+    FN: Fn((usize, usize)) -> I,
+    I: IntoIterator<Item = ((usize, usize), C)>,
+    FH: Fn((usize, usize), (usize, usize)) -> C,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        Some(&[dest]),
+        custom_neighbours(neighbours_func),
+        |pos| heuristic(pos, dest),
+        &mut PathFindLimits::new(),
+    )
+}
+
+/// Finds the shortest path between `start` and every other element in
+/// the given `view`, using a custom `neighbours_func` to generate the possible
+/// movements from each location.
+///
+/// Under the hood, this uses the Djikstra algorithm.
+///
+/// See [`pathfind_to_dest_custom`] for details on `neighbours_func`.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiView};
+/// use bidivec::pathfinding;
+///
+/// let map = bidivec![0; 5, 1];
+///
+/// // a map wrapping around horizontally
+/// let res = pathfinding::pathfind_to_whole_custom(&map, (0, 0), |(x, y)| {
+///     vec![(((x + 1) % 5, y), 1u32), (((x + 4) % 5, y), 1u32)]
+/// })?;
+///
+/// assert_eq!(res.tiles[(4, 0)].cost, Some(1));
+/// assert_eq!(res.tiles[(3, 0)].cost, Some(2));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn pathfind_to_whole_custom<V, FN, I, C>(
+    view: &V,
+    start: (usize, usize),
+    neighbours_func: FN,
+) -> Result<PathFindData<C>, BidiError>
+where
+    V: BidiView + ?Sized,
+    C: PathFindCost,
+    FN: Fn((usize, usize)) -> I,
+    I: IntoIterator<Item = ((usize, usize), C)>,
+{
+    pathfind_core(
+        view.size(),
+        &[start],
+        None,
+        custom_neighbours(neighbours_func),
+        |_| C::default(),
+        &mut PathFindLimits::new(),
+    )
+}
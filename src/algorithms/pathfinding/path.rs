@@ -0,0 +1,159 @@
+use super::*;
+
+/// A path over a bidimensional map: the sequence of locations it goes through
+/// (including the start and the end) and its total cost.
+///
+/// Paths are returned by the pathfinders of this module (see for example
+/// [`PathFindData::path`] and [`DStarLite::path`]) and can also be built
+/// manually with [`BidiPath::new`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding;
+///
+/// let mut map = bidivec!{
+///     ['.', '.', '.'],
+///     ['#', '#', '.'],
+///     ['.', '.', '.'],
+/// };
+///
+/// let res = pathfinding::pathfind_to_dest(
+///     &map,
+///     (0, 0),
+///     (0, 2),
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| if *to == '#' { None } else { Some(1u32) },
+/// )?;
+///
+/// let path = res.path().unwrap();
+///
+/// assert_eq!(path.cost(), 6);
+/// assert_eq!(path.len(), 7);
+/// assert_eq!(path.iter_directions().take(3).collect::<Vec<_>>(), vec![(1, 0), (1, 0), (0, 1)]);
+///
+/// path.stamp(&mut map, '*')?;
+///
+/// assert_eq!(map, bidivec!{
+///     ['*', '*', '*'],
+///     ['#', '#', '*'],
+///     ['*', '*', '*'],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BidiPath<C: PathFindCost> {
+    coords: Vec<(usize, usize)>,
+    cost: C,
+}
+
+impl<C: PathFindCost> BidiPath<C> {
+    /// Creates a new path going through the given locations, with the given total cost.
+    pub fn new(coords: Vec<(usize, usize)>, cost: C) -> Self {
+        Self { coords, cost }
+    }
+
+    /// Returns the total cost of the path.
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// Returns the number of locations in the path, including the start and the end.
+    /// The number of steps is one less than this.
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Returns true if the path contains no locations.
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// Returns the first location of the path, or [`None`] if the path is empty.
+    pub fn start(&self) -> Option<(usize, usize)> {
+        self.coords.first().copied()
+    }
+
+    /// Returns the last location of the path, or [`None`] if the path is empty.
+    pub fn end(&self) -> Option<(usize, usize)> {
+        self.coords.last().copied()
+    }
+
+    /// Returns the locations of the path as a slice, from the start to the end.
+    pub fn coords(&self) -> &[(usize, usize)] {
+        &self.coords
+    }
+
+    /// Returns an iterator over the locations of the path, from the start to the end.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.coords.iter().copied()
+    }
+
+    /// Returns an iterator over the locations of the path as [`BidiPoint`]s, from
+    /// the start to the end.
+    pub fn iter_points(&self) -> impl Iterator<Item = BidiPoint> + '_ {
+        self.coords.iter().map(|p| BidiPoint::from(*p))
+    }
+
+    /// Returns an iterator over the steps of the path, as `(dx, dy)` offsets between
+    /// each location and the next one (e.g. `(1, 0)` for a step east, `(-1, -1)` for
+    /// a diagonal step north-west, in a map where y grows southwards); use
+    /// [`BidiDirection::from_offset`] to get named directions.
+    pub fn iter_directions(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.coords.windows(2).map(|step| {
+            (
+                step[1].0 as isize - step[0].0 as isize,
+                step[1].1 as isize - step[0].1 as isize,
+            )
+        })
+    }
+
+    /// Reverses the path in place, so that it goes from the end to the start.
+    ///
+    /// Note that the cost is kept as is, which is correct only if the costs of
+    /// the movements are symmetric.
+    pub fn reverse(&mut self) {
+        self.coords.reverse();
+    }
+
+    /// Sets all the locations of the path in `view` to `value`.
+    ///
+    /// If any location is outside of the view bounds, [`BidiError::OutOfBounds`]
+    /// is returned and the view is left untouched.
+    pub fn stamp<V>(&self, view: &mut V, value: V::Output) -> Result<(), BidiError>
+    where
+        V: BidiViewMut,
+        V::Output: Clone + Sized,
+    {
+        if let Some(p) = self.coords.iter().find(|p| !view.is_in_bounds(p.0, p.1)) {
+            return Err(BidiError::out_of_bounds(p.0, p.1, view.size()));
+        }
+
+        for p in self.coords.iter() {
+            view[*p] = value.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Consumes the path, returning its locations.
+    pub fn into_coords(self) -> Vec<(usize, usize)> {
+        self.coords
+    }
+}
+
+impl<C: PathFindCost> IntoIterator for BidiPath<C> {
+    type Item = (usize, usize);
+    type IntoIter = std::vec::IntoIter<(usize, usize)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coords.into_iter()
+    }
+}
+
+impl<C: PathFindCost> From<BidiPath<C>> for Vec<(usize, usize)> {
+    fn from(path: BidiPath<C>) -> Self {
+        path.coords
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+
+/// The closure generating the movements possible from a location.
+type NeighboursFn<'a, C> = dyn FnMut((usize, usize), &mut Moves<C>) + 'a;
+/// The closure estimating the cost from a location to the destination.
+type HeuristicFn<'a, C> = dyn Fn((usize, usize)) -> C + 'a;
+
+/// The status of a [`PathFindSearch`], returned by [`PathFindSearch::step`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PathFindStatus {
+    /// The search still has locations to explore.
+    Searching,
+    /// The search is finished: the destination was reached, the whole map
+    /// was explored or the search was stopped by its [`PathFindLimits`].
+    Finished,
+}
+
+/// A pathfinding search that can be run a few locations at a time, so that a long
+/// search can be spread over multiple frames (or cancelled, by simply dropping it)
+/// without using threads.
+///
+/// A search is created with [`to_dest`][PathFindSearch::to_dest] or
+/// [`to_whole`][PathFindSearch::to_whole], advanced with [`step`][PathFindSearch::step]
+/// and its results are retrieved with [`into_data`][PathFindSearch::into_data]; the
+/// results are the same as the ones of [`pathfind_to_dest_heuristic`] and
+/// [`pathfind_to_whole`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{heuristics, PathFindDataResult, PathFindSearch, PathFindStatus};
+///
+/// let map = bidivec![1u32; 100, 100];
+///
+/// let mut search = PathFindSearch::to_dest(
+///     &map,
+///     (0, 0),
+///     (99, 99),
+///     BidiNeighbours::Adjacent,
+///     |_, _, to, _| Some(*to),
+///     heuristics::manhattan(1),
+/// )?;
+///
+/// // explore at most 50 locations per frame
+/// let mut frames = 0;
+/// while search.step(50) == PathFindStatus::Searching {
+///     frames += 1;
+/// }
+///
+/// assert!(frames > 1);
+/// assert!(matches!(search.into_data().result, PathFindDataResult::ShortestPathFound(198)));
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub struct PathFindSearch<'a, C: PathFindCost> {
+    core: SearchCore<C, Box<NeighboursFn<'a, C>>, Box<HeuristicFn<'a, C>>>,
+    limits: PathFindLimits<'a, C>,
+}
+
+impl<'a, C: PathFindCost + 'a> PathFindSearch<'a, C> {
+    /// Creates a search for the shortest path between `start` and `dest` in the given
+    /// `view`. See [`pathfind_to_dest_heuristic`] for details on the parameters; use
+    /// `|_, _| C::default()` as the `heuristic` to use the Djikstra algorithm.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if `start` or `dest` are outside of the view.
+    pub fn to_dest<T, V, FC, FH>(
+        view: &'a V,
+        start: (usize, usize),
+        dest: (usize, usize),
+        neighbouring: BidiNeighbours,
+        cost_func: FC,
+        heuristic: FH,
+    ) -> Result<Self, BidiError>
+    where
+        V: BidiView<Output = T> + Sized,
+        T: 'a,
+        FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C> + 'a,
+        FH: Fn((usize, usize), (usize, usize)) -> C + 'a,
+    {
+        let neighbours_func: Box<NeighboursFn<'a, C>> =
+            Box::new(grid_neighbours(view, neighbouring, cost_func));
+        let heuristic: Box<HeuristicFn<'a, C>> = Box::new(move |pos| heuristic(pos, dest));
+
+        Ok(Self {
+            core: SearchCore::new(
+                view.size(),
+                &[start],
+                Some(&[dest]),
+                neighbours_func,
+                heuristic,
+            )?,
+            limits: PathFindLimits::new(),
+        })
+    }
+
+    /// Creates a search for the shortest paths between `start` and every other
+    /// location in the given `view`. See [`pathfind_to_whole`] for details on the
+    /// parameters.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if `start` is outside of the view.
+    pub fn to_whole<T, V, FC>(
+        view: &'a V,
+        start: (usize, usize),
+        neighbouring: BidiNeighbours,
+        cost_func: FC,
+    ) -> Result<Self, BidiError>
+    where
+        V: BidiView<Output = T> + Sized,
+        T: 'a,
+        FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C> + 'a,
+    {
+        let neighbours_func: Box<NeighboursFn<'a, C>> =
+            Box::new(grid_neighbours(view, neighbouring, cost_func));
+        let heuristic: Box<HeuristicFn<'a, C>> = Box::new(|_| C::default());
+
+        Ok(Self {
+            core: SearchCore::new(view.size(), &[start], None, neighbours_func, heuristic)?,
+            limits: PathFindLimits::new(),
+        })
+    }
+
+    /// Restricts the exploration of the search with the given [`PathFindLimits`].
+    pub fn with_limits(mut self, limits: PathFindLimits<'a, C>) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Advances the search, exploring at most `max_visits` locations.
+    pub fn step(&mut self, max_visits: usize) -> PathFindStatus {
+        if self.core.step(max_visits, &mut self.limits) {
+            PathFindStatus::Finished
+        } else {
+            PathFindStatus::Searching
+        }
+    }
+
+    /// Returns true if the search is finished.
+    pub fn is_finished(&self) -> bool {
+        self.core.finished
+    }
+
+    /// Returns the data of the search so far; the costs of the locations explored
+    /// are final, but the path is marked (see [`PathFindDataTile::in_shortest_path`])
+    /// only by [`into_data`][PathFindSearch::into_data].
+    pub fn data(&self) -> &PathFindData<C> {
+        &self.core.data
+    }
+
+    /// Ends the search, returning its results. If the search is not finished, the
+    /// results are partial: in particular, a search towards a destination that
+    /// hasn't been reached yet returns [`PathFindDataResult::PathNotFound`].
+    pub fn into_data(self) -> PathFindData<C> {
+        self.core.finish()
+    }
+}
@@ -0,0 +1,55 @@
+/// Smooths a path by removing the redundant waypoints (string pulling): every
+/// waypoint that can be skipped, because the previous kept waypoint has a line of
+/// sight to the following one, is removed. The first and last locations of the
+/// path are always kept.
+///
+/// `line_of_sight` is a closure like `fn(from: (usize, usize), to: (usize, usize)) -> bool`,
+/// which should return true if an agent can move in a straight line between the two
+/// locations (see for example [`line_of_sight`][super::line_of_sight]). It's called at most once per location
+/// of the path.
+///
+/// Paths found on grids only move between neighbouring locations and look robotic
+/// when followed literally; the result of this function is better suited to steering
+/// behaviours and any-angle movement.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::pathfinding;
+///
+/// let path = [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (4, 2)];
+///
+/// // an open map: everything is visible
+/// assert_eq!(pathfinding::smooth(&path, |_, _| true), vec![(0, 0), (4, 2)]);
+///
+/// // only straight lines are allowed
+/// let smoothed = pathfinding::smooth(&path, |a, b| a.0 == b.0 || a.1 == b.1);
+/// assert_eq!(smoothed, vec![(0, 0), (3, 0), (3, 2), (4, 2)]);
+/// ```
+pub fn smooth<F>(path: &[(usize, usize)], line_of_sight: F) -> Vec<(usize, usize)>
+where
+    F: Fn((usize, usize), (usize, usize)) -> bool,
+{
+    let (first, last) = match (path.first(), path.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return Vec::new(),
+    };
+
+    let mut result = vec![first];
+    let mut anchor = first;
+
+    for window in path.windows(2) {
+        let (current, next) = (window[0], window[1]);
+
+        if current != anchor && !line_of_sight(anchor, next) {
+            result.push(current);
+            anchor = current;
+        }
+    }
+
+    if path.len() > 1 {
+        result.push(last);
+    }
+
+    result
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+/// The edges across which a map wraps around, see [`wrapping_neighbours`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PathFindWrap {
+    /// The left and right edges are connected (e.g. a cylinder, like the maps
+    /// of many strategy games).
+    Horizontal,
+    /// The top and bottom edges are connected.
+    Vertical,
+    /// Both the left and right edges and the top and bottom edges are connected
+    /// (a torus).
+    Both,
+}
+
+impl PathFindWrap {
+    /// Returns true if the map wraps around its left and right edges.
+    pub fn horizontal(self) -> bool {
+        match self {
+            PathFindWrap::Horizontal | PathFindWrap::Both => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the map wraps around its top and bottom edges.
+    pub fn vertical(self) -> bool {
+        match self {
+            PathFindWrap::Vertical | PathFindWrap::Both => true,
+            _ => false,
+        }
+    }
+}
+
+/// Moves `coord` by `offset` (-1, 0 or 1) in a range of `size` coordinates,
+/// wrapping around the edges if `wrap` is true.
+fn step(coord: usize, offset: isize, size: usize, wrap: bool) -> Option<usize> {
+    match offset {
+        -1 if coord > 0 => Some(coord - 1),
+        -1 if wrap => Some(size - 1),
+        1 if coord + 1 < size => Some(coord + 1),
+        1 if wrap => Some(0),
+        0 => Some(coord),
+        _ => None,
+    }
+}
+
+/// Returns a neighbours function for [`pathfind_to_dest_custom`] and
+/// [`pathfind_to_whole_custom`] that moves between the neighbouring locations of
+/// the `view` like the other pathfinding functions, but also across the edges
+/// of the map specified by `wrap`, so that paths can cross the seam of maps
+/// wrapping around (e.g. planets).
+///
+/// `cost_func` is the same as in [`pathfind_to_dest`]. Use [`heuristics::wrapping`]
+/// to adapt heuristics to wrapping maps. Hex maps (see [`BidiNeighbours::Hex`])
+/// can only wrap vertically if their height is even.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiNeighbours};
+/// use bidivec::pathfinding::{self, heuristics, PathFindDataResult, PathFindWrap};
+///
+/// let map = bidivec![1u32; 20, 5];
+///
+/// let res = pathfinding::pathfind_to_dest_custom(
+///     &map,
+///     (1, 2),
+///     (18, 2),
+///     pathfinding::wrapping_neighbours(
+///         &map,
+///         BidiNeighbours::Adjacent,
+///         PathFindWrap::Horizontal,
+///         |_, _, to, _| Some(*to),
+///     ),
+///     heuristics::wrapping(heuristics::manhattan(1), (20, 5), PathFindWrap::Horizontal),
+/// )?;
+///
+/// // crossing the left edge is shorter
+/// assert!(matches!(res.result, PathFindDataResult::ShortestPathFound(3)));
+/// assert!(res.tiles[(19, 2)].in_shortest_path);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn wrapping_neighbours<'a, T, V, FC, C>(
+    view: &'a V,
+    neighbouring: BidiNeighbours,
+    wrap: PathFindWrap,
+    cost_func: FC,
+) -> impl Fn((usize, usize)) -> Moves<C> + 'a
+where
+    V: BidiView<Output = T> + Sized,
+    C: PathFindCost,
+    FC: Fn(&T, (usize, usize), &T, (usize, usize)) -> Option<C> + 'a,
+{
+    let (width, height) = view.size();
+
+    move |pos| {
+        let from = &view[pos];
+        let offsets = neighbouring.offsets_at(pos);
+        let mut moves = Vec::with_capacity(offsets.len());
+
+        for offset in offsets.iter() {
+            let neighbour = match (
+                step(pos.0, offset.x, width, wrap.horizontal()),
+                step(pos.1, offset.y, height, wrap.vertical()),
+            ) {
+                (Some(x), Some(y)) => (x, y),
+                _ => continue,
+            };
+
+            // on maps 1 or 2 locations wide, some wrapping movements are repeated
+            if neighbour == pos || moves.iter().any(|(p, _)| *p == neighbour) {
+                continue;
+            }
+
+            if let Some(cost) = cost_func(from, pos, &view[neighbour], neighbour) {
+                moves.push((neighbour, cost));
+            }
+        }
+
+        moves
+    }
+}
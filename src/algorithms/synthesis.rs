@@ -0,0 +1,417 @@
+//! A module containing algorithms to synthesize new bidimensional data structures
+//! from examples.
+//!
+//! The most important entry points are:
+//! - [`overlapping_model()`]: Generates a new [`BidiVec`][crate::BidiVec] locally similar
+//!   to an example view, using the overlapping model of the wave function collapse algorithm.
+//!
+//! This module requires the `synthesis` feature.
+use crate::*;
+use rand::Rng;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+fn opposite(direction: usize) -> usize {
+    (direction + 2) % 4
+}
+
+/// The options of the [`overlapping_model()`] synthesis.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OverlappingOptions {
+    /// The size of the square patterns extracted from the example. Every
+    /// `pattern_size` x `pattern_size` area of the output is guaranteed to be
+    /// a pattern found in the example. Larger values capture bigger features
+    /// of the example but make contradictions more likely.
+    pub pattern_size: usize,
+    /// If true, the example is considered to wrap around its edges when
+    /// extracting patterns.
+    pub periodic_input: bool,
+    /// If true, the output wraps around its edges (i.e. it can be tiled
+    /// seamlessly).
+    pub periodic_output: bool,
+    /// The number of times the synthesis is restarted after reaching a
+    /// contradiction, before giving up. Values lower than 1 are treated as 1.
+    pub max_attempts: usize,
+}
+
+impl Default for OverlappingOptions {
+    /// Returns options for 3x3 patterns, periodic input, non-periodic output
+    /// and up to 10 attempts.
+    fn default() -> Self {
+        Self {
+            pattern_size: 3,
+            periodic_input: true,
+            periodic_output: false,
+            max_attempts: 10,
+        }
+    }
+}
+
+/// Generates a new [`BidiVec`] of the specified size, where every square area
+/// of `options.pattern_size` items is a pattern found in the `example` view,
+/// with patterns occurring about as frequently as in the example.
+///
+/// This is the overlapping model of the wave function collapse algorithm, and is
+/// commonly used to procedurally generate tile maps and textures from a small sample.
+///
+/// Returns [`BidiError::InvalidArgument`] if the pattern size is zero;
+/// returns [`BidiError::IncompatibleSize`] if the example is smaller than the
+/// pattern size, or if the output is smaller than the pattern size and the
+/// output is not periodic; returns [`BidiError::NoSolution`] if all the attempts
+/// reached a contradiction.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, synthesis, synthesis::OverlappingOptions, BidiView};
+///
+/// let example = bidivec!{
+///     ['.', '.', '.', '.'],
+///     ['#', '#', '#', '#'],
+/// };
+///
+/// let options = OverlappingOptions {
+///     pattern_size: 2,
+///     ..OverlappingOptions::default()
+/// };
+///
+/// let output = synthesis::overlapping_model(&example, 6, 5, &options, &mut rand::thread_rng())?;
+///
+/// assert_eq!(output.size(), (6, 5));
+///
+/// // stripes are reproduced, with the rows alternating
+/// for y in 0..5 {
+///     assert!(output.iter().on_row(y).all(|c| *c == output[(0, y)]));
+/// }
+/// for y in 1..5 {
+///     assert_ne!(output[(0, y)], output[(0, y - 1)]);
+/// }
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn overlapping_model<V, R>(
+    example: &V,
+    width: usize,
+    height: usize,
+    options: &OverlappingOptions,
+    rng: &mut R,
+) -> Result<BidiVec<V::Output>, BidiError>
+where
+    V: BidiView,
+    V::Output: Sized + Clone + Eq + Hash,
+    R: Rng + ?Sized,
+{
+    let n = options.pattern_size;
+
+    if n == 0 {
+        return Err(BidiError::invalid_argument("pattern_size"));
+    }
+    if example.width() < n || example.height() < n {
+        return Err(BidiError::incompatible_size(
+            n,
+            min(example.width(), example.height()),
+        ));
+    }
+
+    if width == 0 || height == 0 {
+        return Ok(BidiVec::new());
+    }
+
+    let (wave_width, wave_height) = if options.periodic_output {
+        (width, height)
+    } else if width < n || height < n {
+        return Err(BidiError::incompatible_size(n, min(width, height)));
+    } else {
+        (width - n + 1, height - n + 1)
+    };
+
+    let (patterns, weights) = extract_patterns(example, n, options.periodic_input);
+    let propagator = build_propagator(&patterns, n);
+
+    let mut wave = Wave::new(
+        wave_width,
+        wave_height,
+        options.periodic_output,
+        &weights,
+        &propagator,
+    );
+
+    for _ in 0..options.max_attempts.max(1) {
+        wave.clear();
+
+        if wave.run(rng) {
+            return Ok(BidiVec::with_size_func_xy(width, height, |x, y| {
+                let (wx, wy) = (x.min(wave_width - 1), y.min(wave_height - 1));
+                let pattern = &patterns[wave.pattern_at(wx + wy * wave_width)];
+                pattern[(x - wx) + (y - wy) * n].clone()
+            }));
+        }
+    }
+
+    Err(BidiError::NoSolution)
+}
+
+fn extract_patterns<V>(example: &V, n: usize, periodic: bool) -> (Vec<Vec<V::Output>>, Vec<f64>)
+where
+    V: BidiView,
+    V::Output: Sized + Clone + Eq + Hash,
+{
+    let (width, height) = example.size();
+    let (max_x, max_y) = if periodic {
+        (width, height)
+    } else {
+        (width - n + 1, height - n + 1)
+    };
+
+    let mut indices = HashMap::new();
+    let mut patterns = Vec::new();
+    let mut weights = Vec::new();
+
+    for y in 0..max_y {
+        for x in 0..max_x {
+            let pattern = (0..n * n)
+                .map(|i| example[((x + i % n) % width, (y + i / n) % height)].clone())
+                .collect::<Vec<_>>();
+
+            if let Some(&index) = indices.get(&pattern) {
+                weights[index] += 1.0;
+            } else {
+                indices.insert(pattern.clone(), patterns.len());
+                patterns.push(pattern);
+                weights.push(1.0);
+            }
+        }
+    }
+
+    (patterns, weights)
+}
+
+/// Returns true if `p2`, placed at offset `(dx, dy)` from `p1`, agrees with
+/// `p1` on all the overlapping items.
+fn agrees<T: Eq>(p1: &[T], p2: &[T], n: usize, (dx, dy): (isize, isize)) -> bool {
+    let n = n as isize;
+    let (min_x, max_x) = if dx < 0 { (0, dx + n) } else { (dx, n) };
+    let (min_y, max_y) = if dy < 0 { (0, dy + n) } else { (dy, n) };
+
+    (min_y..max_y).all(|y| {
+        (min_x..max_x).all(|x| p1[(x + n * y) as usize] == p2[(x - dx + n * (y - dy)) as usize])
+    })
+}
+
+/// Builds, for each direction and pattern, the list of patterns that can be
+/// placed next to it in that direction.
+fn build_propagator<T: Eq>(patterns: &[Vec<T>], n: usize) -> Vec<Vec<Vec<usize>>> {
+    DIRECTIONS
+        .iter()
+        .map(|&direction| {
+            patterns
+                .iter()
+                .map(|p1| {
+                    (0..patterns.len())
+                        .filter(|&t| agrees(p1, &patterns[t], n, direction))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+struct Wave<'a> {
+    width: usize,
+    height: usize,
+    periodic: bool,
+    weights: &'a [f64],
+    weight_log_weights: Vec<f64>,
+    propagator: &'a [Vec<Vec<usize>>],
+    possible: Vec<bool>,
+    compatible: Vec<[usize; 4]>,
+    counts: Vec<usize>,
+    sums_of_weights: Vec<f64>,
+    sums_of_weight_log_weights: Vec<f64>,
+    stack: Vec<(usize, usize)>,
+}
+
+impl<'a> Wave<'a> {
+    fn new(
+        width: usize,
+        height: usize,
+        periodic: bool,
+        weights: &'a [f64],
+        propagator: &'a [Vec<Vec<usize>>],
+    ) -> Self {
+        let cells = width * height;
+        let patterns = weights.len();
+
+        Self {
+            width,
+            height,
+            periodic,
+            weights,
+            weight_log_weights: weights.iter().map(|w| w * w.ln()).collect(),
+            propagator,
+            possible: vec![true; cells * patterns],
+            compatible: vec![[0; 4]; cells * patterns],
+            counts: vec![patterns; cells],
+            sums_of_weights: vec![0.0; cells],
+            sums_of_weight_log_weights: vec![0.0; cells],
+            stack: Vec::new(),
+        }
+    }
+
+    fn patterns(&self) -> usize {
+        self.weights.len()
+    }
+
+    fn clear(&mut self) {
+        let patterns = self.patterns();
+        let sum_of_weights = self.weights.iter().sum();
+        let sum_of_weight_log_weights = self.weight_log_weights.iter().sum();
+
+        for cell in 0..self.counts.len() {
+            for t in 0..patterns {
+                self.possible[cell * patterns + t] = true;
+                for d in 0..4 {
+                    self.compatible[cell * patterns + t][d] = self.propagator[opposite(d)][t].len();
+                }
+            }
+
+            self.counts[cell] = patterns;
+            self.sums_of_weights[cell] = sum_of_weights;
+            self.sums_of_weight_log_weights[cell] = sum_of_weight_log_weights;
+        }
+
+        self.stack.clear();
+
+        // ban the patterns that can't have a neighbour in some direction,
+        // where that neighbour exists
+        for cell in 0..self.counts.len() {
+            for t in 0..patterns {
+                let unsupported = (0..4).any(|d| {
+                    self.compatible[cell * patterns + t][d] == 0
+                        && self.neighbour(cell, DIRECTIONS[opposite(d)]).is_some()
+                });
+
+                if unsupported && self.possible[cell * patterns + t] {
+                    self.ban(cell, t);
+                }
+            }
+        }
+    }
+
+    fn ban(&mut self, cell: usize, t: usize) {
+        let index = cell * self.patterns() + t;
+
+        self.possible[index] = false;
+        self.compatible[index] = [0; 4];
+        self.stack.push((cell, t));
+
+        self.counts[cell] -= 1;
+        self.sums_of_weights[cell] -= self.weights[t];
+        self.sums_of_weight_log_weights[cell] -= self.weight_log_weights[t];
+    }
+
+    fn entropy(&self, cell: usize) -> f64 {
+        let sum = self.sums_of_weights[cell];
+        sum.ln() - self.sums_of_weight_log_weights[cell] / sum
+    }
+
+    fn neighbour(&self, cell: usize, (dx, dy): (isize, isize)) -> Option<usize> {
+        let x = (cell % self.width) as isize + dx;
+        let y = (cell / self.width) as isize + dy;
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        if self.periodic {
+            Some((x.rem_euclid(width) + y.rem_euclid(height) * width) as usize)
+        } else if x < 0 || y < 0 || x >= width || y >= height {
+            None
+        } else {
+            Some((x + y * width) as usize)
+        }
+    }
+
+    fn propagate(&mut self) {
+        let patterns = self.patterns();
+
+        while let Some((cell, t1)) = self.stack.pop() {
+            for (d, &direction) in DIRECTIONS.iter().enumerate() {
+                let neighbour = match self.neighbour(cell, direction) {
+                    Some(neighbour) => neighbour,
+                    None => continue,
+                };
+
+                for &t2 in self.propagator[d][t1].iter() {
+                    let compatible = &mut self.compatible[neighbour * patterns + t2][d];
+
+                    if *compatible > 0 {
+                        *compatible -= 1;
+                        if *compatible == 0 {
+                            self.ban(neighbour, t2);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Runs the synthesis until either all the cells are collapsed to a single
+    /// pattern (returning true) or a contradiction is found (returning false).
+    fn run<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        let patterns = self.patterns();
+
+        loop {
+            self.propagate();
+
+            let mut best = None;
+            let mut min_entropy = std::f64::MAX;
+
+            for cell in 0..self.counts.len() {
+                match self.counts[cell] {
+                    0 => return false,
+                    1 => continue,
+                    _ => {
+                        // a bit of noise breaks ties randomly
+                        let entropy = self.entropy(cell) + rng.gen::<f64>() * 1e-6;
+                        if entropy < min_entropy {
+                            min_entropy = entropy;
+                            best = Some(cell);
+                        }
+                    }
+                }
+            }
+
+            let cell = match best {
+                Some(cell) => cell,
+                None => return true,
+            };
+
+            let mut r = rng.gen::<f64>() * self.sums_of_weights[cell];
+            let mut chosen = 0;
+
+            for t in 0..patterns {
+                if self.possible[cell * patterns + t] {
+                    chosen = t;
+                    if r < self.weights[t] {
+                        break;
+                    }
+                    r -= self.weights[t];
+                }
+            }
+
+            for t in 0..patterns {
+                if t != chosen && self.possible[cell * patterns + t] {
+                    self.ban(cell, t);
+                }
+            }
+        }
+    }
+
+    fn pattern_at(&self, cell: usize) -> usize {
+        let patterns = self.patterns();
+
+        (0..patterns)
+            .find(|&t| self.possible[cell * patterns + t])
+            .unwrap_or(0)
+    }
+}
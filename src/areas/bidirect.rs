@@ -1,5 +1,7 @@
-use crate::BidiRectSigned;
+use crate::bidiiter::coords::{BorderCoords, Coords};
+use crate::{BidiError, BidiRectSigned, BidiView};
 use std::cmp::{max, min};
+use std::convert::TryFrom;
 use std::ops::Range;
 
 /// A simple data structure representing a bidimensional rectangle
@@ -28,6 +30,99 @@ impl BidiRect {
         }
     }
 
+    /// Creates the smallest rectangle containing both the specified points,
+    /// which can be any two opposite corners of the rectangle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// assert_eq!(BidiRect::from_points((4, 1), (2, 3)), BidiRect::new(2, 1, 3, 3));
+    /// ```
+    pub fn from_points(a: (usize, usize), b: (usize, usize)) -> Self {
+        let (x, y) = (min(a.0, b.0), min(a.1, b.1));
+
+        BidiRect {
+            x,
+            y,
+            width: max(a.0, b.0) - x + 1,
+            height: max(a.1, b.1) - y + 1,
+        }
+    }
+
+    /// Creates a rectangle of the specified size centered on `(cx, cy)` (for
+    /// even sizes, the center is the item to the bottom-right of the actual
+    /// center), or [`None`] if the rectangle would have negative coordinates;
+    /// see [`BidiRectSigned::from_center`] for a version accepting them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// assert_eq!(BidiRect::from_center(5, 5, 3, 4), Some(BidiRect::new(4, 3, 3, 4)));
+    /// assert_eq!(BidiRect::from_center(0, 5, 3, 3), None);
+    /// ```
+    pub fn from_center(cx: usize, cy: usize, width: usize, height: usize) -> Option<Self> {
+        Some(BidiRect {
+            x: cx.checked_sub(width / 2)?,
+            y: cy.checked_sub(height / 2)?,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the rectangle grown by `n` in every direction; the top-left
+    /// corner is clamped at `(0, 0)`, and the bottom-right one at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// assert_eq!(BidiRect::new(3, 1, 2, 2).inflate(2), BidiRect::new(1, 0, 6, 5));
+    /// ```
+    pub fn inflate(&self, n: usize) -> Self {
+        let (x, y) = (self.x.saturating_sub(n), self.y.saturating_sub(n));
+
+        BidiRect {
+            x,
+            y,
+            width: self.max_x().saturating_add(n) - x,
+            height: self.max_y().saturating_add(n) - y,
+        }
+    }
+
+    /// Returns the rectangle shrunk by `n` in every direction; rectangles
+    /// too small to be shrunk become empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// assert_eq!(BidiRect::new(3, 1, 6, 5).deflate(2), BidiRect::new(5, 3, 2, 1));
+    /// assert!(BidiRect::new(3, 1, 6, 5).deflate(3).is_empty());
+    /// ```
+    pub fn deflate(&self, n: usize) -> Self {
+        let dx = min(n, self.width / 2);
+        let dy = min(n, self.height / 2);
+
+        BidiRect {
+            x: self.x + dx,
+            y: self.y + dy,
+            width: self.width.saturating_sub(n.saturating_mul(2)),
+            height: self.height.saturating_sub(n.saturating_mul(2)),
+        }
+    }
+
+    /// Returns the portion of the rectangle inside the bounds of a view, or
+    /// [`None`] if the rectangle is completely outside of them
+    pub fn clip_to<V: BidiView + ?Sized>(&self, view: &V) -> Option<Self> {
+        self.intersection(&view.bounding_rect())
+    }
+
     /// Returns the coordinate of the top-left corner
     pub fn min_xy(&self) -> (usize, usize) {
         (self.x, self.y)
@@ -101,7 +196,9 @@ impl BidiRect {
     /// Returns a new rectangle which is the intersection of the
     /// current rectangle and another rectangle.
     /// If the two rectangles do not overlap, `x` and `y` are
-    /// undefined, but the `width` and `height` will be `0`.
+    /// undefined, but the `width` and `height` will be `0`
+    /// (see [`intersection`][BidiRect::intersection] for a version
+    /// returning [`None`] instead).
     pub fn intersect(&self, other: &Self) -> Self {
         BidiRect {
             x: max(self.x, other.x),
@@ -111,6 +208,111 @@ impl BidiRect {
         }
     }
 
+    /// Returns the intersection of the current rectangle and another
+    /// rectangle, or [`None`] if the two rectangles do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let a = BidiRect::new(0, 0, 4, 3);
+    ///
+    /// assert_eq!(a.intersection(&BidiRect::new(2, 1, 5, 5)), Some(BidiRect::new(2, 1, 2, 2)));
+    /// assert_eq!(a.intersection(&BidiRect::new(4, 0, 2, 2)), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let rect = self.intersect(other);
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    /// Returns the smallest rectangle containing both the current rectangle
+    /// and another rectangle. Empty rectangles are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let a = BidiRect::new(1, 1, 2, 2);
+    ///
+    /// assert_eq!(a.union_bounds(&BidiRect::new(5, 0, 1, 1)), BidiRect::new(1, 0, 5, 3));
+    /// assert_eq!(a.union_bounds(&BidiRect::new(9, 9, 0, 0)), a);
+    /// ```
+    pub fn union_bounds(&self, other: &Self) -> Self {
+        if other.is_empty() {
+            return self.clone();
+        } else if self.is_empty() {
+            return other.clone();
+        }
+
+        let (x, y) = (min(self.x, other.x), min(self.y, other.y));
+
+        BidiRect {
+            x,
+            y,
+            width: max(self.max_x(), other.max_x()) - x,
+            height: max(self.max_y(), other.max_y()) - y,
+        }
+    }
+
+    /// Returns true if the rect contains the specified point, expressed
+    /// as a `(x, y)` tuple
+    pub fn contains_point(&self, (x, y): (usize, usize)) -> bool {
+        self.contains(x, y)
+    }
+
+    /// Returns true if all the points of another rectangle are contained in
+    /// the current rectangle (which is always the case for empty rectangles)
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (other.x >= self.x
+                && other.y >= self.y
+                && other.max_x() <= self.max_x()
+                && other.max_y() <= self.max_y())
+    }
+
+    /// Returns true if the current rectangle and another rectangle have
+    /// at least one point in common
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// Returns true if the rectangle contains no points (i.e. its width
+    /// or its height are `0`)
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns the rectangle moved by the specified offset, or [`None`] if
+    /// the result would have negative (or overflowing) coordinates; see
+    /// [`offset`][BidiRect::offset] for a version returning a [`BidiRectSigned`].
+    pub fn translate(&self, dx: isize, dy: isize) -> Option<Self> {
+        let moved = |coord: usize, delta: isize| {
+            if delta >= 0 {
+                coord.checked_add(delta as usize)
+            } else {
+                coord.checked_sub(delta.wrapping_neg() as usize)
+            }
+        };
+
+        let rect = BidiRect {
+            x: moved(self.x, dx)?,
+            y: moved(self.y, dy)?,
+            width: self.width,
+            height: self.height,
+        };
+
+        rect.x.checked_add(rect.width)?;
+        rect.y.checked_add(rect.height)?;
+        Some(rect)
+    }
+
+    /// Returns the rectangle moved by the specified offset, as a [`BidiRectSigned`]
     pub fn offset(&self, dx: isize, dy: isize) -> BidiRectSigned {
         BidiRectSigned {
             x: (self.x as isize) + dx,
@@ -120,6 +322,8 @@ impl BidiRect {
         }
     }
 
+    /// Returns true if the rect contains the specified point, expressed
+    /// with signed coordinates
     pub fn contains_signed(&self, x: isize, y: isize) -> bool {
         if x < 0 || y < 0 {
             false
@@ -127,4 +331,210 @@ impl BidiRect {
             self.contains(x as usize, y as usize)
         }
     }
+
+    /// Returns an iterator over the coordinates contained in the rect, by rows
+    /// (use [`by_column`][Coords::by_column] to iterate by columns)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let coords = BidiRect::new(1, 1, 2, 2).iter().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(coords, vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+    /// ```
+    pub fn iter(&self) -> Coords {
+        Coords::new(self)
+    }
+
+    /// Returns an iterator over the coordinates on the border of the rect,
+    /// clockwise starting from the top-left corner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let coords = BidiRect::new(0, 0, 3, 2).iter_border().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(coords, vec![(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (0, 1)]);
+    /// ```
+    pub fn iter_border(&self) -> BorderCoords {
+        BorderCoords::new(self)
+    }
+
+    /// Splits the rect along a horizontal line, returning the part above
+    /// the line (`at` rows tall) and the part below it, or [`None`] if
+    /// `at` is greater than the height of the rect
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let (top, bottom) = BidiRect::new(1, 1, 4, 5).split_horizontal(2).unwrap();
+    ///
+    /// assert_eq!(top, BidiRect::new(1, 1, 4, 2));
+    /// assert_eq!(bottom, BidiRect::new(1, 3, 4, 3));
+    /// ```
+    pub fn split_horizontal(&self, at: usize) -> Option<(Self, Self)> {
+        if at > self.height {
+            return None;
+        }
+
+        Some((
+            BidiRect::new(self.x, self.y, self.width, at),
+            BidiRect::new(self.x, self.y + at, self.width, self.height - at),
+        ))
+    }
+
+    /// Splits the rect along a vertical line, returning the part to the left
+    /// of the line (`at` columns wide) and the part to the right of it, or
+    /// [`None`] if `at` is greater than the width of the rect
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let (left, right) = BidiRect::new(1, 1, 4, 5).split_vertical(3).unwrap();
+    ///
+    /// assert_eq!(left, BidiRect::new(1, 1, 3, 5));
+    /// assert_eq!(right, BidiRect::new(4, 1, 1, 5));
+    /// ```
+    pub fn split_vertical(&self, at: usize) -> Option<(Self, Self)> {
+        if at > self.width {
+            return None;
+        }
+
+        Some((
+            BidiRect::new(self.x, self.y, at, self.height),
+            BidiRect::new(self.x + at, self.y, self.width - at, self.height),
+        ))
+    }
+
+    /// Subdivides the rect in a grid of `cols` by `rows` rects, as evenly sized as
+    /// possible (the first columns and rows are one larger when the size of the
+    /// rect is not a multiple of `cols` or `rows`). The rects are returned by rows.
+    ///
+    /// If `cols` is greater than the width (or `rows` greater than the height),
+    /// some of the returned rects are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let cells = BidiRect::new(0, 0, 5, 2).subdivide(2, 1);
+    ///
+    /// assert_eq!(cells, vec![BidiRect::new(0, 0, 3, 2), BidiRect::new(3, 0, 2, 2)]);
+    /// ```
+    pub fn subdivide(&self, cols: usize, rows: usize) -> Vec<Self> {
+        // the start and length of the i-th of n parts of a range
+        let part = |start: usize, len: usize, i: usize, n: usize| {
+            let (size, extra) = (len / n, len % n);
+            (
+                start + i * size + min(i, extra),
+                size + usize::from(i < extra),
+            )
+        };
+
+        let mut cells = Vec::with_capacity(cols * rows);
+
+        for row in 0..rows {
+            let (y, height) = part(self.y, self.height, row, rows);
+            for col in 0..cols {
+                let (x, width) = part(self.x, self.width, col, cols);
+                cells.push(BidiRect::new(x, y, width, height));
+            }
+        }
+
+        cells
+    }
+
+    /// Recursively splits the rect at random positions (binary space partitioning),
+    /// until no part can be split without making it narrower than `min_width` or
+    /// shorter than `min_height`, and returns the resulting parts. Parts much longer
+    /// than tall are split vertically, parts much taller than long horizontally,
+    /// the others in a random direction.
+    ///
+    /// This is commonly used to lay out rooms in dungeon generators.
+    ///
+    /// Requires the `rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRect;
+    ///
+    /// let rect = BidiRect::new(0, 0, 40, 30);
+    /// let parts = rect.subdivide_random(6, 5, &mut rand::thread_rng());
+    ///
+    /// assert!(parts.len() > 1);
+    /// assert!(parts.iter().all(|p| rect.contains_rect(p) && p.width >= 6 && p.height >= 5));
+    /// assert_eq!(parts.iter().map(|p| p.width * p.height).sum::<usize>(), 40 * 30);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn subdivide_random<R>(&self, min_width: usize, min_height: usize, rng: &mut R) -> Vec<Self>
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let (min_width, min_height) = (max(min_width, 1), max(min_height, 1));
+        let mut parts = Vec::new();
+        let mut pending = vec![self.clone()];
+
+        while let Some(rect) = pending.pop() {
+            let can_split_h = rect.height >= 2 * min_height;
+            let can_split_v = rect.width >= 2 * min_width;
+
+            let vertical = match (can_split_v, can_split_h) {
+                (false, false) => {
+                    parts.push(rect);
+                    continue;
+                }
+                (true, false) => true,
+                (false, true) => false,
+                _ if rect.width * 4 >= rect.height * 5 => true,
+                _ if rect.height * 4 >= rect.width * 5 => false,
+                _ => rng.gen(),
+            };
+
+            let (a, b) = if vertical {
+                let at = rng.gen_range(min_width..=rect.width - min_width);
+                rect.split_vertical(at)
+            } else {
+                let at = rng.gen_range(min_height..=rect.height - min_height);
+                rect.split_horizontal(at)
+            }
+            .expect("split position out of range");
+
+            pending.push(b);
+            pending.push(a);
+        }
+
+        parts
+    }
+}
+
+impl TryFrom<BidiRectSigned> for BidiRect {
+    type Error = BidiError;
+
+    /// Converts a [`BidiRectSigned`] to a [`BidiRect`], returning
+    /// [`BidiError::NegativeCoordinates`] if it has negative coordinates.
+    fn try_from(rect: BidiRectSigned) -> Result<Self, Self::Error> {
+        if rect.x < 0 || rect.y < 0 {
+            Err(BidiError::NegativeCoordinates {
+                x: rect.x,
+                y: rect.y,
+            })
+        } else {
+            Ok(BidiRect::new(
+                rect.x as usize,
+                rect.y as usize,
+                rect.width,
+                rect.height,
+            ))
+        }
+    }
 }
@@ -1,4 +1,7 @@
-use std::cmp::min;
+use crate::bidiiter::coords::{SignedBorderCoords, SignedCoords};
+use crate::{BidiError, BidiRect, BidiView};
+use std::cmp::{max, min};
+use std::convert::TryFrom;
 use std::ops::Range;
 
 /// A simple data structure representing a bidimensional rectangle
@@ -28,6 +31,136 @@ impl BidiRectSigned {
         }
     }
 
+    /// Creates the smallest rectangle containing both the specified points,
+    /// which can be any two opposite corners of the rectangle
+    pub fn from_points(a: (isize, isize), b: (isize, isize)) -> Self {
+        let (x, y) = (min(a.0, b.0), min(a.1, b.1));
+
+        BidiRectSigned {
+            x,
+            y,
+            width: (max(a.0, b.0) - x) as usize + 1,
+            height: (max(a.1, b.1) - y) as usize + 1,
+        }
+    }
+
+    /// Creates a rectangle of the specified size centered on `(cx, cy)` (for
+    /// even sizes, the center is the item to the bottom-right of the actual
+    /// center)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiRect, BidiRectSigned};
+    ///
+    /// let v = bidivec![0; 10, 10];
+    ///
+    /// // a 5x5 brush centered near the top-left corner
+    /// let brush = BidiRectSigned::from_center(1, 0, 5, 5);
+    ///
+    /// assert_eq!(brush, BidiRectSigned::new(-1, -2, 5, 5));
+    /// assert_eq!(brush.clip_to(&v), Some(BidiRect::new(0, 0, 4, 3)));
+    /// ```
+    pub fn from_center(cx: isize, cy: isize, width: usize, height: usize) -> Self {
+        BidiRectSigned {
+            x: cx - (width / 2) as isize,
+            y: cy - (height / 2) as isize,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the rectangle grown by `n` in every direction; the top-left
+    /// corner is clamped at `(isize::MIN, isize::MIN)`, and the size at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRectSigned;
+    ///
+    /// let rect = BidiRectSigned::new(-1, 3, 2, 2);
+    ///
+    /// assert_eq!(rect.inflate(2), BidiRectSigned::new(-3, 1, 6, 6));
+    /// assert_eq!(
+    ///     rect.inflate(usize::MAX),
+    ///     BidiRectSigned::new(isize::MIN, isize::MIN, usize::MAX, usize::MAX)
+    /// );
+    /// ```
+    pub fn inflate(&self, n: usize) -> Self {
+        // grows by up to `n`, as long as the coordinate doesn't go below isize::MIN
+        let grow = |coord: isize, size: usize| {
+            let grown = min(n, coord.wrapping_sub(std::isize::MIN) as usize);
+            let size = size.saturating_add(grown).saturating_add(n);
+            (coord.wrapping_sub(grown as isize), size)
+        };
+        let (x, width) = grow(self.x, self.width);
+        let (y, height) = grow(self.y, self.height);
+
+        BidiRectSigned {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the rectangle shrunk by `n` in every direction; rectangles
+    /// too small to be shrunk become empty
+    pub fn deflate(&self, n: usize) -> Self {
+        let dx = min(n, self.width / 2);
+        let dy = min(n, self.height / 2);
+
+        BidiRectSigned {
+            x: self.x + dx as isize,
+            y: self.y + dy as isize,
+            width: self.width.saturating_sub(n.saturating_mul(2)),
+            height: self.height.saturating_sub(n.saturating_mul(2)),
+        }
+    }
+
+    /// Returns the portion of the rectangle inside the bounds of a view, or
+    /// [`None`] if the rectangle is completely outside of them
+    pub fn clip_to<V: BidiView + ?Sized>(&self, view: &V) -> Option<BidiRect> {
+        let bounds = BidiRectSigned::new(0, 0, view.width(), view.height());
+        let rect = self.intersection(&bounds)?;
+        BidiRect::try_from(rect).ok()
+    }
+
+    /// Returns the portion of the rectangle inside the bounds of a view, like
+    /// [`clip_to`][BidiRectSigned::clip_to], together with the offset of its
+    /// top-left corner from the top-left corner of this rectangle; returns
+    /// [`None`] if the rectangle is completely outside of the bounds of the view.
+    ///
+    /// This is what's needed to copy a rectangle of data placed at signed
+    /// coordinates into a view, clipping it: the returned rect is the destination
+    /// area in the view, and the offset is where the source data starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiRect, BidiRectSigned};
+    ///
+    /// let view = bidivec![0; 5, 5];
+    /// let rect = BidiRectSigned::new(-2, 3, 4, 4);
+    ///
+    /// assert_eq!(
+    ///     rect.intersect_view(&view),
+    ///     Some((BidiRect::new(0, 3, 2, 2), (2, 0)))
+    /// );
+    /// assert_eq!(BidiRectSigned::new(-4, 0, 4, 4).intersect_view(&view), None);
+    /// ```
+    pub fn intersect_view<V: BidiView + ?Sized>(
+        &self,
+        view: &V,
+    ) -> Option<(BidiRect, (usize, usize))> {
+        let rect = self.clip_to(view)?;
+        let offset = (
+            (rect.x as isize - self.x) as usize,
+            (rect.y as isize - self.y) as usize,
+        );
+        Some((rect, offset))
+    }
+
     /// Returns the coordinate of the top-left corner
     pub fn min_xy(&self) -> (isize, isize) {
         (self.x, self.y)
@@ -100,4 +233,141 @@ impl BidiRectSigned {
     pub fn contains_y(&self, y: isize) -> bool {
         y >= self.y && y < self.max_y()
     }
+
+    /// Returns the intersection of the current rectangle and another
+    /// rectangle, or [`None`] if the two rectangles do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRectSigned;
+    ///
+    /// let a = BidiRectSigned::new(-2, -2, 4, 4);
+    ///
+    /// assert_eq!(
+    ///     a.intersection(&BidiRectSigned::new(0, -5, 10, 4)),
+    ///     Some(BidiRectSigned::new(0, -2, 2, 1))
+    /// );
+    /// assert_eq!(a.intersection(&BidiRectSigned::new(2, 0, 2, 2)), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (x, y) = (max(self.x, other.x), max(self.y, other.y));
+        let (max_x, max_y) = (
+            min(self.max_x(), other.max_x()),
+            min(self.max_y(), other.max_y()),
+        );
+
+        if max_x > x && max_y > y {
+            Some(BidiRectSigned {
+                x,
+                y,
+                width: (max_x - x) as usize,
+                height: (max_y - y) as usize,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest rectangle containing both the current rectangle
+    /// and another rectangle. Empty rectangles are ignored.
+    pub fn union_bounds(&self, other: &Self) -> Self {
+        if other.is_empty() {
+            return self.clone();
+        } else if self.is_empty() {
+            return other.clone();
+        }
+
+        let (x, y) = (min(self.x, other.x), min(self.y, other.y));
+
+        BidiRectSigned {
+            x,
+            y,
+            width: (max(self.max_x(), other.max_x()) - x) as usize,
+            height: (max(self.max_y(), other.max_y()) - y) as usize,
+        }
+    }
+
+    /// Returns true if the rect contains the specified point, expressed
+    /// as a `(x, y)` tuple
+    pub fn contains_point(&self, (x, y): (isize, isize)) -> bool {
+        self.contains(x, y)
+    }
+
+    /// Returns true if all the points of another rectangle are contained in
+    /// the current rectangle (which is always the case for empty rectangles)
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (other.x >= self.x
+                && other.y >= self.y
+                && other.max_x() <= self.max_x()
+                && other.max_y() <= self.max_y())
+    }
+
+    /// Returns true if the current rectangle and another rectangle have
+    /// at least one point in common
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns true if the rectangle contains no points (i.e. its width
+    /// or its height are `0`)
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns the rectangle moved by the specified offset; the top-left
+    /// corner is clamped at the bounds of `isize`
+    pub fn translate(&self, dx: isize, dy: isize) -> Self {
+        BidiRectSigned {
+            x: self.x.saturating_add(dx),
+            y: self.y.saturating_add(dy),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Returns an iterator over the coordinates contained in the rect, by rows
+    /// (use [`by_column`][SignedCoords::by_column] to iterate by columns)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiRectSigned;
+    ///
+    /// let coords = BidiRectSigned::new(-1, 0, 2, 2).iter().by_column().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(coords, vec![(-1, 0), (-1, 1), (0, 0), (0, 1)]);
+    /// ```
+    pub fn iter(&self) -> SignedCoords {
+        SignedCoords::new(self)
+    }
+
+    /// Returns an iterator over the coordinates on the border of the rect,
+    /// clockwise starting from the top-left corner
+    pub fn iter_border(&self) -> SignedBorderCoords {
+        SignedBorderCoords::new(self)
+    }
+}
+
+impl TryFrom<BidiRect> for BidiRectSigned {
+    type Error = BidiError;
+
+    /// Converts a [`BidiRect`] to a [`BidiRectSigned`], returning
+    /// [`BidiError::OutOfBounds`] if its coordinates don't fit an [`isize`].
+    fn try_from(rect: BidiRect) -> Result<Self, Self::Error> {
+        if rect.max_x() > std::isize::MAX as usize || rect.max_y() > std::isize::MAX as usize {
+            Err(BidiError::rect_out_of_bounds(
+                &rect,
+                (std::isize::MAX as usize, std::isize::MAX as usize),
+            ))
+        } else {
+            Ok(BidiRectSigned::new(
+                rect.x as isize,
+                rect.y as isize,
+                rect.width,
+                rect.height,
+            ))
+        }
+    }
 }
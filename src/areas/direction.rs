@@ -0,0 +1,156 @@
+use crate::{BidiNeighbours, BidiVector};
+
+/// A direction on a bidimensional grid, where y grows southwards (i.e. `North` is
+/// towards the first row, `West` towards the first column).
+///
+/// The four cardinal directions are the movements between
+/// [adjacent][BidiNeighbours::Adjacent] items, while the eight directions
+/// are the movements between [bordering][BidiNeighbours::Bordering] items.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiDirection, BidiView, BidiVector};
+///
+/// let v = bidivec![0; 3, 3];
+///
+/// assert_eq!(BidiDirection::North.offset(), BidiVector::new(0, -1));
+/// assert_eq!(BidiDirection::North.rotate_cw(), BidiDirection::East);
+/// assert_eq!(BidiDirection::NorthWest.opposite(), BidiDirection::SouthEast);
+///
+/// assert_eq!(v.step(1, 0, BidiDirection::SouthEast), Some((2, 1)));
+/// assert_eq!(v.step(1, 0, BidiDirection::North), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum BidiDirection {
+    /// Towards smaller y coordinates
+    North,
+    /// Towards smaller y and greater x coordinates
+    NorthEast,
+    /// Towards greater x coordinates
+    East,
+    /// Towards greater x and y coordinates
+    SouthEast,
+    /// Towards greater y coordinates
+    South,
+    /// Towards greater y and smaller x coordinates
+    SouthWest,
+    /// Towards smaller x coordinates
+    West,
+    /// Towards smaller x and y coordinates
+    NorthWest,
+}
+
+impl BidiDirection {
+    /// All the eight directions, in clockwise order starting from [`BidiDirection::North`]
+    pub const ALL: [BidiDirection; 8] = [
+        BidiDirection::North,
+        BidiDirection::NorthEast,
+        BidiDirection::East,
+        BidiDirection::SouthEast,
+        BidiDirection::South,
+        BidiDirection::SouthWest,
+        BidiDirection::West,
+        BidiDirection::NorthWest,
+    ];
+
+    /// The four cardinal directions, in clockwise order starting from [`BidiDirection::North`]
+    pub const CARDINALS: [BidiDirection; 4] = [
+        BidiDirection::North,
+        BidiDirection::East,
+        BidiDirection::South,
+        BidiDirection::West,
+    ];
+
+    /// The six directions of a hex map, in clockwise order starting from
+    /// [`BidiDirection::NorthEast`]
+    pub const HEX: [BidiDirection; 6] = [
+        BidiDirection::NorthEast,
+        BidiDirection::East,
+        BidiDirection::SouthEast,
+        BidiDirection::SouthWest,
+        BidiDirection::West,
+        BidiDirection::NorthWest,
+    ];
+
+    /// Returns the directions of the movements between neighbouring items,
+    /// in clockwise order.
+    ///
+    /// Note that on hex maps the [`offset`][BidiDirection::offset] of a step
+    /// in a diagonal direction depends on the row; use
+    /// [`BidiNeighbours::offsets_at`] instead.
+    pub fn all(neighbouring: BidiNeighbours) -> &'static [BidiDirection] {
+        match neighbouring {
+            BidiNeighbours::Adjacent => &Self::CARDINALS,
+            BidiNeighbours::Bordering => &Self::ALL,
+            BidiNeighbours::Hex(_) => &Self::HEX,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(index: usize) -> Self {
+        Self::ALL[index % 8]
+    }
+
+    /// Returns the offset of a step in this direction
+    pub fn offset(self) -> BidiVector {
+        match self {
+            BidiDirection::North => BidiVector::new(0, -1),
+            BidiDirection::NorthEast => BidiVector::new(1, -1),
+            BidiDirection::East => BidiVector::new(1, 0),
+            BidiDirection::SouthEast => BidiVector::new(1, 1),
+            BidiDirection::South => BidiVector::new(0, 1),
+            BidiDirection::SouthWest => BidiVector::new(-1, 1),
+            BidiDirection::West => BidiVector::new(-1, 0),
+            BidiDirection::NorthWest => BidiVector::new(-1, -1),
+        }
+    }
+
+    /// Returns the direction of a step with the specified offset, or [`None`]
+    /// if the offset is not a single step between bordering items
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiDirection, BidiVector};
+    ///
+    /// assert_eq!(BidiDirection::from_offset(BidiVector::new(-1, 1)), Some(BidiDirection::SouthWest));
+    /// assert_eq!(BidiDirection::from_offset(BidiVector::new(2, 0)), None);
+    /// ```
+    pub fn from_offset(offset: BidiVector) -> Option<Self> {
+        Self::ALL.iter().copied().find(|d| d.offset() == offset)
+    }
+
+    /// Returns true for the four diagonal directions
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Returns the opposite direction
+    pub fn opposite(self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+
+    /// Returns the direction rotated by 90 degrees clockwise
+    pub fn rotate_cw(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    /// Returns the direction rotated by 90 degrees counter-clockwise
+    pub fn rotate_ccw(self) -> Self {
+        Self::from_index(self.index() + 6)
+    }
+
+    /// Returns the direction rotated by 45 degrees clockwise
+    pub fn rotate_cw_45(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Returns the direction rotated by 45 degrees counter-clockwise
+    pub fn rotate_ccw_45(self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::BidiVector;
+
+/// The layout of a hex map stored in a rectangular data structure, where
+/// hexes are "pointy topped" and every other row is shifted right by half a hex.
+///
+/// Use [`BidiNeighbours::Hex`][crate::BidiNeighbours::Hex] to get the neighbours
+/// of a hex in iterators, editing functions and pathfinding.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum BidiHexLayout {
+    /// Odd rows (1, 3, 5, ...) are shifted right by half a hex
+    OddRows,
+    /// Even rows (0, 2, 4, ...) are shifted right by half a hex
+    EvenRows,
+}
+
+impl BidiHexLayout {
+    fn is_shifted(self, y: usize) -> bool {
+        let odd = y & 1 == 1;
+        match self {
+            BidiHexLayout::OddRows => odd,
+            BidiHexLayout::EvenRows => !odd,
+        }
+    }
+
+    /// Returns the offsets of the six neighbours of the hex at the specified row
+    pub(crate) fn offsets_at(self, y: usize) -> &'static [BidiVector] {
+        const UNSHIFTED: [BidiVector; 6] = [
+            BidiVector { x: -1, y: -1 },
+            BidiVector { x: -1, y: 0 },
+            BidiVector { x: -1, y: 1 },
+            BidiVector { x: 0, y: 1 },
+            BidiVector { x: 1, y: 0 },
+            BidiVector { x: 0, y: -1 },
+        ];
+        const SHIFTED: [BidiVector; 6] = [
+            BidiVector { x: 0, y: -1 },
+            BidiVector { x: -1, y: 0 },
+            BidiVector { x: 0, y: 1 },
+            BidiVector { x: 1, y: 1 },
+            BidiVector { x: 1, y: 0 },
+            BidiVector { x: 1, y: -1 },
+        ];
+
+        if self.is_shifted(y) {
+            &SHIFTED
+        } else {
+            &UNSHIFTED
+        }
+    }
+
+    /// Returns the distance between two hexes, in steps between neighbouring hexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiHexLayout;
+    ///
+    /// assert_eq!(BidiHexLayout::OddRows.distance((0, 0), (3, 0)), 3);
+    /// assert_eq!(BidiHexLayout::OddRows.distance((0, 0), (1, 1)), 2);
+    /// assert_eq!(BidiHexLayout::EvenRows.distance((0, 0), (1, 1)), 1);
+    /// ```
+    pub fn distance(self, a: (usize, usize), b: (usize, usize)) -> usize {
+        BidiHex::from_offset(a, self).distance(BidiHex::from_offset(b, self))
+    }
+}
+
+/// The axial coordinates of a hex in a hex map.
+///
+/// Axial coordinates make the math of hex maps (distances, neighbours, rotations)
+/// straightforward, but don't map directly to rectangular storage; use
+/// [`BidiHex::from_offset`] and [`BidiHex::to_offset`] to convert from and to the
+/// coordinates of the items of a bidimensional data structure, given its [`BidiHexLayout`].
+///
+/// The `q` axis grows towards the east (like x), and the `r` axis grows towards
+/// the south-east (so that `r` is the row).
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiHex, BidiHexLayout};
+///
+/// let layout = BidiHexLayout::OddRows;
+/// let hex = BidiHex::from_offset((3, 3), layout);
+///
+/// assert_eq!(hex, BidiHex::new(2, 3));
+/// assert_eq!(hex.to_offset(layout), Some((3, 3)));
+/// assert_eq!(hex.distance(BidiHex::new(0, 0)), 5);
+///
+/// let mut neighbours = hex
+///     .neighbours()
+///     .iter()
+///     .filter_map(|h| h.to_offset(layout))
+///     .collect::<Vec<_>>();
+/// neighbours.sort();
+///
+/// assert_eq!(neighbours, vec![(2, 3), (3, 2), (3, 4), (4, 2), (4, 3), (4, 4)]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BidiHex {
+    /// The coordinate along the east axis
+    pub q: isize,
+    /// The coordinate along the south-east axis (i.e. the row)
+    pub r: isize,
+}
+
+impl BidiHex {
+    /// Creates a new hex from its axial coordinates
+    pub fn new(q: isize, r: isize) -> Self {
+        BidiHex { q, r }
+    }
+
+    /// Converts the coordinates of an item of a bidimensional data structure
+    /// storing a hex map with the specified layout to axial coordinates
+    pub fn from_offset(pos: (usize, usize), layout: BidiHexLayout) -> Self {
+        let (x, y) = (pos.0 as isize, pos.1 as isize);
+        // in even rows layouts, odd rows are shifted left relatively to row 0
+        let shift = match layout {
+            BidiHexLayout::OddRows => 0,
+            BidiHexLayout::EvenRows => y & 1,
+        };
+
+        BidiHex {
+            q: x - (y - (y & 1)) / 2 - shift,
+            r: y,
+        }
+    }
+
+    /// Converts the axial coordinates to the coordinates of an item of a
+    /// bidimensional data structure storing a hex map with the specified layout,
+    /// or [`None`] if the coordinates would be negative
+    pub fn to_offset(self, layout: BidiHexLayout) -> Option<(usize, usize)> {
+        let shift = match layout {
+            BidiHexLayout::OddRows => 0,
+            BidiHexLayout::EvenRows => self.r & 1,
+        };
+        let x = self.q + (self.r - (self.r & 1)) / 2 + shift;
+
+        if x < 0 || self.r < 0 {
+            None
+        } else {
+            Some((x as usize, self.r as usize))
+        }
+    }
+
+    /// Returns the distance between two hexes, in steps between neighbouring hexes
+    pub fn distance(self, other: BidiHex) -> usize {
+        let (dq, dr) = (self.q - other.q, self.r - other.r);
+        ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as usize
+    }
+
+    /// Returns the six neighbours of the hex, clockwise starting from the one
+    /// to the north-west
+    pub fn neighbours(self) -> [BidiHex; 6] {
+        let (q, r) = (self.q, self.r);
+        [
+            BidiHex::new(q, r - 1),
+            BidiHex::new(q + 1, r - 1),
+            BidiHex::new(q + 1, r),
+            BidiHex::new(q, r + 1),
+            BidiHex::new(q - 1, r + 1),
+            BidiHex::new(q - 1, r),
+        ]
+    }
+}
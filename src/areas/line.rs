@@ -0,0 +1,116 @@
+/// An iterator over the locations covered by a segment between the centers of two
+/// locations of a grid (a "supercover" line): every location the segment passes
+/// through is returned, in order from the start to the end, both included.
+///
+/// Unlike Bresenham lines, when the segment passes exactly through the corner of
+/// a location both the locations sharing that corner are returned (horizontal
+/// neighbour first), so that no diagonal gap exists in the line.
+///
+/// Used by [`pathfinding::line_of_sight`][crate::pathfinding::line_of_sight].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::BidiLine;
+///
+/// let line = BidiLine::new((0, 0), (4, 1)).collect::<Vec<_>>();
+/// assert_eq!(line, vec![(0, 0), (1, 0), (2, 0), (2, 1), (3, 1), (4, 1)]);
+///
+/// // passing through corners covers both sides
+/// let line = BidiLine::new((0, 0), (2, 2)).collect::<Vec<_>>();
+/// assert_eq!(line, vec![(0, 0), (1, 0), (0, 1), (1, 1), (2, 1), (1, 2), (2, 2)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BidiLine {
+    pos: (usize, usize),
+    dx: usize,
+    dy: usize,
+    step_x: isize,
+    step_y: isize,
+    ix: usize,
+    iy: usize,
+    pending: Vec<(usize, usize)>,
+    done: bool,
+}
+
+impl BidiLine {
+    /// Creates an iterator over the locations covered by the segment from `from`
+    /// to `to`.
+    pub fn new(from: (usize, usize), to: (usize, usize)) -> Self {
+        let (dx, step_x) = if to.0 >= from.0 {
+            (to.0 - from.0, 1)
+        } else {
+            (from.0 - to.0, -1)
+        };
+        let (dy, step_y) = if to.1 >= from.1 {
+            (to.1 - from.1, 1)
+        } else {
+            (from.1 - to.1, -1)
+        };
+
+        Self {
+            pos: from,
+            dx,
+            dy,
+            step_x,
+            step_y,
+            ix: 0,
+            iy: 0,
+            pending: Vec::with_capacity(2),
+            done: false,
+        }
+    }
+
+    fn moved(pos: usize, step: isize) -> usize {
+        if step > 0 {
+            pos + 1
+        } else {
+            pos - 1
+        }
+    }
+}
+
+impl Iterator for BidiLine {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pos) = self.pending.pop() {
+            return Some(pos);
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let current = self.pos;
+
+        if self.ix == self.dx && self.iy == self.dy {
+            self.done = true;
+            return Some(current);
+        }
+
+        // Compares the distance to the next vertical and horizontal edges
+        // crossed by the segment, as (0.5 + ix) / dx vs (0.5 + iy) / dy.
+        let to_vertical = (1 + 2 * self.ix as u128) * self.dy as u128;
+        let to_horizontal = (1 + 2 * self.iy as u128) * self.dx as u128;
+
+        if to_vertical == to_horizontal {
+            // through a corner: both the locations sharing it are covered
+            let x = Self::moved(self.pos.0, self.step_x);
+            let y = Self::moved(self.pos.1, self.step_y);
+            self.pending.push((self.pos.0, y));
+            self.pending.push((x, self.pos.1));
+            self.pos = (x, y);
+            self.ix += 1;
+            self.iy += 1;
+        } else if to_vertical < to_horizontal {
+            self.pos.0 = Self::moved(self.pos.0, self.step_x);
+            self.ix += 1;
+        } else {
+            self.pos.1 = Self::moved(self.pos.1, self.step_y);
+            self.iy += 1;
+        }
+
+        Some(current)
+    }
+}
@@ -1,3 +1,7 @@
 pub(crate) mod bidirect;
 pub(crate) mod bidirect_signed;
+pub(crate) mod direction;
+pub(crate) mod hex;
+pub(crate) mod line;
 pub(crate) mod neighbours;
+pub(crate) mod point;
@@ -1,3 +1,5 @@
+use crate::{BidiHexLayout, BidiVector};
+
 /// A definition of neighbouring elements, used in various algorithms
 /// throughout the crate.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -12,6 +14,12 @@ pub enum BidiNeighbours {
     /// element above to the right, below to the right, below to
     /// the left and above to the left.
     Bordering,
+    /// Consider the data structure as a hex map with the specified layout,
+    /// and the 6 bordering hexes as neighbours: the hexes directly to the
+    /// left and to the right, and the two hexes touching the element in
+    /// each of the rows above and below (which ones depend on whether the
+    /// row is shifted or not).
+    Hex(BidiHexLayout),
 }
 
 impl BidiNeighbours {
@@ -81,13 +89,99 @@ impl BidiNeighbours {
                     v.push((x, y - 1));
                 }
             }
+            BidiNeighbours::Hex(layout) => {
+                for offset in layout.offsets_at(y).iter() {
+                    let go_x = match offset.x {
+                        -1 => go_w,
+                        1 => go_e,
+                        _ => true,
+                    };
+                    let go_y = match offset.y {
+                        -1 => go_n,
+                        1 => go_s,
+                        _ => true,
+                    };
+
+                    if go_x && go_y {
+                        v.push((
+                            (x as isize + offset.x) as usize,
+                            (y as isize + offset.y) as usize,
+                        ));
+                    }
+                }
+            }
         }
     }
 
-    pub(crate) fn prealloc_vec(self) -> Vec<(usize, usize)> {
+    /// Returns the offsets of the neighbouring elements from an element.
+    ///
+    /// For [`BidiNeighbours::Hex`], the offsets depend on the row of the element:
+    /// these are the offsets from an element of row 0, see
+    /// [`offsets_at`][BidiNeighbours::offsets_at].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiNeighbours, BidiVector};
+    ///
+    /// assert_eq!(BidiNeighbours::Adjacent.offsets().len(), 4);
+    /// assert!(BidiNeighbours::Bordering.offsets().contains(&BidiVector::new(1, -1)));
+    /// ```
+    pub fn offsets(self) -> &'static [BidiVector] {
+        const ADJACENT: [BidiVector; 4] = [
+            BidiVector { x: -1, y: 0 },
+            BidiVector { x: 0, y: 1 },
+            BidiVector { x: 1, y: 0 },
+            BidiVector { x: 0, y: -1 },
+        ];
+        const BORDERING: [BidiVector; 8] = [
+            BidiVector { x: -1, y: -1 },
+            BidiVector { x: -1, y: 0 },
+            BidiVector { x: -1, y: 1 },
+            BidiVector { x: 0, y: 1 },
+            BidiVector { x: 1, y: 1 },
+            BidiVector { x: 1, y: 0 },
+            BidiVector { x: 1, y: -1 },
+            BidiVector { x: 0, y: -1 },
+        ];
+
         match self {
-            BidiNeighbours::Adjacent => Vec::with_capacity(4),
-            BidiNeighbours::Bordering => Vec::with_capacity(8),
+            BidiNeighbours::Adjacent => &ADJACENT,
+            BidiNeighbours::Bordering => &BORDERING,
+            BidiNeighbours::Hex(layout) => layout.offsets_at(0),
         }
     }
+
+    /// Returns the offsets of the neighbouring elements from the element at
+    /// the specified coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiHexLayout, BidiNeighbours, BidiVector};
+    ///
+    /// let hex = BidiNeighbours::Hex(BidiHexLayout::OddRows);
+    ///
+    /// // row 1 is shifted right, so its north-east neighbour is one column to the right
+    /// assert!(!hex.offsets_at((5, 0)).contains(&BidiVector::new(1, -1)));
+    /// assert!(hex.offsets_at((5, 1)).contains(&BidiVector::new(1, -1)));
+    /// ```
+    pub fn offsets_at(self, pos: (usize, usize)) -> &'static [BidiVector] {
+        match self {
+            BidiNeighbours::Hex(layout) => layout.offsets_at(pos.1),
+            _ => self.offsets(),
+        }
+    }
+
+    pub(crate) fn max_count(self) -> usize {
+        match self {
+            BidiNeighbours::Adjacent => 4,
+            BidiNeighbours::Bordering => 8,
+            BidiNeighbours::Hex(_) => 6,
+        }
+    }
+
+    pub(crate) fn prealloc_vec(self) -> Vec<(usize, usize)> {
+        Vec::with_capacity(self.max_count())
+    }
 }
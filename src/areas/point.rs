@@ -0,0 +1,186 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The coordinates of an item in a bidimensional data structure.
+///
+/// Coordinates are expressed as `(x, y)` tuples throughout the crate; [`BidiPoint`]
+/// is an alternative that makes call sites easier to read (and harder to swap), and
+/// can be used to index all the data structures. Conversions from and to tuples
+/// are provided.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiPoint, BidiVector};
+///
+/// let mut v = bidivec!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// };
+///
+/// let p = BidiPoint::new(2, 0);
+/// assert_eq!(v[p], 3);
+///
+/// let below = p.checked_add(BidiVector::new(0, 1)).unwrap();
+/// v[below] = 60;
+/// assert_eq!(v[(2, 1)], 60);
+///
+/// assert_eq!(below - p, BidiVector::new(0, 1));
+/// assert_eq!(p.checked_add(BidiVector::new(-3, 0)), None);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BidiPoint {
+    /// The x coordinate (i.e. the column)
+    pub x: usize,
+    /// The y coordinate (i.e. the row)
+    pub y: usize,
+}
+
+impl BidiPoint {
+    /// Creates a new point
+    pub fn new(x: usize, y: usize) -> Self {
+        BidiPoint { x, y }
+    }
+
+    /// Returns the point as a `(x, y)` tuple
+    pub fn to_tuple(self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Returns the point moved by the specified vector, or [`None`] if
+    /// the result would have negative (or overflowing) coordinates
+    pub fn checked_add(self, v: BidiVector) -> Option<Self> {
+        let moved = |coord: usize, delta: isize| {
+            if delta >= 0 {
+                coord.checked_add(delta as usize)
+            } else {
+                coord.checked_sub(delta.wrapping_neg() as usize)
+            }
+        };
+
+        Some(BidiPoint {
+            x: moved(self.x, v.x)?,
+            y: moved(self.y, v.y)?,
+        })
+    }
+
+    /// Returns the point as a [`BidiVector`] from the origin
+    pub fn to_vector(self) -> BidiVector {
+        BidiVector::new(self.x as isize, self.y as isize)
+    }
+}
+
+impl From<(usize, usize)> for BidiPoint {
+    fn from((x, y): (usize, usize)) -> Self {
+        BidiPoint { x, y }
+    }
+}
+
+impl From<BidiPoint> for (usize, usize) {
+    fn from(p: BidiPoint) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Sub for BidiPoint {
+    type Output = BidiVector;
+
+    /// Returns the vector going from `rhs` to `self`
+    fn sub(self, rhs: Self) -> BidiVector {
+        BidiVector::new(
+            self.x as isize - rhs.x as isize,
+            self.y as isize - rhs.y as isize,
+        )
+    }
+}
+
+/// A signed offset between two [`BidiPoint`]s, such as the offset of a
+/// neighbouring item (see [`BidiNeighbours::offsets`][crate::BidiNeighbours::offsets])
+/// or the step of a path.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::BidiVector;
+///
+/// let v = BidiVector::new(1, -2);
+///
+/// assert_eq!(v + BidiVector::new(1, 1), BidiVector::new(2, -1));
+/// assert_eq!(-v, BidiVector::new(-1, 2));
+/// assert_eq!(v * 3, BidiVector::new(3, -6));
+/// assert_eq!(BidiVector::from((4, 5)).x, 4);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BidiVector {
+    /// The horizontal component (positive towards the right)
+    pub x: isize,
+    /// The vertical component (positive downwards)
+    pub y: isize,
+}
+
+impl BidiVector {
+    /// Creates a new vector
+    pub fn new(x: isize, y: isize) -> Self {
+        BidiVector { x, y }
+    }
+
+    /// Returns the vector as a `(x, y)` tuple
+    pub fn to_tuple(self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+}
+
+impl From<(isize, isize)> for BidiVector {
+    fn from((x, y): (isize, isize)) -> Self {
+        BidiVector { x, y }
+    }
+}
+
+impl From<BidiVector> for (isize, isize) {
+    fn from(v: BidiVector) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl Add for BidiVector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        BidiVector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for BidiVector {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for BidiVector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        BidiVector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for BidiVector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for BidiVector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        BidiVector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for BidiVector {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        BidiVector::new(self.x * rhs, self.y * rhs)
+    }
+}
@@ -0,0 +1,209 @@
+//! Iterators over the coordinates contained in a rect, created by
+//! [`BidiRect::iter()`], [`BidiRect::iter_border()`],
+//! [`BidiRectSigned::iter()`] and [`BidiRectSigned::iter_border()`].
+use crate::bidiiter::borderstate::IterBorderState;
+use crate::bidiiter::rectstate::OnRectState;
+use crate::{BidiRect, BidiRectSigned};
+use std::iter::Iterator;
+
+/// An iterator over the coordinates contained in a [`BidiRect`], by rows
+/// (or by columns, see [`Coords::by_column`]). Created by [`BidiRect::iter()`].
+///
+/// # Panics
+///
+/// Calling [`Coords::by_column`] after the iteration has been started will
+/// cause a panic.
+pub struct Coords {
+    origin: (usize, usize),
+    size: BidiRect,
+    by_column: bool,
+    state: OnRectState,
+}
+
+impl Coords {
+    pub(crate) fn new(rect: &BidiRect) -> Self {
+        Self {
+            origin: (rect.x, rect.y),
+            size: BidiRect::new(0, 0, rect.width, rect.height),
+            by_column: false,
+            state: OnRectState::NotStarted,
+        }
+    }
+
+    /// Returns an iterator which yields the coordinates by columns instead
+    /// of by rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::BidiRect;
+    ///
+    /// let coords = BidiRect::new(1, 1, 2, 2).iter().by_column().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(coords, vec![(1, 1), (1, 2), (2, 1), (2, 2)]);
+    /// ```
+    pub fn by_column(mut self) -> Self {
+        self.state.assert_not_started("by_column()");
+        self.by_column = true;
+        self
+    }
+
+    fn next_offset(&mut self) -> Option<(usize, usize)> {
+        self.state.advance(&self.size, self.by_column);
+
+        match self.state {
+            OnRectState::Iterating(x, y) => Some((x, y)),
+            _ => None,
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        let (width, height) = (self.size.width, self.size.height);
+        let done = match self.state {
+            OnRectState::NotStarted => 0,
+            OnRectState::Iterating(x, y) if self.by_column => x * height + y + 1,
+            OnRectState::Iterating(x, y) => y * width + x + 1,
+            OnRectState::Terminated => return 0,
+        };
+        width * height - done
+    }
+}
+
+impl Iterator for Coords {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.next_offset()?;
+        Some((self.origin.0 + x, self.origin.1 + y))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Coords {}
+
+/// An iterator over the coordinates contained in a [`BidiRectSigned`], by rows
+/// (or by columns, see [`SignedCoords::by_column`]). Created by [`BidiRectSigned::iter()`].
+///
+/// # Panics
+///
+/// Calling [`SignedCoords::by_column`] after the iteration has been started will
+/// cause a panic.
+pub struct SignedCoords {
+    origin: (isize, isize),
+    inner: Coords,
+}
+
+impl SignedCoords {
+    pub(crate) fn new(rect: &BidiRectSigned) -> Self {
+        Self {
+            origin: (rect.x, rect.y),
+            inner: Coords::new(&BidiRect::new(0, 0, rect.width, rect.height)),
+        }
+    }
+
+    /// Returns an iterator which yields the coordinates by columns instead
+    /// of by rows.
+    pub fn by_column(mut self) -> Self {
+        self.inner = self.inner.by_column();
+        self
+    }
+}
+
+impl Iterator for SignedCoords {
+    type Item = (isize, isize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.inner.next_offset()?;
+        Some((self.origin.0 + x as isize, self.origin.1 + y as isize))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for SignedCoords {}
+
+/// Walks the border of a rect of the given size, clockwise from its top-left corner.
+struct BorderWalk {
+    size: BidiRect,
+    border: BidiRectSigned,
+    state: IterBorderState,
+}
+
+impl BorderWalk {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            size: BidiRect::new(0, 0, width, height),
+            border: BidiRectSigned::new(0, 0, width, height),
+            // the border state doesn't support degenerate rects
+            state: if width == 0 || height == 0 {
+                IterBorderState::Terminated
+            } else {
+                IterBorderState::NotStarted
+            },
+        }
+    }
+
+    fn next_offset(&mut self) -> Option<(usize, usize)> {
+        self.state.advance(&self.size, &self.border);
+
+        match self.state {
+            IterBorderState::Iterating(x, y) => Some((x as usize, y as usize)),
+            _ => None,
+        }
+    }
+}
+
+/// An iterator over the coordinates on the border of a [`BidiRect`], clockwise
+/// starting from its top-left corner. Created by [`BidiRect::iter_border()`].
+pub struct BorderCoords {
+    origin: (usize, usize),
+    walk: BorderWalk,
+}
+
+impl BorderCoords {
+    pub(crate) fn new(rect: &BidiRect) -> Self {
+        Self {
+            origin: (rect.x, rect.y),
+            walk: BorderWalk::new(rect.width, rect.height),
+        }
+    }
+}
+
+impl Iterator for BorderCoords {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.walk.next_offset()?;
+        Some((self.origin.0 + x, self.origin.1 + y))
+    }
+}
+
+/// An iterator over the coordinates on the border of a [`BidiRectSigned`], clockwise
+/// starting from its top-left corner. Created by [`BidiRectSigned::iter_border()`].
+pub struct SignedBorderCoords {
+    origin: (isize, isize),
+    walk: BorderWalk,
+}
+
+impl SignedBorderCoords {
+    pub(crate) fn new(rect: &BidiRectSigned) -> Self {
+        Self {
+            origin: (rect.x, rect.y),
+            walk: BorderWalk::new(rect.width, rect.height),
+        }
+    }
+}
+
+impl Iterator for SignedBorderCoords {
+    type Item = (isize, isize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.walk.next_offset()?;
+        Some((self.origin.0 + x as isize, self.origin.1 + y as isize))
+    }
+}
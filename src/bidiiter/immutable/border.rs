@@ -5,14 +5,14 @@ use crate::BidiView;
 use std::iter::Iterator;
 
 /// An iterator type returning items in a rectangular region.
-pub struct OnBorder<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct OnBorder<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     pub(super) view: &'v V,
     pub(super) rect: BidiRect,
     pub(super) border: BidiRectSigned,
     pub(super) state: IterBorderState,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> OnBorder<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> OnBorder<'v, T, V> {
     /// Returns an iterator which yields the items with their original
     /// coordinates. Note that all the coordinates are relative to the
     /// [`BidiView`] (or other data structure) the iterator was created
@@ -28,7 +28,7 @@ impl<'v, T: 'v, V: BidiView<Output = T>> OnBorder<'v, T, V> {
     }
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for OnBorder<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for OnBorder<'v, T, V> {
     type Item = &'v T;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
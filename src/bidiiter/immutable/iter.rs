@@ -54,14 +54,15 @@ use std::iter::Iterator;
 /// Calling the methods altering the iteration (`by_column`, `on_row`,
 /// `on_column`, `on_rect` and `with_coords`) after the iteration has
 /// been started will cause a panic.
-pub struct Iter<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct Iter<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     view: &'v V,
     rect: BidiRect,
     state: OnRectState,
     by_column: bool,
+    row: Option<std::slice::Iter<'v, T>>,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iter<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iter<'v, T, V> {
     pub(crate) fn new(view: &'v V) -> Self {
         let rect = view.bounding_rect();
         Self {
@@ -69,6 +70,7 @@ impl<'v, T: 'v, V: BidiView<Output = T>> Iter<'v, T, V> {
             rect,
             state: OnRectState::NotStarted,
             by_column: false,
+            row: None,
         }
     }
 
@@ -151,6 +153,7 @@ impl<'v, T: 'v, V: BidiView<Output = T>> Iter<'v, T, V> {
             rect,
             by_column: self.by_column,
             state: OnRectState::NotStarted,
+            row: None,
         }
     }
 
@@ -181,6 +184,7 @@ impl<'v, T: 'v, V: BidiView<Output = T>> Iter<'v, T, V> {
             rect,
             by_column: self.by_column,
             state: OnRectState::NotStarted,
+            row: None,
         }
     }
 
@@ -212,6 +216,7 @@ impl<'v, T: 'v, V: BidiView<Output = T>> Iter<'v, T, V> {
             rect,
             by_column: self.by_column,
             state: OnRectState::NotStarted,
+            row: None,
         }
     }
 
@@ -294,15 +299,64 @@ impl<'v, T: 'v, V: BidiView<Output = T>> Iter<'v, T, V> {
             points,
         }
     }
+
+    /// Returns an iterator which yields the items within `thickness` items
+    /// from the edges of the view (that is, a frame around the whole view),
+    /// by rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{BidiVec, bidivec};
+    ///
+    /// let bvec = bidivec!{
+    ///     [ 1,  2,  3,  4],
+    ///     [ 5,  6,  7,  8],
+    ///     [ 9, 10, 11, 12],
+    ///     [13, 14, 15, 16],
+    /// };
+    ///
+    /// let v = bvec.iter()
+    ///     .on_view_border(1)
+    ///     .copied()
+    ///     .collect::<Vec<i32>>();
+    ///
+    /// assert_eq!(v, vec![1, 2, 3, 4, 5, 8, 9, 12, 13, 14, 15, 16]);
+    /// ```
+    pub fn on_view_border(self, thickness: usize) -> precalc::OnElements<'v, T, V> {
+        self.state.assert_not_started("on_view_border()");
+        let points =
+            crate::bidiiter::view_border_points(self.view.width(), self.view.height(), thickness);
+        precalc::OnElements {
+            view: self.view,
+            started: false,
+            points,
+        }
+    }
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for Iter<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for Iter<'v, T, V> {
     type Item = &'v T;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if let Some(item) = self.row.as_mut().and_then(|row| row.next()) {
+            return Some(item);
+        }
+
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
-            self.view.get(x, y)
+            if !self.by_column {
+                // views with contiguous rows yield the rest of the row from a slice
+                if let Some(row) = self.view.row_slice(y) {
+                    self.state.skip_to_row_end(&self.rect);
+                    let mut row = row[x..self.rect.max_x()].iter();
+                    let item = row.next();
+                    self.row = Some(row);
+                    return item;
+                }
+            }
+
+            // the iteration rect is within the bounds of the view
+            Some(unsafe { self.view.get_unchecked(x, y) })
         } else {
             None
         }
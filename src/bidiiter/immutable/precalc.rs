@@ -4,13 +4,13 @@ use std::iter::Iterator;
 /// An iterator type returning precalculated items.
 /// Refer to the function that produced this iterator for
 /// further details.
-pub struct OnElements<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct OnElements<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     pub(super) view: &'v V,
     pub(super) points: Vec<(usize, usize)>,
     pub(super) started: bool,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> OnElements<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> OnElements<'v, T, V> {
     /// Returns an iterator which yields the items with their original
     /// coordinates. Note that all the coordinates are relative to the
     /// [`BidiView`] (or other data structure) the iterator was created
@@ -26,7 +26,7 @@ impl<'v, T: 'v, V: BidiView<Output = T>> OnElements<'v, T, V> {
     }
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for OnElements<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for OnElements<'v, T, V> {
     type Item = &'v T;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
@@ -4,14 +4,15 @@ use crate::BidiView;
 use std::iter::Iterator;
 
 /// An iterator type returning items in a rectangular region.
-pub struct OnRect<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct OnRect<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     pub(super) view: &'v V,
     pub(super) rect: BidiRect,
     pub(super) state: OnRectState,
     pub(super) by_column: bool,
+    pub(super) row: Option<std::slice::Iter<'v, T>>,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> OnRect<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> OnRect<'v, T, V> {
     /// Returns an iterator which yields the items with their original
     /// coordinates. Note that all the coordinates are relative to the
     /// [`BidiView`] (or other data structure) the iterator was created
@@ -35,13 +36,29 @@ impl<'v, T: 'v, V: BidiView<Output = T>> OnRect<'v, T, V> {
     }
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for OnRect<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for OnRect<'v, T, V> {
     type Item = &'v T;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if let Some(item) = self.row.as_mut().and_then(|row| row.next()) {
+            return Some(item);
+        }
+
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
-            self.view.get(x, y)
+            if !self.by_column {
+                // views with contiguous rows yield the rest of the row from a slice
+                if let Some(row) = self.view.row_slice(y) {
+                    self.state.skip_to_row_end(&self.rect);
+                    let mut row = row[x..self.rect.max_x()].iter();
+                    let item = row.next();
+                    self.row = Some(row);
+                    return item;
+                }
+            }
+
+            // the iteration rect is within the bounds of the view
+            Some(unsafe { self.view.get_unchecked(x, y) })
         } else {
             None
         }
@@ -5,14 +5,14 @@ use crate::BidiView;
 use std::iter::Iterator;
 
 /// An iterator type returning items in a rectangular region.
-pub struct OnBorder<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct OnBorder<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     pub(crate) view: &'v V,
     pub(crate) rect: BidiRect,
     pub(crate) state: IterBorderState,
     pub(crate) border: BidiRectSigned,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for OnBorder<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for OnBorder<'v, T, V> {
     type Item = (usize, usize, &'v T);
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
@@ -8,14 +8,14 @@ use crate::BidiView;
 use std::iter::Iterator;
 
 /// An iterator type returning items with their coordinates.
-pub struct WithCoords<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct WithCoords<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     pub(crate) view: &'v V,
     pub(crate) rect: BidiRect,
     pub(crate) state: OnRectState,
     pub(crate) by_column: bool,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> WithCoords<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> WithCoords<'v, T, V> {
     /// Returns an iterator which yields the items by columns instead
     /// of by rows as it would otherwise do.
     pub fn by_column(mut self) -> Self {
@@ -100,15 +100,29 @@ impl<'v, T: 'v, V: BidiView<Output = T>> WithCoords<'v, T, V> {
             points,
         }
     }
+
+    /// Returns an iterator which yields the items within `thickness` items
+    /// from the edges of the view (that is, a frame around the whole view),
+    /// by rows.
+    pub fn on_view_border(self, thickness: usize) -> precalc::OnElements<'v, T, V> {
+        self.state.assert_not_started("on_view_border()");
+        let points =
+            crate::bidiiter::view_border_points(self.view.width(), self.view.height(), thickness);
+        precalc::OnElements {
+            view: self.view,
+            points,
+        }
+    }
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for WithCoords<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for WithCoords<'v, T, V> {
     type Item = (usize, usize, &'v T);
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
-            self.view.get(x, y).map(|v| (x, y, v))
+            // the iteration rect is within the bounds of the view
+            Some((x, y, unsafe { self.view.get_unchecked(x, y) }))
         } else {
             None
         }
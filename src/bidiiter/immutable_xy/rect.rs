@@ -5,14 +5,14 @@ use std::iter::Iterator;
 
 /// An iterator type returning items with their coordinates, on a
 /// rectangular region.
-pub struct OnRect<'v, T: 'v, V: BidiView<Output = T>> {
+pub struct OnRect<'v, T: 'v, V: BidiView<Output = T> + ?Sized> {
     pub(crate) view: &'v V,
     pub(crate) rect: BidiRect,
     pub(crate) state: OnRectState,
     pub(crate) by_column: bool,
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> OnRect<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> OnRect<'v, T, V> {
     /// Returns an iterator which yields the items by columns instead
     /// of by rows as it would otherwise do.
     pub fn by_column(mut self) -> Self {
@@ -22,13 +22,14 @@ impl<'v, T: 'v, V: BidiView<Output = T>> OnRect<'v, T, V> {
     }
 }
 
-impl<'v, T: 'v, V: BidiView<Output = T>> Iterator for OnRect<'v, T, V> {
+impl<'v, T: 'v, V: BidiView<Output = T> + ?Sized> Iterator for OnRect<'v, T, V> {
     type Item = (usize, usize, &'v T);
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
-            self.view.get(x, y).map(|v| (x, y, v))
+            // the iteration rect is within the bounds of the view
+            Some((x, y, unsafe { self.view.get_unchecked(x, y) }))
         } else {
             None
         }
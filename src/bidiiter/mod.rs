@@ -8,6 +8,7 @@
 //! * [`BidiGrowVec::iter()`] and [`BidiGrowVec::iter_mut()`]
 //! * [`BidiMutSlice::iter()`] and [`BidiMutSlice::iter_mut()`]
 //! * [`BidiSlice::iter()`]
+//! * [`iter_dyn()`], for trait objects
 //!
 //! # Immutable iterators
 //!
@@ -76,10 +77,12 @@
 //! `on_column`, `on_rect`, `with_coords`, etc.) after the iteration has
 //! been started will cause a panic.
 
+use crate::BidiView;
 #[cfg(doc)]
 use crate::*;
 
 mod borderstate;
+pub mod coords;
 pub mod immutable;
 pub mod immutable_xy;
 pub mod mutable;
@@ -88,3 +91,62 @@ mod rectstate;
 
 pub use immutable::iter::Iter;
 pub use mutable::iter::IterMut;
+
+/// Returns an iterator over the items of a view which isn't [`Sized`], most notably
+/// a `&dyn BidiView` trait object, on which [`BidiView::iter()`] can't be called.
+/// The iterator supports the same refinements (like `with_coords` or `on_rect`)
+/// of the ones returned by [`BidiView::iter()`].
+///
+/// # Examples
+/// ```
+/// # use bidivec::{bidiarray, BidiView};
+/// use bidivec::bidiiter::iter_dyn;
+///
+/// fn sum_of_diagonal(v: &dyn BidiView<Output = i32>) -> i32 {
+///     iter_dyn(v)
+///         .with_coords()
+///         .filter(|(x, y, _)| x == y)
+///         .map(|(_, _, n)| n)
+///         .sum()
+/// }
+///
+/// let v = bidiarray!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+///     [7, 8, 9],
+/// };
+///
+/// assert_eq!(sum_of_diagonal(&v), 15);
+/// assert_eq!(iter_dyn(v.as_bidiview()).count(), 9);
+/// ```
+pub fn iter_dyn<V>(view: &V) -> Iter<'_, V::Output, V>
+where
+    V: BidiView + ?Sized,
+    V::Output: Sized,
+{
+    Iter::new(view)
+}
+
+/// Returns the coordinates of the items within `thickness` items from the edges
+/// of a view of the specified size, in reverse row-major order (as they are
+/// popped by the precalculated iterators).
+pub(crate) fn view_border_points(
+    width: usize,
+    height: usize,
+    thickness: usize,
+) -> Vec<(usize, usize)> {
+    let mut points = Vec::new();
+
+    for y in (0..height).rev() {
+        if y < thickness || y >= height.saturating_sub(thickness) {
+            points.extend((0..width).rev().map(|x| (x, y)));
+        } else {
+            let left = thickness.min(width);
+            let right = width.saturating_sub(thickness).max(left);
+            points.extend((right..width).rev().map(|x| (x, y)));
+            points.extend((0..left).rev().map(|x| (x, y)));
+        }
+    }
+
+    points
+}
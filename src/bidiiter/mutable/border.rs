@@ -36,10 +36,7 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for OnBorder<'v, T,
 
         if let IterBorderState::Iterating(x, y) = self.state {
             let refptr = {
-                let mutref = match self.view.get_mut_signed(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                let mutref = self.view.get_mut_signed(x, y)?;
 
                 let refptr: *mut T = mutref;
                 refptr
@@ -72,6 +72,7 @@ pub struct IterMut<'v, T: 'v, V: BidiViewMutIterable<Output = T>> {
     rect: BidiRect,
     state: OnRectState,
     by_column: bool,
+    row: Option<std::slice::IterMut<'v, T>>,
 }
 
 impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> IterMut<'v, T, V> {
@@ -82,6 +83,7 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> IterMut<'v, T, V> {
             rect,
             state: OnRectState::NotStarted,
             by_column: false,
+            row: None,
         }
     }
 
@@ -177,6 +179,7 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> IterMut<'v, T, V> {
             rect,
             by_column: self.by_column,
             state: OnRectState::NotStarted,
+            row: None,
         }
     }
 
@@ -214,6 +217,7 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> IterMut<'v, T, V> {
             rect,
             by_column: self.by_column,
             state: OnRectState::NotStarted,
+            row: None,
         }
     }
 
@@ -249,6 +253,7 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> IterMut<'v, T, V> {
             rect,
             by_column: self.by_column,
             state: OnRectState::NotStarted,
+            row: None,
         }
     }
 
@@ -342,19 +347,64 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> IterMut<'v, T, V> {
             points,
         }
     }
+
+    /// Returns an iterator which yields the items within `thickness` items
+    /// from the edges of the view (that is, a frame around the whole view),
+    /// by rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{BidiVec, bidivec};
+    ///
+    /// let mut bvec = bidivec![1; 4, 3];
+    ///
+    /// for item in bvec.iter_mut().on_view_border(1) {
+    ///     *item = 0;
+    /// }
+    ///
+    /// assert_eq!(bvec, bidivec!{
+    ///     [0, 0, 0, 0],
+    ///     [0, 1, 1, 0],
+    ///     [0, 0, 0, 0],
+    /// });
+    /// ```
+    pub fn on_view_border(self, thickness: usize) -> precalc::OnElements<'v, T, V> {
+        self.state.assert_not_started("on_view_border()");
+        let points =
+            crate::bidiiter::view_border_points(self.view.width(), self.view.height(), thickness);
+        precalc::OnElements {
+            view: self.view,
+            started: false,
+            points,
+        }
+    }
 }
 
 impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for IterMut<'v, T, V> {
     type Item = &'v mut T;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if let Some(item) = self.row.as_mut().and_then(|row| row.next()) {
+            return Some(item);
+        }
+
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
+            if !self.by_column {
+                // views with contiguous rows yield the rest of the row from a slice
+                if let Some(row) = self.view.row_slice_mut(y) {
+                    let rowptr: *mut [T] = &mut row[x..self.rect.max_x()];
+                    self.state.skip_to_row_end(&self.rect);
+                    let mut row = unsafe { (*rowptr).iter_mut() };
+                    let item = row.next();
+                    self.row = Some(row);
+                    return item;
+                }
+            }
+
             let refptr = {
-                let mutref = match self.view.get_mut(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                // the iteration rect is within the bounds of the view
+                let mutref = unsafe { self.view.get_unchecked_mut(x, y) };
 
                 let refptr: *mut T = mutref;
                 refptr
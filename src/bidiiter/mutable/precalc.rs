@@ -33,10 +33,7 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for OnElements<'v,
         self.started = true;
         if let Some((x, y)) = self.points.pop() {
             let refptr = {
-                let mutref = match self.view.get_mut(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                let mutref = self.view.get_mut(x, y)?;
 
                 let refptr: *mut T = mutref;
                 refptr
@@ -9,6 +9,7 @@ pub struct OnRect<'v, T: 'v, V: BidiViewMutIterable<Output = T>> {
     pub(crate) rect: BidiRect,
     pub(crate) state: OnRectState,
     pub(crate) by_column: bool,
+    pub(crate) row: Option<std::slice::IterMut<'v, T>>,
 }
 
 impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> OnRect<'v, T, V> {
@@ -39,13 +40,27 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for OnRect<'v, T, V
     type Item = &'v mut T;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if let Some(item) = self.row.as_mut().and_then(|row| row.next()) {
+            return Some(item);
+        }
+
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
+            if !self.by_column {
+                // views with contiguous rows yield the rest of the row from a slice
+                if let Some(row) = self.view.row_slice_mut(y) {
+                    let rowptr: *mut [T] = &mut row[x..self.rect.max_x()];
+                    self.state.skip_to_row_end(&self.rect);
+                    let mut row = unsafe { (*rowptr).iter_mut() };
+                    let item = row.next();
+                    self.row = Some(row);
+                    return item;
+                }
+            }
+
             let refptr = {
-                let mutref = match self.view.get_mut(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                // the iteration rect is within the bounds of the view
+                let mutref = unsafe { self.view.get_unchecked_mut(x, y) };
 
                 let refptr: *mut T = mutref;
                 refptr
@@ -100,6 +100,19 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> WithCoords<'v, T, V> {
             points,
         }
     }
+
+    /// Returns an iterator which yields the items within `thickness` items
+    /// from the edges of the view (that is, a frame around the whole view),
+    /// by rows.
+    pub fn on_view_border(self, thickness: usize) -> precalc::OnElements<'v, T, V> {
+        self.state.assert_not_started("on_view_border()");
+        let points =
+            crate::bidiiter::view_border_points(self.view.width(), self.view.height(), thickness);
+        precalc::OnElements {
+            view: self.view,
+            points,
+        }
+    }
 }
 
 impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for WithCoords<'v, T, V> {
@@ -109,10 +122,8 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for WithCoords<'v,
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
             let refptr = {
-                let mutref = match self.view.get_mut(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                // the iteration rect is within the bounds of the view
+                let mutref = unsafe { self.view.get_unchecked_mut(x, y) };
 
                 let refptr: *mut T = mutref;
                 refptr
@@ -15,16 +15,13 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for OnElements<'v,
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
         if let Some((x, y)) = self.points.pop() {
             let refptr = {
-                let mutref = match self.view.get_mut(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                let mutref = self.view.get_mut(x, y)?;
 
                 let refptr: *mut T = mutref;
                 refptr
             };
 
-            unsafe { Some((x as usize, y as usize, &mut *refptr)) }
+            unsafe { Some((x, y, &mut *refptr)) }
         } else {
             None
         }
@@ -29,10 +29,8 @@ impl<'v, T: 'v, V: BidiViewMutIterable<Output = T>> Iterator for OnRect<'v, T, V
         self.state.advance(&self.rect, self.by_column);
         if let OnRectState::Iterating(x, y) = self.state {
             let refptr = {
-                let mutref = match self.view.get_mut(x, y) {
-                    Some(r) => r,
-                    None => return None,
-                };
+                // the iteration rect is within the bounds of the view
+                let mutref = unsafe { self.view.get_unchecked_mut(x, y) };
 
                 let refptr: *mut T = mutref;
                 refptr
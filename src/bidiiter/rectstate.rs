@@ -14,6 +14,14 @@ impl OnRectState {
         }
     }
 
+    /// Moves an iteration by rows to the last item of the current row of `rect`,
+    /// for iterators yielding the rest of the row from a slice.
+    pub(crate) fn skip_to_row_end(&mut self, rect: &BidiRect) {
+        if let OnRectState::Iterating(_, y) = *self {
+            *self = OnRectState::Iterating(rect.max_x() - 1, y);
+        }
+    }
+
     pub(crate) fn advance(&mut self, rect: &BidiRect, by_col: bool) {
         *self = match self {
             OnRectState::Terminated => OnRectState::Terminated,
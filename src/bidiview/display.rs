@@ -0,0 +1,158 @@
+//! A module containing a configurable formatter for bidimensional data
+//! structures, that prints one row per line.
+//!
+//! The formatter is usually created through [`BidiView::display()`][crate::BidiView::display]
+//! and configured through its builder methods; containers of [`Display`] types
+//! also implement [`Display`] themselves, with the default settings and cells
+//! separated by a space.
+//!
+//! # Examples
+//!
+//! ```
+//! use bidivec::{bidivec, BidiView};
+//!
+//! let map = bidivec!{
+//!     [0, 1, 0],
+//!     [0, 0, 1],
+//! };
+//!
+//! assert_eq!(
+//!     map.display()
+//!         .with_cell(|n| if *n == 0 { ".".to_string() } else { "#".to_string() })
+//!         .render(),
+//!     ".#.\n..#"
+//! );
+//!
+//! let v = bidivec!{
+//!     [1, 20, 3],
+//!     [400, 5, 6],
+//! };
+//!
+//! assert_eq!(v.display().column_sep(" ").render(), "  1  20   3\n400   5   6");
+//! assert_eq!(v.to_string(), "  1  20   3\n400   5   6");
+//! ```
+use super::*;
+use std::fmt::{self, Display};
+
+type CellFn<'a, T> = dyn Fn(&T) -> String + 'a;
+
+/// A configurable formatter for a [`BidiView`], see the [module level
+/// documentation][self].
+pub struct BidiDisplay<'a, V: BidiView + ?Sized> {
+    view: &'a V,
+    cell: Box<CellFn<'a, V::Output>>,
+    column_sep: String,
+    row_sep: String,
+    pad: bool,
+}
+
+impl<'a, V: BidiView + ?Sized> BidiDisplay<'a, V> {
+    /// Creates a new formatter for the given view, which formats each
+    /// item with the specified function. This is useful for views whose
+    /// items don't implement [`Display`]; otherwise,
+    /// [`BidiView::display()`][crate::BidiView::display] is more convenient.
+    pub fn new<F>(view: &'a V, cell: F) -> Self
+    where
+        F: Fn(&V::Output) -> String + 'a,
+    {
+        Self {
+            view,
+            cell: Box::new(cell),
+            column_sep: String::new(),
+            row_sep: "\n".to_string(),
+            pad: true,
+        }
+    }
+
+    /// Sets the function used to format each item
+    pub fn with_cell<F>(mut self, cell: F) -> Self
+    where
+        F: Fn(&V::Output) -> String + 'a,
+    {
+        self.cell = Box::new(cell);
+        self
+    }
+
+    /// Sets the separator printed between the items of a row (by default,
+    /// nothing)
+    pub fn column_sep(mut self, sep: &str) -> Self {
+        self.column_sep = sep.to_string();
+        self
+    }
+
+    /// Sets the separator printed between rows (by default, a newline)
+    pub fn row_sep(mut self, sep: &str) -> Self {
+        self.row_sep = sep.to_string();
+        self
+    }
+
+    /// Sets whether shorter items are padded with spaces on the left, so
+    /// that all the columns have the same width (by default, true)
+    pub fn pad(mut self, pad: bool) -> Self {
+        self.pad = pad;
+        self
+    }
+
+    /// Renders the view to a string
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<'a, V: BidiView + ?Sized> Display for BidiDisplay<'a, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = (self.view.width(), self.view.height());
+
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|pos| (self.cell)(&self.view[pos]))
+            .collect::<Vec<_>>();
+
+        let cell_width = if self.pad {
+            cells.iter().map(|c| c.chars().count()).max().unwrap_or(0)
+        } else {
+            0
+        };
+
+        for (i, cell) in cells.iter().enumerate() {
+            if i > 0 {
+                if i % width == 0 {
+                    f.write_str(&self.row_sep)?;
+                } else {
+                    f.write_str(&self.column_sep)?;
+                }
+            }
+
+            for _ in cell.chars().count()..cell_width {
+                f.write_str(" ")?;
+            }
+            f.write_str(cell)?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_display {
+    ($($t:ident),*) => {
+        $(impl<T: Display> Display for crate::$t<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.display().column_sep(" ").fmt(f)
+            }
+        })*
+    };
+}
+
+impl_display!(BidiVec, BidiArray, BidiGrowVec);
+
+impl<'a, T: Display> Display for crate::BidiSlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display().column_sep(" ").fmt(f)
+    }
+}
+
+impl<'a, T: Display> Display for crate::BidiMutSlice<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display().column_sep(" ").fmt(f)
+    }
+}
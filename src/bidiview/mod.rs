@@ -1,6 +1,9 @@
+pub mod display;
 mod traits;
 pub mod transforming;
 
+pub use traits::BidiBuild;
+pub use traits::BidiConstruct;
 pub use traits::BidiFrom;
 pub use traits::BidiView;
 pub use traits::BidiViewMut;
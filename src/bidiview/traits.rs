@@ -1,6 +1,8 @@
+use super::display::BidiDisplay;
 use super::transforming::*;
 use crate::bidiiter::*;
-use crate::{BidiError, BidiRect};
+use crate::{BidiDirection, BidiError, BidiRect, BidiVec};
+use std::cmp::min;
 use std::ops::{Index, IndexMut};
 
 /// An object-safe trait providing a bidimensional view over a data structure.
@@ -64,7 +66,8 @@ pub trait BidiView: Index<(usize, usize)> {
     }
 
     /// Returns true if two bidimensional views are equivalent (that is they
-    /// have the same width, height and equal elements
+    /// have the same width, height and equal elements). Rows which are contiguous
+    /// in memory in both views (see [`BidiView::row_slice`]) are compared as slices.
     ///
     /// # Examples
     /// ```
@@ -85,21 +88,10 @@ pub trait BidiView: Index<(usize, usize)> {
     fn equivalent<V>(&self, other: &V) -> bool
     where
         Self: Sized,
-        Self::Output: PartialEq + Sized + std::fmt::Debug,
+        Self::Output: PartialEq + Sized,
         V: BidiView<Output = Self::Output>,
     {
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                print!("{:?}", self[(x, y)]);
-            }
-            println!();
-        }
-
-        if other.width() != self.width() || other.height() != self.height() {
-            false
-        } else {
-            self.iter().eq(other.iter())
-        }
+        crate::collections::comparing::views_eq(self, other)
     }
 
     /// Returns the item at (x, y) coordinates, or [`None`] if the
@@ -122,6 +114,11 @@ pub trait BidiView: Index<(usize, usize)> {
     /// Returns the item at (x, y) coordinates (using signed coordinates),
     /// or [`None`] if the coordinates are out of range.
     ///
+    /// Signed coordinates spare the casts when the coordinates are computed
+    /// from offsets (e.g. the neighbours of a location), which can be negative.
+    /// To clamp or wrap out of range coordinates instead, use
+    /// [`BidiView::get_clamped`] or [`BidiView::get_wrapped`].
+    ///
     /// # Examples
     /// ```
     /// # use bidivec::{bidivec, bidiarray, BidiView};
@@ -143,6 +140,175 @@ pub trait BidiView: Index<(usize, usize)> {
         }
     }
 
+    /// Returns the item at the `(x, y)` coordinates, or [`BidiError::OutOfBounds`]
+    /// if the coordinates are out of range. This is the non-panicking counterpart
+    /// of indexing, that can be bubbled up with `?`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiError, BidiView};
+    ///
+    /// let a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(a.try_index((1, 0))?, &2);
+    /// assert_eq!(
+    ///     a.try_index((1, 2)),
+    ///     Err(BidiError::OutOfBounds { x: 1, y: 2, width: 2, height: 2 })
+    /// );
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn try_index(&self, index: (usize, usize)) -> Result<&Self::Output, BidiError> {
+        self.get(index.0, index.1)
+            .ok_or_else(|| BidiError::out_of_bounds(index.0, index.1, self.size()))
+    }
+
+    /// Returns the item at (x, y) coordinates, or `default` if the coordinates are
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(a.get_or(1, 1, &0), &4);
+    /// assert_eq!(a.get_or(2, 1, &0), &0);
+    /// ```
+    fn get_or<'a>(&'a self, x: usize, y: usize, default: &'a Self::Output) -> &'a Self::Output {
+        self.get(x, y).unwrap_or(default)
+    }
+
+    /// Returns the item at (x, y) coordinates (using signed coordinates), clamping
+    /// coordinates which are out of range to the nearest edge of the view (e.g. to
+    /// apply a kernel near the edges of an image). Returns [`None`] only if the
+    /// view is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(a.get_clamped(1, 0), Some(&2));
+    /// assert_eq!(a.get_clamped(-1, 5), Some(&3));
+    /// assert_eq!(a.get_clamped(7, -3), Some(&2));
+    /// ```
+    fn get_clamped(&self, x: isize, y: isize) -> Option<&Self::Output> {
+        let (width, height) = self.size();
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let clamp = |v: isize, len: usize| {
+            if v < 0 {
+                0
+            } else {
+                min(v as usize, len - 1)
+            }
+        };
+
+        self.get(clamp(x, width), clamp(y, height))
+    }
+
+    /// Returns the item at (x, y) coordinates (using signed coordinates), wrapping
+    /// coordinates which are out of range around the edges of the view, as if the
+    /// view was tiled infinitely in all directions. Returns [`None`] only if the
+    /// view is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let a = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(a.get_wrapped(1, 0), Some(&2));
+    /// assert_eq!(a.get_wrapped(-1, 0), Some(&3));
+    /// assert_eq!(a.get_wrapped(4, -3), Some(&5));
+    /// ```
+    fn get_wrapped(&self, x: isize, y: isize) -> Option<&Self::Output> {
+        let (width, height) = self.size();
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let wrap = |v: isize, len: usize| {
+            if v < 0 {
+                let rem = (v.wrapping_neg() as usize) % len;
+                (len - rem) % len
+            } else {
+                v as usize % len
+            }
+        };
+
+        self.get(wrap(x, width), wrap(y, height))
+    }
+
+    /// Returns the item at (x, y) coordinates, skipping the bounds check where
+    /// the view supports it. This is used by iterators, which validate the whole
+    /// traversal up front, so that they don't pay for a bounds check per item.
+    ///
+    /// The default implementation goes through [`Index`], so it still panics if
+    /// the coordinates are out of range.
+    ///
+    /// # Safety
+    ///
+    /// The coordinates must be lower than the width and height of the view.
+    /// Implementations which skip the bounds check must guarantee that the size
+    /// of the view can't change while the view is borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(unsafe { a.get_unchecked(1, 1) }, &4);
+    /// ```
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Output {
+        &self[(x, y)]
+    }
+
+    /// Returns the coordinates one step away from (x, y) in the specified
+    /// direction, or [`None`] if they are out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiDirection, BidiView};
+    ///
+    /// let a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(a.step(0, 0, BidiDirection::East), Some((1, 0)));
+    /// assert_eq!(a.step(0, 0, BidiDirection::West), None);
+    /// ```
+    fn step(&self, x: usize, y: usize, dir: BidiDirection) -> Option<(usize, usize)> {
+        let offset = dir.offset();
+        let (x, y) = (x as isize + offset.x, y as isize + offset.y);
+
+        if x < 0 || y < 0 || !self.is_in_bounds(x as usize, y as usize) {
+            None
+        } else {
+            Some((x as usize, y as usize))
+        }
+    }
+
     /// Returns the bounding rect of the view
     ///
     /// # Examples
@@ -165,6 +331,116 @@ pub trait BidiView: Index<(usize, usize)> {
         BidiRect::new(0, 0, self.width(), self.height())
     }
 
+    /// Returns true if the (x, y) coordinates are within the bounds of the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert!(v.is_in_bounds(2, 1));
+    /// assert!(!v.is_in_bounds(1, 2));
+    /// ```
+    fn is_in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width() && y < self.height()
+    }
+
+    /// Returns the coordinates of the item at the (dx, dy) offset from the (x, y)
+    /// coordinates, or [`None`] if they are out of the bounds of the view. No
+    /// overflow can happen, whatever the coordinates and the offset.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(v.offset_coords(2, 0, -1, 1), Some((1, 1)));
+    /// assert_eq!(v.offset_coords(0, 0, -1, 0), None);
+    /// assert_eq!(v.offset_coords(2, 1, 1, 0), None);
+    /// ```
+    fn offset_coords(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = offset_coord(x, dx)?;
+        let y = offset_coord(y, dy)?;
+
+        if self.is_in_bounds(x, y) {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the coordinates of the item at the (dx, dy) offset from the (x, y)
+    /// coordinates, wrapping around the edges of the view (as on a torus), or
+    /// [`None`] if the view is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(v.wrapping_offset_coords(2, 0, -1, 1), Some((1, 1)));
+    /// assert_eq!(v.wrapping_offset_coords(0, 0, -1, 0), Some((2, 0)));
+    /// assert_eq!(v.wrapping_offset_coords(2, 1, 1, 7), Some((0, 0)));
+    /// ```
+    fn wrapping_offset_coords(
+        &self,
+        x: usize,
+        y: usize,
+        dx: isize,
+        dy: isize,
+    ) -> Option<(usize, usize)> {
+        let (width, height) = self.size();
+
+        if width == 0 || height == 0 {
+            None
+        } else {
+            Some((wrap_coord(x, dx, width), wrap_coord(y, dy, height)))
+        }
+    }
+
+    /// Returns the items of the specified row as a contiguous slice, or [`None`]
+    /// if the row is out of range or the view doesn't lay out its rows contiguously
+    /// in memory.
+    ///
+    /// The default implementation always returns [`None`]; the data structures of
+    /// this crate return the row, which allows algorithms to process whole rows
+    /// at once (e.g. [`editing::copy`][crate::editing::copy] copies rows with
+    /// [`copy_from_slice`][slice::copy_from_slice]).
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(v.row_slice(1), Some(&[4, 5, 6][..]));
+    /// assert_eq!(v.row_slice(2), None);
+    ///
+    /// // transformed views don't have contiguous rows
+    /// assert_eq!(v.to_transposed().row_slice(0), None);
+    /// ```
+    fn row_slice(&self, _row: usize) -> Option<&[Self::Output]>
+    where
+        Self::Output: Sized,
+    {
+        None
+    }
+
     /// Returns an iterator over the items of the view
     ///
     /// # Examples
@@ -184,7 +460,7 @@ pub trait BidiView: Index<(usize, usize)> {
     ///
     /// assert_eq!(Some(6), get_max(&v));
     /// ```
-    fn iter(&self) -> Iter<Self::Output, Self>
+    fn iter(&self) -> Iter<'_, Self::Output, Self>
     where
         Self::Output: Sized,
         Self: Sized,
@@ -192,6 +468,31 @@ pub trait BidiView: Index<(usize, usize)> {
         Iter::new(self)
     }
 
+    /// Returns a formatter printing the view one row per line, which can be
+    /// configured (e.g. to change how items are formatted or separated) and then
+    /// rendered to a string or used with `format!`. See the [`display`][crate::display]
+    /// module.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let v = bidiarray!{
+    ///     ['#', '.'],
+    ///     ['.', '#'],
+    /// };
+    ///
+    /// assert_eq!(v.display().render(), "#.\n.#");
+    /// assert_eq!(format!("{}", v.display().row_sep("|")), "#.|.#");
+    /// ```
+    fn display(&self) -> BidiDisplay<'_, Self>
+    where
+        Self::Output: std::fmt::Display,
+        Self: Sized,
+    {
+        BidiDisplay::new(self, |item: &Self::Output| item.to_string())
+    }
+
     /// Returns a bidiview that represents data in this bidiview as
     /// transposed (that is, flipped over its diagonal).
     ///
@@ -219,6 +520,38 @@ pub trait BidiView: Index<(usize, usize)> {
         TransposingBidiView::new(self)
     }
 
+    /// Returns a bidiview that represents data in this bidiview as
+    /// anti-transposed (that is, flipped over its anti-diagonal, going
+    /// from the top right corner to the bottom left one).
+    ///
+    /// Together with [`BidiView::to_transposed`], the rotations and the
+    /// reversals of rows and columns, this completes the set of the eight
+    /// transformations mapping a rectangle onto itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView};
+    ///
+    /// let v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let v = v.to_anti_transposed();
+    ///
+    /// assert!(v.equivalent(&bidiarray!{
+    ///     [6, 3],
+    ///     [5, 2],
+    ///     [4, 1],
+    /// }));
+    /// ```
+    fn to_anti_transposed(self) -> AntiTransposingBidiView<Self>
+    where
+        Self: Sized,
+    {
+        AntiTransposingBidiView::new(self)
+    }
+
     /// Returns a bidiview that represents data in this bidiview as
     /// rotated by 180°.
     ///
@@ -380,60 +713,631 @@ pub trait BidiView: Index<(usize, usize)> {
     {
         CroppingBidiView::new(self, rect)
     }
-}
 
-/// An object-safe trait providing a mutable bidimensional view over a data structure.
-pub trait BidiViewMut: BidiView + IndexMut<(usize, usize)> {
-    /// Mutably returns the item at (x, y) coordinates, or [`None`] if the
-    /// coordinates are out of range.
+    /// Returns a bidiview that samples one item every `step_x` columns and every
+    /// `step_y` rows of this bidiview, starting from the top left corner; the
+    /// resulting view is as small as needed to contain the sampled items.
+    ///
+    /// This is useful, for example, to render a large data structure at a lower
+    /// level of detail without building a downscaled copy; combine it with
+    /// [`BidiView::to_cropped`] to choose the first sampled item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step_x` or `step_y` are zero.
     ///
     /// # Examples
     /// ```
-    /// # use bidivec::{bidivec, bidiarray, BidiView, BidiViewMut};
+    /// # use bidivec::{bidiarray, BidiView};
     ///
-    /// let mut a = bidiarray!{
-    ///     [1, 2],
-    ///     [3, 4],
+    /// let v = bidiarray!{
+    ///     [1, 2, 3, 4, 5],
+    ///     [6, 7, 8, 9, 10],
+    ///     [11, 12, 13, 14, 15],
     /// };
     ///
-    /// *a.get_mut(0, 0).unwrap() = 8;
+    /// let v = v.to_strided(2, 2);
     ///
-    /// assert_eq!(a[(0, 0)], 8);
-    /// assert!(a.get_mut(3, 0).is_none());
+    /// assert!(v.equivalent(&bidiarray!{
+    ///     [1, 3, 5],
+    ///     [11, 13, 15],
+    /// }));
     /// ```
-    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Output>;
+    fn to_strided(self, step_x: usize, step_y: usize) -> StridingBidiView<Self>
+    where
+        Self: Sized,
+    {
+        StridingBidiView::new(self, step_x, step_y)
+    }
 
-    /// Mutably returns the item at (x, y) coordinates (using signed coordinates),
-    /// or [`None`] if the coordinates are out of range.
+    /// Returns a view of the items of `rect`, borrowing the view instead of
+    /// consuming it (as [`BidiView::to_cropped`] does), or [`BidiError::OutOfBounds`]
+    /// if the rectangle doesn't fit in the view.
     ///
     /// # Examples
     /// ```
-    /// # use bidivec::{bidivec, bidiarray, BidiView, BidiViewMut};
+    /// # use bidivec::{bidivec, BidiView, BidiRect};
     ///
-    /// let mut a = bidiarray!{
-    ///     [1, 2],
-    ///     [3, 4],
+    /// let v = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
     /// };
     ///
-    /// *a.get_mut_signed(0, 0).unwrap() = 8;
+    /// let cropped = v.try_index_rect(&BidiRect::new(1, 0, 2, 2))?;
     ///
-    /// assert_eq!(a[(0, 0)], 8);
-    /// assert!(a.get_mut_signed(-1, 0).is_none());
-    fn get_mut_signed(&mut self, x: isize, y: isize) -> Option<&mut Self::Output> {
-        if x < 0 || y < 0 {
-            None
-        } else {
-            self.get_mut(x as usize, y as usize)
-        }
+    /// assert!(cropped.equivalent(&bidivec!{
+    ///     [2, 3],
+    ///     [5, 6],
+    /// }));
+    /// assert!(cropped.try_index((2, 0)).is_err());
+    /// assert!(v.try_index_rect(&BidiRect::new(2, 0, 2, 2)).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn try_index_rect(
+        &self,
+        rect: &BidiRect,
+    ) -> Result<CroppingBidiView<BorrowingBidiView<'_, Self>>, BidiError>
+    where
+        Self: Sized,
+    {
+        CroppingBidiView::new(BorrowingBidiView::new(self), rect)
     }
-}
 
-/// An unsafe trait for views which can have a [`BidiViewMut`] mutable iterator.
-/// This is `unsafe` because additional constraints must be guaranteed by a [`BidiViewMut`]
-/// to be safely mutably iterable.
-///
-/// # Safety
-///
+    /// Returns a new [`BidiVec<u8>`] with the items of the view linearly mapped from
+    /// the `min..=max` range to `0..=255` (e.g. to upload the view as a grayscale
+    /// texture). Items outside of the range are clamped; if `max` is not greater than
+    /// `min`, or an item is NaN, the item is mapped to 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidivec, BidiView};
+    ///
+    /// let heights = bidivec!{
+    ///     [-10, 0, 10],
+    ///     [20, 30, 100],
+    /// };
+    ///
+    /// assert_eq!(heights.normalize_to_u8(0.0, 30.0), bidivec!{
+    ///     [0, 0, 85],
+    ///     [170, 255, 255],
+    /// });
+    /// ```
+    fn normalize_to_u8(&self, min: f64, max: f64) -> BidiVec<u8>
+    where
+        Self: Sized,
+        Self::Output: Copy + Into<f64>,
+    {
+        map_rect(self, &self.bounding_rect(), |v| normalize_u8(v, min, max))
+    }
+
+    /// Like [`normalize_to_u8`][BidiView::normalize_to_u8], but only maps the items in
+    /// the specified rect, returning a [`BidiVec<u8>`] with the size of the rect.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if the rect is not contained in the view.
+    fn normalize_rect_to_u8(
+        &self,
+        rect: &BidiRect,
+        min: f64,
+        max: f64,
+    ) -> Result<BidiVec<u8>, BidiError>
+    where
+        Self: Sized,
+        Self::Output: Copy + Into<f64>,
+    {
+        check_rect(self, rect)?;
+        Ok(map_rect(self, rect, |v| normalize_u8(v, min, max)))
+    }
+
+    /// Returns a new [`BidiVec<f32>`] with the items of the view linearly mapped to
+    /// the `0.0..=1.0` range, where the minimum item of the view is mapped to `0.0`
+    /// and the maximum to `1.0` (e.g. to upload the view as a floating point texture).
+    /// If all the items are equal they are mapped to `0.0`; NaN items are ignored when
+    /// finding the range, and are mapped to `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidivec, BidiView};
+    ///
+    /// let heights = bidivec!{
+    ///     [10u16, 20],
+    ///     [30, 50],
+    /// };
+    ///
+    /// assert_eq!(heights.to_f32_normalized(), bidivec!{
+    ///     [0.0, 0.25],
+    ///     [0.5, 1.0],
+    /// });
+    /// ```
+    fn to_f32_normalized(&self) -> BidiVec<f32>
+    where
+        Self: Sized,
+        Self::Output: Copy + Into<f64>,
+    {
+        normalize_f32(self, &self.bounding_rect())
+    }
+
+    /// Like [`to_f32_normalized`][BidiView::to_f32_normalized], but only maps the
+    /// items in the specified rect (also finding the minimum and maximum items in
+    /// the rect only), returning a [`BidiVec<f32>`] with the size of the rect.
+    ///
+    /// Returns [`BidiError::OutOfBounds`] if the rect is not contained in the view.
+    fn to_f32_normalized_rect(&self, rect: &BidiRect) -> Result<BidiVec<f32>, BidiError>
+    where
+        Self: Sized,
+        Self::Output: Copy + Into<f64>,
+    {
+        check_rect(self, rect)?;
+        Ok(normalize_f32(self, rect))
+    }
+}
+
+fn check_rect<V: BidiView>(view: &V, rect: &BidiRect) -> Result<(), BidiError> {
+    let end_x = rect.x.checked_add(rect.width);
+    let end_y = rect.y.checked_add(rect.height);
+
+    if end_x.map_or(true, |end| end > view.width()) || end_y.map_or(true, |end| end > view.height())
+    {
+        Err(BidiError::rect_out_of_bounds(rect, view.size()))
+    } else {
+        Ok(())
+    }
+}
+
+fn map_rect<V, T, F>(view: &V, rect: &BidiRect, mut f: F) -> BidiVec<T>
+where
+    V: BidiView,
+    V::Output: Copy + Into<f64>,
+    F: FnMut(f64) -> T,
+{
+    BidiVec::with_size_func_xy(rect.width, rect.height, |x, y| {
+        f(view[(rect.x + x, rect.y + y)].into())
+    })
+}
+
+fn normalize_u8(value: f64, min: f64, max: f64) -> u8 {
+    if max > min {
+        // NaN values become 0, as f64::max returns the non-NaN operand
+        let t = ((value - min) / (max - min)).max(0.0).min(1.0);
+        (t * 255.0).round() as u8
+    } else {
+        0
+    }
+}
+
+fn normalize_f32<V>(view: &V, rect: &BidiRect) -> BidiVec<f32>
+where
+    V: BidiView,
+    V::Output: Copy + Into<f64>,
+{
+    let (mut min, mut max) = (std::f64::INFINITY, std::f64::NEG_INFINITY);
+
+    for y in rect.y..rect.y + rect.height {
+        for x in rect.x..rect.x + rect.width {
+            let value: f64 = view[(x, y)].into();
+            min = min.min(value);
+            max = max.max(value);
+        }
+    }
+
+    map_rect(view, rect, |value| {
+        if max > min && !value.is_nan() {
+            ((value - min) / (max - min)) as f32
+        } else {
+            0.0
+        }
+    })
+}
+
+fn clone_rect_over<S, D>(source: &S, dest: &mut D, x: usize, y: usize, size: (usize, usize))
+where
+    S: BidiView + ?Sized,
+    D: BidiViewMut<Output = S::Output>,
+    S::Output: Clone + Sized,
+{
+    for sy in 0..size.1 {
+        // if both rows are contiguous in memory, clone them in a single operation
+        if let Some(src) = source.row_slice(sy).and_then(|r| r.get(..size.0)) {
+            if let Some(dst) = dest
+                .row_slice_mut(y + sy)
+                .and_then(|r| r.get_mut(x..x + size.0))
+            {
+                dst.clone_from_slice(src);
+                continue;
+            }
+        }
+
+        for sx in 0..size.0 {
+            dest[(x + sx, y + sy)] = source[(sx, sy)].clone();
+        }
+    }
+}
+
+fn swap_unaliased<V>(view: &mut V, a: (usize, usize), b: (usize, usize))
+where
+    V: BidiViewMutIterable,
+    V::Output: Sized,
+{
+    let pa: *mut V::Output = &mut view[a];
+    let pb: *mut V::Output = &mut view[b];
+    // Safety: BidiViewMutIterable guarantees that different coordinates
+    // refer to different items, and the two pointers are valid for the
+    // duration of the swap.
+    unsafe { std::ptr::swap(pa, pb) };
+}
+
+fn offset_coord(coord: usize, delta: isize) -> Option<usize> {
+    if delta >= 0 {
+        coord.checked_add(delta as usize)
+    } else {
+        coord.checked_sub(delta.wrapping_neg() as usize)
+    }
+}
+
+fn wrap_coord(coord: usize, delta: isize, size: usize) -> usize {
+    ((coord as i128 + delta as i128).rem_euclid(size as i128)) as usize
+}
+
+/// An object-safe trait providing a mutable bidimensional view over a data structure.
+pub trait BidiViewMut: BidiView + IndexMut<(usize, usize)> {
+    /// Mutably returns the item at (x, y) coordinates, or [`None`] if the
+    /// coordinates are out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidivec, bidiarray, BidiView, BidiViewMut};
+    ///
+    /// let mut a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// *a.get_mut(0, 0).unwrap() = 8;
+    ///
+    /// assert_eq!(a[(0, 0)], 8);
+    /// assert!(a.get_mut(3, 0).is_none());
+    /// ```
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Output>;
+
+    /// Mutably returns the item at (x, y) coordinates (using signed coordinates),
+    /// or [`None`] if the coordinates are out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidivec, bidiarray, BidiView, BidiViewMut};
+    ///
+    /// let mut a = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// *a.get_mut_signed(0, 0).unwrap() = 8;
+    ///
+    /// assert_eq!(a[(0, 0)], 8);
+    /// assert!(a.get_mut_signed(-1, 0).is_none());
+    fn get_mut_signed(&mut self, x: isize, y: isize) -> Option<&mut Self::Output> {
+        if x < 0 || y < 0 {
+            None
+        } else {
+            self.get_mut(x as usize, y as usize)
+        }
+    }
+
+    /// Mutably returns the item at (x, y) coordinates, skipping the bounds check
+    /// where the view supports it. See [`BidiView::get_unchecked`].
+    ///
+    /// The default implementation goes through [`IndexMut`], so it still panics if
+    /// the coordinates are out of range.
+    ///
+    /// # Safety
+    ///
+    /// The coordinates must be lower than the width and height of the view.
+    /// Implementations which skip the bounds check must guarantee that the size
+    /// of the view can't change while the view is borrowed.
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut Self::Output {
+        &mut self[(x, y)]
+    }
+
+    /// Mutably returns the items of the specified row as a contiguous slice, or
+    /// [`None`] if the row is out of range or the view doesn't lay out its rows
+    /// contiguously in memory. See [`BidiView::row_slice`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiViewMut};
+    ///
+    /// let mut v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// v.row_slice_mut(0).unwrap().copy_from_slice(&[7, 8, 9]);
+    ///
+    /// assert_eq!(v, bidiarray!{
+    ///     [7, 8, 9],
+    ///     [4, 5, 6],
+    /// });
+    /// ```
+    fn row_slice_mut(&mut self, _row: usize) -> Option<&mut [Self::Output]>
+    where
+        Self::Output: Sized,
+    {
+        None
+    }
+
+    /// Swaps the items at the `a` and `b` coordinates. If any of the coordinates
+    /// are out of range, [`BidiError::OutOfBounds`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiView, BidiViewMut};
+    ///
+    /// let v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let mut t = v.to_transposed();
+    /// t.swap((0, 0), (1, 2))?;
+    ///
+    /// assert!(t.equivalent(&bidiarray!{
+    ///     [6, 4],
+    ///     [2, 5],
+    ///     [3, 1],
+    /// }));
+    /// assert!(t.swap((0, 0), (2, 0)).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> Result<(), BidiError>
+    where
+        Self::Output: Sized,
+    {
+        let size = self.size();
+        let pa: *mut Self::Output = self
+            .get_mut(a.0, a.1)
+            .ok_or_else(|| BidiError::out_of_bounds(a.0, a.1, size))?;
+        let pb: *mut Self::Output = self
+            .get_mut(b.0, b.1)
+            .ok_or_else(|| BidiError::out_of_bounds(b.0, b.1, size))?;
+
+        // the pointers might point to the same item, as some views map
+        // different coordinates to the same item; ptr::swap supports that.
+        unsafe {
+            std::ptr::swap(pa, pb);
+        }
+        Ok(())
+    }
+
+    /// Replaces the item at (x, y) coordinates with `value`, returning the
+    /// previous item, or [`BidiError::OutOfBounds`] if the coordinates are out
+    /// of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiViewMut};
+    ///
+    /// let mut v = bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(v.replace(1, 0, 20)?, 2);
+    /// assert_eq!(v[(1, 0)], 20);
+    /// assert!(v.replace(2, 0, 20).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn replace(
+        &mut self,
+        x: usize,
+        y: usize,
+        value: Self::Output,
+    ) -> Result<Self::Output, BidiError>
+    where
+        Self::Output: Sized,
+    {
+        let size = self.size();
+        self.get_mut(x, y)
+            .map(|item| std::mem::replace(item, value))
+            .ok_or_else(|| BidiError::out_of_bounds(x, y, size))
+    }
+
+    /// Takes the item at (x, y) coordinates, leaving the default value in its
+    /// place, or returns [`BidiError::OutOfBounds`] if the coordinates are out
+    /// of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiViewMut};
+    ///
+    /// let mut v = bidiarray!{
+    ///     [String::from("a"), String::from("b")],
+    /// };
+    ///
+    /// assert_eq!(v.take(1, 0)?, "b");
+    /// assert_eq!(v[(1, 0)], "");
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn take(&mut self, x: usize, y: usize) -> Result<Self::Output, BidiError>
+    where
+        Self::Output: Sized + Default,
+    {
+        self.replace(x, y, Self::Output::default())
+    }
+
+    /// Overwrites the items starting at (x, y) coordinates with clones of all the
+    /// items of `view` (e.g. to paste a small grid into a bigger one), or returns
+    /// [`BidiError::OutOfBounds`] if `view` doesn't fit. See
+    /// [`insert_view_clipped`][BidiViewMut::insert_view_clipped] to paste only
+    /// the part that fits, and the [`editing`][crate::editing] module for more
+    /// ways to copy and blend views.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, bidivec, BidiViewMut};
+    ///
+    /// let mut v = bidivec![0; 4, 3];
+    ///
+    /// v.insert_view(1, 1, &bidiarray![1, 2, 3; 3])?;
+    ///
+    /// assert_eq!(v, bidivec!{
+    ///     [0, 0, 0, 0],
+    ///     [0, 1, 2, 3],
+    ///     [0, 0, 0, 0],
+    /// });
+    /// assert!(v.insert_view(2, 1, &bidiarray![1, 2, 3; 3]).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn insert_view<V>(&mut self, x: usize, y: usize, view: &V) -> Result<(), BidiError>
+    where
+        Self: Sized,
+        V: BidiView<Output = Self::Output> + ?Sized,
+        Self::Output: Clone + Sized,
+    {
+        let fits = match (x.checked_add(view.width()), y.checked_add(view.height())) {
+            (Some(end_x), Some(end_y)) => end_x <= self.width() && end_y <= self.height(),
+            _ => false,
+        };
+
+        if !fits {
+            let rect = BidiRect::new(x, y, view.width(), view.height());
+            return Err(BidiError::rect_out_of_bounds(&rect, self.size()));
+        }
+
+        clone_rect_over(view, self, x, y, view.size());
+        Ok(())
+    }
+
+    /// Overwrites the items starting at (x, y) coordinates with clones of the items
+    /// of `view`, ignoring the items that would fall out of bounds. Returns the
+    /// rectangle that has been overwritten, or [`None`] if no item has been.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, bidivec, BidiRect, BidiViewMut};
+    ///
+    /// let mut v = bidivec![0; 4, 3];
+    ///
+    /// let rect = v.insert_view_clipped(2, 1, &bidiarray![1, 2, 3; 3]);
+    ///
+    /// assert_eq!(rect, Some(BidiRect::new(2, 1, 2, 1)));
+    /// assert_eq!(v, bidivec!{
+    ///     [0, 0, 0, 0],
+    ///     [0, 0, 1, 2],
+    ///     [0, 0, 0, 0],
+    /// });
+    /// assert_eq!(v.insert_view_clipped(4, 0, &bidiarray![1, 2, 3; 3]), None);
+    /// ```
+    fn insert_view_clipped<V>(&mut self, x: usize, y: usize, view: &V) -> Option<BidiRect>
+    where
+        Self: Sized,
+        V: BidiView<Output = Self::Output> + ?Sized,
+        Self::Output: Clone + Sized,
+    {
+        if !self.is_in_bounds(x, y) {
+            return None;
+        }
+
+        let rect = BidiRect::new(x, y, view.width(), view.height()).clip_to(self)?;
+
+        clone_rect_over(view, self, x, y, (rect.width, rect.height));
+        Some(rect)
+    }
+
+    /// Moves the items of the specified rectangle out of the view, to a new
+    /// [`BidiVec`], filling the hole with clones of `fill` (as in the "cut" operation
+    /// of an editor), or returns [`BidiError::OutOfBounds`] if the rectangle
+    /// isn't completely inside the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidivec, BidiRect, BidiViewMut};
+    ///
+    /// let mut v = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let cut = v.cut(&BidiRect::new(1, 0, 2, 2), 0)?;
+    ///
+    /// assert_eq!(cut, bidivec!{
+    ///     [2, 3],
+    ///     [5, 6],
+    /// });
+    /// assert_eq!(v, bidivec!{
+    ///     [1, 0, 0],
+    ///     [4, 0, 0],
+    /// });
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn cut(
+        &mut self,
+        rect: &BidiRect,
+        fill: Self::Output,
+    ) -> Result<BidiVec<Self::Output>, BidiError>
+    where
+        Self: Sized,
+        Self::Output: Clone + Sized,
+    {
+        check_rect(self, rect)?;
+
+        Ok(BidiVec::with_size_func_xy(
+            rect.width,
+            rect.height,
+            |x, y| std::mem::replace(&mut self[(rect.x + x, rect.y + y)], fill.clone()),
+        ))
+    }
+
+    /// Moves the items of the `from` rectangle so that its top-left corner is at
+    /// the `to` coordinates (the rectangles can overlap), filling the vacated
+    /// items with clones of `fill`. Returns [`BidiError::OutOfBounds`], without
+    /// altering the view, if either rectangle isn't completely inside the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidivec, BidiRect, BidiViewMut};
+    ///
+    /// let mut v = bidivec!{
+    ///     [1, 2, 0],
+    ///     [3, 4, 0],
+    ///     [0, 0, 0],
+    /// };
+    ///
+    /// v.move_rect(&BidiRect::new(0, 0, 2, 2), (1, 1), 0)?;
+    ///
+    /// assert_eq!(v, bidivec!{
+    ///     [0, 0, 0],
+    ///     [0, 1, 2],
+    ///     [0, 3, 4],
+    /// });
+    /// assert!(v.move_rect(&BidiRect::new(1, 1, 2, 2), (2, 0), 0).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn move_rect(
+        &mut self,
+        from: &BidiRect,
+        to: (usize, usize),
+        fill: Self::Output,
+    ) -> Result<(), BidiError>
+    where
+        Self: Sized,
+        Self::Output: Clone + Sized,
+    {
+        check_rect(self, &BidiRect::new(to.0, to.1, from.width, from.height))?;
+        let moved = self.cut(from, fill)?;
+
+        for (i, item) in moved.into_vec().into_iter().enumerate() {
+            self[(to.0 + i % from.width, to.1 + i / from.width)] = item;
+        }
+
+        Ok(())
+    }
+}
+
+/// An unsafe trait for views which can have a [`BidiViewMut`] mutable iterator.
+/// This is `unsafe` because additional constraints must be guaranteed by a [`BidiViewMut`]
+/// to be safely mutably iterable.
+///
+/// # Safety
+///
 /// Types implementing this trait must absolutely guarantee that a given item is accessed
 /// uniquely through a given `(x, y)` pair of coordinates, or, more explicitely, that
 /// given two set of coordinates `(x, y)` and `(x', y')`, they refer to the same item in
@@ -468,13 +1372,129 @@ pub unsafe trait BidiViewMutIterable: BidiViewMut {
     ///     [0, 0, 0],
     /// }));
     /// ```
-    fn iter_mut(&mut self) -> IterMut<Self::Output, Self>
+    fn iter_mut(&mut self) -> IterMut<'_, Self::Output, Self>
     where
         Self::Output: Sized,
         Self: Sized,
     {
         IterMut::new(self)
     }
+
+    /// Reverses the order of the items in all the columns of the specified
+    /// rectangle, that is, flips the rectangle in place over its horizontal
+    /// axis. Returns [`BidiError::OutOfBounds`] if the rectangle isn't completely
+    /// inside the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiRect, BidiViewMutIterable};
+    ///
+    /// let mut v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// };
+    ///
+    /// v.reverse_columns_in_rect(&BidiRect::new(1, 0, 2, 3))?;
+    ///
+    /// assert_eq!(v, bidiarray!{
+    ///     [1, 8, 9],
+    ///     [4, 5, 6],
+    ///     [7, 2, 3],
+    /// });
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn reverse_columns_in_rect(&mut self, rect: &BidiRect) -> Result<(), BidiError>
+    where
+        Self::Output: Sized,
+        Self: Sized,
+    {
+        check_rect(self, rect)?;
+
+        for dy in 0..rect.height / 2 {
+            for x in rect.x..rect.max_x() {
+                swap_unaliased(self, (x, rect.y + dy), (x, rect.max_y() - dy - 1));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reverses the order of the items in all the rows of the specified
+    /// rectangle, that is, flips the rectangle in place over its vertical
+    /// axis. Returns [`BidiError::OutOfBounds`] if the rectangle isn't completely
+    /// inside the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiRect, BidiViewMutIterable};
+    ///
+    /// let mut v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// };
+    ///
+    /// v.reverse_rows_in_rect(&BidiRect::new(0, 1, 3, 2))?;
+    ///
+    /// assert_eq!(v, bidiarray!{
+    ///     [1, 2, 3],
+    ///     [6, 5, 4],
+    ///     [9, 8, 7],
+    /// });
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn reverse_rows_in_rect(&mut self, rect: &BidiRect) -> Result<(), BidiError>
+    where
+        Self::Output: Sized,
+        Self: Sized,
+    {
+        check_rect(self, rect)?;
+
+        for y in rect.y..rect.max_y() {
+            for dx in 0..rect.width / 2 {
+                swap_unaliased(self, (rect.x + dx, y), (rect.max_x() - dx - 1, y));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rotates the specified rectangle 180° in place. Returns
+    /// [`BidiError::OutOfBounds`] if the rectangle isn't completely inside the view.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bidivec::{bidiarray, BidiRect, BidiViewMutIterable};
+    ///
+    /// let mut v = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9],
+    /// };
+    ///
+    /// v.rotate180_in_rect(&BidiRect::new(0, 0, 2, 3))?;
+    ///
+    /// assert_eq!(v, bidiarray!{
+    ///     [8, 7, 3],
+    ///     [5, 4, 6],
+    ///     [2, 1, 9],
+    /// });
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    fn rotate180_in_rect(&mut self, rect: &BidiRect) -> Result<(), BidiError>
+    where
+        Self::Output: Sized,
+        Self: Sized,
+    {
+        check_rect(self, rect)?;
+
+        let len = rect.width * rect.height;
+        let coords = |i: usize| (rect.x + i % rect.width, rect.y + i / rect.width);
+
+        for i in 0..len / 2 {
+            swap_unaliased(self, coords(i), coords(len - i - 1));
+        }
+        Ok(())
+    }
 }
 
 /// An object-safe trait that bidimensional data structures can implement to
@@ -488,6 +1508,124 @@ pub trait BidiFrom<S>: Sized {
     fn from_view_cut(source: S, cut: &BidiRect) -> Result<Self, BidiError>;
 }
 
+/// A trait abstracting the constructors shared by all the owned bidimensional
+/// data structures ([`BidiVec`], [`BidiGrowVec`][crate::BidiGrowVec],
+/// [`BidiArray`][crate::BidiArray] and `BidiSmallVec`), so that generic code
+/// can create whichever of them the caller wants.
+///
+/// The methods behave as the inherent methods with the same name on every data
+/// structure; an empty data structure is returned by [`Default`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiArray, BidiConstruct, BidiGrowVec, BidiView};
+///
+/// fn multiplication_table<C>(size: usize) -> C
+/// where
+///     C: BidiConstruct + BidiView<Output = usize>,
+/// {
+///     C::with_size_func_xy(size, size, |x, y| (x + 1) * (y + 1))
+/// }
+///
+/// let a = multiplication_table::<BidiArray<_>>(10);
+/// let g = multiplication_table::<BidiGrowVec<_>>(10);
+///
+/// assert_eq!(a[(6, 7)], 56);
+/// assert!(a.equivalent(&g));
+/// assert!(BidiArray::<usize>::default().is_empty());
+/// ```
+pub trait BidiConstruct: BidiViewMut + Default + Sized
+where
+    Self::Output: Sized,
+{
+    /// Constructs a new data structure with the specified size, cloning the
+    /// specified item in every position.
+    fn with_elem(value: Self::Output, width: usize, height: usize) -> Self
+    where
+        Self::Output: Clone;
+
+    /// Constructs a new data structure with the specified size, using the
+    /// default value in every position.
+    fn with_size_default(width: usize, height: usize) -> Self
+    where
+        Self::Output: Default;
+
+    /// Constructs a new data structure with the specified size, using the
+    /// specified closure to produce values. The order the closure is called
+    /// when producing a new value is not guaranteed.
+    fn with_size_func<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut() -> Self::Output;
+
+    /// Constructs a new data structure with the specified size, using the
+    /// specified closure to produce values from their coordinates. The order
+    /// the closure is called when producing a new value is not guaranteed.
+    fn with_size_func_xy<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> Self::Output;
+}
+
+/// A trait abstracting the construction of the growable bidimensional data
+/// structures ([`BidiVec`] and [`BidiGrowVec`][crate::BidiGrowVec]) from
+/// iterators and rows, so that generic algorithms can build whichever of them
+/// the caller wants, in the same vein of [`FromIterator`][std::iter::FromIterator].
+///
+/// The methods behave as the inherent methods with the same name on every data
+/// structure. Data structures that can't grow, like [`BidiArray`][crate::BidiArray],
+/// only implement [`BidiConstruct`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiBuild, BidiGrowVec, BidiVec, BidiView};
+///
+/// fn parse<C>(text: &str) -> Result<C, bidivec::BidiError>
+/// where
+///     C: BidiBuild + BidiView<Output = char>,
+/// {
+///     let mut bvec = C::default();
+///     for line in text.lines() {
+///         bvec.push_row(line.chars())?;
+///     }
+///     Ok(bvec)
+/// }
+///
+/// let v = parse::<BidiVec<_>>("ab\ncd")?;
+/// let g = parse::<BidiGrowVec<_>>("ab\ncd")?;
+///
+/// assert_eq!(v[(1, 1)], 'd');
+/// assert!(v.equivalent(&g));
+/// assert!(parse::<BidiVec<_>>("ab\nc").is_err());
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub trait BidiBuild: BidiConstruct
+where
+    Self::Output: Sized,
+{
+    /// Creates a data structure from an iterator of items laid out by rows, with
+    /// the specified row size. If the number of items is not a multiple of the
+    /// row size, [`BidiError::IncompatibleSize`] is returned.
+    fn from_iterator<I>(iter: I, row_size: usize) -> Result<Self, BidiError>
+    where
+        I: Iterator<Item = Self::Output>;
+
+    /// Creates a data structure from an iterator of rows, each of them an
+    /// iterator of items. All the rows must have the same length, or
+    /// [`BidiError::IncompatibleSize`] is returned.
+    fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Output>;
+
+    /// Appends a row to the bottom of the data structure. If the length of
+    /// the row doesn't match the width, [`BidiError::IncompatibleSize`] is
+    /// returned.
+    fn push_row<I>(&mut self, row: I) -> Result<(), BidiError>
+    where
+        I: IntoIterator<Item = Self::Output>;
+}
+
 #[allow(dead_code)]
 fn canary_trait_object_safe_bidiview(_: &dyn BidiView<Output = ()>) -> ! {
     // This exists only to make compilation fail if for whatever reason
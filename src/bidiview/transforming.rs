@@ -9,6 +9,7 @@ use super::*;
 use crate::{BidiError, BidiRect};
 use std::cmp::min;
 use std::ops::{Index, IndexMut};
+use std::rc::Rc;
 
 macro_rules! impl_transform_type {
     ($t:ty, $src:tt) => {
@@ -38,6 +39,9 @@ macro_rules! impl_transform_type {
             }
 
             fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+                if x >= self._width() || y >= self._height() {
+                    return None;
+                }
                 let pos = self._pos(x, y);
                 self.$src.get(pos.0, pos.1)
             }
@@ -45,6 +49,9 @@ macro_rules! impl_transform_type {
 
         impl<S: BidiView + BidiViewMut> BidiViewMut for $t {
             fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Output> {
+                if x >= self._width() || y >= self._height() {
+                    return None;
+                }
                 let pos = self._pos(x, y);
                 self.$src.get_mut(pos.0, pos.1)
             }
@@ -53,7 +60,7 @@ macro_rules! impl_transform_type {
 }
 
 /// Used as an output type by [`BidiView::to_transposed()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct TransposingBidiView<S: BidiView>(S);
 impl_transform_type!(TransposingBidiView<S>, 0);
 impl<S: BidiView> TransposingBidiView<S> {
@@ -71,8 +78,27 @@ impl<S: BidiView> TransposingBidiView<S> {
     }
 }
 
+/// Used as an output type by [`BidiView::to_anti_transposed()`].
+#[derive(Clone, Debug)]
+pub struct AntiTransposingBidiView<S: BidiView>(S);
+impl_transform_type!(AntiTransposingBidiView<S>, 0);
+impl<S: BidiView> AntiTransposingBidiView<S> {
+    pub fn new(source: S) -> Self {
+        Self(source)
+    }
+    fn _pos(&self, x: usize, y: usize) -> (usize, usize) {
+        (self.0.width() - y - 1, self.0.height() - x - 1)
+    }
+    fn _width(&self) -> usize {
+        self.0.height()
+    }
+    fn _height(&self) -> usize {
+        self.0.width()
+    }
+}
+
 /// Used as an output type by [`BidiView::to_reversed_columns()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReversingColumnsBidiView<S: BidiView>(S);
 impl_transform_type!(ReversingColumnsBidiView<S>, 0);
 impl<S: BidiView> ReversingColumnsBidiView<S> {
@@ -91,7 +117,7 @@ impl<S: BidiView> ReversingColumnsBidiView<S> {
 }
 
 /// Used as an output type by [`BidiView::to_reversed_rows()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReversingRowsBidiView<S: BidiView>(S);
 impl_transform_type!(ReversingRowsBidiView<S>, 0);
 impl<S: BidiView> ReversingRowsBidiView<S> {
@@ -110,7 +136,7 @@ impl<S: BidiView> ReversingRowsBidiView<S> {
 }
 
 /// Used as an output type by [`BidiView::to_rotated180()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Rotating180BidiView<S: BidiView>(S);
 impl_transform_type!(Rotating180BidiView<S>, 0);
 impl<S: BidiView> Rotating180BidiView<S> {
@@ -129,7 +155,7 @@ impl<S: BidiView> Rotating180BidiView<S> {
 }
 
 /// Used as an output type by [`BidiView::to_rotated270ccw()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Rotating270BidiView<S: BidiView>(S);
 impl_transform_type!(Rotating270BidiView<S>, 0);
 impl<S: BidiView> Rotating270BidiView<S> {
@@ -148,7 +174,7 @@ impl<S: BidiView> Rotating270BidiView<S> {
 }
 
 /// Used as an output type by [`BidiView::to_rotated90ccw()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Rotating90BidiView<S: BidiView>(S);
 impl_transform_type!(Rotating90BidiView<S>, 0);
 impl<S: BidiView> Rotating90BidiView<S> {
@@ -167,7 +193,7 @@ impl<S: BidiView> Rotating90BidiView<S> {
 }
 
 /// Used as an output type by [`BidiView::to_cropped()`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CroppingBidiView<S: BidiView> {
     source: S,
     rect: BidiRect,
@@ -176,7 +202,7 @@ impl_transform_type!(CroppingBidiView<S>, source);
 impl<S: BidiView> CroppingBidiView<S> {
     pub fn new(source: S, r: &BidiRect) -> Result<Self, BidiError> {
         if (r.width + r.x > source.width()) || (r.height + r.y > source.height()) {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::rect_out_of_bounds(r, source.size()))
         } else {
             Ok(Self {
                 source,
@@ -195,3 +221,314 @@ impl<S: BidiView> CroppingBidiView<S> {
         min(self.rect.height, self.source.height())
     }
 }
+
+/// Used as an output type by [`BidiView::to_strided()`].
+#[derive(Clone, Debug)]
+pub struct StridingBidiView<S: BidiView> {
+    source: S,
+    step_x: usize,
+    step_y: usize,
+}
+impl_transform_type!(StridingBidiView<S>, source);
+impl<S: BidiView> StridingBidiView<S> {
+    pub fn new(source: S, step_x: usize, step_y: usize) -> Self {
+        assert!(
+            step_x != 0 && step_y != 0,
+            "strides must be greater than zero"
+        );
+        Self {
+            source,
+            step_x,
+            step_y,
+        }
+    }
+
+    fn _pos(&self, x: usize, y: usize) -> (usize, usize) {
+        (x * self.step_x, y * self.step_y)
+    }
+    fn _width(&self) -> usize {
+        let width = self.source.width();
+        width / self.step_x + usize::from(width % self.step_x != 0)
+    }
+    fn _height(&self) -> usize {
+        let height = self.source.height();
+        height / self.step_y + usize::from(height % self.step_y != 0)
+    }
+}
+
+/// Used as an output type by [`repeat()`].
+#[derive(Clone, Debug)]
+pub struct RepeatingBidiView<S: BidiView> {
+    source: S,
+    width: usize,
+    height: usize,
+}
+impl_transform_type!(RepeatingBidiView<S>, source);
+impl<S: BidiView> RepeatingBidiView<S> {
+    pub fn new(source: S, width: usize, height: usize) -> Self {
+        Self {
+            source,
+            width,
+            height,
+        }
+    }
+
+    fn _pos(&self, x: usize, y: usize) -> (usize, usize) {
+        (x % self.source.width(), y % self.source.height())
+    }
+    fn _width(&self) -> usize {
+        if self.source.width() == 0 || self.source.height() == 0 {
+            0
+        } else {
+            self.width
+        }
+    }
+    fn _height(&self) -> usize {
+        if self.source.width() == 0 || self.source.height() == 0 {
+            0
+        } else {
+            self.height
+        }
+    }
+}
+
+/// Used as an output type by [`BidiView::try_index_rect()`], to view another
+/// view by reference.
+#[derive(Debug)]
+pub struct BorrowingBidiView<'a, S: BidiView + ?Sized>(&'a S);
+impl<'a, S: BidiView + ?Sized> BorrowingBidiView<'a, S> {
+    pub fn new(source: &'a S) -> Self {
+        Self(source)
+    }
+}
+
+impl<'a, S: BidiView + ?Sized> Clone for BorrowingBidiView<'a, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, S: BidiView + ?Sized> Copy for BorrowingBidiView<'a, S> {}
+
+impl<'a, S: BidiView + ?Sized> Index<(usize, usize)> for BorrowingBidiView<'a, S> {
+    type Output = S::Output;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<'a, S: BidiView + ?Sized> BidiView for BorrowingBidiView<'a, S> {
+    fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+        self.0.get(x, y)
+    }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Output {
+        self.0.get_unchecked(x, y)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[Self::Output]>
+    where
+        Self::Output: Sized,
+    {
+        self.0.row_slice(row)
+    }
+}
+
+/// A view owning the data structure it views through a reference counted
+/// pointer, so that it's `'static` and cheap to clone. Transformations can be
+/// applied on top of it (e.g. through [`BidiView::to_cropped`]), and the result
+/// can be stored without borrowing the data structure.
+///
+/// The view is read-only, as the data structure may be shared by many views.
+///
+/// # Examples
+/// ```
+/// use bidivec::{bidivec, BidiRect, BidiView};
+/// use bidivec::transforming::{CroppingBidiView, OwnedBidiView};
+///
+/// struct Sprite {
+///     frame: CroppingBidiView<OwnedBidiView<bidivec::BidiVec<char>>>,
+/// }
+///
+/// let sheet = OwnedBidiView::new(bidivec!{
+///     ['a', 'b', 'c', 'd'],
+///     ['e', 'f', 'g', 'h'],
+/// });
+///
+/// let sprites = (0..2)
+///     .map(|i| Sprite {
+///         frame: sheet.clone().to_cropped(&BidiRect::new(i * 2, 0, 2, 2)).unwrap(),
+///     })
+///     .collect::<Vec<_>>();
+///
+/// assert!(sprites[1].frame.equivalent(&bidivec!{
+///     ['c', 'd'],
+///     ['g', 'h'],
+/// }));
+/// ```
+#[derive(Debug)]
+pub struct OwnedBidiView<S: BidiView>(Rc<S>);
+impl<S: BidiView> OwnedBidiView<S> {
+    pub fn new(source: S) -> Self {
+        Self(Rc::new(source))
+    }
+
+    /// Returns the data structure viewed by this view.
+    pub fn source(&self) -> &Rc<S> {
+        &self.0
+    }
+}
+
+impl<S: BidiView> From<Rc<S>> for OwnedBidiView<S> {
+    fn from(source: Rc<S>) -> Self {
+        Self(source)
+    }
+}
+
+impl<S: BidiView> Clone for OwnedBidiView<S> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<S: BidiView> Index<(usize, usize)> for OwnedBidiView<S> {
+    type Output = S::Output;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<S: BidiView> BidiView for OwnedBidiView<S> {
+    fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+        self.0.get(x, y)
+    }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &Self::Output {
+        self.0.get_unchecked(x, y)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[Self::Output]>
+    where
+        Self::Output: Sized,
+    {
+        self.0.row_slice(row)
+    }
+}
+
+/// Used as an output type by [`constant()`].
+#[derive(Clone, Debug)]
+pub struct ConstantBidiView<T> {
+    value: T,
+    width: usize,
+    height: usize,
+}
+impl<T> ConstantBidiView<T> {
+    pub fn new(value: T, width: usize, height: usize) -> Self {
+        Self {
+            value,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for ConstantBidiView<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "Indexes out of view bounds: ({},{}) out of {}x{}",
+                index.0, index.1, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> BidiView for ConstantBidiView<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+        if x < self.width && y < self.height {
+            Some(&self.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns a view of the specified size, containing `value` in every position.
+///
+/// No memory is allocated for the items, which makes this a cheap source for
+/// functions like [`editing::copy`][crate::editing::copy] or
+/// [`editing::blend`][crate::editing::blend].
+///
+/// # Examples
+/// ```
+/// # use bidivec::{bidivec, BidiView, BidiRect};
+/// use bidivec::{editing, transforming};
+///
+/// let mut v = bidivec![0; 4, 3];
+/// let fill = transforming::constant(7, 2, 2);
+///
+/// editing::copy(&fill, &mut v, &fill.bounding_rect(), (1, 1))?;
+///
+/// assert_eq!(v, bidivec!{
+///     [0, 0, 0, 0],
+///     [0, 7, 7, 0],
+///     [0, 7, 7, 0],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub fn constant<T>(value: T, width: usize, height: usize) -> ConstantBidiView<T> {
+    ConstantBidiView::new(value, width, height)
+}
+
+/// Returns a view of the specified size, tiling the items of `view` over it
+/// (as a pattern repeated along both axes, starting at the top left corner).
+/// If `view` is empty, the returned view is empty too.
+///
+/// The items are not copied, which makes this a cheap source for functions like
+/// [`editing::copy`][crate::editing::copy] or [`editing::blend`][crate::editing::blend].
+///
+/// # Examples
+/// ```
+/// # use bidivec::{bidiarray, BidiView};
+/// use bidivec::transforming;
+///
+/// let pattern = bidiarray!{
+///     ['#', '.'],
+///     ['.', '#'],
+/// };
+///
+/// let checkerboard = transforming::repeat(pattern, 5, 3);
+///
+/// assert_eq!(checkerboard.display().render(), "#.#.#\n.#.#.\n#.#.#");
+/// ```
+pub fn repeat<S: BidiView>(view: S, width: usize, height: usize) -> RepeatingBidiView<S> {
+    RepeatingBidiView::new(view, width, height)
+}
@@ -5,6 +5,8 @@ use std::default::Default;
 use std::ops::Range;
 use std::ops::{Index, IndexMut};
 
+use crate::collections::bidimutslice::for_each_col_in;
+use crate::collections::bidislice::coords_of_item;
 use crate::*;
 
 /// A contiguous bidimensional array type with heap-allocated contents,
@@ -67,7 +69,7 @@ use crate::*;
 /// let four = v[(0, 1)];
 /// v[(1, 1)] = v[(1, 0)] + v[(2, 0)];
 /// ```
-#[derive(Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BidiArray<T> {
     pub(crate) data: Box<[T]>,
     pub(crate) row_size: usize,
@@ -224,6 +226,34 @@ impl<T> BidiArray<T> {
         }
     }
 
+    /// Constructs a new [`BidiArray<T>`] with the specified size, calling
+    /// the specified closure with the coordinates of every item, by rows.
+    /// This is the same as [`BidiArray::with_size_func_xy`], named after
+    /// [`std::array::from_fn`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds [`isize::MAX`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiArray, bidiarray};
+    ///
+    /// let bvec = BidiArray::from_fn(3, 2, |x, y| x * 10 + y);
+    ///
+    /// assert_eq!(bvec, bidiarray!{
+    ///     [0, 10, 20],
+    ///     [1, 11, 21],
+    /// });
+    /// ```
+    pub fn from_fn<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::with_size_func_xy(width, height, f)
+    }
+
     /// Creates a [`BidiArray<T>`] directly from the raw components of another vector.
     ///
     /// # Safety
@@ -244,7 +274,7 @@ impl<T> BidiArray<T> {
                 row_size,
             })
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, length))
         }
     }
 
@@ -276,6 +306,86 @@ impl<T> BidiArray<T> {
         Self::from_vec(vec, row_size)
     }
 
+    /// Creates a bidiarray from an iterator yielding the items by columns (e.g.
+    /// data coming from a column-major source), using the specified `col_size`
+    /// (that is, the height of the bidiarray).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiArray, BidiView};
+    ///
+    /// let bvec = BidiArray::from_iterator_by_cols(0..6, 2)?;
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(0, 1)], 1);
+    /// assert_eq!(bvec[(1, 0)], 2);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_iterator_by_cols(
+        iter: impl Iterator<Item = T>,
+        col_size: usize,
+    ) -> Result<Self, BidiError> {
+        let mut bvec = Self::from_iterator(iter, col_size)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
+    /// Creates a bidiarray from an iterator of rows, each of them an iterator of
+    /// items. All the rows must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiArray, BidiView};
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiArray::from_rows(rows)?;
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(2, 0)], 3);
+    /// assert_eq!(bvec[(0, 1)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        BidiVec::from_rows(rows).map(BidiVec::into_bidiarray)
+    }
+
+    /// Creates a bidiarray from an iterator of columns, each of them an iterator of
+    /// items. All the columns must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// The items are collected by rows and then transposed in place, so this
+    /// takes linear time, unlike pushing the columns one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiArray, BidiView};
+    ///
+    /// let cols = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiArray::from_cols(cols)?;
+    ///
+    /// assert_eq!(bvec.size(), (2, 3));
+    /// assert_eq!(bvec[(0, 2)], 3);
+    /// assert_eq!(bvec[(1, 0)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_cols<I, C>(cols: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = T>,
+    {
+        let mut bvec = Self::from_rows(cols)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
     /// Creates a bidiarray from another view iterator, using the specified
     /// mapping function to create and/or transform elements.
     ///
@@ -306,6 +416,36 @@ impl<T> BidiArray<T> {
         Self::with_size_func_xy(view.width(), view.height(), |x, y| mapper(&view[(x, y)]))
     }
 
+    /// Consumes the bidiarray, creating a new one of the same size with the
+    /// items transformed by the specified function.
+    ///
+    /// Items are moved into the function, so they don't need to implement
+    /// [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiArray, bidiarray};
+    ///
+    /// let from = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let to = from.map(|n| n.to_string());
+    ///
+    /// assert_eq!(to[(2, 1)], "6");
+    /// ```
+    pub fn map<U, F>(self, f: F) -> BidiArray<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        BidiArray {
+            row_size: self.row_size,
+            data: self.data.into_vec().into_iter().map(f).collect(),
+        }
+    }
+
     /// Creates a [`BidiArray<T>`] from a [`Vec<T>`] and a specified row size.
     ///
     /// # Examples
@@ -329,7 +469,7 @@ impl<T> BidiArray<T> {
                 row_size,
             })
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, vec.len()))
         }
     }
 
@@ -375,7 +515,7 @@ impl<T> BidiArray<T> {
     /// assert_eq!(bvec.height(), 3);
     /// ```
     pub fn height(&self) -> usize {
-        self.data.len() / self.row_size
+        self.data.len().checked_div(self.row_size).unwrap_or(0)
     }
 
     /// Returns true if the bidiarray contains no elements (that
@@ -394,6 +534,33 @@ impl<T> BidiArray<T> {
         self.data.is_empty()
     }
 
+    /// Checks that the internal state of the bidiarray is consistent, returning
+    /// [`BidiError::IncompatibleSize`] if it's not (e.g. if the number of items
+    /// is not a multiple of the row size).
+    ///
+    /// BidiArrays built through the methods of the crate are always consistent:
+    /// this is meant to verify bidiarrays whose memory has been written through
+    /// unsafe code (e.g. by native code through FFI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidiarray;
+    ///
+    /// let barr = bidiarray![0; 3, 2];
+    ///
+    /// assert!(barr.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), BidiError> {
+        if (self.data.is_empty() && self.row_size == 0)
+            || (self.row_size != 0 && (self.data.len() % self.row_size) == 0)
+        {
+            Ok(())
+        } else {
+            Err(BidiError::incompatible_size(self.row_size, self.data.len()))
+        }
+    }
+
     /// Extracts a slice containing the specified range of bidiarray contents,
     /// laid out linearly, by rows.
     pub fn as_slice<R: SliceIndex<[T]>>(&self, range: R) -> &R::Output {
@@ -406,6 +573,13 @@ impl<T> BidiArray<T> {
         &mut self.data[range]
     }
 
+    /// Returns a [`BidiCellSlice`] over the items of the bidiarray, through which
+    /// they can be modified from shared references.
+    pub fn as_cell_slice(&mut self) -> BidiCellSlice<'_, T> {
+        let row_size = self.row_size;
+        BidiCellSlice::from_mut_slice(&mut self.data, row_size).unwrap()
+    }
+
     /// Returns a raw pointer to the bidiarray's buffer.
     ///
     /// The caller must ensure that the bidiarray outlives the pointer this
@@ -484,6 +658,114 @@ impl<T> BidiArray<T> {
         self.data.into_vec()
     }
 
+    /// Converts the bidiarray into a `Vec<T>` where items are linearly
+    /// laid out by columns (e.g. to pass them to column-major code).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiArray;
+    ///
+    /// let bvec = BidiArray::from_iterator(0..6, 3)?;
+    ///
+    /// assert_eq!(bvec.into_vec_by_cols(), vec![0, 3, 1, 4, 2, 5]);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn into_vec_by_cols(mut self) -> Vec<T> {
+        self.transpose();
+        self.into_vec()
+    }
+
+    /// Returns the rows of the bidiarray, each as a slice of items.
+    /// This is O(h), as it only allocates the vector of slices.
+    pub fn as_rows(&self) -> Vec<&[T]> {
+        if self.width() == 0 {
+            Vec::new()
+        } else {
+            self.data.chunks(self.width()).collect()
+        }
+    }
+
+    /// Calls a closure on every row of the bidiarray, from the top, passing the index
+    /// of the row and the row as a mutable slice of items, so that row-local
+    /// algorithms can use the methods of slices instead of accessing the items one
+    /// at a time.
+    pub fn for_each_row_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let width = self.width();
+
+        if width != 0 {
+            for (y, row) in self.data.chunks_mut(width).enumerate() {
+                f(y, row);
+            }
+        }
+    }
+
+    /// Calls a closure on every column of the bidiarray, from the left, passing the
+    /// index of the column and the column as a mutable slice of items. Since
+    /// columns aren't contiguous in memory, the items of each column are moved to
+    /// a temporary buffer for the duration of the call, and moved back afterwards.
+    pub fn for_each_col_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let width = self.width();
+        for_each_col_in(&mut self.data, width, f);
+    }
+
+    /// Converts the bidiarray into a `Vec<Vec<T>>` where items are arranged
+    /// as in a vec of rows, where each row is itself a vec of items.
+    /// This is O(n).
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let (width, height) = self.size();
+        let mut items = self.into_vec().into_iter();
+
+        (0..height)
+            .map(|_| items.by_ref().take(width).collect())
+            .collect()
+    }
+
+    /// Decomposes the bidiarray into a [`BidiRawParts`][ffi::BidiRawParts]
+    /// descriptor with a stable C ABI, to hand it across an FFI boundary.
+    ///
+    /// The memory is not freed: the only way to free it is to convert the
+    /// descriptor back to a bidiarray with [`BidiArray::from_raw_descriptor`] and
+    /// drop it. See the [`ffi`] module.
+    pub fn into_raw_parts(self) -> ffi::BidiRawParts<T> {
+        let (len, width) = (self.data.len(), self.row_size);
+        let ptr = Box::into_raw(self.data) as *mut T;
+
+        ffi::BidiRawParts {
+            ptr,
+            len,
+            width,
+            pitch: width,
+        }
+    }
+
+    /// Creates a bidiarray from a [`BidiRawParts`][ffi::BidiRawParts] descriptor
+    /// returned by [`BidiArray::into_raw_parts`].
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the descriptor is not valid
+    /// for a bidiarray (e.g. because `pitch` is different from `width`); in that
+    /// case, the memory is not freed.
+    ///
+    /// # Safety
+    ///
+    /// The descriptor must have been returned by [`BidiArray::into_raw_parts`]
+    /// (possibly changing the width, but not the length), and it must not be used
+    /// again after this call. Native code may have changed the items, but not `ptr`
+    /// or `len`.
+    pub unsafe fn from_raw_descriptor(parts: ffi::BidiRawParts<T>) -> Result<Self, BidiError> {
+        if parts.pitch != parts.width {
+            return Err(BidiError::incompatible_size(parts.width, parts.pitch));
+        }
+
+        Self::from_raw_parts(parts.ptr, parts.len, parts.width)
+    }
+
     /// Swaps two elements in the bidiarray. If any of the coordinates are out
     /// of range, [`BidiError::OutOfBounds`] is returned
     ///
@@ -598,13 +880,36 @@ impl<T> BidiArray<T> {
         self.calc_index(x, y).is_ok()
     }
 
+    /// Returns the coordinates of an item of the bidiarray, given a reference to it
+    /// (e.g. one obtained while iterating), or [`None`] if the reference doesn't
+    /// point to an item of the bidiarray. The coordinates are computed from the address
+    /// of the item, so this is `O(1)`; for zero-sized types, [`None`] is always
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidiarray;
+    ///
+    /// let bvec = bidiarray!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let max = bvec.iter().max().unwrap();
+    /// assert_eq!(bvec.coords_of(max), Some((2, 1)));
+    /// assert_eq!(bvec.coords_of(&6), None);
+    /// ```
+    pub fn coords_of(&self, item: &T) -> Option<(usize, usize)> {
+        coords_of_item(&self.data, self.row_size, item)
+    }
+
     #[inline(always)]
     fn calc_index(&self, x: usize, y: usize) -> Result<usize, BidiError> {
-        let idx = y * self.row_size + x;
-        if x >= self.row_size || idx >= self.data.len() {
-            Err(BidiError::OutOfBounds)
+        if x >= self.row_size || y >= self.data.len() / self.row_size {
+            Err(BidiError::out_of_bounds(x, y, self.size()))
         } else {
-            Ok(idx)
+            Ok(y * self.row_size + x)
         }
     }
 
@@ -629,7 +934,7 @@ impl<T> BidiArray<T> {
     /// ```
     pub fn reverse_row(&mut self, row: usize) -> Result<(), BidiError> {
         if row >= self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(0, row, self.size()));
         }
 
         let width = self.width();
@@ -662,7 +967,7 @@ impl<T> BidiArray<T> {
     /// ```
     pub fn reverse_col(&mut self, col: usize) -> Result<(), BidiError> {
         if col >= self.width() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(col, 0, self.size()));
         }
 
         let height = self.height();
@@ -679,20 +984,30 @@ impl<T> BidiArray<T> {
     /// rows). As such, the result of a transposition is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidiarray width and height are different (i.e. it's not a square).
+    /// This is performed in-place; if the bidiarray width and height are different
+    /// (i.e. it's not a square) it requires one bit of additional memory per item,
+    /// and items are moved along the cycles of the transposition, which is O(n) but
+    /// not cache friendly.
     pub fn transpose(&mut self) {
         let mut slice = BidiMutSlice::new(&mut self.data, self.row_size).unwrap();
         slice.transpose();
         self.row_size = self.height();
     }
 
+    /// Transposes the bidiarray processing square bidiarrays in tiles of `block_size`
+    /// × `block_size` items; see [`BidiMutSlice::transpose_with_block_size`].
+    pub fn transpose_with_block_size(&mut self, block_size: usize) {
+        let mut slice = BidiMutSlice::new(&mut self.data, self.row_size).unwrap();
+        slice.transpose_with_block_size(block_size);
+        self.row_size = self.height();
+    }
+
     /// Rotates the bidiarray 90°, counter-clockwise (or, 270° clockwise).
     /// The result of such a rotation is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidiarray width and height are different (i.e. it's not a square).
+    /// This is performed in-place; see [`BidiArray::transpose`] for the memory
+    /// requirements.
     pub fn rotate90ccw(&mut self) {
         self.transpose();
         self.reverse_columns();
@@ -707,27 +1022,82 @@ impl<T> BidiArray<T> {
     /// The result of such a rotation is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidiarray width and height are different (i.e. it's not a square).
+    /// This is performed in-place; see [`BidiArray::transpose`] for the memory
+    /// requirements.
     pub fn rotate270ccw(&mut self) {
         self.transpose();
         self.reverse_rows();
     }
 
+    /// Anti-transposes the bidiarray, that is an operation that flips the bidiarray
+    /// over its anti-diagonal (going from the top right corner to the bottom
+    /// left one). As for a transposition, the result is as wide as the original
+    /// was tall, and as tall as the original was wide.
+    pub fn anti_transpose(&mut self) {
+        self.transpose();
+        self.rotate180();
+    }
+
     /// Reverse the order of items in all columns. This is equivalent to flipping
     /// the data structure over its horizontal axis.
     pub fn reverse_columns(&mut self) {
-        for col in 0..self.width() {
-            self.reverse_col(col).unwrap();
-        }
+        BidiMutSlice::new(&mut self.data, self.row_size)
+            .unwrap()
+            .reverse_columns();
     }
 
     /// Reverse the order of items in all rows. This is equivalent to flipping
     /// the data structure over its vertical axis.
     pub fn reverse_rows(&mut self) {
-        for row in 0..self.height() {
-            self.reverse_row(row).unwrap();
-        }
+        BidiMutSlice::new(&mut self.data, self.row_size)
+            .unwrap()
+            .reverse_rows();
+    }
+
+    /// Transposes the bidiarray in-place and returns it; see [`BidiArray::transpose`].
+    pub fn into_transposed(mut self) -> Self {
+        self.transpose();
+        self
+    }
+
+    /// Anti-transposes the bidiarray in-place and returns it; see [`BidiArray::anti_transpose`].
+    pub fn into_anti_transposed(mut self) -> Self {
+        self.anti_transpose();
+        self
+    }
+
+    /// Rotates the bidiarray 90° counter-clockwise in-place and returns it; see
+    /// [`BidiArray::rotate90ccw`].
+    pub fn into_rotated90ccw(mut self) -> Self {
+        self.rotate90ccw();
+        self
+    }
+
+    /// Rotates the bidiarray 180° in-place and returns it.
+    pub fn into_rotated180(mut self) -> Self {
+        self.rotate180();
+        self
+    }
+
+    /// Rotates the bidiarray 270° counter-clockwise in-place and returns it; see
+    /// [`BidiArray::rotate270ccw`].
+    pub fn into_rotated270ccw(mut self) -> Self {
+        self.rotate270ccw();
+        self
+    }
+
+    /// Reverses the order of items in all columns in-place and returns the bidiarray;
+    /// see [`BidiArray::reverse_columns`].
+    pub fn into_reversed_columns(mut self) -> Self {
+        self.reverse_columns();
+        self
+    }
+
+    /// Reverses the order of items in all rows in-place and returns the bidiarray;
+    /// see [`BidiArray::reverse_rows`].
+    pub fn into_reversed_rows(mut self) -> Self {
+        self.reverse_rows();
+        self
     }
 
     /// Converts this instance into a [`BidiVec<T>`]
@@ -753,16 +1123,52 @@ impl<T> BidiArray<T> {
     }
 
     /// Returns an iterator over the items of the view
-    pub fn iter(&self) -> Iter<T, Self> {
+    pub fn iter(&self) -> Iter<'_, T, Self> {
         Iter::new(self)
     }
 
     /// Returns a mutable iterator over the items of the view
-    pub fn iter_mut(&mut self) -> IterMut<T, Self> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, Self> {
         IterMut::new(self)
     }
 }
 
+impl<T> Default for BidiArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BidiConstruct for BidiArray<T> {
+    fn with_elem(value: T, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::with_elem(value, width, height)
+    }
+
+    fn with_size_default(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
+        Self::with_size_default(width, height)
+    }
+
+    fn with_size_func<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::with_size_func(width, height, f)
+    }
+
+    fn with_size_func_xy<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::with_size_func_xy(width, height, f)
+    }
+}
+
 impl<T> BidiFrom<&dyn BidiView<Output = T>> for BidiArray<T>
 where
     T: Clone,
@@ -776,8 +1182,8 @@ where
     }
 
     fn from_view_cut(source: &dyn BidiView<Output = T>, cut: &BidiRect) -> Result<Self, BidiError> {
-        if cut.max_x() > source.height() || cut.max_y() > source.width() {
-            return Err(BidiError::OutOfBounds);
+        if cut.max_x() > source.width() || cut.max_y() > source.height() {
+            return Err(BidiError::rect_out_of_bounds(cut, source.size()));
         }
 
         Ok(BidiArray::<T>::with_size_func_xy(
@@ -894,6 +1300,26 @@ impl<T> IndexMut<(usize, usize)> for BidiArray<T> {
     }
 }
 
+impl<T> Index<BidiPoint> for BidiArray<T> {
+    type Output = T;
+
+    /// Accesses an element in the BidiArray, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl<T> IndexMut<BidiPoint> for BidiArray<T> {
+    /// Mutably accesses an element in the BidiArray, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index_mut(&mut self, index: BidiPoint) -> &mut Self::Output {
+        &mut self[(index.x, index.y)]
+    }
+}
+
 impl<T> BidiView for BidiArray<T> {
     fn width(&self) -> usize {
         BidiArray::<T>::width(self)
@@ -905,12 +1331,39 @@ impl<T> BidiView for BidiArray<T> {
     fn get(&self, x: usize, y: usize) -> Option<&T> {
         self.get(x, y)
     }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        self.data.get_unchecked(y * self.width() + x)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> BidiViewMut for BidiArray<T> {
     fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
         self.get_mut(x, y)
     }
+
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
+        let idx = y * self.width() + x;
+        self.data.get_unchecked_mut(idx)
+    }
+
+    fn row_slice_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&mut self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
 }
 
 unsafe impl<T> BidiViewMutIterable for BidiArray<T> {}
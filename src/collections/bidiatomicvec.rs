@@ -0,0 +1,365 @@
+use std::ops::Index;
+use std::sync::atomic::{
+    AtomicI16, AtomicI32, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU8, AtomicUsize,
+    Ordering,
+};
+
+use crate::{BidiArray, BidiError, BidiPoint, BidiView};
+
+/// A trait for the atomic integer types that can be stored in a [`BidiAtomicVec`]
+/// (e.g. [`AtomicU8`], [`AtomicU32`] or [`AtomicUsize`]), exposing the operations
+/// shared by all of them.
+pub trait BidiAtomic: Send + Sync {
+    /// The integer type stored by the atomic type.
+    type Value: Copy;
+
+    /// Creates a new atomic holding the specified value.
+    fn new(value: Self::Value) -> Self;
+    /// Loads the value of the atomic.
+    fn load(&self, order: Ordering) -> Self::Value;
+    /// Stores a value into the atomic.
+    fn store(&self, value: Self::Value, order: Ordering);
+    /// Stores a value into the atomic, returning the previous value.
+    fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    /// Adds to the value of the atomic (wrapping around on overflow), returning
+    /// the previous value.
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    /// Subtracts from the value of the atomic (wrapping around on overflow),
+    /// returning the previous value.
+    fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value;
+    /// Consumes the atomic, returning the value it holds.
+    fn into_inner(self) -> Self::Value;
+}
+
+macro_rules! impl_atomic {
+    ($($atomic:ty => $t:ty),*) => {
+        $(impl BidiAtomic for $atomic {
+            type Value = $t;
+
+            fn new(value: $t) -> Self {
+                <$atomic>::new(value)
+            }
+
+            fn load(&self, order: Ordering) -> $t {
+                <$atomic>::load(self, order)
+            }
+
+            fn store(&self, value: $t, order: Ordering) {
+                <$atomic>::store(self, value, order)
+            }
+
+            fn swap(&self, value: $t, order: Ordering) -> $t {
+                <$atomic>::swap(self, value, order)
+            }
+
+            fn fetch_add(&self, value: $t, order: Ordering) -> $t {
+                <$atomic>::fetch_add(self, value, order)
+            }
+
+            fn fetch_sub(&self, value: $t, order: Ordering) -> $t {
+                <$atomic>::fetch_sub(self, value, order)
+            }
+
+            fn into_inner(self) -> $t {
+                <$atomic>::into_inner(self)
+            }
+        })*
+    };
+}
+
+impl_atomic!(
+    AtomicU8 => u8, AtomicU16 => u16, AtomicU32 => u32, AtomicUsize => usize,
+    AtomicI8 => i8, AtomicI16 => i16, AtomicI32 => i32, AtomicIsize => isize
+);
+
+/// A bidimensional array of atomic integers, which can be updated concurrently
+/// by many threads through a shared reference, without locking.
+///
+/// The size of a bidiatomicvec is fixed at construction. Items are accessed by
+/// coordinates with the [`load`][BidiAtomicVec::load], [`store`][BidiAtomicVec::store],
+/// [`swap`][BidiAtomicVec::swap], [`fetch_add`][BidiAtomicVec::fetch_add] and
+/// [`fetch_sub`][BidiAtomicVec::fetch_sub] methods, each taking the memory
+/// [`Ordering`] to use; all the other atomic operations are available through
+/// indexing, which returns a reference to the atomic itself.
+///
+/// As a [`BidiView`] over the atomics, a bidiatomicvec can be read by the algorithms
+/// of the crate; [`BidiAtomicVec::snapshot`] copies the current values to a
+/// [`BidiArray`].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::BidiAtomicVec;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let occupancy = Arc::new(BidiAtomicVec::<AtomicU32>::with_size_default(4, 4));
+///
+/// let agents = (0..4)
+///     .map(|agent| {
+///         let occupancy = Arc::clone(&occupancy);
+///         thread::spawn(move || {
+///             for step in 0..4 {
+///                 occupancy.fetch_add(step, agent, 1, Ordering::Relaxed).unwrap();
+///             }
+///         })
+///     })
+///     .collect::<Vec<_>>();
+///
+/// for agent in agents {
+///     agent.join().unwrap();
+/// }
+///
+/// let snapshot = occupancy.snapshot(Ordering::Acquire);
+/// assert!(snapshot.iter().all(|&n| n == 1));
+///
+/// // other atomic operations are available on the items
+/// occupancy[(2, 3)].fetch_or(6, Ordering::Relaxed);
+/// assert_eq!(occupancy.load(2, 3, Ordering::Relaxed)?, 7);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+#[derive(Debug)]
+pub struct BidiAtomicVec<A: BidiAtomic> {
+    data: Box<[A]>,
+    row_size: usize,
+}
+
+impl<A: BidiAtomic> BidiAtomicVec<A> {
+    /// Constructs a new [`BidiAtomicVec`] with the specified size, holding
+    /// `value` in every position.
+    pub fn with_elem(value: A::Value, width: usize, height: usize) -> Self {
+        Self::with_size_func_xy(width, height, |_, _| value)
+    }
+
+    /// Constructs a new [`BidiAtomicVec`] with the specified size, holding
+    /// the default value (that is, zero) in every position.
+    pub fn with_size_default(width: usize, height: usize) -> Self
+    where
+        A::Value: Default,
+    {
+        Self::with_elem(A::Value::default(), width, height)
+    }
+
+    /// Constructs a new [`BidiAtomicVec`] with the specified size, using the
+    /// specified closure to produce the initial values from their coordinates.
+    pub fn with_size_func_xy<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> A::Value,
+    {
+        let mut data = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                data.push(A::new(f(x, y)));
+            }
+        }
+
+        Self {
+            data: data.into_boxed_slice(),
+            row_size: if height == 0 { 0 } else { width },
+        }
+    }
+
+    /// Constructs a new [`BidiAtomicVec`] holding the values of another view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidiarray, BidiAtomicVec};
+    /// use std::sync::atomic::{AtomicU8, Ordering};
+    ///
+    /// let v = BidiAtomicVec::<AtomicU8>::from_view(&bidiarray!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// });
+    ///
+    /// assert_eq!(v.load(0, 1, Ordering::Relaxed)?, 3);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_view<V>(view: &V) -> Self
+    where
+        V: BidiView<Output = A::Value>,
+    {
+        Self::with_size_func_xy(view.width(), view.height(), |x, y| view[(x, y)])
+    }
+
+    /// Returns the width (that is, the size of a row) of the bidiatomicvec.
+    pub fn width(&self) -> usize {
+        self.row_size
+    }
+
+    /// Returns the height (that is, the size of a column) of the bidiatomicvec.
+    pub fn height(&self) -> usize {
+        self.data.len().checked_div(self.row_size).unwrap_or(0)
+    }
+
+    /// Returns the number of items contained in the bidiatomicvec.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the bidiatomicvec contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the atomic at (x, y) coordinates, or [`None`] if the coordinates
+    /// are out of range.
+    pub fn get(&self, x: usize, y: usize) -> Option<&A> {
+        self.calc_index(x, y).ok().map(|idx| &self.data[idx])
+    }
+
+    /// Checks if the specified coordinates are inside the bidiatomicvec bounds.
+    pub fn valid_coords(&self, x: usize, y: usize) -> bool {
+        self.calc_index(x, y).is_ok()
+    }
+
+    /// Loads the value at (x, y) coordinates, or returns [`BidiError::OutOfBounds`]
+    /// if the coordinates are out of range.
+    pub fn load(&self, x: usize, y: usize, order: Ordering) -> Result<A::Value, BidiError> {
+        Ok(self.atomic(x, y)?.load(order))
+    }
+
+    /// Stores a value at (x, y) coordinates, or returns [`BidiError::OutOfBounds`]
+    /// if the coordinates are out of range.
+    pub fn store(
+        &self,
+        x: usize,
+        y: usize,
+        value: A::Value,
+        order: Ordering,
+    ) -> Result<(), BidiError> {
+        self.atomic(x, y)?.store(value, order);
+        Ok(())
+    }
+
+    /// Stores a value at (x, y) coordinates returning the previous value, or
+    /// returns [`BidiError::OutOfBounds`] if the coordinates are out of range.
+    pub fn swap(
+        &self,
+        x: usize,
+        y: usize,
+        value: A::Value,
+        order: Ordering,
+    ) -> Result<A::Value, BidiError> {
+        Ok(self.atomic(x, y)?.swap(value, order))
+    }
+
+    /// Adds to the value at (x, y) coordinates (wrapping around on overflow)
+    /// returning the previous value, or returns [`BidiError::OutOfBounds`] if
+    /// the coordinates are out of range.
+    pub fn fetch_add(
+        &self,
+        x: usize,
+        y: usize,
+        value: A::Value,
+        order: Ordering,
+    ) -> Result<A::Value, BidiError> {
+        Ok(self.atomic(x, y)?.fetch_add(value, order))
+    }
+
+    /// Subtracts from the value at (x, y) coordinates (wrapping around on overflow)
+    /// returning the previous value, or returns [`BidiError::OutOfBounds`] if
+    /// the coordinates are out of range.
+    pub fn fetch_sub(
+        &self,
+        x: usize,
+        y: usize,
+        value: A::Value,
+        order: Ordering,
+    ) -> Result<A::Value, BidiError> {
+        Ok(self.atomic(x, y)?.fetch_sub(value, order))
+    }
+
+    /// Copies the current values to a [`BidiArray`], loading each of them with
+    /// the specified ordering.
+    ///
+    /// Values are loaded one at a time: if other threads are updating the
+    /// bidiatomicvec at the same time, the snapshot may contain values written
+    /// at different moments.
+    pub fn snapshot(&self, order: Ordering) -> BidiArray<A::Value> {
+        BidiArray::with_size_func_xy(self.width(), self.height(), |x, y| self[(x, y)].load(order))
+    }
+
+    /// Consumes the bidiatomicvec, returning its values in a [`BidiArray`].
+    pub fn into_bidiarray(self) -> BidiArray<A::Value> {
+        let row_size = self.row_size;
+        let data = self
+            .data
+            .into_vec()
+            .into_iter()
+            .map(A::into_inner)
+            .collect();
+        BidiArray::from_vec(data, row_size).unwrap()
+    }
+
+    fn atomic(&self, x: usize, y: usize) -> Result<&A, BidiError> {
+        let idx = self.calc_index(x, y)?;
+        Ok(&self.data[idx])
+    }
+
+    #[inline(always)]
+    fn calc_index(&self, x: usize, y: usize) -> Result<usize, BidiError> {
+        if x >= self.width() || y >= self.height() {
+            Err(BidiError::out_of_bounds(
+                x,
+                y,
+                (self.width(), self.height()),
+            ))
+        } else {
+            Ok(y * self.row_size + x)
+        }
+    }
+}
+
+impl<A: BidiAtomic> Index<(usize, usize)> for BidiAtomicVec<A> {
+    type Output = A;
+
+    /// Accesses an atomic in the BidiAtomicVec, using its cartesian coordinates.
+    /// If coordinates are outside of range, it panics.
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "Indexes out of bidiatomicvec bounds: ({},{}) out of {}x{}",
+                index.0,
+                index.1,
+                self.width(),
+                self.height()
+            )
+        })
+    }
+}
+
+impl<A: BidiAtomic> Index<BidiPoint> for BidiAtomicVec<A> {
+    type Output = A;
+
+    /// Accesses an atomic in the BidiAtomicVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl<A: BidiAtomic> BidiView for BidiAtomicVec<A> {
+    fn width(&self) -> usize {
+        BidiAtomicVec::width(self)
+    }
+
+    fn height(&self) -> usize {
+        BidiAtomicVec::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&A> {
+        BidiAtomicVec::get(self, x, y)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[A]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{BidiGrowVec, BidiVec};
+use std::cmp::max;
+
+/// A view into a single location of a [`BidiVec`] or a [`BidiGrowVec`], which
+/// may be outside of its current bounds. It's returned by [`BidiVec::entry`]
+/// and [`BidiGrowVec::entry`].
+///
+/// Inserting into a location outside of the bounds grows the data structure
+/// just enough to contain it.
+#[derive(Debug)]
+pub struct BidiEntry<'a, C> {
+    container: &'a mut C,
+    x: usize,
+    y: usize,
+}
+
+impl<'a, C> BidiEntry<'a, C> {
+    pub(crate) fn new(container: &'a mut C, x: usize, y: usize) -> Self {
+        Self { container, x, y }
+    }
+
+    /// Returns the coordinates of the entry.
+    pub fn coords(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+}
+
+macro_rules! impl_entry {
+    ($container:ident) => {
+        impl<'a, T> BidiEntry<'a, $container<T>> {
+            /// Returns true if the entry is within the current bounds of the
+            /// data structure.
+            pub fn is_occupied(&self) -> bool {
+                self.container.valid_coords(self.x, self.y)
+            }
+
+            /// Returns the item of the entry, or [`None`] if the entry is
+            /// outside of the current bounds of the data structure.
+            pub fn get(&self) -> Option<&T> {
+                self.container.get(self.x, self.y)
+            }
+
+            /// Returns the item of the entry; if the entry is outside of the
+            /// current bounds of the data structure, the data structure is grown
+            /// to contain it, filling all the new locations with items produced
+            /// by `f`.
+            pub fn or_insert_with<F>(self, f: F) -> &'a mut T
+            where
+                F: FnMut() -> T,
+            {
+                if !self.is_occupied() {
+                    let width = max(self.container.width(), self.x + 1);
+                    let height = max(self.container.height(), self.y + 1);
+                    self.container.resize_with(width, height, f);
+                }
+
+                &mut self.container[(self.x, self.y)]
+            }
+
+            /// Returns the item of the entry; if the entry is outside of the
+            /// current bounds of the data structure, the data structure is grown
+            /// to contain it, filling all the new locations with `value`.
+            pub fn or_insert(self, value: T) -> &'a mut T
+            where
+                T: Clone,
+            {
+                self.or_insert_with(|| value.clone())
+            }
+
+            /// Returns the item of the entry; if the entry is outside of the
+            /// current bounds of the data structure, the data structure is grown
+            /// to contain it, filling all the new locations with the default value.
+            pub fn or_default(self) -> &'a mut T
+            where
+                T: Default,
+            {
+                self.or_insert_with(T::default)
+            }
+
+            /// Calls `f` on the item of the entry, if the entry is within the
+            /// current bounds of the data structure.
+            pub fn and_modify<F>(self, f: F) -> Self
+            where
+                F: FnOnce(&mut T),
+            {
+                if let Some(item) = self.container.get_mut(self.x, self.y) {
+                    f(item);
+                }
+                self
+            }
+        }
+    };
+}
+
+impl_entry!(BidiVec);
+impl_entry!(BidiGrowVec);
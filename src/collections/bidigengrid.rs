@@ -0,0 +1,184 @@
+use std::ops::Index;
+
+use crate::{BidiPoint, BidiView};
+
+/// A bidimensional grid of marks (e.g. the visited locations of a search or of a
+/// flood fill) that can be cleared in constant time, so that it can be reused by
+/// many searches over the same view without reallocating or refilling it.
+///
+/// Instead of a flag, every location stores the generation in which it was last
+/// marked: a location is marked if its generation is the current one, and
+/// [`clear_all`][BidiGenGrid::clear_all] simply starts a new generation. Only
+/// once every 2<sup>32</sup> clears the grid has to be actually refilled.
+///
+/// As a [`BidiView`] over [`bool`]s, a gengrid can be read by the algorithms of
+/// the crate, e.g. to display the marked locations.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiGenGrid, BidiVec};
+///
+/// let map = BidiVec::with_elem('.', 40, 30);
+/// let mut visited = BidiGenGrid::for_view(&map);
+///
+/// for start in 0..3 {
+///     assert!(visited.mark(start, 0));
+///     assert!(!visited.mark(start, 0));
+///     assert!(visited.is_marked(start, 0));
+///
+///     // ...search from (start, 0)...
+///
+///     visited.clear_all();
+///     assert!(!visited.is_marked(start, 0));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BidiGenGrid {
+    stamps: Vec<u32>,
+    generation: u32,
+    row_size: usize,
+}
+
+impl BidiGenGrid {
+    /// Constructs a new [`BidiGenGrid`] with the specified size, with no
+    /// location marked.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            stamps: vec![0; width * height],
+            generation: 1,
+            row_size: if height == 0 { 0 } else { width },
+        }
+    }
+
+    /// Constructs a new [`BidiGenGrid`] with the same size as the specified
+    /// view, with no location marked.
+    pub fn for_view<V: BidiView + ?Sized>(view: &V) -> Self {
+        Self::new(view.width(), view.height())
+    }
+
+    /// Returns the width (that is, the size of a row) of the gengrid.
+    pub fn width(&self) -> usize {
+        self.row_size
+    }
+
+    /// Returns the height (that is, the size of a column) of the gengrid.
+    pub fn height(&self) -> usize {
+        self.stamps.len().checked_div(self.row_size).unwrap_or(0)
+    }
+
+    /// Checks if the specified coordinates are inside the gengrid bounds.
+    pub fn valid_coords(&self, x: usize, y: usize) -> bool {
+        x < self.width() && y < self.height()
+    }
+
+    /// Marks the location at (x, y) coordinates, returning true if it
+    /// wasn't already marked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of range.
+    pub fn mark(&mut self, x: usize, y: usize) -> bool {
+        let idx = self.calc_index(x, y);
+        let was_marked = self.stamps[idx] == self.generation;
+        self.stamps[idx] = self.generation;
+        !was_marked
+    }
+
+    /// Unmarks the location at (x, y) coordinates, returning true if it
+    /// was marked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of range.
+    pub fn unmark(&mut self, x: usize, y: usize) -> bool {
+        let idx = self.calc_index(x, y);
+        let was_marked = self.stamps[idx] == self.generation;
+        self.stamps[idx] = 0;
+        was_marked
+    }
+
+    /// Returns true if the location at (x, y) coordinates is marked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of range.
+    pub fn is_marked(&self, x: usize, y: usize) -> bool {
+        self.stamps[self.calc_index(x, y)] == self.generation
+    }
+
+    /// Unmarks all the locations, in constant time.
+    pub fn clear_all(&mut self) {
+        if self.generation == std::u32::MAX {
+            for stamp in self.stamps.iter_mut() {
+                *stamp = 0;
+            }
+            self.generation = 1;
+        } else {
+            self.generation += 1;
+        }
+    }
+
+    /// Changes the size of the gengrid, unmarking all the locations. The
+    /// allocated memory is reused when possible.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.stamps.resize(width * height, 0);
+        self.row_size = if height == 0 { 0 } else { width };
+        self.clear_all();
+    }
+
+    fn calc_index(&self, x: usize, y: usize) -> usize {
+        if !self.valid_coords(x, y) {
+            panic!(
+                "Indexes out of bidigengrid bounds: ({},{}) out of {}x{}",
+                x,
+                y,
+                self.width(),
+                self.height()
+            );
+        }
+        y * self.row_size + x
+    }
+}
+
+impl Index<(usize, usize)> for BidiGenGrid {
+    type Output = bool;
+
+    /// Returns whether a location of the BidiGenGrid is marked, using its
+    /// cartesian coordinates. If coordinates are outside of range, it panics.
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        if self.is_marked(index.0, index.1) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl Index<BidiPoint> for BidiGenGrid {
+    type Output = bool;
+
+    /// Returns whether a location of the BidiGenGrid is marked, using a
+    /// [`BidiPoint`]. If coordinates are outside of range, it panics.
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl BidiView for BidiGenGrid {
+    fn width(&self) -> usize {
+        BidiGenGrid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        BidiGenGrid::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&bool> {
+        if self.valid_coords(x, y) {
+            Some(&self[(x, y)])
+        } else {
+            None
+        }
+    }
+}
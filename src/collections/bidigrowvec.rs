@@ -1,9 +1,12 @@
 use crate::bidiiter::{Iter, IterMut};
 use std::cmp::{min, Ordering};
 use std::default::Default;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::Iterator;
-use std::ops::{Index, IndexMut};
+use std::ops::{Index, IndexMut, RangeBounds};
 
+use crate::collections::bidimutslice::with_gathered_items;
 use crate::*;
 
 /// A growable bidimensional array type with heap-allocated contents,
@@ -80,9 +83,30 @@ use crate::*;
 /// let four = v[(0, 1)];
 /// v[(1, 1)] = v[(1, 0)] + v[(2, 0)];
 /// ```
-#[derive(Clone, Default, Debug, PartialEq)]
+///
+/// # Row pooling
+///
+/// Editors that constantly insert and delete rows can opt-in to keep the
+/// allocations of deleted rows in an internal pool, to be reused by the rows
+/// inserted afterwards (see [`BidiGrowVec::enable_row_pool`]). The pool is
+/// not part of the value of the bidigrowvec: it's ignored by comparisons and
+/// hashing, and it's not cloned.
+///
+/// ```
+/// # use bidivec::{BidiGrowVec, bidigrowvec};
+/// let mut v = bidigrowvec![0; 100, 10];
+/// v.enable_row_pool();
+///
+/// v.delete_row(3).unwrap();
+/// assert_eq!(v.pooled_rows(), 1);
+///
+/// // the row reuses the allocation of the deleted one
+/// v.push_row(vec![1; 100]).unwrap();
+/// assert_eq!(v.pooled_rows(), 0);
+/// ```
 pub struct BidiGrowVec<T> {
     pub(crate) data: Vec<Vec<T>>,
+    pool: Option<Vec<Vec<T>>>,
 }
 
 impl<T> BidiGrowVec<T> {
@@ -98,7 +122,10 @@ impl<T> BidiGrowVec<T> {
     /// ```
     #[inline]
     pub fn new() -> Self {
-        Self { data: Vec::new() }
+        Self {
+            data: Vec::new(),
+            pool: None,
+        }
     }
 
     /// Constructs a new [`BidiGrowVec<T>`] with the specified size,
@@ -126,6 +153,7 @@ impl<T> BidiGrowVec<T> {
     {
         let mut this = Self {
             data: Vec::with_capacity(height),
+            pool: None,
         };
         this.resize(width, height, value);
         this
@@ -185,6 +213,7 @@ impl<T> BidiGrowVec<T> {
     {
         let mut this = Self {
             data: Vec::with_capacity(height),
+            pool: None,
         };
         this.resize_with(width, height, f);
         this
@@ -220,6 +249,7 @@ impl<T> BidiGrowVec<T> {
     {
         let mut this = Self {
             data: Vec::with_capacity(height),
+            pool: None,
         };
         this.resize_with_xy(width, height, f);
         this
@@ -252,6 +282,99 @@ impl<T> BidiGrowVec<T> {
         Self::from_vec(vec, row_size)
     }
 
+    /// Creates a bidigrowvec from an iterator yielding the items by columns (e.g.
+    /// data coming from a column-major source), using the specified `col_size`
+    /// (that is, the height of the bidigrowvec).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiGrowVec, BidiView};
+    ///
+    /// let bvec = BidiGrowVec::from_iterator_by_cols(0..6, 2).unwrap();
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(0, 1)], 1);
+    /// assert_eq!(bvec[(1, 0)], 2);
+    /// ```
+    pub fn from_iterator_by_cols(
+        iter: impl Iterator<Item = T>,
+        col_size: usize,
+    ) -> Result<Self, BidiError> {
+        let mut bvec = Self::from_iterator(iter, col_size)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
+    /// Creates a bidigrowvec from an iterator of rows, each of them an iterator of
+    /// items. All the rows must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiGrowVec, BidiView};
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiGrowVec::from_rows(rows)?;
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(2, 0)], 3);
+    /// assert_eq!(bvec[(0, 1)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut data: Vec<Vec<T>> = Vec::new();
+
+        for row in rows {
+            let row = row.into_iter().collect::<Vec<T>>();
+            if let Some(first) = data.first() {
+                if row.len() != first.len() {
+                    return Err(BidiError::incompatible_size(first.len(), row.len()));
+                }
+            }
+            data.push(row);
+        }
+
+        let mut bvec = Self { data, pool: None };
+        bvec.collapse();
+        Ok(bvec)
+    }
+
+    /// Creates a bidigrowvec from an iterator of columns, each of them an iterator of
+    /// items. All the columns must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// The items are collected by rows and then transposed in place, so this
+    /// takes linear time, unlike pushing the columns one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiGrowVec, BidiView};
+    ///
+    /// let cols = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiGrowVec::from_cols(cols)?;
+    ///
+    /// assert_eq!(bvec.size(), (2, 3));
+    /// assert_eq!(bvec[(0, 2)], 3);
+    /// assert_eq!(bvec[(1, 0)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_cols<I, C>(cols: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = T>,
+    {
+        let mut bvec = Self::from_rows(cols)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
     /// Creates a bidigrowvec from another view iterator, using the specified
     /// mapping function to create and/or transform elements.
     ///
@@ -301,11 +424,12 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn from_vec(vec: Vec<T>, row_size: usize) -> Result<Self, BidiError> {
         if vec.is_empty() && row_size == 0 {
-            Ok(Self { data: Vec::new() })
+            Ok(Self::new())
         } else if row_size != 0 && (vec.len() % row_size) == 0 {
             let height = vec.len() / row_size;
             let mut this = Self {
                 data: Vec::with_capacity(height),
+                pool: None,
             };
             let mut vec = vec;
 
@@ -318,7 +442,7 @@ impl<T> BidiGrowVec<T> {
 
             Ok(this)
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, vec.len()))
         }
     }
 
@@ -339,7 +463,7 @@ impl<T> BidiGrowVec<T> {
     /// assert!(bvec.is_empty());
     /// ```
     pub fn clear(&mut self) {
-        self.data.clear();
+        self.recycle_rows(..);
     }
 
     /// Returns the number of items contained in the bidigrowvec.
@@ -407,6 +531,36 @@ impl<T> BidiGrowVec<T> {
         self.data.is_empty()
     }
 
+    /// Checks that the internal state of the bidigrowvec is consistent, returning
+    /// [`BidiError::IncompatibleSize`] if it's not (e.g. if the number of items
+    /// is not a multiple of the row size, or if rows have different lengths).
+    ///
+    /// BidiGrowVecs built through the methods of the crate are always consistent:
+    /// this is meant to verify bidigrowvecs whose memory has been written through
+    /// unsafe code (e.g. by native code through FFI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidigrowvec;
+    ///
+    /// let bvec = bidigrowvec![0; 3, 2];
+    ///
+    /// assert!(bvec.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), BidiError> {
+        let width = self.width();
+
+        if width == 0 && !self.data.is_empty() {
+            return Err(BidiError::incompatible_size(1, 0));
+        }
+
+        match self.data.iter().find(|row| row.len() != width) {
+            Some(row) => Err(BidiError::incompatible_size(width, row.len())),
+            None => Ok(()),
+        }
+    }
+
     /// Resizes the[`BidiGrowVec`] in-place so that it has new width and
     /// height.
     ///
@@ -577,7 +731,10 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn truncate(&mut self, new_width: usize, new_height: usize) -> Result<(), BidiError> {
         if new_width > self.width() || new_height > self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::rect_out_of_bounds(
+                &BidiRect::new(0, 0, new_width, new_height),
+                self.size(),
+            ));
         }
 
         if new_width == self.width() && new_height == self.height() {
@@ -587,7 +744,7 @@ impl<T> BidiGrowVec<T> {
         if new_width == 0 || new_height == 0 {
             self.clear();
         } else {
-            self.data.truncate(new_height);
+            self.recycle_rows(new_height..);
 
             for y in 0..new_height {
                 self.data[y].truncate(new_width);
@@ -597,8 +754,11 @@ impl<T> BidiGrowVec<T> {
         Ok(())
     }
 
-    /// Shrinks the capacity of the bidigrowvec as much as possible.
+    /// Shrinks the capacity of the bidigrowvec as much as possible, including
+    /// dropping the rows kept in the row pool, if any.
     pub fn shrink_to_fit(&mut self) {
+        self.shrink_row_pool();
+
         for v in self.data.iter_mut() {
             v.shrink_to_fit();
         }
@@ -621,6 +781,23 @@ impl<T> BidiGrowVec<T> {
         result
     }
 
+    /// Converts the bidigrowvec into a `Vec<T>` where items are linearly
+    /// laid out by columns (e.g. to pass them to column-major code).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiGrowVec;
+    ///
+    /// let bvec = BidiGrowVec::from_iterator(0..6, 3).unwrap();
+    ///
+    /// assert_eq!(bvec.into_vec_by_cols(), vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn into_vec_by_cols(mut self) -> Vec<T> {
+        self.transpose();
+        self.into_vec()
+    }
+
     /// Converts the vector into a `Vec<Vec<T>>` where items are arranged
     /// as in a vec of rows, where each row is itself a vec of items.
     /// This is O(1).
@@ -628,6 +805,65 @@ impl<T> BidiGrowVec<T> {
         self.data
     }
 
+    /// Returns the rows of the bidigrowvec, each as a slice of items.
+    /// This is O(h), as it only allocates the vector of slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidigrowvec;
+    ///
+    /// let bvec = bidigrowvec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(bvec.as_rows(), vec![&[1, 2, 3], &[4, 5, 6]]);
+    /// ```
+    pub fn as_rows(&self) -> Vec<&[T]> {
+        self.data.iter().map(Vec::as_slice).collect()
+    }
+
+    /// Calls a closure on every row of the bidigrowvec, from the top, passing the index
+    /// of the row and the row as a mutable slice of items, so that row-local
+    /// algorithms can use the methods of slices instead of accessing the items one
+    /// at a time.
+    pub fn for_each_row_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        for (y, row) in self.data.iter_mut().enumerate() {
+            f(y, row.as_mut_slice());
+        }
+    }
+
+    /// Calls a closure on every column of the bidigrowvec, from the left, passing the
+    /// index of the column and the column as a mutable slice of items. Since
+    /// columns aren't contiguous in memory, the items of each column are moved to
+    /// a temporary buffer for the duration of the call, and moved back afterwards.
+    pub fn for_each_col_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let width = self.width();
+        let mut ptrs = Vec::with_capacity(self.height());
+        let mut scratch = Vec::with_capacity(self.height());
+
+        for x in 0..width {
+            ptrs.clear();
+            ptrs.extend(self.data.iter_mut().map(|row| &mut row[x] as *mut T));
+            // SAFETY: the pointers are distinct, and the bidigrowvec is mutably borrowed
+            unsafe { with_gathered_items(&ptrs, &mut scratch, |col| f(x, col)) };
+        }
+    }
+
+    /// Converts the bidigrowvec into a `Vec<Vec<T>>` where items are arranged
+    /// as in a vec of rows, where each row is itself a vec of items.
+    /// This is the same as [`BidiGrowVec::into_vec_of_vec`], and is O(1).
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        self.data
+    }
+
     /// Swaps two elements in the bidigrowvec. If any of the coordinates are out
     /// of range, [`BidiError::OutOfBounds`] is returned
     ///
@@ -664,7 +900,8 @@ impl<T> BidiGrowVec<T> {
             }
             Ok(())
         } else {
-            Err(BidiError::OutOfBounds)
+            let (x, y) = if self.valid_coords(a.0, a.1) { b } else { a };
+            Err(BidiError::out_of_bounds(x, y, self.size()))
         }
     }
 
@@ -713,7 +950,7 @@ impl<T> BidiGrowVec<T> {
                 for v in self.data.iter_mut() {
                     v.truncate(saved_width);
                 }
-                Err(BidiError::IncompatibleSize)
+                Err(BidiError::incompatible_size(self.height(), rows_changed))
             } else {
                 Ok(())
             }
@@ -738,19 +975,18 @@ impl<T> BidiGrowVec<T> {
     /// assert_eq!(bvec[(2, 0)], 3);
     /// ```
     pub fn push_row<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), BidiError> {
-        if self.data.is_empty() {
-            self.data.push(iter.into_iter().collect::<Vec<T>>());
+        let row_data = self.new_row(iter);
+
+        if self.data.is_empty() && row_data.is_empty() {
+            self.recycle_row(row_data);
             Ok(())
+        } else if !self.data.is_empty() && row_data.len() != self.width() {
+            let found = row_data.len();
+            self.recycle_row(row_data);
+            Err(BidiError::incompatible_size(self.width(), found))
         } else {
-            let width = self.width();
-            self.data.push(iter.into_iter().collect::<Vec<T>>());
-
-            if self.data[self.data.len() - 1].len() != width {
-                self.data.truncate(self.data.len() - 1);
-                Err(BidiError::IncompatibleSize)
-            } else {
-                Ok(())
-            }
+            self.data.push(row_data);
+            Ok(())
         }
     }
 
@@ -783,7 +1019,7 @@ impl<T> BidiGrowVec<T> {
         iter: I,
     ) -> Result<(), BidiError> {
         match col.cmp(&self.width()) {
-            Ordering::Greater => Err(BidiError::OutOfBounds),
+            Ordering::Greater => Err(BidiError::out_of_bounds(col, 0, self.size())),
             Ordering::Equal => self.push_col(iter),
             Ordering::Less => {
                 let saved_width = self.width();
@@ -806,7 +1042,7 @@ impl<T> BidiGrowVec<T> {
                             v.remove(col);
                         }
                     }
-                    Err(BidiError::IncompatibleSize)
+                    Err(BidiError::incompatible_size(self.height(), rows_changed))
                 } else {
                     Ok(())
                 }
@@ -842,13 +1078,15 @@ impl<T> BidiGrowVec<T> {
         iter: I,
     ) -> Result<(), BidiError> {
         match (row).cmp(&self.data.len()) {
-            Ordering::Greater => Err(BidiError::OutOfBounds),
+            Ordering::Greater => Err(BidiError::out_of_bounds(0, row, self.size())),
             Ordering::Equal => self.push_row(iter),
             Ordering::Less => {
-                let row_data = iter.into_iter().collect::<Vec<T>>();
+                let row_data = self.new_row(iter);
 
                 if row_data.len() != self.width() {
-                    Err(BidiError::IncompatibleSize)
+                    let found = row_data.len();
+                    self.recycle_row(row_data);
+                    Err(BidiError::incompatible_size(self.width(), found))
                 } else {
                     self.data.insert(row, row_data);
                     Ok(())
@@ -882,7 +1120,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn remove_col(&mut self, col: usize) -> Result<Vec<T>, BidiError> {
         if col >= self.width() {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::out_of_bounds(col, 0, self.size()))
         } else {
             let mut result = Vec::with_capacity(self.height());
 
@@ -918,7 +1156,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn remove_row(&mut self, row: usize) -> Result<Vec<T>, BidiError> {
         if row >= self.height() {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::out_of_bounds(0, row, self.size()))
         } else {
             Ok(self.data.remove(row))
         }
@@ -949,7 +1187,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn delete_col(&mut self, col: usize) -> Result<(), BidiError> {
         if col >= self.width() {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::out_of_bounds(col, 0, self.size()))
         } else {
             for v in self.data.iter_mut() {
                 v.remove(col);
@@ -962,9 +1200,9 @@ impl<T> BidiGrowVec<T> {
     /// Deletes the specified row from the bidigrowvec. If the row is
     /// outside of range, [`BidiError::OutOfBounds`] is returned.
     ///
-    /// This method is no faster than `remove_row` and exists only
-    /// for symmetry with `delete_col` and other data structures
-    /// where `delete_row` offers a performance advantage.
+    /// This method is no faster than `remove_row`, unless the row pool
+    /// is enabled, in which case the allocation of the deleted row is
+    /// kept for reuse (see [`BidiGrowVec::enable_row_pool`]).
     ///
     /// # Examples
     ///
@@ -984,7 +1222,8 @@ impl<T> BidiGrowVec<T> {
     /// assert_eq!(bvec[(1, 1)], 8);
     /// ```
     pub fn delete_row(&mut self, row: usize) -> Result<(), BidiError> {
-        self.remove_row(row)?;
+        let row_data = self.remove_row(row)?;
+        self.recycle_row(row_data);
         Ok(())
     }
 
@@ -1037,7 +1276,9 @@ impl<T> BidiGrowVec<T> {
     /// assert_eq!(bvec.height(), 2);
     /// ```
     pub fn delete_last_row(&mut self) {
-        self.data.pop();
+        if let Some(row_data) = self.data.pop() {
+            self.recycle_row(row_data);
+        }
     }
 
     /// Removes the last column from the bidigrowvec, returning its data.
@@ -1118,11 +1359,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     #[inline(always)]
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        if self.valid_coords(x, y) {
-            Some(&self.data[y][x])
-        } else {
-            None
-        }
+        self.data.get(y).and_then(|row| row.get(x))
     }
 
     /// Mutably accesses an element in the BidiGrowVec, using its cartesian coordinates.
@@ -1152,11 +1389,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     #[inline(always)]
     pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
-        if self.valid_coords(x, y) {
-            Some(&mut self.data[y][x])
-        } else {
-            None
-        }
+        self.data.get_mut(y).and_then(|row| row.get_mut(x))
     }
 
     /// Checks if the specified coordinates are inside the bidigrowvec bounds
@@ -1180,6 +1413,32 @@ impl<T> BidiGrowVec<T> {
         x < self.width() && y < self.height()
     }
 
+    /// Returns the entry at (x, y) coordinates, to read, modify or insert its item.
+    /// The coordinates can be outside of the bounds of the bidigrowvec: inserting into
+    /// such an entry grows the bidigrowvec to contain it (see [`BidiEntry`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidigrowvec, BidiView};
+    ///
+    /// let mut bvec = bidigrowvec!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// *bvec.entry(1, 1).or_insert(0) += 10;
+    /// *bvec.entry(2, 0).or_insert(0) += 5;
+    ///
+    /// assert_eq!(bvec, bidigrowvec!{
+    ///     [1, 2, 5],
+    ///     [3, 14, 0],
+    /// });
+    /// ```
+    pub fn entry(&mut self, x: usize, y: usize) -> BidiEntry<'_, Self> {
+        BidiEntry::new(self, x, y)
+    }
+
     /// Reverses the order of the items in the specified row.
     ///
     /// # Examples
@@ -1201,7 +1460,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn reverse_row(&mut self, row: usize) -> Result<(), BidiError> {
         if row >= self.height() {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::out_of_bounds(0, row, self.size()))
         } else {
             self.data[row].reverse();
             Ok(())
@@ -1229,7 +1488,7 @@ impl<T> BidiGrowVec<T> {
     /// ```
     pub fn reverse_col(&mut self, col: usize) -> Result<(), BidiError> {
         if col >= self.width() {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::out_of_bounds(col, 0, self.size()))
         } else {
             let height = self.height();
 
@@ -1309,6 +1568,15 @@ impl<T> BidiGrowVec<T> {
         self.reverse_rows();
     }
 
+    /// Anti-transposes the bidigrowvec, that is an operation that flips the bidigrowvec
+    /// over its anti-diagonal (going from the top right corner to the bottom
+    /// left one). As for a transposition, the result is as wide as the original
+    /// was tall, and as tall as the original was wide.
+    pub fn anti_transpose(&mut self) {
+        self.transpose();
+        self.rotate180();
+    }
+
     /// Reverse the order of items in all columns. This is equivalent to flipping
     /// the data structure over its horizontal axis.
     pub fn reverse_columns(&mut self) {
@@ -1323,6 +1591,52 @@ impl<T> BidiGrowVec<T> {
         }
     }
 
+    /// Transposes the bidigrowvec in-place and returns it; see [`BidiGrowVec::transpose`].
+    pub fn into_transposed(mut self) -> Self {
+        self.transpose();
+        self
+    }
+
+    /// Anti-transposes the bidigrowvec in-place and returns it; see [`BidiGrowVec::anti_transpose`].
+    pub fn into_anti_transposed(mut self) -> Self {
+        self.anti_transpose();
+        self
+    }
+
+    /// Rotates the bidigrowvec 90° counter-clockwise in-place and returns it; see
+    /// [`BidiGrowVec::rotate90ccw`].
+    pub fn into_rotated90ccw(mut self) -> Self {
+        self.rotate90ccw();
+        self
+    }
+
+    /// Rotates the bidigrowvec 180° in-place and returns it.
+    pub fn into_rotated180(mut self) -> Self {
+        self.rotate180();
+        self
+    }
+
+    /// Rotates the bidigrowvec 270° counter-clockwise in-place and returns it; see
+    /// [`BidiGrowVec::rotate270ccw`].
+    pub fn into_rotated270ccw(mut self) -> Self {
+        self.rotate270ccw();
+        self
+    }
+
+    /// Reverses the order of items in all columns in-place and returns the bidigrowvec;
+    /// see [`BidiGrowVec::reverse_columns`].
+    pub fn into_reversed_columns(mut self) -> Self {
+        self.reverse_columns();
+        self
+    }
+
+    /// Reverses the order of items in all rows in-place and returns the bidigrowvec;
+    /// see [`BidiGrowVec::reverse_rows`].
+    pub fn into_reversed_rows(mut self) -> Self {
+        self.reverse_rows();
+        self
+    }
+
     /// Crops the data structure to its new bounds by moving the origin to
     /// a new location, reducing the width and height and dropping excess
     /// data.
@@ -1331,22 +1645,112 @@ impl<T> BidiGrowVec<T> {
             self.clear();
             Ok(())
         } else if (rect.max_x() > self.width()) || (rect.max_y() > self.height()) {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::rect_out_of_bounds(rect, self.size()))
         } else {
-            self.truncate(rect.max_x(), rect.max_y()).unwrap();
-            for _ in 0..rect.x {
-                self.remove_col(0).unwrap();
-            }
-            for _ in 0..rect.y {
-                self.remove_row(0).unwrap();
+            self.recycle_rows(rect.max_y()..);
+            self.recycle_rows(..rect.y);
+
+            for row in self.data.iter_mut() {
+                row.truncate(rect.max_x());
+                row.drain(..rect.x);
             }
+
             Ok(())
         }
     }
 
     fn collapse(&mut self) {
         if !self.data.is_empty() && self.data[0].is_empty() {
-            self.data.clear();
+            self.recycle_rows(..);
+        }
+    }
+
+    /// Enables the row pool: from now on, the allocations of the rows deleted
+    /// from the bidigrowvec (by [`BidiGrowVec::delete_row`],
+    /// [`BidiGrowVec::delete_last_row`], [`BidiGrowVec::truncate`],
+    /// [`BidiGrowVec::clear`] and similar methods) are kept in an internal pool,
+    /// and reused by the rows inserted afterwards (by [`BidiGrowVec::push_row`],
+    /// [`BidiGrowVec::insert_row`] and similar methods).
+    ///
+    /// Rows returned to the caller (e.g. by [`BidiGrowVec::remove_row`]) are not
+    /// pooled. The pool is never shrunk automatically: use
+    /// [`BidiGrowVec::shrink_row_pool`] or [`BidiGrowVec::shrink_to_fit`] to
+    /// release its memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiGrowVec, bidigrowvec};
+    ///
+    /// let mut bvec = bidigrowvec![0; 64, 64];
+    /// bvec.enable_row_pool();
+    ///
+    /// bvec.truncate(64, 60).unwrap();
+    /// assert_eq!(bvec.pooled_rows(), 4);
+    ///
+    /// bvec.insert_row(10, vec![1; 64]).unwrap();
+    /// assert_eq!(bvec.pooled_rows(), 3);
+    ///
+    /// bvec.shrink_row_pool();
+    /// assert_eq!(bvec.pooled_rows(), 0);
+    /// ```
+    pub fn enable_row_pool(&mut self) {
+        if self.pool.is_none() {
+            self.pool = Some(Vec::new());
+        }
+    }
+
+    /// Disables the row pool (see [`BidiGrowVec::enable_row_pool`]), dropping
+    /// the rows it contains.
+    pub fn disable_row_pool(&mut self) {
+        self.pool = None;
+    }
+
+    /// Returns true if the row pool is enabled (see [`BidiGrowVec::enable_row_pool`]).
+    pub fn has_row_pool(&self) -> bool {
+        self.pool.is_some()
+    }
+
+    /// Returns the number of row allocations currently kept in the row pool
+    /// (see [`BidiGrowVec::enable_row_pool`]).
+    pub fn pooled_rows(&self) -> usize {
+        self.pool.as_ref().map_or(0, |pool| pool.len())
+    }
+
+    /// Drops the rows kept in the row pool, releasing their memory, without
+    /// disabling it (see [`BidiGrowVec::enable_row_pool`]).
+    pub fn shrink_row_pool(&mut self) {
+        if let Some(pool) = self.pool.as_mut() {
+            *pool = Vec::new();
+        }
+    }
+
+    fn new_row<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Vec<T> {
+        match self.pool.as_mut().and_then(|pool| pool.pop()) {
+            Some(mut row_data) => {
+                row_data.extend(iter);
+                row_data
+            }
+            None => iter.into_iter().collect(),
+        }
+    }
+
+    fn recycle_row(&mut self, mut row_data: Vec<T>) {
+        if let Some(pool) = self.pool.as_mut() {
+            row_data.clear();
+            pool.push(row_data);
+        }
+    }
+
+    fn recycle_rows<R: RangeBounds<usize>>(&mut self, range: R) {
+        match self.pool.as_mut() {
+            Some(pool) => pool.extend(self.data.drain(range).map(|mut row_data| {
+                row_data.clear();
+                row_data
+            })),
+            None => {
+                self.data.drain(range);
+            }
         }
     }
 
@@ -1373,16 +1777,76 @@ impl<T> BidiGrowVec<T> {
     }
 
     /// Returns an iterator over the items of the view
-    pub fn iter(&self) -> Iter<T, Self> {
+    pub fn iter(&self) -> Iter<'_, T, Self> {
         Iter::new(self)
     }
 
     /// Returns a mutable iterator over the items of the view
-    pub fn iter_mut(&mut self) -> IterMut<T, Self> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, Self> {
         IterMut::new(self)
     }
 }
 
+impl<T> Default for BidiGrowVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BidiConstruct for BidiGrowVec<T> {
+    fn with_elem(value: T, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::with_elem(value, width, height)
+    }
+
+    fn with_size_default(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
+        Self::with_size_default(width, height)
+    }
+
+    fn with_size_func<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::with_size_func(width, height, f)
+    }
+
+    fn with_size_func_xy<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::with_size_func_xy(width, height, f)
+    }
+}
+
+impl<T> BidiBuild for BidiGrowVec<T> {
+    fn from_iterator<I>(iter: I, row_size: usize) -> Result<Self, BidiError>
+    where
+        I: Iterator<Item = T>,
+    {
+        Self::from_iterator(iter, row_size)
+    }
+
+    fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        Self::from_rows(rows)
+    }
+
+    fn push_row<I>(&mut self, row: I) -> Result<(), BidiError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.push_row(row)
+    }
+}
+
 impl<T> BidiFrom<&dyn BidiView<Output = T>> for BidiGrowVec<T>
 where
     T: Clone,
@@ -1396,8 +1860,8 @@ where
     }
 
     fn from_view_cut(source: &dyn BidiView<Output = T>, cut: &BidiRect) -> Result<Self, BidiError> {
-        if cut.max_x() > source.height() || cut.max_y() > source.width() {
-            return Err(BidiError::OutOfBounds);
+        if cut.max_x() > source.width() || cut.max_y() > source.height() {
+            return Err(BidiError::rect_out_of_bounds(cut, source.size()));
         }
 
         Ok(BidiGrowVec::<T>::with_size_func_xy(
@@ -1496,6 +1960,26 @@ impl<T> IndexMut<(usize, usize)> for BidiGrowVec<T> {
     }
 }
 
+impl<T> Index<BidiPoint> for BidiGrowVec<T> {
+    type Output = T;
+
+    /// Accesses an element in the BidiGrowVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl<T> IndexMut<BidiPoint> for BidiGrowVec<T> {
+    /// Mutably accesses an element in the BidiGrowVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index_mut(&mut self, index: BidiPoint) -> &mut Self::Output {
+        &mut self[(index.x, index.y)]
+    }
+}
+
 impl<T> BidiView for BidiGrowVec<T> {
     fn width(&self) -> usize {
         BidiGrowVec::<T>::width(self)
@@ -1507,16 +1991,63 @@ impl<T> BidiView for BidiGrowVec<T> {
     fn get(&self, x: usize, y: usize) -> Option<&T> {
         self.get(x, y)
     }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        self.data.get_unchecked(y).get_unchecked(x)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        self.data.get(row).map(|r| r.as_slice())
+    }
 }
 
 impl<T> BidiViewMut for BidiGrowVec<T> {
     fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
         self.get_mut(x, y)
     }
+
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
+        self.data.get_unchecked_mut(y).get_unchecked_mut(x)
+    }
+
+    fn row_slice_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        self.data.get_mut(row).map(|r| r.as_mut_slice())
+    }
 }
 
 unsafe impl<T> BidiViewMutIterable for BidiGrowVec<T> {}
 
+impl<T: Clone> Clone for BidiGrowVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            pool: self.pool.as_ref().map(|_| Vec::new()),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BidiGrowVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BidiGrowVec")
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for BidiGrowVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T: Eq> Eq for BidiGrowVec<T> {}
+
+impl<T: Hash> Hash for BidiGrowVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
 impl<T> From<BidiVec<T>> for BidiGrowVec<T> {
     /// Creates a new instance of [`BidiGrowVec<T>`] from an existing [`BidiVec<T>`].
     /// This operation is `O(width*height)` in the worst case.
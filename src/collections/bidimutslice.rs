@@ -1,9 +1,10 @@
 use crate::bidiiter::{Iter, IterMut};
-use std::iter::Iterator;
+use std::cmp::{max, min};
 #[rustversion::since(1.48)]
 use std::ops::Range;
 use std::ops::{Index, IndexMut};
 
+use crate::collections::bidislice::coords_of_item;
 use crate::*;
 
 /// A bidimensional view over a mutable slice (for the immutable version,
@@ -27,7 +28,7 @@ use crate::*;
 ///
 /// assert_eq!(bslice[(1, 1)], 12);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct BidiMutSlice<'a, T> {
     pub(crate) data: &'a mut [T],
     pub(crate) row_size: usize,
@@ -49,7 +50,7 @@ impl<'a, T> BidiMutSlice<'a, T> {
         if (data.is_empty() && row_size == 0) || row_size != 0 && (data.len() % row_size) == 0 {
             Ok(Self { data, row_size })
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, data.len()))
         }
     }
 
@@ -98,7 +99,7 @@ impl<'a, T> BidiMutSlice<'a, T> {
     /// assert_eq!(bslice.height(), 3);
     /// ```
     pub fn height(&self) -> usize {
-        self.data.len() / self.row_size
+        self.data.len().checked_div(self.row_size).unwrap_or(0)
     }
 
     /// Returns true if the bidislice contains no elements (that
@@ -118,12 +119,85 @@ impl<'a, T> BidiMutSlice<'a, T> {
         self.data.is_empty()
     }
 
+    /// Checks that the internal state of the bidimutslice is consistent, returning
+    /// [`BidiError::IncompatibleSize`] if it's not (e.g. if the number of items
+    /// is not a multiple of the row size).
+    ///
+    /// BidiMutSlices built through the methods of the crate are always consistent:
+    /// this is meant to verify bidimutslices whose memory has been written through
+    /// unsafe code (e.g. by native code through FFI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiMutSlice;
+    ///
+    /// let mut data = [1, 2, 3, 4, 5, 6];
+    /// let bslice = BidiMutSlice::new(&mut data, 3)?;
+    ///
+    /// assert!(bslice.validate().is_ok());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), BidiError> {
+        if (self.data.is_empty() && self.row_size == 0)
+            || (self.row_size != 0 && (self.data.len() % self.row_size) == 0)
+        {
+            Ok(())
+        } else {
+            Err(BidiError::incompatible_size(self.row_size, self.data.len()))
+        }
+    }
+
     /// Turns this bidislice back into the slice that was used to
     /// create it.
     pub fn into_slice(self) -> &'a mut [T] {
         self.data
     }
 
+    /// Returns a [`BidiCellSlice`] over the items of the bidislice, through which
+    /// they can be modified from shared references.
+    pub fn as_cell_slice(&mut self) -> BidiCellSlice<'_, T> {
+        BidiCellSlice::from_mut_slice(self.data, self.row_size).unwrap()
+    }
+
+    /// Returns the rows of the bidimutslice, each as a slice of items.
+    /// This is O(h), as it only allocates the vector of slices.
+    pub fn as_rows(&self) -> Vec<&[T]> {
+        if self.width() == 0 {
+            Vec::new()
+        } else {
+            self.data.chunks(self.width()).collect()
+        }
+    }
+
+    /// Calls a closure on every row of the bidimutslice, from the top, passing the index
+    /// of the row and the row as a mutable slice of items, so that row-local
+    /// algorithms can use the methods of slices instead of accessing the items one
+    /// at a time.
+    pub fn for_each_row_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let width = self.width();
+
+        if width != 0 {
+            for (y, row) in self.data.chunks_mut(width).enumerate() {
+                f(y, row);
+            }
+        }
+    }
+
+    /// Calls a closure on every column of the bidimutslice, from the left, passing the
+    /// index of the column and the column as a mutable slice of items. Since
+    /// columns aren't contiguous in memory, the items of each column are moved to
+    /// a temporary buffer for the duration of the call, and moved back afterwards.
+    pub fn for_each_col_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        for_each_col_in(self.data, self.row_size, f);
+    }
+
     /// Returns a raw pointer to the bidislice's buffer.
     ///
     /// The caller must ensure that the underlying slice outlives the pointer this
@@ -279,13 +353,35 @@ impl<'a, T> BidiMutSlice<'a, T> {
         self.calc_index(x, y).is_ok()
     }
 
+    /// Returns the coordinates of an item of the bidimutslice, given a reference to it
+    /// (e.g. one obtained while iterating), or [`None`] if the reference doesn't
+    /// point to an item of the bidimutslice. The coordinates are computed from the address
+    /// of the item, so this is `O(1)`; for zero-sized types, [`None`] is always
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiMutSlice;
+    ///
+    /// let mut data = [1, 2, 3, 4, 5, 6];
+    /// let bvec = BidiMutSlice::new(&mut data, 3)?;
+    ///
+    /// let max = bvec.iter().max().unwrap();
+    /// assert_eq!(bvec.coords_of(max), Some((2, 1)));
+    /// assert_eq!(bvec.coords_of(&6), None);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn coords_of(&self, item: &T) -> Option<(usize, usize)> {
+        coords_of_item(self.data, self.row_size, item)
+    }
+
     #[inline(always)]
     fn calc_index(&self, x: usize, y: usize) -> Result<usize, BidiError> {
-        let idx = y * self.row_size + x;
-        if x >= self.row_size || idx >= self.data.len() {
-            Err(BidiError::OutOfBounds)
+        if x >= self.row_size || y >= self.data.len() / self.row_size {
+            Err(BidiError::out_of_bounds(x, y, self.size()))
         } else {
-            Ok(idx)
+            Ok(y * self.row_size + x)
         }
     }
 
@@ -307,7 +403,7 @@ impl<'a, T> BidiMutSlice<'a, T> {
     /// ```
     pub fn reverse_row(&mut self, row: usize) -> Result<(), BidiError> {
         if row >= self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(0, row, self.size()));
         }
 
         let width = self.width();
@@ -337,7 +433,7 @@ impl<'a, T> BidiMutSlice<'a, T> {
     /// ```
     pub fn reverse_col(&mut self, col: usize) -> Result<(), BidiError> {
         if col >= self.width() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(col, 0, self.size()));
         }
 
         let height = self.height();
@@ -354,50 +450,82 @@ impl<'a, T> BidiMutSlice<'a, T> {
     /// rows). As such, the result of a transposition is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidislice width and height are different (i.e. it's not a square).
+    /// This is performed in-place; if the bidislice width and height are different
+    /// (i.e. it's not a square) it requires one bit of additional memory per item,
+    /// and items are moved along the cycles of the transposition, which is O(n) but
+    /// not cache friendly.
+    ///
+    /// Square bidislices are transposed in tiles, sized so that two tiles fit in
+    /// 32KB (a common size for L1 data caches); see
+    /// [`BidiMutSlice::transpose_with_block_size`] to use a different size.
     pub fn transpose(&mut self) {
+        self.transpose_with_block_size(transpose_block_size::<T>());
+    }
+
+    /// Transposes the bidislice like [`BidiMutSlice::transpose`], processing square
+    /// bidislices in tiles of `block_size` × `block_size` items, which greatly reduces
+    /// cache misses on large data; a `block_size` of 0 is treated as 1.
+    ///
+    /// The best block size depends on the size of the items and on the cache of the
+    /// machine, and is best found by benchmarking. Non-square bidislices are not
+    /// tiled, and `block_size` is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiMutSlice, BidiView};
+    ///
+    /// let mut data = (0..16).collect::<Vec<i32>>();
+    /// let mut bslice = BidiMutSlice::new(&mut data, 4).unwrap();
+    ///
+    /// bslice.transpose_with_block_size(2);
+    ///
+    /// assert_eq!(bslice[(1, 0)], 4);
+    /// assert_eq!(bslice[(0, 1)], 1);
+    /// assert_eq!(bslice[(3, 2)], 14);
+    /// ```
+    pub fn transpose_with_block_size(&mut self, block_size: usize) {
         let width = self.width();
         let height = self.height();
 
         if width == height {
-            for x in 0..width {
-                for y in (x + 1)..height {
-                    self.swap((x, y), (y, x)).unwrap();
+            let block_size = max(block_size, 1);
+
+            for block_x in (0..width).step_by(block_size) {
+                for block_y in (block_x..height).step_by(block_size) {
+                    for x in block_x..min(block_x + block_size, width) {
+                        let start_y = if block_x == block_y { x + 1 } else { block_y };
+
+                        for y in start_y..min(block_y + block_size, height) {
+                            self.data.swap(y * width + x, x * width + y);
+                        }
+                    }
                 }
             }
         } else if width != 0 && height != 0 {
+            // Non-square transpositions follow the cycles of the permutation moving
+            // the item at index `i` to index `i * height % last`, keeping track of
+            // the visited indices in a bitset (one bit per item).
             let last = self.data.len() - 1;
-            let mut visited = vec![false; self.data.len()].into_boxed_slice();
+            let mut visited = vec![0u64; (self.data.len() + 63) / 64];
 
-            visited[0] = true;
-            visited[last] = true;
+            for start in 1..last {
+                if visited[start / 64] & (1 << (start % 64)) != 0 {
+                    continue;
+                }
 
-            let mut next_index = Some(1);
-            while let Some(mut i) = next_index {
-                let cycle_start = i;
+                let mut i = start;
                 loop {
-                    let next = (i * width) % last;
+                    visited[i / 64] |= 1 << (i % 64);
 
-                    if visited[next] {
-                        visited[i] = true;
+                    let next = (i * width) % last;
+                    if next == start {
                         break;
                     }
 
-                    self.data.swap(next, i);
-                    visited[i] = true;
+                    self.data.swap(i, next);
                     i = next;
-
-                    if i == cycle_start {
-                        break;
-                    }
                 }
-
-                next_index = visited
-                    .iter()
-                    .enumerate()
-                    .find(|(_, v)| !**v)
-                    .map(|(idx, _)| idx);
             }
             self.row_size = height;
         }
@@ -407,8 +535,8 @@ impl<'a, T> BidiMutSlice<'a, T> {
     /// The result of such a rotation is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidislice width and height are different (i.e. it's not a square).
+    /// This is performed in-place; see [`BidiMutSlice::transpose`] for the memory
+    /// requirements.
     pub fn rotate90ccw(&mut self) {
         self.transpose();
         self.reverse_columns();
@@ -423,26 +551,42 @@ impl<'a, T> BidiMutSlice<'a, T> {
     /// The result of such a rotation is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidislice width and height are different (i.e. it's not a square).
+    /// This is performed in-place; see [`BidiMutSlice::transpose`] for the memory
+    /// requirements.
     pub fn rotate270ccw(&mut self) {
         self.transpose();
         self.reverse_rows();
     }
 
+    /// Anti-transposes the bidislice, that is an operation that flips the bidislice
+    /// over its anti-diagonal (going from the top right corner to the bottom
+    /// left one). As for a transposition, the result is as wide as the original
+    /// was tall, and as tall as the original was wide.
+    pub fn anti_transpose(&mut self) {
+        self.transpose();
+        self.rotate180();
+    }
+
     /// Reverse the order of items in all columns. This is equivalent to flipping
     /// the data structure over its horizontal axis.
     pub fn reverse_columns(&mut self) {
-        for col in 0..self.width() {
-            self.reverse_col(col).unwrap();
+        let width = self.width();
+        let height = self.height();
+
+        // swapping whole rows keeps the memory accesses sequential
+        for y in 0..(height / 2) {
+            let (top, bottom) = self.data.split_at_mut((height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
         }
     }
 
     /// Reverse the order of items in all rows. This is equivalent to flipping
     /// the data structure over its vertical axis.
     pub fn reverse_rows(&mut self) {
-        for row in 0..self.height() {
-            self.reverse_row(row).unwrap();
+        if self.row_size != 0 {
+            for row in self.data.chunks_mut(self.row_size) {
+                row.reverse();
+            }
         }
     }
 
@@ -457,12 +601,12 @@ impl<'a, T> BidiMutSlice<'a, T> {
     }
 
     /// Returns an iterator over the items of the view
-    pub fn iter(&self) -> Iter<T, Self> {
+    pub fn iter(&self) -> Iter<'_, T, Self> {
         Iter::new(self)
     }
 
     /// Returns a mutable iterator over the items of the view
-    pub fn iter_mut(&mut self) -> IterMut<T, Self> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, Self> {
         IterMut::new(self)
     }
 }
@@ -533,6 +677,26 @@ impl<'a, T> IndexMut<(usize, usize)> for BidiMutSlice<'a, T> {
     }
 }
 
+impl<'a, T> Index<BidiPoint> for BidiMutSlice<'a, T> {
+    type Output = T;
+
+    /// Accesses an element in the BidiMutSlice, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl<'a, T> IndexMut<BidiPoint> for BidiMutSlice<'a, T> {
+    /// Mutably accesses an element in the BidiMutSlice, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index_mut(&mut self, index: BidiPoint) -> &mut Self::Output {
+        &mut self[(index.x, index.y)]
+    }
+}
+
 impl<'a, T> BidiView for BidiMutSlice<'a, T> {
     fn width(&self) -> usize {
         BidiMutSlice::<T>::width(self)
@@ -544,12 +708,113 @@ impl<'a, T> BidiView for BidiMutSlice<'a, T> {
     fn get(&self, x: usize, y: usize) -> Option<&T> {
         self.get(x, y)
     }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        self.data.get_unchecked(y * self.width() + x)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a, T> BidiViewMut for BidiMutSlice<'a, T> {
     fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
         self.get_mut(x, y)
     }
+
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
+        let idx = y * self.width() + x;
+        self.data.get_unchecked_mut(idx)
+    }
+
+    fn row_slice_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&mut self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
 }
 
 unsafe impl<'a, T> BidiViewMutIterable for BidiMutSlice<'a, T> {}
+
+/// The block size used by [`BidiMutSlice::transpose`] (and by the transpositions and
+/// rotations of the other linear data structures): the largest power of two between
+/// 8 and 256 such that two blocks of items fit in 32KB.
+fn transpose_block_size<T>() -> usize {
+    let item_size = max(std::mem::size_of::<T>(), 1);
+    let mut block_size = 8;
+
+    while block_size < 256 && 2 * (block_size * 2) * (block_size * 2) * item_size <= 32 * 1024 {
+        block_size *= 2;
+    }
+
+    block_size
+}
+
+/// Calls `f` on every column of the items of a linear data structure, passing the
+/// index of the column and the column gathered in a contiguous scratch buffer.
+pub(crate) fn for_each_col_in<T, F>(data: &mut [T], row_size: usize, mut f: F)
+where
+    F: FnMut(usize, &mut [T]),
+{
+    let height = data.len().checked_div(row_size).unwrap_or(0);
+    let base = data.as_mut_ptr();
+    let mut ptrs = Vec::with_capacity(height);
+    let mut scratch = Vec::with_capacity(height);
+
+    for x in 0..row_size {
+        ptrs.clear();
+        // SAFETY: all the offsets are within `data`
+        ptrs.extend((0..height).map(|y| unsafe { base.add(y * row_size + x) }));
+        // SAFETY: the pointers are distinct, and `data` is mutably borrowed for the call
+        unsafe { with_gathered_items(&ptrs, &mut scratch, |col| f(x, col)) };
+    }
+}
+
+/// Moves the items pointed by `ptrs` in the (empty) `scratch` buffer, calls `f` on
+/// them and moves them back, even if `f` panics.
+///
+/// # Safety
+///
+/// The pointers must point to distinct, initialized items, which must not be
+/// accessed in any other way until the function returns.
+pub(crate) unsafe fn with_gathered_items<T, F>(ptrs: &[*mut T], scratch: &mut Vec<T>, f: F)
+where
+    F: FnOnce(&mut [T]),
+{
+    struct Scatter<'a, T> {
+        ptrs: &'a [*mut T],
+        scratch: &'a mut Vec<T>,
+    }
+
+    impl<'a, T> Drop for Scatter<'a, T> {
+        fn drop(&mut self) {
+            // SAFETY: the scratch buffer holds the items moved out of the pointers,
+            // which are moved back exactly once
+            unsafe {
+                for (i, &ptr) in self.ptrs.iter().enumerate() {
+                    std::ptr::copy_nonoverlapping(self.scratch.as_ptr().add(i), ptr, 1);
+                }
+                self.scratch.set_len(0);
+            }
+        }
+    }
+
+    debug_assert!(scratch.is_empty());
+    scratch.reserve(ptrs.len());
+    for (i, &ptr) in ptrs.iter().enumerate() {
+        std::ptr::copy_nonoverlapping(ptr, scratch.as_mut_ptr().add(i), 1);
+    }
+    scratch.set_len(ptrs.len());
+
+    let guard = Scatter { ptrs, scratch };
+    f(guard.scratch.as_mut_slice());
+}
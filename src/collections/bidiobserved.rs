@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+use std::ops::{Index, IndexMut};
+
+use crate::*;
+
+/// A wrapper over a [`BidiViewMut`] that calls a callback for every item changed
+/// through it, passing the coordinates of the item, its old value and its new value.
+///
+/// Items changed through [`BidiObserved::set`] are notified right away. Items
+/// mutably accessed in any other way (indexing, [`BidiViewMut::get_mut`], mutable
+/// iterators) can't be compared with their old values until the mutable reference
+/// is released, so they are notified when [`BidiObserved::flush`] is called or when
+/// the observer is dropped. Items whose value has not changed are never notified.
+///
+/// Since the observer is itself a [`BidiViewMut`], it can be passed to any
+/// function of the crate (e.g. the [`editing`][crate::editing] functions), to
+/// capture undo information or to validate the changes as they happen.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiObserved, BidiRect};
+///
+/// let mut v = bidivec!{
+///     [0, 0, 0],
+///     [0, 0, 0],
+/// };
+/// let mut changes = Vec::new();
+///
+/// let mut observed = BidiObserved::new(&mut v, |x, y, old: &i32, new: &i32| {
+///     changes.push((x, y, *old, *new));
+/// });
+///
+/// observed.set(0, 0, 5)?;
+/// editing::copy(&bidivec![1, 0; 2], &mut observed, &BidiRect::new(0, 0, 2, 1), (1, 1))?;
+/// drop(observed);
+///
+/// assert_eq!(changes, vec![(0, 0, 0, 5), (1, 1, 0, 1)]);
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub struct BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    view: &'a mut V,
+    callback: F,
+    touched: HashSet<(usize, usize)>,
+    olds: Vec<(usize, usize, V::Output)>,
+}
+
+impl<'a, V, F> BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    /// Creates a new observer over the specified view, calling `callback` with
+    /// the coordinates, the old value and the new value of every changed item.
+    pub fn new(view: &'a mut V, callback: F) -> Self {
+        Self {
+            view,
+            callback,
+            touched: HashSet::new(),
+            olds: Vec::new(),
+        }
+    }
+
+    fn touch(&mut self, x: usize, y: usize) {
+        if x < self.view.width() && y < self.view.height() && self.touched.insert((x, y)) {
+            self.olds.push((x, y, self.view[(x, y)].clone()));
+        }
+    }
+
+    /// Sets the item at (x, y) coordinates to `value`, calling the callback right
+    /// away if the item has changed, or returns [`BidiError::OutOfBounds`] if the
+    /// coordinates are out of range.
+    pub fn set(&mut self, x: usize, y: usize, value: V::Output) -> Result<(), BidiError> {
+        // pending changes of the same item must be notified first, to keep the
+        // notifications in order
+        if self.touched.contains(&(x, y)) {
+            self.flush();
+        }
+
+        let size = self.view.size();
+        let item = self
+            .view
+            .get_mut(x, y)
+            .ok_or_else(|| BidiError::out_of_bounds(x, y, size))?;
+
+        if *item != value {
+            let old = std::mem::replace(item, value);
+            (self.callback)(x, y, &old, item);
+        }
+        Ok(())
+    }
+
+    /// Calls the callback for all the items that have been mutably accessed
+    /// (and changed) since the last flush. Called automatically when the
+    /// observer is dropped.
+    pub fn flush(&mut self) {
+        self.touched.clear();
+
+        for (x, y, old) in std::mem::take(&mut self.olds).into_iter() {
+            let new = &self.view[(x, y)];
+            if *new != old {
+                (self.callback)(x, y, &old, new);
+            }
+        }
+    }
+}
+
+impl<'a, V, F> Drop for BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl<'a, V, F> Index<(usize, usize)> for BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    type Output = V::Output;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.view[index]
+    }
+}
+
+impl<'a, V, F> IndexMut<(usize, usize)> for BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.touch(index.0, index.1);
+        &mut self.view[index]
+    }
+}
+
+impl<'a, V, F> BidiView for BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    fn width(&self) -> usize {
+        self.view.width()
+    }
+
+    fn height(&self) -> usize {
+        self.view.height()
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+        self.view.get(x, y)
+    }
+}
+
+impl<'a, V, F> BidiViewMut for BidiObserved<'a, V, F>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Output> {
+        self.touch(x, y);
+        self.view.get_mut(x, y)
+    }
+}
+
+unsafe impl<'a, V, F> BidiViewMutIterable for BidiObserved<'a, V, F>
+where
+    V: BidiViewMutIterable,
+    V::Output: Clone + PartialEq,
+    F: FnMut(usize, usize, &V::Output, &V::Output),
+{
+}
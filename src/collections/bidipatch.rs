@@ -0,0 +1,379 @@
+use std::collections::HashSet;
+use std::ops::{Index, IndexMut};
+
+use crate::*;
+
+/// A single change recorded in a [`BidiPatch`]: the coordinates of the changed
+/// item, its value before the change and its value after the change.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BidiPatchEntry<T> {
+    /// The x coordinate of the changed item
+    pub x: usize,
+    /// The y coordinate of the changed item
+    pub y: usize,
+    /// The value of the item before the change
+    pub old: T,
+    /// The value of the item after the change
+    pub new: T,
+}
+
+/// A journal of changes to a bidimensional data structure, that can be applied
+/// to (or reverted from) any [`BidiViewMut`] of a compatible size. Patches are
+/// the building block of undo/redo support in editors.
+///
+/// A patch can be built manually, computed as the difference of two views
+/// (see [`BidiPatch::from_diff`]) or captured while editing a view through a
+/// [`BidiPatchRecorder`] (see [`BidiPatch::record`]).
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, editing, BidiPatch, BidiRect};
+///
+/// let mut v = bidivec!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// };
+///
+/// let mut recorder = BidiPatch::record(&mut v);
+/// recorder[(1, 1)] = 50;
+/// editing::copy(&bidivec![0; 2, 1], &mut recorder, &BidiRect::new(0, 0, 2, 1), (0, 0))?;
+/// let patch = recorder.finish();
+///
+/// assert_eq!(patch.len(), 3);
+/// assert_eq!(v, bidivec!{
+///     [0, 0, 3],
+///     [4, 50, 6],
+/// });
+///
+/// // undo
+/// patch.revert(&mut v)?;
+/// assert_eq!(v, bidivec!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// });
+///
+/// // redo
+/// patch.apply(&mut v)?;
+/// assert_eq!(v, bidivec!{
+///     [0, 0, 3],
+///     [4, 50, 6],
+/// });
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BidiPatch<T> {
+    entries: Vec<BidiPatchEntry<T>>,
+}
+
+impl<T> Default for BidiPatch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BidiPatch<T> {
+    /// Constructs a new, empty [`BidiPatch<T>`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Starts recording the changes done to the specified view. All
+    /// the mutations must be done through the returned [`BidiPatchRecorder`];
+    /// call [`BidiPatchRecorder::finish`] to obtain the resulting patch.
+    pub fn record<V>(view: &mut V) -> BidiPatchRecorder<'_, V>
+    where
+        V: BidiViewMut<Output = T>,
+        T: Clone + PartialEq,
+    {
+        BidiPatchRecorder {
+            view,
+            touched: HashSet::new(),
+            olds: Vec::new(),
+        }
+    }
+
+    /// Creates a patch that, applied to `old_view`, produces `new_view`.
+    /// The two views must have the same size, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiPatch};
+    ///
+    /// let a = bidivec!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    /// let b = bidivec!{
+    ///     [1, 7],
+    ///     [3, 4],
+    /// };
+    ///
+    /// let patch = BidiPatch::from_diff(&a, &b)?;
+    ///
+    /// assert_eq!(patch.len(), 1);
+    /// assert_eq!(patch.iter().next().unwrap().new, 7);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_diff<V1, V2>(old_view: &V1, new_view: &V2) -> Result<Self, BidiError>
+    where
+        V1: BidiView<Output = T>,
+        V2: BidiView<Output = T>,
+        T: Clone + PartialEq,
+    {
+        if old_view.size() != new_view.size() {
+            return Err(if old_view.width() != new_view.width() {
+                BidiError::incompatible_size(old_view.width(), new_view.width())
+            } else {
+                BidiError::incompatible_size(old_view.height(), new_view.height())
+            });
+        }
+
+        let mut patch = Self::new();
+
+        for y in 0..old_view.height() {
+            for x in 0..old_view.width() {
+                let (old, new) = (&old_view[(x, y)], &new_view[(x, y)]);
+                if old != new {
+                    patch.push(x, y, old.clone(), new.clone());
+                }
+            }
+        }
+
+        Ok(patch)
+    }
+
+    /// Appends a new entry to the patch.
+    pub fn push(&mut self, x: usize, y: usize, old: T, new: T) {
+        self.entries.push(BidiPatchEntry { x, y, old, new });
+    }
+
+    /// Returns the number of entries in the patch.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the patch contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the entries of the patch, in the
+    /// order they would be applied.
+    pub fn iter(&self) -> std::slice::Iter<'_, BidiPatchEntry<T>> {
+        self.entries.iter()
+    }
+
+    /// Appends all the entries of another patch to this one, so that
+    /// applying the result is equivalent to applying the two patches
+    /// in sequence.
+    pub fn append(&mut self, other: &mut BidiPatch<T>) {
+        self.entries.append(&mut other.entries);
+    }
+
+    /// Returns a patch that undoes the changes of this patch (i.e. one
+    /// whose [`apply`][BidiPatch::apply] is the [`revert`][BidiPatch::revert]
+    /// of this patch).
+    pub fn into_inverted(self) -> Self {
+        Self {
+            entries: self
+                .entries
+                .into_iter()
+                .rev()
+                .map(|e| BidiPatchEntry {
+                    x: e.x,
+                    y: e.y,
+                    old: e.new,
+                    new: e.old,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the bounding rect of all the entries in the patch, or
+    /// [`None`] if the patch is empty.
+    pub fn bounding_rect(&self) -> Option<BidiRect> {
+        let first = self.entries.first()?;
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (first.x, first.y, first.x, first.y);
+
+        for e in self.entries.iter() {
+            min_x = min_x.min(e.x);
+            min_y = min_y.min(e.y);
+            max_x = max_x.max(e.x);
+            max_y = max_y.max(e.y);
+        }
+
+        Some(BidiRect::new(
+            min_x,
+            min_y,
+            max_x - min_x + 1,
+            max_y - min_y + 1,
+        ))
+    }
+
+    /// Applies the patch to a view, writing the new value of each entry.
+    ///
+    /// If any entry is outside of the view bounds, [`BidiError::OutOfBounds`]
+    /// is returned and the view is left untouched.
+    pub fn apply<V>(&self, view: &mut V) -> Result<(), BidiError>
+    where
+        V: BidiViewMut<Output = T>,
+        T: Clone,
+    {
+        self.check_bounds(view)?;
+
+        for e in self.entries.iter() {
+            view[(e.x, e.y)] = e.new.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Reverts the patch from a view, writing the old value of each entry
+    /// (in reverse order).
+    ///
+    /// If any entry is outside of the view bounds, [`BidiError::OutOfBounds`]
+    /// is returned and the view is left untouched.
+    pub fn revert<V>(&self, view: &mut V) -> Result<(), BidiError>
+    where
+        V: BidiViewMut<Output = T>,
+        T: Clone,
+    {
+        self.check_bounds(view)?;
+
+        for e in self.entries.iter().rev() {
+            view[(e.x, e.y)] = e.old.clone();
+        }
+
+        Ok(())
+    }
+
+    fn check_bounds<V>(&self, view: &V) -> Result<(), BidiError>
+    where
+        V: BidiView<Output = T>,
+    {
+        match self.entries.iter().find(|e| !view.is_in_bounds(e.x, e.y)) {
+            Some(e) => Err(BidiError::out_of_bounds(e.x, e.y, view.size())),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> IntoIterator for BidiPatch<T> {
+    type Item = BidiPatchEntry<T>;
+    type IntoIter = std::vec::IntoIter<BidiPatchEntry<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+/// A wrapper over a [`BidiViewMut`] that records all the changes done through
+/// it, to produce a [`BidiPatch`]. Created by [`BidiPatch::record`].
+///
+/// The recorder captures the old value of an item the first time it is
+/// mutably accessed; the new values are captured when [`BidiPatchRecorder::finish`]
+/// is called, and items that have been accessed but not changed are not
+/// included in the patch.
+///
+/// Since the recorder is itself a [`BidiViewMut`], it can be passed to any
+/// function of the crate (e.g. the [`editing`][crate::editing] functions).
+pub struct BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+{
+    view: &'a mut V,
+    touched: HashSet<(usize, usize)>,
+    olds: Vec<(usize, usize, V::Output)>,
+}
+
+impl<'a, V> BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+{
+    fn touch(&mut self, x: usize, y: usize) {
+        if x < self.view.width() && y < self.view.height() && self.touched.insert((x, y)) {
+            self.olds.push((x, y, self.view[(x, y)].clone()));
+        }
+    }
+
+    /// Stops recording and returns the patch containing all the changes
+    /// done through this recorder.
+    pub fn finish(self) -> BidiPatch<V::Output> {
+        let mut patch = BidiPatch::new();
+
+        for (x, y, old) in self.olds.into_iter() {
+            let new = &self.view[(x, y)];
+            if *new != old {
+                patch.push(x, y, old, new.clone());
+            }
+        }
+
+        patch
+    }
+}
+
+impl<'a, V> Index<(usize, usize)> for BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+{
+    type Output = V::Output;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.view[index]
+    }
+}
+
+impl<'a, V> IndexMut<(usize, usize)> for BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.touch(index.0, index.1);
+        &mut self.view[index]
+    }
+}
+
+impl<'a, V> BidiView for BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+{
+    fn width(&self) -> usize {
+        self.view.width()
+    }
+
+    fn height(&self) -> usize {
+        self.view.height()
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+        self.view.get(x, y)
+    }
+}
+
+impl<'a, V> BidiViewMut for BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMut,
+    V::Output: Clone + PartialEq,
+{
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Output> {
+        self.touch(x, y);
+        self.view.get_mut(x, y)
+    }
+}
+
+unsafe impl<'a, V> BidiViewMutIterable for BidiPatchRecorder<'a, V>
+where
+    V: BidiViewMutIterable,
+    V::Output: Clone + PartialEq,
+{
+}
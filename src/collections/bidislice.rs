@@ -1,9 +1,10 @@
 use crate::bidiiter::Iter;
+use std::cell::Cell;
 use std::ops::Index;
 #[rustversion::since(1.48)]
 use std::ops::Range;
 
-use crate::{BidiError, BidiView};
+use crate::{BidiError, BidiPoint, BidiView};
 
 /// A bidimensional view over an immutable slice (for the mutable version,
 /// see [`BidiMutSlice`][crate::BidiMutSlice]).
@@ -22,7 +23,7 @@ use crate::{BidiError, BidiView};
 ///
 /// assert_eq!(bslice[(1, 1)], 5);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct BidiSlice<'a, T> {
     pub(crate) data: &'a [T],
     pub(crate) row_size: usize,
@@ -44,7 +45,7 @@ impl<'a, T> BidiSlice<'a, T> {
         if (data.is_empty() && row_size == 0) || row_size != 0 && (data.len() % row_size) == 0 {
             Ok(Self { data, row_size })
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, data.len()))
         }
     }
 
@@ -93,7 +94,7 @@ impl<'a, T> BidiSlice<'a, T> {
     /// assert_eq!(bslice.height(), 3);
     /// ```
     pub fn height(&self) -> usize {
-        self.data.len() / self.row_size
+        self.data.len().checked_div(self.row_size).unwrap_or(0)
     }
 
     /// Returns true if the bidislice contains no elements (that
@@ -113,6 +114,35 @@ impl<'a, T> BidiSlice<'a, T> {
         self.data.is_empty()
     }
 
+    /// Checks that the internal state of the bidislice is consistent, returning
+    /// [`BidiError::IncompatibleSize`] if it's not (e.g. if the number of items
+    /// is not a multiple of the row size).
+    ///
+    /// BidiSlices built through the methods of the crate are always consistent:
+    /// this is meant to verify bidislices whose memory has been written through
+    /// unsafe code (e.g. by native code through FFI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSlice;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let bslice = BidiSlice::new(&data, 3)?;
+    ///
+    /// assert!(bslice.validate().is_ok());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), BidiError> {
+        if (self.data.is_empty() && self.row_size == 0)
+            || (self.row_size != 0 && (self.data.len() % self.row_size) == 0)
+        {
+            Ok(())
+        } else {
+            Err(BidiError::incompatible_size(self.row_size, self.data.len()))
+        }
+    }
+
     /// Turns this bidislice back into the slice that was used to
     /// create it.
     pub fn into_slice(self) -> &'a [T] {
@@ -124,6 +154,16 @@ impl<'a, T> BidiSlice<'a, T> {
         self.data
     }
 
+    /// Returns the rows of the bidislice, each as a slice of items.
+    /// This is O(h), as it only allocates the vector of slices.
+    pub fn as_rows(&self) -> Vec<&'a [T]> {
+        if self.width() == 0 {
+            Vec::new()
+        } else {
+            self.data.chunks(self.width()).collect()
+        }
+    }
+
     /// Returns a raw pointer to the bidislice's buffer.
     ///
     /// The caller must ensure that the underlying slice outlives the pointer this
@@ -196,13 +236,35 @@ impl<'a, T> BidiSlice<'a, T> {
         self.calc_index(x, y).is_ok()
     }
 
+    /// Returns the coordinates of an item of the bidislice, given a reference to it
+    /// (e.g. one obtained while iterating), or [`None`] if the reference doesn't
+    /// point to an item of the bidislice. The coordinates are computed from the address
+    /// of the item, so this is `O(1)`; for zero-sized types, [`None`] is always
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSlice;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let bvec = BidiSlice::new(&data, 3)?;
+    ///
+    /// let max = bvec.iter().max().unwrap();
+    /// assert_eq!(bvec.coords_of(max), Some((2, 1)));
+    /// assert_eq!(bvec.coords_of(&6), None);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn coords_of(&self, item: &T) -> Option<(usize, usize)> {
+        coords_of_item(self.data, self.row_size, item)
+    }
+
     #[inline(always)]
     fn calc_index(&self, x: usize, y: usize) -> Result<usize, BidiError> {
-        let idx = y * self.row_size + x;
-        if x >= self.row_size || idx >= self.data.len() {
-            Err(BidiError::OutOfBounds)
+        if x >= self.row_size || y >= self.data.len() / self.row_size {
+            Err(BidiError::out_of_bounds(x, y, self.size()))
         } else {
-            Ok(idx)
+            Ok(y * self.row_size + x)
         }
     }
 
@@ -212,11 +274,82 @@ impl<'a, T> BidiSlice<'a, T> {
     }
 
     /// Returns an iterator over the items of the view
-    pub fn iter(&self) -> Iter<T, Self> {
+    pub fn iter(&self) -> Iter<'_, T, Self> {
         Iter::new(self)
     }
 }
 
+/// A bidimensional view over a slice of [`Cell`]s, whose items can be modified
+/// through a shared reference. This allows many closures to cooperate on the
+/// same data structure (e.g. in callback based cellular automata), which
+/// wouldn't be possible with a [`BidiMutSlice`][crate::BidiMutSlice].
+///
+/// Bidicellslices can be created from a mutable slice with
+/// [`BidiCellSlice::from_mut_slice`], or from the data structures with a linear
+/// layout through their `as_cell_slice` methods (e.g. [`BidiVec::as_cell_slice`][crate::BidiVec::as_cell_slice]).
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiCellSlice};
+///
+/// let mut bvec = bidivec!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// };
+///
+/// let cells: BidiCellSlice<i32> = bvec.as_cell_slice();
+/// let double = |x, y| cells[(x, y)].set(cells[(x, y)].get() * 2);
+/// let negate = |x, y| cells[(x, y)].set(-cells[(x, y)].get());
+///
+/// double(0, 0);
+/// negate(0, 0);
+/// negate(2, 1);
+///
+/// assert_eq!(bvec, bidivec!{
+///     [-2, 2, 3],
+///     [4, 5, -6],
+/// });
+/// ```
+pub type BidiCellSlice<'a, T> = BidiSlice<'a, Cell<T>>;
+
+impl<'a, T> BidiSlice<'a, Cell<T>> {
+    /// Constructs a new bidicellslice over the items of a mutable slice, in the same
+    /// vein of [`Cell::from_mut`] and [`Cell::as_slice_of_cells`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiCellSlice;
+    ///
+    /// let mut slice = [1, 2, 3, 4, 5, 6];
+    /// let cells = BidiCellSlice::from_mut_slice(&mut slice, 3)?;
+    ///
+    /// cells.set(1, 1, 50)?;
+    ///
+    /// assert_eq!(slice, [1, 2, 3, 4, 50, 6]);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_mut_slice(data: &'a mut [T], row_size: usize) -> Result<Self, BidiError> {
+        Self::new(Cell::from_mut(data).as_slice_of_cells(), row_size)
+    }
+
+    /// Sets the item at (x, y) coordinates, or returns [`BidiError::OutOfBounds`]
+    /// if the coordinates are out of range.
+    pub fn set(&self, x: usize, y: usize, value: T) -> Result<(), BidiError> {
+        let idx = self.calc_index(x, y)?;
+        self.data[idx].set(value);
+        Ok(())
+    }
+
+    /// Replaces the item at (x, y) coordinates, returning the previous item, or
+    /// returns [`BidiError::OutOfBounds`] if the coordinates are out of range.
+    pub fn replace(&self, x: usize, y: usize, value: T) -> Result<T, BidiError> {
+        let idx = self.calc_index(x, y)?;
+        Ok(self.data[idx].replace(value))
+    }
+}
+
 impl<'a, T> Index<(usize, usize)> for BidiSlice<'a, T> {
     type Output = T;
 
@@ -250,6 +383,17 @@ impl<'a, T> Index<(usize, usize)> for BidiSlice<'a, T> {
     }
 }
 
+impl<'a, T> Index<BidiPoint> for BidiSlice<'a, T> {
+    type Output = T;
+
+    /// Accesses an element in the BidiSlice, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
 impl<'a, T> BidiView for BidiSlice<'a, T> {
     fn width(&self) -> usize {
         BidiSlice::<T>::width(self)
@@ -261,4 +405,37 @@ impl<'a, T> BidiView for BidiSlice<'a, T> {
     fn get(&self, x: usize, y: usize) -> Option<&T> {
         self.get(x, y)
     }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        self.data.get_unchecked(y * self.width() + x)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the coordinates of `item` in the items of a linear data structure,
+/// by comparing its address with the addresses of the items.
+pub(crate) fn coords_of_item<T>(data: &[T], row_size: usize, item: &T) -> Option<(usize, usize)> {
+    let size = std::mem::size_of::<T>();
+    let start = data.as_ptr() as usize;
+    let addr = item as *const T as usize;
+
+    if size == 0 || addr < start || addr >= start + std::mem::size_of_val(data) {
+        return None;
+    }
+
+    let offset = addr - start;
+    if offset % size != 0 {
+        return None;
+    }
+
+    let idx = offset / size;
+    Some((idx % row_size, idx / row_size))
 }
@@ -0,0 +1,888 @@
+use crate::bidiiter::{Iter, IterMut};
+use core::slice::SliceIndex;
+use smallvec::{Array, SmallVec};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, IndexMut};
+
+use crate::collections::bidimutslice::for_each_col_in;
+use crate::collections::bidislice::coords_of_item;
+use crate::*;
+
+/// A contiguous bidimensional vector type that stores up to `A::size()` items
+/// inline, without any heap allocation, based on an underlying `SmallVec<A>`
+/// (from the `smallvec` crate). Grids with more items than that are stored on
+/// the heap, like a [`BidiVec<T>`].
+///
+/// This is meant for the many tiny grids (e.g. convolution kernels, or the
+/// 3x3 neighbourhood of a tile) created and dropped in hot loops, where the
+/// cost of allocating is comparable to the cost of the work done on the grid.
+/// The capacity is specified through an array type, e.g. `BidiSmallVec<[f32; 9]>`
+/// holds up to 9 `f32` items inline.
+///
+/// This bidimensional data structure lays out its elements linearly in memory,
+/// by rows, like [`BidiVec<T>`] and [`BidiArray<T>`].
+///
+/// Requires the `smallvec` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::BidiSmallVec;
+///
+/// let kernel = BidiSmallVec::<[i32; 9]>::from_iterator(
+///     vec![0, 1, 0, 1, -4, 1, 0, 1, 0].into_iter(),
+///     3,
+/// )?;
+///
+/// assert!(!kernel.spilled());
+/// assert_eq!(kernel[(1, 1)], -4);
+/// assert_eq!(kernel.iter().sum::<i32>(), 0);
+///
+/// // larger grids are moved to the heap
+/// let large = BidiSmallVec::<[i32; 9]>::with_elem(1, 4, 4);
+/// assert!(large.spilled());
+/// # Ok::<(), bidivec::BidiError>(())
+/// ```
+pub struct BidiSmallVec<A: Array> {
+    pub(crate) data: SmallVec<A>,
+    pub(crate) row_size: usize,
+}
+
+impl<A: Array> BidiSmallVec<A> {
+    /// Constructs a new, empty [`BidiSmallVec<A>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(unused_mut)]
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let mut bvec: BidiSmallVec<[i32; 9]> = BidiSmallVec::new();
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            data: SmallVec::new(),
+            row_size: 0,
+        }
+    }
+
+    /// Constructs a new [`BidiSmallVec<A>`] with the specified size,
+    /// cloning the specified item in every position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 9]>::with_elem(5, 3, 3);
+    ///
+    /// assert_eq!(bvec.len(), 9);
+    /// assert_eq!(bvec.width(), 3);
+    /// assert_eq!(bvec.height(), 3);
+    /// assert_eq!(bvec[(1, 2)], 5);
+    /// ```
+    pub fn with_elem(value: A::Item, width: usize, height: usize) -> Self
+    where
+        A::Item: Clone,
+    {
+        Self {
+            data: SmallVec::from_elem(value, width * height),
+            row_size: width,
+        }
+    }
+
+    /// Constructs a new [`BidiSmallVec<A>`] with the specified size,
+    /// using the default value in every position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 9]>::with_size_default(3, 3);
+    ///
+    /// assert_eq!(bvec.len(), 9);
+    /// assert_eq!(bvec[(1, 2)], 0);
+    /// ```
+    pub fn with_size_default(width: usize, height: usize) -> Self
+    where
+        A::Item: Default,
+    {
+        Self::with_size_func(width, height, A::Item::default)
+    }
+
+    /// Constructs a new [`BidiSmallVec<A>`] with the specified size,
+    /// using the specified closure to produce values.
+    /// The order the closure is called when producing a new value is
+    /// not guaranteed. If the item produced is depending on the its
+    /// coordinates, use `BidiSmallVec::with_size_func_xy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 9]>::with_size_func(3, 3, || 137);
+    ///
+    /// assert_eq!(bvec.len(), 9);
+    /// assert_eq!(bvec[(1, 2)], 137);
+    /// ```
+    pub fn with_size_func<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut() -> A::Item,
+    {
+        Self {
+            data: (0..width * height).map(|_| f()).collect(),
+            row_size: width,
+        }
+    }
+
+    /// Constructs a new [`BidiSmallVec<A>`] with the specified size,
+    /// using the specified closure to produce values.
+    /// The closure receives the item coordinates as an input, and it's
+    /// called by rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[usize; 9]>::with_size_func_xy(3, 3, |x, y| x + y);
+    ///
+    /// assert_eq!(bvec.len(), 9);
+    /// assert_eq!(bvec[(1, 2)], 3);
+    /// ```
+    pub fn with_size_func_xy<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> A::Item,
+    {
+        let mut data = SmallVec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(x, y));
+            }
+        }
+
+        Self {
+            data,
+            row_size: width,
+        }
+    }
+
+    /// Creates a bidismallvec from an iterator, using the specified
+    /// `row_size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 9]>::from_iterator(0..9, 3)?;
+    ///
+    /// assert_eq!(bvec.width(), 3);
+    /// assert_eq!(bvec.height(), 3);
+    /// assert_eq!(bvec[(1, 2)], 7);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_iterator(
+        iter: impl Iterator<Item = A::Item>,
+        row_size: usize,
+    ) -> Result<Self, BidiError> {
+        Self::from_smallvec(iter.collect(), row_size)
+    }
+
+    /// Creates a bidismallvec from an iterator yielding the items by columns (e.g.
+    /// data coming from a column-major source), using the specified `col_size`
+    /// (that is, the height of the bidismallvec).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiSmallVec, BidiView};
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 6]>::from_iterator_by_cols(0..6, 2)?;
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(0, 1)], 1);
+    /// assert_eq!(bvec[(1, 0)], 2);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_iterator_by_cols(
+        iter: impl Iterator<Item = A::Item>,
+        col_size: usize,
+    ) -> Result<Self, BidiError> {
+        let mut bvec = Self::from_iterator(iter, col_size)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
+    /// Creates a bidismallvec from an iterator of rows, each of them an iterator of
+    /// items. All the rows must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiSmallVec, BidiView};
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiSmallVec::<[i32; 8]>::from_rows(rows)?;
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(2, 0)], 3);
+    /// assert_eq!(bvec[(0, 1)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = A::Item>,
+    {
+        BidiVec::from_rows(rows).map(Self::from)
+    }
+
+    /// Creates a bidismallvec from an iterator of columns, each of them an iterator of
+    /// items. All the columns must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// The items are collected by rows and then transposed in place, so this
+    /// takes linear time, unlike pushing the columns one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiSmallVec, BidiView};
+    ///
+    /// let cols = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiSmallVec::<[i32; 8]>::from_cols(cols)?;
+    ///
+    /// assert_eq!(bvec.size(), (2, 3));
+    /// assert_eq!(bvec[(0, 2)], 3);
+    /// assert_eq!(bvec[(1, 0)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_cols<I, C>(cols: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = A::Item>,
+    {
+        let mut bvec = Self::from_rows(cols)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
+    /// Creates a bidismallvec from a `Vec<T>`, using the specified `row_size`.
+    /// If the vector fits in the inline storage its items are moved there,
+    /// otherwise its allocation is reused.
+    pub fn from_vec(vec: Vec<A::Item>, row_size: usize) -> Result<Self, BidiError> {
+        let mut data = SmallVec::from_vec(vec);
+        data.shrink_to_fit();
+        Self::from_smallvec(data, row_size)
+    }
+
+    /// Creates a bidismallvec from a `SmallVec<A>`, using the specified `row_size`.
+    pub fn from_smallvec(data: SmallVec<A>, row_size: usize) -> Result<Self, BidiError> {
+        if (data.is_empty() && row_size == 0) || row_size != 0 && (data.len() % row_size) == 0 {
+            Ok(Self { data, row_size })
+        } else {
+            Err(BidiError::incompatible_size(row_size, data.len()))
+        }
+    }
+
+    /// Returns the number of items contained in the bidismallvec.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the width (that is, the size of a row) in the bidismallvec.
+    pub fn width(&self) -> usize {
+        self.row_size
+    }
+
+    /// Returns the height (that is, the size of a column) in the bidismallvec.
+    pub fn height(&self) -> usize {
+        self.data.len().checked_div(self.row_size).unwrap_or(0)
+    }
+
+    /// Returns true if the bidismallvec contains no elements (that
+    /// implies that its width, height and len are all zero).
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Checks that the internal state of the bidismallvec is consistent, returning
+    /// [`BidiError::IncompatibleSize`] if it's not (e.g. if the number of items
+    /// is not a multiple of the row size).
+    ///
+    /// BidiSmallVecs built through the methods of the crate are always consistent:
+    /// this is meant to verify bidismallvecs whose memory has been written through
+    /// unsafe code (e.g. by native code through FFI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 16]>::with_elem(0, 3, 2);
+    ///
+    /// assert!(bvec.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), BidiError> {
+        if (self.data.is_empty() && self.row_size == 0)
+            || (self.row_size != 0 && (self.data.len() % self.row_size) == 0)
+        {
+            Ok(())
+        } else {
+            Err(BidiError::incompatible_size(self.row_size, self.data.len()))
+        }
+    }
+
+    /// Returns the maximum number of items that can be stored inline,
+    /// without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// assert_eq!(BidiSmallVec::<[u8; 16]>::inline_size(), 16);
+    /// ```
+    pub fn inline_size() -> usize {
+        A::size()
+    }
+
+    /// Returns true if the items have been moved to the heap, because
+    /// there are (or there have been) too many to be stored inline.
+    pub fn spilled(&self) -> bool {
+        self.data.spilled()
+    }
+
+    /// Moves the items back to the inline storage if they fit, releasing
+    /// the heap allocation.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    /// Extracts a slice containing the specified range of bidismallvec contents,
+    /// laid out linearly, by rows.
+    pub fn as_slice<R: SliceIndex<[A::Item]>>(&self, range: R) -> &R::Output {
+        &self.data[range]
+    }
+
+    /// Extracts a slice containing the specified range of bidismallvec contents,
+    /// laid out linearly, by rows.
+    pub fn as_mut_slice<R: SliceIndex<[A::Item]>>(&mut self, range: R) -> &mut R::Output {
+        &mut self.data[range]
+    }
+
+    /// Returns a [`BidiCellSlice`] over the items of the bidismallvec, through which
+    /// they can be modified from shared references.
+    pub fn as_cell_slice(&mut self) -> BidiCellSlice<'_, A::Item> {
+        let row_size = self.row_size;
+        BidiCellSlice::from_mut_slice(&mut self.data, row_size).unwrap()
+    }
+
+    /// Converts the bidismallvec into a `SmallVec<A>` where items are linearly
+    /// laid out by rows.
+    pub fn into_smallvec(self) -> SmallVec<A> {
+        self.data
+    }
+
+    /// Converts the bidismallvec into a `Vec<T>` where items are linearly
+    /// laid out by rows. This doesn't allocate if the items have been spilled
+    /// to the heap.
+    pub fn into_vec(self) -> Vec<A::Item> {
+        self.data.into_vec()
+    }
+
+    /// Converts the bidismallvec into a `Vec<T>` where items are linearly
+    /// laid out by columns (e.g. to pass them to column-major code).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 6]>::from_iterator(0..6, 3)?;
+    ///
+    /// assert_eq!(bvec.into_vec_by_cols(), vec![0, 3, 1, 4, 2, 5]);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn into_vec_by_cols(mut self) -> Vec<A::Item> {
+        self.transpose();
+        self.into_vec()
+    }
+
+    /// Returns the rows of the bidismallvec, each as a slice of items.
+    /// This is O(h), as it only allocates the vector of slices.
+    pub fn as_rows(&self) -> Vec<&[A::Item]> {
+        if self.width() == 0 {
+            Vec::new()
+        } else {
+            self.data.chunks(self.width()).collect()
+        }
+    }
+
+    /// Calls a closure on every row of the bidismallvec, from the top, passing the index
+    /// of the row and the row as a mutable slice of items, so that row-local
+    /// algorithms can use the methods of slices instead of accessing the items one
+    /// at a time.
+    pub fn for_each_row_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [A::Item]),
+    {
+        let width = self.width();
+
+        if width != 0 {
+            for (y, row) in self.data.chunks_mut(width).enumerate() {
+                f(y, row);
+            }
+        }
+    }
+
+    /// Calls a closure on every column of the bidismallvec, from the left, passing the
+    /// index of the column and the column as a mutable slice of items. Since
+    /// columns aren't contiguous in memory, the items of each column are moved to
+    /// a temporary buffer for the duration of the call, and moved back afterwards.
+    pub fn for_each_col_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(usize, &mut [A::Item]),
+    {
+        let width = self.width();
+        for_each_col_in(&mut self.data, width, f);
+    }
+
+    /// Converts the bidismallvec into a `Vec<Vec<A::Item>>` where items are arranged
+    /// as in a vec of rows, where each row is itself a vec of items.
+    /// This is O(n).
+    pub fn into_rows(self) -> Vec<Vec<A::Item>> {
+        let (width, height) = self.size();
+        let mut items = self.into_vec().into_iter();
+
+        (0..height)
+            .map(|_| items.by_ref().take(width).collect())
+            .collect()
+    }
+
+    /// Swaps two elements in the bidismallvec. If any of the coordinates
+    /// are out of range, [`BidiError::OutOfBounds`] is returned.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> Result<(), BidiError> {
+        let idx_a = self.calc_index(a.0, a.1)?;
+        let idx_b = self.calc_index(b.0, b.1)?;
+
+        self.data.swap(idx_a, idx_b);
+        Ok(())
+    }
+
+    /// Accesses an element in the BidiSmallVec, using its cartesian coordinates.
+    /// If coordinates are outside of range, [`None`] is returned.
+    #[inline(always)]
+    pub fn get(&self, x: usize, y: usize) -> Option<&A::Item> {
+        match self.calc_index(x, y) {
+            Ok(idx) => Some(unsafe { self.data.get_unchecked(idx) }),
+            Err(_) => None,
+        }
+    }
+
+    /// Mutably accesses an element in the BidiSmallVec, using its cartesian coordinates.
+    /// If coordinates are outside of range, [`None`] is returned.
+    #[inline(always)]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut A::Item> {
+        match self.calc_index(x, y) {
+            Ok(idx) => Some(unsafe { self.data.get_unchecked_mut(idx) }),
+            Err(_) => None,
+        }
+    }
+
+    /// Checks if the specified coordinates are inside the bidismallvec bounds
+    #[inline(always)]
+    pub fn valid_coords(&self, x: usize, y: usize) -> bool {
+        self.calc_index(x, y).is_ok()
+    }
+
+    /// Returns the coordinates of an item of the bidismallvec, given a reference to it
+    /// (e.g. one obtained while iterating), or [`None`] if the reference doesn't
+    /// point to an item of the bidismallvec. The coordinates are computed from the address
+    /// of the item, so this is `O(1)`; for zero-sized types, [`None`] is always
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiSmallVec;
+    ///
+    /// let bvec = BidiSmallVec::<[i32; 8]>::from_vec(vec![1, 2, 3, 4, 5, 6], 3)?;
+    ///
+    /// let max = bvec.iter().max().unwrap();
+    /// assert_eq!(bvec.coords_of(max), Some((2, 1)));
+    /// assert_eq!(bvec.coords_of(&6), None);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn coords_of(&self, item: &A::Item) -> Option<(usize, usize)> {
+        coords_of_item(&self.data, self.row_size, item)
+    }
+
+    #[inline(always)]
+    fn calc_index(&self, x: usize, y: usize) -> Result<usize, BidiError> {
+        if x >= self.row_size || y >= self.data.len() / self.row_size {
+            Err(BidiError::out_of_bounds(x, y, self.size()))
+        } else {
+            Ok(y * self.row_size + x)
+        }
+    }
+
+    /// Transposes the bidismallvec in-place; see [`BidiMutSlice::transpose`].
+    pub fn transpose(&mut self) {
+        let mut slice = BidiMutSlice::new(&mut self.data, self.row_size).unwrap();
+        slice.transpose();
+        self.row_size = self.height();
+    }
+
+    /// Rotates the bidismallvec 90°, counter-clockwise (or, 270° clockwise).
+    pub fn rotate90ccw(&mut self) {
+        self.transpose();
+        self.reverse_columns();
+    }
+
+    /// Rotates the bidismallvec 180°.
+    pub fn rotate180(&mut self) {
+        self.data.reverse();
+    }
+
+    /// Rotates the bidismallvec 270°, counter-clockwise (or, 90° clockwise).
+    pub fn rotate270ccw(&mut self) {
+        self.transpose();
+        self.reverse_rows();
+    }
+
+    /// Anti-transposes the bidismallvec, that is an operation that flips the bidismallvec
+    /// over its anti-diagonal (going from the top right corner to the bottom
+    /// left one). As for a transposition, the result is as wide as the original
+    /// was tall, and as tall as the original was wide.
+    pub fn anti_transpose(&mut self) {
+        self.transpose();
+        self.rotate180();
+    }
+
+    /// Reverse the order of items in all columns. This is equivalent to flipping
+    /// the data structure over its horizontal axis.
+    pub fn reverse_columns(&mut self) {
+        BidiMutSlice::new(&mut self.data, self.row_size)
+            .unwrap()
+            .reverse_columns();
+    }
+
+    /// Reverse the order of items in all rows. This is equivalent to flipping
+    /// the data structure over its vertical axis.
+    pub fn reverse_rows(&mut self) {
+        BidiMutSlice::new(&mut self.data, self.row_size)
+            .unwrap()
+            .reverse_rows();
+    }
+
+    /// Transposes the bidismallvec in-place and returns it; see [`BidiSmallVec::transpose`].
+    pub fn into_transposed(mut self) -> Self {
+        self.transpose();
+        self
+    }
+
+    /// Anti-transposes the bidismallvec in-place and returns it; see [`BidiSmallVec::anti_transpose`].
+    pub fn into_anti_transposed(mut self) -> Self {
+        self.anti_transpose();
+        self
+    }
+
+    /// Rotates the bidismallvec 90° counter-clockwise in-place and returns it; see
+    /// [`BidiSmallVec::rotate90ccw`].
+    pub fn into_rotated90ccw(mut self) -> Self {
+        self.rotate90ccw();
+        self
+    }
+
+    /// Rotates the bidismallvec 180° in-place and returns it.
+    pub fn into_rotated180(mut self) -> Self {
+        self.rotate180();
+        self
+    }
+
+    /// Rotates the bidismallvec 270° counter-clockwise in-place and returns it; see
+    /// [`BidiSmallVec::rotate270ccw`].
+    pub fn into_rotated270ccw(mut self) -> Self {
+        self.rotate270ccw();
+        self
+    }
+
+    /// Reverses the order of items in all columns in-place and returns the bidismallvec;
+    /// see [`BidiSmallVec::reverse_columns`].
+    pub fn into_reversed_columns(mut self) -> Self {
+        self.reverse_columns();
+        self
+    }
+
+    /// Reverses the order of items in all rows in-place and returns the bidismallvec;
+    /// see [`BidiSmallVec::reverse_rows`].
+    pub fn into_reversed_rows(mut self) -> Self {
+        self.reverse_rows();
+        self
+    }
+
+    /// Converts this instance into a [`BidiVec<T>`]
+    /// This operation is `O(1)` if the items have been spilled to the heap,
+    /// and `O(width*height)` otherwise.
+    pub fn into_bidivec(self) -> BidiVec<A::Item> {
+        BidiVec::from(self)
+    }
+
+    /// Converts this bidismallvec to an immutable [`BidiView`].
+    pub fn as_bidiview(&self) -> &dyn BidiView<Output = A::Item> {
+        self
+    }
+
+    /// Converts this bidismallvec to a mutable [`BidiView`].
+    pub fn as_bidiview_mut(&mut self) -> &dyn BidiViewMut<Output = A::Item> {
+        self
+    }
+
+    /// Returns an iterator over the items of the view
+    pub fn iter(&self) -> Iter<'_, A::Item, Self> {
+        Iter::new(self)
+    }
+
+    /// Returns a mutable iterator over the items of the view
+    pub fn iter_mut(&mut self) -> IterMut<'_, A::Item, Self> {
+        IterMut::new(self)
+    }
+}
+
+impl<A: Array> Default for BidiSmallVec<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Array> Clone for BidiSmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            row_size: self.row_size,
+        }
+    }
+}
+
+impl<A: Array> fmt::Debug for BidiSmallVec<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BidiSmallVec")
+            .field("data", &self.data)
+            .field("row_size", &self.row_size)
+            .finish()
+    }
+}
+
+impl<A: Array> PartialEq for BidiSmallVec<A>
+where
+    A::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.row_size == other.row_size && self.data == other.data
+    }
+}
+
+impl<A: Array> Eq for BidiSmallVec<A> where A::Item: Eq {}
+
+impl<A: Array> Hash for BidiSmallVec<A>
+where
+    A::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.row_size.hash(state);
+    }
+}
+
+impl<A: Array> Index<(usize, usize)> for BidiSmallVec<A> {
+    type Output = A::Item;
+
+    /// Accesses an element in the BidiSmallVec, using its cartesian coordinates.
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        let idx = self.calc_index(index.0, index.1).unwrap_or_else(|_| {
+            panic!(
+                "Indexes out of bidismallvec bounds: ({},{}) out of {}x{}",
+                index.0,
+                index.1,
+                self.width(),
+                self.height()
+            )
+        });
+        unsafe { self.data.get_unchecked(idx) }
+    }
+}
+
+impl<A: Array> IndexMut<(usize, usize)> for BidiSmallVec<A> {
+    /// Mutably accesses an element in the BidiSmallVec, using its cartesian coordinates.
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let idx = self.calc_index(index.0, index.1).unwrap_or_else(|_| {
+            panic!(
+                "Indexes out of bidismallvec bounds: ({},{}) out of {}x{}",
+                index.0,
+                index.1,
+                self.width(),
+                self.height()
+            )
+        });
+        unsafe { self.data.get_unchecked_mut(idx) }
+    }
+}
+
+impl<A: Array> Index<BidiPoint> for BidiSmallVec<A> {
+    type Output = A::Item;
+
+    /// Accesses an element in the BidiSmallVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl<A: Array> IndexMut<BidiPoint> for BidiSmallVec<A> {
+    /// Mutably accesses an element in the BidiSmallVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index_mut(&mut self, index: BidiPoint) -> &mut Self::Output {
+        &mut self[(index.x, index.y)]
+    }
+}
+
+impl<A: Array> BidiView for BidiSmallVec<A> {
+    fn width(&self) -> usize {
+        BidiSmallVec::<A>::width(self)
+    }
+    fn height(&self) -> usize {
+        BidiSmallVec::<A>::height(self)
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&A::Item> {
+        self.get(x, y)
+    }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &A::Item {
+        self.data.get_unchecked(y * self.width() + x)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[A::Item]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
+}
+
+impl<A: Array> BidiViewMut for BidiSmallVec<A> {
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut A::Item> {
+        self.get_mut(x, y)
+    }
+
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut A::Item {
+        let idx = y * self.width() + x;
+        self.data.get_unchecked_mut(idx)
+    }
+
+    fn row_slice_mut(&mut self, row: usize) -> Option<&mut [A::Item]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&mut self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<A: Array> BidiViewMutIterable for BidiSmallVec<A> {}
+
+impl<A: Array> BidiConstruct for BidiSmallVec<A> {
+    fn with_elem(value: A::Item, width: usize, height: usize) -> Self
+    where
+        A::Item: Clone,
+    {
+        Self::with_elem(value, width, height)
+    }
+
+    fn with_size_default(width: usize, height: usize) -> Self
+    where
+        A::Item: Default,
+    {
+        Self::with_size_default(width, height)
+    }
+
+    fn with_size_func<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut() -> A::Item,
+    {
+        Self::with_size_func(width, height, f)
+    }
+
+    fn with_size_func_xy<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> A::Item,
+    {
+        Self::with_size_func_xy(width, height, f)
+    }
+}
+
+impl<A: Array> BidiFrom<&dyn BidiView<Output = A::Item>> for BidiSmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn from_view(source: &dyn BidiView<Output = A::Item>) -> Result<Self, BidiError> {
+        Ok(Self::with_size_func_xy(
+            source.width(),
+            source.height(),
+            |x, y| source[(x, y)].clone(),
+        ))
+    }
+
+    fn from_view_cut(
+        source: &dyn BidiView<Output = A::Item>,
+        cut: &BidiRect,
+    ) -> Result<Self, BidiError> {
+        if cut.max_x() > source.width() || cut.max_y() > source.height() {
+            return Err(BidiError::rect_out_of_bounds(cut, source.size()));
+        }
+
+        Ok(Self::with_size_func_xy(cut.width, cut.height, |x, y| {
+            source[(x + cut.x, y + cut.y)].clone()
+        }))
+    }
+}
+
+impl<A: Array> From<BidiVec<A::Item>> for BidiSmallVec<A> {
+    /// Creates a new instance of [`BidiSmallVec<A>`] from an existing [`BidiVec<T>`],
+    /// moving the items to the inline storage if they fit.
+    fn from(other: BidiVec<A::Item>) -> Self {
+        let row_size = other.row_size.unwrap_or(0);
+        Self::from_vec(other.data, row_size).unwrap()
+    }
+}
+
+impl<A: Array> From<BidiSmallVec<A>> for BidiVec<A::Item> {
+    /// Creates a new instance of [`BidiVec<T>`] from an existing [`BidiSmallVec<A>`].
+    fn from(other: BidiSmallVec<A>) -> Self {
+        let row_size = other.row_size;
+        BidiVec::from_vec(other.data.into_vec(), row_size).unwrap()
+    }
+}
@@ -0,0 +1,129 @@
+use std::ops::{Index, IndexMut};
+
+use crate::*;
+
+/// A wrapper over a [`BidiViewMut`] that keeps track of the region that has been
+/// modified through it, as the bounding rectangle of all the items that have been
+/// mutably accessed.
+///
+/// This is useful to renderers (redrawing only the changed part of a grid) and to
+/// network synchronization (sending only the changed part), without diffing the
+/// whole data structure; see [`BidiPatchRecorder`] for a wrapper recording the
+/// actual changes instead.
+///
+/// Items are considered modified as soon as they are mutably accessed, even if
+/// their value does not change. Since the tracker is itself a [`BidiViewMut`], it
+/// can be passed to any function of the crate (e.g. the [`editing`][crate::editing]
+/// functions).
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{BidiRect, BidiTracked, BidiVec, BidiViewMut};
+///
+/// let mut tracked = BidiTracked::new(BidiVec::with_elem(0, 10, 10));
+///
+/// tracked[(2, 3)] = 1;
+/// *tracked.get_mut(5, 1).unwrap() = 2;
+///
+/// assert_eq!(tracked.take_dirty(), Some(BidiRect::new(2, 1, 4, 3)));
+/// assert_eq!(tracked.take_dirty(), None);
+///
+/// let v = tracked.into_inner();
+/// assert_eq!(v[(5, 1)], 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BidiTracked<V: BidiViewMut> {
+    view: V,
+    dirty: Option<BidiRect>,
+}
+
+impl<V: BidiViewMut> BidiTracked<V> {
+    /// Creates a new tracker over the specified view, with no dirty region.
+    pub fn new(view: V) -> Self {
+        Self { view, dirty: None }
+    }
+
+    fn touch(&mut self, x: usize, y: usize) {
+        self.mark_dirty(&BidiRect::new(x, y, 1, 1));
+    }
+
+    /// Returns the bounding rectangle of all the items modified since the tracker
+    /// was created or the dirty region was last taken, or [`None`] if no item has
+    /// been modified.
+    pub fn dirty(&self) -> Option<&BidiRect> {
+        self.dirty.as_ref()
+    }
+
+    /// Returns true if any item has been modified since the tracker was created
+    /// or the dirty region was last taken.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.is_some()
+    }
+
+    /// Returns the bounding rectangle of all the items modified since the tracker
+    /// was created or the dirty region was last taken (or [`None`] if no item has
+    /// been modified), resetting the dirty region.
+    pub fn take_dirty(&mut self) -> Option<BidiRect> {
+        self.dirty.take()
+    }
+
+    /// Adds the specified rectangle (clipped to the bounds of the view) to the
+    /// dirty region, e.g. to force a full redraw.
+    pub fn mark_dirty(&mut self, rect: &BidiRect) {
+        if let Some(rect) = rect.clip_to(&self.view) {
+            self.dirty = Some(match self.dirty.take() {
+                Some(dirty) => dirty.union_bounds(&rect),
+                None => rect,
+            });
+        }
+    }
+
+    /// Returns a reference to the wrapped view.
+    pub fn inner(&self) -> &V {
+        &self.view
+    }
+
+    /// Consumes the tracker, returning the wrapped view.
+    pub fn into_inner(self) -> V {
+        self.view
+    }
+}
+
+impl<V: BidiViewMut> Index<(usize, usize)> for BidiTracked<V> {
+    type Output = V::Output;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.view[index]
+    }
+}
+
+impl<V: BidiViewMut> IndexMut<(usize, usize)> for BidiTracked<V> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.touch(index.0, index.1);
+        &mut self.view[index]
+    }
+}
+
+impl<V: BidiViewMut> BidiView for BidiTracked<V> {
+    fn width(&self) -> usize {
+        self.view.width()
+    }
+
+    fn height(&self) -> usize {
+        self.view.height()
+    }
+
+    fn get(&self, x: usize, y: usize) -> Option<&Self::Output> {
+        self.view.get(x, y)
+    }
+}
+
+impl<V: BidiViewMut> BidiViewMut for BidiTracked<V> {
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Self::Output> {
+        self.touch(x, y);
+        self.view.get_mut(x, y)
+    }
+}
+
+unsafe impl<V: BidiViewMutIterable> BidiViewMutIterable for BidiTracked<V> {}
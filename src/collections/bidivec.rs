@@ -2,6 +2,7 @@ use core::slice::SliceIndex;
 use std::cmp::{min, Ordering};
 #[rustversion::since(1.57)]
 use std::collections::TryReserveError;
+use std::convert::TryFrom;
 use std::default::Default;
 use std::iter::Iterator;
 #[rustversion::since(1.48)]
@@ -9,20 +10,16 @@ use std::ops::Range;
 use std::ops::{Index, IndexMut};
 
 use crate::bidiiter::{Iter, IterMut};
+use crate::collections::bidimutslice::for_each_col_in;
+use crate::collections::bidislice::coords_of_item;
 use crate::*;
 
 #[cfg(debug_assertions)]
 macro_rules! check_consistent {
     ($e:expr) => {{
-        let this = $e;
-        match this.row_size {
-            None => if !this.data.is_empty() {
-                panic!("BidiVec consistency failed: vec has {} elements, row_size is {:?}", this.data.len(), this.row_size);
-            }
-            Some(l) => if this.data.is_empty() || (this.data.len() % l) != 0 {
-                panic!("BidiVec consistency failed: vec has {} elements, row_size is {:?}", this.data.len(), this.row_size);
-            },
-        };
+        if let Err(e) = $e.validate() {
+            panic!("BidiVec consistency failed: {}", e);
+        }
     }};
 }
 
@@ -110,7 +107,7 @@ macro_rules! check_consistent {
 /// let four = v[(0, 1)];
 /// v[(1, 1)] = v[(1, 0)] + v[(2, 0)];
 /// ```
-#[derive(Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BidiVec<T> {
     pub(crate) data: Vec<T>,
     pub(crate) row_size: Option<usize>,
@@ -375,7 +372,7 @@ impl<T> BidiVec<T> {
         row_size: usize,
         capacity: usize,
     ) -> Result<Self, BidiError> {
-        if length == 0 && row_size == 0 {
+        if length == 0 {
             Ok(Self {
                 data: Vec::from_raw_parts(ptr, 0, capacity),
                 row_size: None,
@@ -386,7 +383,7 @@ impl<T> BidiVec<T> {
                 row_size: Some(row_size),
             })
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, length))
         }
     }
 
@@ -417,6 +414,99 @@ impl<T> BidiVec<T> {
         Self::from_vec(vec, row_size)
     }
 
+    /// Creates a bidivec from an iterator yielding the items by columns (e.g.
+    /// data coming from a column-major source), using the specified `col_size`
+    /// (that is, the height of the bidivec).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, BidiView};
+    ///
+    /// let bvec = BidiVec::from_iterator_by_cols(0..6, 2).unwrap();
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(0, 1)], 1);
+    /// assert_eq!(bvec[(1, 0)], 2);
+    /// ```
+    pub fn from_iterator_by_cols(
+        iter: impl Iterator<Item = T>,
+        col_size: usize,
+    ) -> Result<Self, BidiError> {
+        let mut bvec = Self::from_iterator(iter, col_size)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
+    /// Creates a bidivec from an iterator of rows, each of them an iterator of
+    /// items. All the rows must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, BidiView};
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiVec::from_rows(rows)?;
+    ///
+    /// assert_eq!(bvec.size(), (3, 2));
+    /// assert_eq!(bvec[(2, 0)], 3);
+    /// assert_eq!(bvec[(0, 1)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut data = Vec::new();
+        let mut width = None;
+
+        for row in rows {
+            let len = data.len();
+            data.extend(row);
+
+            let found = data.len() - len;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(BidiError::incompatible_size(expected, found));
+            }
+        }
+
+        Self::from_vec(data, width.unwrap_or(0))
+    }
+
+    /// Creates a bidivec from an iterator of columns, each of them an iterator of
+    /// items. All the columns must have the same length, or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// The items are collected by rows and then transposed in place, so this
+    /// takes linear time, unlike pushing the columns one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, BidiView};
+    ///
+    /// let cols = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let bvec = BidiVec::from_cols(cols)?;
+    ///
+    /// assert_eq!(bvec.size(), (2, 3));
+    /// assert_eq!(bvec[(0, 2)], 3);
+    /// assert_eq!(bvec[(1, 0)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_cols<I, C>(cols: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = T>,
+    {
+        let mut bvec = Self::from_rows(cols)?;
+        bvec.transpose();
+        Ok(bvec)
+    }
+
     /// Creates a bidivec from another view iterator, using the specified
     /// mapping function to create and/or transform elements.
     ///
@@ -447,6 +537,66 @@ impl<T> BidiVec<T> {
         Self::with_size_func_xy(view.width(), view.height(), |x, y| mapper(&view[(x, y)]))
     }
 
+    /// Creates a bidivec from a string representing a grid (e.g. an ASCII art map),
+    /// where each line is a row and each character is an item, converted through
+    /// the specified function (which also receives the coordinates of the item).
+    ///
+    /// Empty lines at the beginning and at the end of the string are ignored, so
+    /// that grids can be written as multiline string literals; all the other lines
+    /// must have the same length (in characters), or [`BidiError::IncompatibleSize`]
+    /// is returned.
+    ///
+    /// [`BidiVec<char>`] also implements `TryFrom<&str>`, to create grids of
+    /// characters directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, bidivec};
+    ///
+    /// let map = BidiVec::from_str_grid(
+    ///     "
+    /// #..#
+    /// ...#
+    /// ",
+    ///     |c, _, _| c == '#',
+    /// )?;
+    ///
+    /// assert_eq!(map, bidivec!{
+    ///     [true, false, false, true],
+    ///     [false, false, false, true],
+    /// });
+    ///
+    /// assert!(BidiVec::from_str_grid("##\n#", |c, _, _| c).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_str_grid<F>(s: &str, mut f: F) -> Result<Self, BidiError>
+    where
+        F: FnMut(char, usize, usize) -> T,
+    {
+        let mut lines = s.lines().collect::<Vec<_>>();
+
+        while lines.last().map_or(false, |l| l.is_empty()) {
+            lines.pop();
+        }
+        let first = lines.iter().position(|l| !l.is_empty()).unwrap_or(0);
+        let lines = &lines[first..];
+
+        let width = lines.first().map_or(0, |l| l.chars().count());
+        let mut data = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let len = data.len();
+            data.extend(line.chars().enumerate().map(|(x, c)| f(c, x, y)));
+
+            if data.len() - len != width {
+                return Err(BidiError::incompatible_size(width, data.len() - len));
+            }
+        }
+
+        Self::from_vec(data, width)
+    }
+
     /// Creates a [`BidiVec<T>`] from a `Vec<T>` and a specified row size.
     ///
     /// # Examples
@@ -463,7 +613,7 @@ impl<T> BidiVec<T> {
     /// assert_eq!(bvec[(1, 2)], 7);
     /// ```
     pub fn from_vec(vec: Vec<T>, row_size: usize) -> Result<Self, BidiError> {
-        if vec.is_empty() && row_size == 0 {
+        if vec.is_empty() {
             Ok(Self {
                 data: vec,
                 row_size: None,
@@ -474,7 +624,7 @@ impl<T> BidiVec<T> {
                 row_size: Some(row_size),
             })
         } else {
-            Err(BidiError::IncompatibleSize)
+            Err(BidiError::incompatible_size(row_size, vec.len()))
         }
     }
 
@@ -568,6 +718,35 @@ impl<T> BidiVec<T> {
         self.data.is_empty()
     }
 
+    /// Checks that the internal state of the bidivec is consistent, returning
+    /// [`BidiError::IncompatibleSize`] if it's not (e.g. if the number of items
+    /// is not a multiple of the row size).
+    ///
+    /// BidiVecs built through the methods of the crate are always consistent:
+    /// this is meant to verify bidivecs whose memory has been written through
+    /// unsafe code (e.g. by native code through FFI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let bvec = bidivec![0; 3, 2];
+    ///
+    /// assert!(bvec.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), BidiError> {
+        match self.row_size {
+            None if self.data.is_empty() => Ok(()),
+            None => Err(BidiError::incompatible_size(0, self.data.len())),
+            Some(0) => Err(BidiError::incompatible_size(1, 0)),
+            Some(l) if self.data.is_empty() || (self.data.len() % l) != 0 => {
+                Err(BidiError::incompatible_size(l, self.data.len()))
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
     /// Resizes the[`BidiVec`] in-place so that it has new width and
     /// height.
     ///
@@ -745,7 +924,10 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn truncate(&mut self, new_width: usize, new_height: usize) -> Result<(), BidiError> {
         if new_width > self.width() || new_height > self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::rect_out_of_bounds(
+                &BidiRect::new(0, 0, new_width, new_height),
+                self.size(),
+            ));
         }
 
         if new_width == self.width() && new_height == self.height() {
@@ -755,10 +937,9 @@ impl<T> BidiVec<T> {
         if new_width == 0 || new_height == 0 {
             self.clear();
         } else {
-            while self.width() > new_width {
-                self.delete_last_col();
-            }
-            self.data.truncate(new_height * self.width());
+            let width = self.width();
+            self.data.truncate(new_height * width);
+            self.delete_cols(new_width, width - new_width);
         }
 
         check_consistent!(&self);
@@ -898,6 +1079,33 @@ impl<T> BidiVec<T> {
         &mut self.data[range]
     }
 
+    /// Returns a [`BidiCellSlice`] over the items of the bidivec, through which
+    /// they can be modified from shared references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let mut bvec = bidivec!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// let cells = bvec.as_cell_slice();
+    /// let swap_first = |x, y| cells[(0, 0)].swap(&cells[(x, y)]);
+    /// swap_first(1, 1);
+    ///
+    /// assert_eq!(bvec, bidivec!{
+    ///     [4, 2],
+    ///     [3, 1],
+    /// });
+    /// ```
+    pub fn as_cell_slice(&mut self) -> BidiCellSlice<'_, T> {
+        let row_size = self.width();
+        BidiCellSlice::from_mut_slice(&mut self.data, row_size).unwrap()
+    }
+
     /// Returns a raw pointer to the bidivec's buffer.
     ///
     /// The caller must ensure that the bidivec outlives the pointer this
@@ -976,6 +1184,139 @@ impl<T> BidiVec<T> {
         self.data
     }
 
+    /// Converts the bidivec into a `Vec<T>` where items are linearly
+    /// laid out by columns (e.g. to pass them to column-major code).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::BidiVec;
+    ///
+    /// let bvec = BidiVec::from_iterator(0..6, 3).unwrap();
+    ///
+    /// assert_eq!(bvec.into_vec_by_cols(), vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn into_vec_by_cols(mut self) -> Vec<T> {
+        self.transpose();
+        self.into_vec()
+    }
+
+    /// Returns the rows of the bidivec, each as a slice of items.
+    /// This is O(h), as it only allocates the vector of slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let bvec = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(bvec.as_rows(), vec![&[1, 2, 3], &[4, 5, 6]]);
+    /// ```
+    pub fn as_rows(&self) -> Vec<&[T]> {
+        if self.width() == 0 {
+            Vec::new()
+        } else {
+            self.data.chunks(self.width()).collect()
+        }
+    }
+
+    /// Calls a closure on every row of the bidivec, from the top, passing the index
+    /// of the row and the row as a mutable slice of items, so that row-local
+    /// algorithms can use the methods of slices instead of accessing the items one
+    /// at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let mut bvec = bidivec!{
+    ///     [3, 1, 2],
+    ///     [6, 5, 4],
+    /// };
+    ///
+    /// bvec.for_each_row_mut(|y, row| {
+    ///     row.sort_unstable();
+    ///     row[0] += y * 10;
+    /// });
+    ///
+    /// assert_eq!(bvec, bidivec!{
+    ///     [1, 2, 3],
+    ///     [14, 5, 6],
+    /// });
+    /// ```
+    pub fn for_each_row_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let width = self.width();
+
+        if width != 0 {
+            for (y, row) in self.data.chunks_mut(width).enumerate() {
+                f(y, row);
+            }
+        }
+    }
+
+    /// Calls a closure on every column of the bidivec, from the left, passing the
+    /// index of the column and the column as a mutable slice of items. Since
+    /// columns aren't contiguous in memory, the items of each column are moved to
+    /// a temporary buffer for the duration of the call, and moved back afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let mut bvec = bidivec!{
+    ///     [4, 1, 5],
+    ///     [2, 3, 6],
+    /// };
+    ///
+    /// bvec.for_each_col_mut(|_, col| col.sort_unstable());
+    ///
+    /// assert_eq!(bvec, bidivec!{
+    ///     [2, 1, 5],
+    ///     [4, 3, 6],
+    /// });
+    /// ```
+    pub fn for_each_col_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let width = self.width();
+        for_each_col_in(&mut self.data, width, f);
+    }
+
+    /// Converts the bidivec into a `Vec<Vec<T>>` where items are arranged
+    /// as in a vec of rows, where each row is itself a vec of items.
+    /// This is O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let bvec = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(bvec.into_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let (width, height) = self.size();
+        let mut items = self.into_vec().into_iter();
+
+        (0..height)
+            .map(|_| items.by_ref().take(width).collect())
+            .collect()
+    }
+
     /// Swaps two elements in the bidivec. If any of the coordinates are out
     /// of range, [`BidiError::OutOfBounds`] is returned
     ///
@@ -1012,8 +1353,8 @@ impl<T> BidiVec<T> {
     /// exactly `height()` elements, or [`BidiError::IncompatibleSize`] is
     /// returned.
     /// If the bidivec is not empty, this operation is also expensive
-    /// as it requires O(column_size * bidivec_size) time; use[`BidiGrowVec`] for
-    /// faster column pushes (at the loss of linear layout).
+    /// as it requires moving almost all the items in the buffer; use [`BidiGrowVec`]
+    /// for faster column pushes (at the loss of linear layout).
     ///
     /// # Examples
     ///
@@ -1031,44 +1372,13 @@ impl<T> BidiVec<T> {
         match self.row_size {
             None => {
                 self.data.extend(iter);
-                self.row_size = Some(1);
+                if !self.data.is_empty() {
+                    self.row_size = Some(1);
+                }
                 check_consistent!(self);
                 Ok(())
             }
-            Some(row_size) => {
-                let mut rows_changed: usize = 0;
-                let mut force_rollback: bool = false;
-
-                for (row, val) in iter.into_iter().enumerate() {
-                    let insertion_point = (row + 1) * row_size + row;
-
-                    match insertion_point.cmp(&self.data.len()) {
-                        Ordering::Less => self.data.insert(insertion_point, val),
-                        Ordering::Equal => self.data.push(val),
-                        Ordering::Greater => {
-                            force_rollback = true;
-                            break;
-                        }
-                    }
-
-                    rows_changed += 1;
-                }
-
-                if force_rollback
-                    || ((self.data.len() % (row_size + 1)) != 0)
-                    || (rows_changed == 0)
-                {
-                    for row in (0..rows_changed).rev() {
-                        self.data.remove((row + 1) * row_size + row);
-                    }
-                    check_consistent!(self);
-                    Err(BidiError::IncompatibleSize)
-                } else {
-                    self.row_size = Some(row_size + 1);
-                    check_consistent!(self);
-                    Ok(())
-                }
-            }
+            Some(row_size) => self.insert_col_items(row_size, iter),
         }
     }
 
@@ -1093,7 +1403,9 @@ impl<T> BidiVec<T> {
         match self.row_size {
             None => {
                 self.data.extend(iter);
-                self.row_size = Some(self.data.len());
+                if !self.data.is_empty() {
+                    self.row_size = Some(self.data.len());
+                }
                 check_consistent!(self);
                 Ok(())
             }
@@ -1102,10 +1414,12 @@ impl<T> BidiVec<T> {
 
                 self.data.extend(iter);
 
-                if self.data.len() != (row_size + rollback_len) {
+                let found = self.data.len() - rollback_len;
+
+                if found != row_size {
                     self.data.truncate(rollback_len);
                     check_consistent!(self);
-                    Err(BidiError::IncompatibleSize)
+                    Err(BidiError::incompatible_size(row_size, found))
                 } else {
                     check_consistent!(self);
                     Ok(())
@@ -1120,8 +1434,8 @@ impl<T> BidiVec<T> {
     /// returned.
     ///
     /// If the bidivec is not empty, this operation is also expensive
-    /// as it requires O(column_size * bidivec_size) time; use[`BidiGrowVec`] for
-    /// faster column pushes (at the loss of linear layout).
+    /// as it requires moving almost all the items in the buffer; use [`BidiGrowVec`]
+    /// for faster column pushes (at the loss of linear layout).
     ///
     /// # Examples
     ///
@@ -1145,50 +1459,10 @@ impl<T> BidiVec<T> {
     ) -> Result<(), BidiError> {
         match self.row_size {
             None if col == 0 => self.push_col(iter),
-            None => Err(BidiError::OutOfBounds),
+            None => Err(BidiError::out_of_bounds(col, 0, (0, 0))),
             Some(row_size) => match col.cmp(&row_size) {
-                Ordering::Greater => Err(BidiError::OutOfBounds),
-                Ordering::Equal => self.push_col(iter),
-                Ordering::Less => {
-                    let expected_len = self.data.len() + self.height();
-                    let expected_count = self.height();
-                    let mut insertion_count = 0;
-                    let mut overflowed = false;
-
-                    for v in iter.into_iter() {
-                        let new_index = col + insertion_count * (row_size + 1);
-                        insertion_count += 1;
-
-                        if new_index >= self.data.len() {
-                            overflowed = true;
-                            break;
-                        }
-
-                        self.data.insert(new_index, v);
-
-                        if insertion_count > expected_count {
-                            break;
-                        }
-                    }
-
-                    if overflowed || self.data.len() != expected_len {
-                        for i in 0..insertion_count {
-                            let del_index = col + i * row_size;
-
-                            if del_index >= self.data.len() {
-                                break;
-                            }
-
-                            self.data.remove(del_index);
-                        }
-                        //check_consistent!(self);
-                        Err(BidiError::IncompatibleSize)
-                    } else {
-                        self.row_size = Some(row_size + 1);
-                        check_consistent!(self);
-                        Ok(())
-                    }
-                }
+                Ordering::Greater => Err(BidiError::out_of_bounds(col, 0, self.size())),
+                _ => self.insert_col_items(col, iter),
             },
         }
     }
@@ -1222,9 +1496,9 @@ impl<T> BidiVec<T> {
     ) -> Result<(), BidiError> {
         match self.row_size {
             None if row == 0 => self.push_row(iter),
-            None => Err(BidiError::OutOfBounds),
+            None => Err(BidiError::out_of_bounds(0, row, (0, 0))),
             Some(row_size) => match (row * row_size).cmp(&self.data.len()) {
-                Ordering::Greater => Err(BidiError::OutOfBounds),
+                Ordering::Greater => Err(BidiError::out_of_bounds(0, row, self.size())),
                 Ordering::Equal => self.push_row(iter),
                 Ordering::Less => {
                     let expected_len = self.data.len() + row_size;
@@ -1245,7 +1519,7 @@ impl<T> BidiVec<T> {
                             self.data.remove(insertion_base);
                         }
                         check_consistent!(self);
-                        Err(BidiError::IncompatibleSize)
+                        Err(BidiError::incompatible_size(row_size, insertion_count))
                     } else {
                         check_consistent!(self);
                         Ok(())
@@ -1280,25 +1554,10 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn remove_col(&mut self, col: usize) -> Result<Vec<T>, BidiError> {
         if col >= self.width() {
-            return Err(BidiError::OutOfBounds);
-        }
-
-        let row_size = self.row_size.unwrap();
-        let mut result = Vec::with_capacity(self.height());
-
-        for i in (0..self.height()).rev() {
-            result.push(self.data.remove(i * row_size + col));
-        }
-
-        if self.data.is_empty() {
-            self.row_size = None;
-        } else {
-            self.row_size = Some(row_size - 1);
+            return Err(BidiError::out_of_bounds(col, 0, self.size()));
         }
 
-        result.reverse();
-
-        Ok(result)
+        Ok(self.take_col(col))
     }
 
     /// Removes the specified row from the bidivec. If the row is
@@ -1326,7 +1585,7 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn remove_row(&mut self, row: usize) -> Result<Vec<T>, BidiError> {
         if row >= self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(0, row, self.size()));
         }
 
         let row_size = self.row_size.unwrap();
@@ -1368,21 +1627,10 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn delete_col(&mut self, col: usize) -> Result<(), BidiError> {
         if col >= self.width() {
-            return Err(BidiError::OutOfBounds);
-        }
-
-        if let Some(row_size) = self.row_size {
-            for i in (0..self.height()).rev() {
-                self.data.remove(i * row_size + col);
-            }
-
-            if self.data.is_empty() {
-                self.row_size = None;
-            } else {
-                self.row_size = Some(row_size - 1);
-            }
+            return Err(BidiError::out_of_bounds(col, 0, self.size()));
         }
 
+        self.delete_cols(col, 1);
         Ok(())
     }
 
@@ -1411,7 +1659,7 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn delete_row(&mut self, row: usize) -> Result<(), BidiError> {
         if row >= self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(0, row, self.size()));
         }
 
         if let Some(row_size) = self.row_size {
@@ -1450,17 +1698,7 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn delete_last_col(&mut self) {
         if let Some(row_size) = self.row_size {
-            for i in (0..self.height()).rev() {
-                self.data.remove((i + 1) * row_size - 1);
-            }
-
-            if self.data.is_empty() {
-                self.row_size = None;
-            } else {
-                self.row_size = Some(row_size - 1);
-            }
-
-            check_consistent!(self);
+            self.delete_cols(row_size - 1, 1);
         }
     }
 
@@ -1520,26 +1758,8 @@ impl<T> BidiVec<T> {
     /// ```
     #[must_use]
     pub fn pop_col(&mut self) -> Option<Vec<T>> {
-        if let Some(row_size) = self.row_size {
-            let mut result = Vec::with_capacity(self.height());
-
-            for i in (0..self.height()).rev() {
-                result.push(self.data.remove((i + 1) * row_size - 1));
-            }
-
-            if self.data.is_empty() {
-                self.row_size = None;
-            } else {
-                self.row_size = Some(row_size - 1);
-            }
-
-            result.reverse();
-
-            check_consistent!(self);
-            Some(result)
-        } else {
-            None
-        }
+        let row_size = self.row_size?;
+        Some(self.take_col(row_size - 1))
     }
 
     /// Removes the last row from the bidivec, returning its data.
@@ -1663,23 +1883,185 @@ impl<T> BidiVec<T> {
         self.calc_index(x, y).is_ok()
     }
 
+    /// Returns the coordinates of an item of the bidivec, given a reference to it
+    /// (e.g. one obtained while iterating), or [`None`] if the reference doesn't
+    /// point to an item of the bidivec. The coordinates are computed from the address
+    /// of the item, so this is `O(1)`; for zero-sized types, [`None`] is always
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let bvec = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let max = bvec.iter().max().unwrap();
+    /// assert_eq!(bvec.coords_of(max), Some((2, 1)));
+    /// assert_eq!(bvec.coords_of(&6), None);
+    /// ```
+    pub fn coords_of(&self, item: &T) -> Option<(usize, usize)> {
+        coords_of_item(&self.data, self.row_size.unwrap_or(0), item)
+    }
+
+    /// Returns the entry at (x, y) coordinates, to read, modify or insert its item.
+    /// The coordinates can be outside of the bounds of the bidivec: inserting into
+    /// such an entry grows the bidivec to contain it (see [`BidiEntry`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiView};
+    ///
+    /// let mut bvec = bidivec!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// *bvec.entry(1, 1).or_insert(0) += 10;
+    /// *bvec.entry(2, 0).or_insert(0) += 5;
+    ///
+    /// assert_eq!(bvec, bidivec!{
+    ///     [1, 2, 5],
+    ///     [3, 14, 0],
+    /// });
+    /// ```
+    pub fn entry(&mut self, x: usize, y: usize) -> BidiEntry<'_, Self> {
+        BidiEntry::new(self, x, y)
+    }
+
     #[inline(always)]
     fn calc_index(&self, x: usize, y: usize) -> Result<usize, BidiError> {
         check_consistent!(&self);
 
         match self.row_size {
             Some(w) => {
-                let idx = y * w + x;
-                if x >= w || idx >= self.data.len() {
-                    Err(BidiError::OutOfBounds)
+                if x >= w || y >= self.data.len() / w {
+                    Err(BidiError::out_of_bounds(x, y, self.size()))
                 } else {
-                    Ok(idx)
+                    Ok(y * w + x)
                 }
             }
-            None => Err(BidiError::OutOfBounds),
+            None => Err(BidiError::out_of_bounds(x, y, (0, 0))),
+        }
+    }
+
+    /// Deletes `count` columns starting from column `first`, compacting the
+    /// remaining items in a single pass. The columns must be within bounds.
+    fn delete_cols(&mut self, first: usize, count: usize) {
+        if let Some(row_size) = self.row_size {
+            if count == 0 {
+                return;
+            }
+
+            if count >= row_size {
+                self.clear();
+                return;
+            }
+
+            let mut x = 0;
+            self.data.retain(|_| {
+                let keep = x < first || x >= first + count;
+                x = if x + 1 == row_size { 0 } else { x + 1 };
+                keep
+            });
+
+            self.row_size = Some(row_size - count);
+            check_consistent!(self);
         }
     }
 
+    /// Inserts the items of `iter` as a new column at index `col`, which must be
+    /// within `0..=width`, growing the buffer and moving the items backwards in
+    /// place, in a single pass.
+    /// The bidivec must not be empty. If `iter` doesn't contain exactly `height`
+    /// items, [`BidiError::IncompatibleSize`] is returned and the bidivec is left
+    /// unchanged.
+    fn insert_col_items<I: IntoIterator<Item = T>>(
+        &mut self,
+        col: usize,
+        iter: I,
+    ) -> Result<(), BidiError> {
+        let row_size = self.row_size.unwrap();
+        let height = self.height();
+        let mut iter = iter.into_iter();
+        let items = iter.by_ref().take(height).collect::<Vec<T>>();
+
+        if items.len() != height {
+            return Err(BidiError::incompatible_size(height, items.len()));
+        }
+        if iter.next().is_some() {
+            return Err(BidiError::incompatible_size(height, height + 1));
+        }
+
+        let mut items = items;
+        let len = self.data.len();
+        self.data.reserve(height);
+
+        // SAFETY: the rows are moved to their new positions starting from the last
+        // one, so that no item is overwritten before being moved; the buffer has
+        // room for all of them, and its length covers them only once they have all
+        // been moved. No code that can panic runs while items are duplicated.
+        unsafe {
+            let ptr = self.data.as_mut_ptr();
+
+            for y in (0..height).rev() {
+                let (src, dst) = (y * row_size, y * (row_size + 1));
+                std::ptr::copy(ptr.add(src + col), ptr.add(dst + col + 1), row_size - col);
+                std::ptr::write(ptr.add(dst + col), items.pop().unwrap());
+                std::ptr::copy(ptr.add(src), ptr.add(dst), col);
+            }
+
+            self.data.set_len(len + height);
+        }
+
+        self.row_size = Some(row_size + 1);
+        check_consistent!(self);
+        Ok(())
+    }
+
+    /// Removes the specified column, returning its items; the remaining items
+    /// are compacted in place, in a single pass. The column must be within
+    /// bounds.
+    fn take_col(&mut self, col: usize) -> Vec<T> {
+        let row_size = self.row_size.unwrap();
+        let height = self.height();
+        let len = self.data.len();
+        let mut result = Vec::with_capacity(height);
+
+        // SAFETY: the length of the buffer is zeroed while the items are moved, so
+        // that each item is owned either by `result` or by the compacted buffer;
+        // pushing to `result` can't reallocate, so no code that can panic runs.
+        unsafe {
+            let ptr = self.data.as_mut_ptr();
+            self.data.set_len(0);
+            let mut dest = 0;
+
+            for i in 0..len {
+                if i % row_size == col {
+                    result.push(std::ptr::read(ptr.add(i)));
+                } else {
+                    std::ptr::copy(ptr.add(i), ptr.add(dest), 1);
+                    dest += 1;
+                }
+            }
+
+            self.data.set_len(dest);
+        }
+
+        self.row_size = if self.data.is_empty() {
+            None
+        } else {
+            Some(row_size - 1)
+        };
+
+        check_consistent!(self);
+        result
+    }
+
     /// Reverses the order of the items in the specified row.
     ///
     /// # Examples
@@ -1701,7 +2083,7 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn reverse_row(&mut self, row: usize) -> Result<(), BidiError> {
         if row >= self.height() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(0, row, self.size()));
         }
 
         let width = self.width();
@@ -1734,7 +2116,7 @@ impl<T> BidiVec<T> {
     /// ```
     pub fn reverse_col(&mut self, col: usize) -> Result<(), BidiError> {
         if col >= self.width() {
-            return Err(BidiError::OutOfBounds);
+            return Err(BidiError::out_of_bounds(col, 0, self.size()));
         }
 
         let height = self.height();
@@ -1751,8 +2133,10 @@ impl<T> BidiVec<T> {
     /// rows). As such, the result of a transposition is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidivec width and height are different (i.e. it's not a square).
+    /// This is performed in-place; if the bidivec width and height are different
+    /// (i.e. it's not a square) it requires one bit of additional memory per item,
+    /// and items are moved along the cycles of the transposition, which is O(n) but
+    /// not cache friendly.
     pub fn transpose(&mut self) {
         if let Some(row_size) = self.row_size {
             let mut slice = BidiMutSlice::new(&mut self.data, row_size).unwrap();
@@ -1761,12 +2145,22 @@ impl<T> BidiVec<T> {
         }
     }
 
+    /// Transposes the bidivec processing square bidivecs in tiles of `block_size`
+    /// × `block_size` items; see [`BidiMutSlice::transpose_with_block_size`].
+    pub fn transpose_with_block_size(&mut self, block_size: usize) {
+        if let Some(row_size) = self.row_size {
+            let mut slice = BidiMutSlice::new(&mut self.data, row_size).unwrap();
+            slice.transpose_with_block_size(block_size);
+            self.row_size = Some(self.height());
+        }
+    }
+
     /// Rotates the bidivec 90°, counter-clockwise (or, 270° clockwise).
     /// The result of such a rotation is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidivec width and height are different (i.e. it's not a square).
+    /// This is performed in-place; see [`BidiVec::transpose`] for the memory
+    /// requirements.
     pub fn rotate90ccw(&mut self) {
         self.transpose();
         self.reverse_columns();
@@ -1781,46 +2175,153 @@ impl<T> BidiVec<T> {
     /// The result of such a rotation is as wide as the original
     /// was tall, and as tall as the original was wide.
     ///
-    /// While this is performed in-place, it still requires O(n) additional memory
-    /// if the bidivec width and height are different (i.e. it's not a square).
+    /// This is performed in-place; see [`BidiVec::transpose`] for the memory
+    /// requirements.
     pub fn rotate270ccw(&mut self) {
         self.transpose();
         self.reverse_rows();
     }
 
+    /// Anti-transposes the bidivec, that is an operation that flips the bidivec
+    /// over its anti-diagonal (going from the top right corner to the bottom
+    /// left one). As for a transposition, the result is as wide as the original
+    /// was tall, and as tall as the original was wide.
+    pub fn anti_transpose(&mut self) {
+        self.transpose();
+        self.rotate180();
+    }
+
     /// Reverse the order of items in all columns. This is equivalent to flipping
     /// the data structure over its horizontal axis.
     pub fn reverse_columns(&mut self) {
-        for col in 0..self.width() {
-            self.reverse_col(col).unwrap();
+        if let Some(row_size) = self.row_size {
+            BidiMutSlice::new(&mut self.data, row_size)
+                .unwrap()
+                .reverse_columns();
         }
     }
 
     /// Reverse the order of items in all rows. This is equivalent to flipping
     /// the data structure over its vertical axis.
     pub fn reverse_rows(&mut self) {
-        for row in 0..self.height() {
-            self.reverse_row(row).unwrap();
+        if let Some(row_size) = self.row_size {
+            BidiMutSlice::new(&mut self.data, row_size)
+                .unwrap()
+                .reverse_rows();
         }
     }
 
+    /// Transposes the bidivec in-place and returns it; see [`BidiVec::transpose`].
+    pub fn into_transposed(mut self) -> Self {
+        self.transpose();
+        self
+    }
+
+    /// Anti-transposes the bidivec in-place and returns it; see [`BidiVec::anti_transpose`].
+    pub fn into_anti_transposed(mut self) -> Self {
+        self.anti_transpose();
+        self
+    }
+
+    /// Rotates the bidivec 90° counter-clockwise in-place and returns it; see
+    /// [`BidiVec::rotate90ccw`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let bvec = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// assert_eq!(bvec.into_rotated90ccw(), bidivec!{
+    ///     [3, 6],
+    ///     [2, 5],
+    ///     [1, 4],
+    /// });
+    /// ```
+    pub fn into_rotated90ccw(mut self) -> Self {
+        self.rotate90ccw();
+        self
+    }
+
+    /// Rotates the bidivec 180° in-place and returns it.
+    pub fn into_rotated180(mut self) -> Self {
+        self.rotate180();
+        self
+    }
+
+    /// Rotates the bidivec 270° counter-clockwise in-place and returns it; see
+    /// [`BidiVec::rotate270ccw`].
+    pub fn into_rotated270ccw(mut self) -> Self {
+        self.rotate270ccw();
+        self
+    }
+
+    /// Reverses the order of items in all columns in-place and returns the bidivec;
+    /// see [`BidiVec::reverse_columns`].
+    pub fn into_reversed_columns(mut self) -> Self {
+        self.reverse_columns();
+        self
+    }
+
+    /// Reverses the order of items in all rows in-place and returns the bidivec;
+    /// see [`BidiVec::reverse_rows`].
+    pub fn into_reversed_rows(mut self) -> Self {
+        self.reverse_rows();
+        self
+    }
+
     /// Crops the data structure to its new bounds by moving the origin to
     /// a new location, reducing the width and height and dropping excess
     /// data.
+    ///
+    /// This is performed in-place, moving each retained item once, and doesn't
+    /// reallocate the underlying `Vec<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiRect};
+    ///
+    /// let mut bvec = bidivec!{
+    ///     [1, 2, 3, 4],
+    ///     [5, 6, 7, 8],
+    ///     [9, 10, 11, 12],
+    /// };
+    ///
+    /// bvec.crop(&BidiRect::new(1, 1, 2, 2))?;
+    ///
+    /// assert_eq!(bvec, bidivec!{
+    ///     [6, 7],
+    ///     [10, 11],
+    /// });
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
     pub fn crop(&mut self, rect: &BidiRect) -> Result<(), BidiError> {
         if rect.width == 0 || rect.height == 0 {
             self.clear();
             Ok(())
         } else if (rect.max_x() > self.width()) || (rect.max_y() > self.height()) {
-            Err(BidiError::OutOfBounds)
+            Err(BidiError::rect_out_of_bounds(rect, self.size()))
         } else {
-            self.truncate(rect.max_x(), rect.max_y()).unwrap();
-            for _ in 0..rect.x {
-                self.remove_col(0).unwrap();
-            }
-            for _ in 0..rect.y {
-                self.remove_row(0).unwrap();
+            // every retained item is moved forward (or stays where it is),
+            // so a single forward pass of swaps compacts the rect at the front
+            let width = self.width();
+            let mut dest = 0;
+
+            for y in rect.y..rect.max_y() {
+                for x in rect.x..rect.max_x() {
+                    self.data.swap(dest, y * width + x);
+                    dest += 1;
+                }
             }
+
+            self.data.truncate(dest);
+            self.row_size = Some(rect.width);
+            check_consistent!(self);
             Ok(())
         }
     }
@@ -1848,16 +2349,113 @@ impl<T> BidiVec<T> {
     }
 
     /// Returns an iterator over the items of the view
-    pub fn iter(&self) -> Iter<T, Self> {
+    pub fn iter(&self) -> Iter<'_, T, Self> {
         Iter::new(self)
     }
 
     /// Returns a mutable iterator over the items of the view
-    pub fn iter_mut(&mut self) -> IterMut<T, Self> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, Self> {
         IterMut::new(self)
     }
 }
 
+#[cfg(feature = "noise")]
+impl BidiVec<f64> {
+    /// Constructs a new [`BidiVec<f64>`] with the specified size, filled with
+    /// the values of a noise function evaluated according to the specified
+    /// [`NoiseParams`][crate::editing::NoiseParams]. See
+    /// [`editing::fill_noise`][crate::editing::fill_noise] for details.
+    ///
+    /// Requires the `noise` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, editing::NoiseParams};
+    ///
+    /// let params = NoiseParams {
+    ///     scale: 0.5,
+    ///     ..NoiseParams::default()
+    /// };
+    /// let bvec = BidiVec::from_noise(4, 3, &params, |x, y| x + y);
+    ///
+    /// assert_eq!(bvec.width(), 4);
+    /// assert_eq!(bvec.height(), 3);
+    /// assert_eq!(bvec[(3, 2)], 2.5);
+    /// ```
+    pub fn from_noise<N>(
+        width: usize,
+        height: usize,
+        params: &editing::NoiseParams,
+        noise: N,
+    ) -> Self
+    where
+        N: Fn(f64, f64) -> f64,
+    {
+        Self::with_size_func_xy(width, height, |x, y| params.sample(&noise, x, y))
+    }
+}
+
+impl<T> Default for BidiVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> BidiConstruct for BidiVec<T> {
+    fn with_elem(value: T, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::with_elem(value, width, height)
+    }
+
+    fn with_size_default(width: usize, height: usize) -> Self
+    where
+        T: Default,
+    {
+        Self::with_size_default(width, height)
+    }
+
+    fn with_size_func<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        Self::with_size_func(width, height, f)
+    }
+
+    fn with_size_func_xy<F>(width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::with_size_func_xy(width, height, f)
+    }
+}
+
+impl<T> BidiBuild for BidiVec<T> {
+    fn from_iterator<I>(iter: I, row_size: usize) -> Result<Self, BidiError>
+    where
+        I: Iterator<Item = T>,
+    {
+        Self::from_iterator(iter, row_size)
+    }
+
+    fn from_rows<I, R>(rows: I) -> Result<Self, BidiError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        Self::from_rows(rows)
+    }
+
+    fn push_row<I>(&mut self, row: I) -> Result<(), BidiError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.push_row(row)
+    }
+}
+
 impl<T> BidiFrom<&dyn BidiView<Output = T>> for BidiVec<T>
 where
     T: Clone,
@@ -1871,8 +2469,8 @@ where
     }
 
     fn from_view_cut(source: &dyn BidiView<Output = T>, cut: &BidiRect) -> Result<Self, BidiError> {
-        if cut.max_x() > source.height() || cut.max_y() > source.width() {
-            return Err(BidiError::OutOfBounds);
+        if cut.max_x() > source.width() || cut.max_y() > source.height() {
+            return Err(BidiError::rect_out_of_bounds(cut, source.size()));
         }
 
         Ok(BidiVec::<T>::with_size_func_xy(
@@ -1989,6 +2587,26 @@ impl<T> IndexMut<(usize, usize)> for BidiVec<T> {
     }
 }
 
+impl<T> Index<BidiPoint> for BidiVec<T> {
+    type Output = T;
+
+    /// Accesses an element in the BidiVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index(&self, index: BidiPoint) -> &Self::Output {
+        &self[(index.x, index.y)]
+    }
+}
+
+impl<T> IndexMut<BidiPoint> for BidiVec<T> {
+    /// Mutably accesses an element in the BidiVec, using a [`BidiPoint`].
+    /// If coordinates are outside of range, it panics.
+    #[inline(always)]
+    fn index_mut(&mut self, index: BidiPoint) -> &mut Self::Output {
+        &mut self[(index.x, index.y)]
+    }
+}
+
 impl<T> BidiView for BidiVec<T> {
     fn width(&self) -> usize {
         BidiVec::<T>::width(self)
@@ -2000,12 +2618,39 @@ impl<T> BidiView for BidiVec<T> {
     fn get(&self, x: usize, y: usize) -> Option<&T> {
         self.get(x, y)
     }
+
+    unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        self.data.get_unchecked(y * self.width() + x)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> BidiViewMut for BidiVec<T> {
     fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
         self.get_mut(x, y)
     }
+
+    unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
+        let idx = y * self.width() + x;
+        self.data.get_unchecked_mut(idx)
+    }
+
+    fn row_slice_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        let width = self.width();
+        if row < self.height() {
+            Some(&mut self.data[row * width..(row + 1) * width])
+        } else {
+            None
+        }
+    }
 }
 
 unsafe impl<T> BidiViewMutIterable for BidiVec<T> {}
@@ -2020,6 +2665,16 @@ impl<T> From<BidiGrowVec<T>> for BidiVec<T> {
     }
 }
 
+impl TryFrom<&str> for BidiVec<char> {
+    type Error = BidiError;
+
+    /// Creates a new instance of [`BidiVec<char>`] from a string representing
+    /// a grid of characters, one row per line; see [`BidiVec::from_str_grid`].
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str_grid(s, |c, _, _| c)
+    }
+}
+
 impl<T> From<BidiArray<T>> for BidiVec<T> {
     /// Creates a new instance of [`BidiVec<T>`] from an existing [`BidiVec<T>`].
     /// This operation is `O(1)` in the worst case.
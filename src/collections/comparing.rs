@@ -0,0 +1,137 @@
+//! Comparisons between different kinds of bidimensional data structures.
+//!
+//! Two data structures are equal if they have the same width, the same height and
+//! equal items at the same coordinates, regardless of their type (e.g. a [`BidiVec`]
+//! can be compared to a [`BidiGrowVec`], or to any `dyn BidiView`).
+//!
+//! Owned data structures of the same type are also ordered, shape first (by width,
+//! then by height) and then lexicographically by items, in row-major order.
+use crate::*;
+use std::cmp::Ordering;
+
+/// Compares two views by shape first, and then row by row, comparing rows
+/// as slices when they are contiguous in memory in both views.
+pub(crate) fn views_eq<A, B, T>(a: &A, b: &B) -> bool
+where
+    A: BidiView<Output = T> + ?Sized,
+    B: BidiView<Output = T> + ?Sized,
+    T: PartialEq,
+{
+    if a.size() != b.size() {
+        return false;
+    }
+
+    for y in 0..a.height() {
+        if let (Some(row_a), Some(row_b)) = (a.row_slice(y), b.row_slice(y)) {
+            if row_a != row_b {
+                return false;
+            }
+            continue;
+        }
+
+        for x in 0..a.width() {
+            if a[(x, y)] != b[(x, y)] {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+macro_rules! impl_cross_eq {
+    ($([$lhs:ty] == [$rhs:ty]),* $(,)?) => {
+        $(impl<'a, 'b, T: PartialEq> PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                views_eq(self, other)
+            }
+        })*
+    };
+}
+
+impl_cross_eq!(
+    [BidiVec<T>] == [BidiArray<T>],
+    [BidiVec<T>] == [BidiGrowVec<T>],
+    [BidiVec<T>] == [BidiSlice<'b, T>],
+    [BidiVec<T>] == [BidiMutSlice<'b, T>],
+    [BidiArray<T>] == [BidiVec<T>],
+    [BidiArray<T>] == [BidiGrowVec<T>],
+    [BidiArray<T>] == [BidiSlice<'b, T>],
+    [BidiArray<T>] == [BidiMutSlice<'b, T>],
+    [BidiGrowVec<T>] == [BidiVec<T>],
+    [BidiGrowVec<T>] == [BidiArray<T>],
+    [BidiGrowVec<T>] == [BidiSlice<'b, T>],
+    [BidiGrowVec<T>] == [BidiMutSlice<'b, T>],
+    [BidiSlice<'a, T>] == [BidiVec<T>],
+    [BidiSlice<'a, T>] == [BidiArray<T>],
+    [BidiSlice<'a, T>] == [BidiGrowVec<T>],
+    [BidiSlice<'a, T>] == [BidiMutSlice<'b, T>],
+    [BidiMutSlice<'a, T>] == [BidiVec<T>],
+    [BidiMutSlice<'a, T>] == [BidiArray<T>],
+    [BidiMutSlice<'a, T>] == [BidiGrowVec<T>],
+    [BidiMutSlice<'a, T>] == [BidiSlice<'b, T>],
+    [BidiVec<T>] == [dyn BidiView<Output = T> + 'b],
+    [BidiArray<T>] == [dyn BidiView<Output = T> + 'b],
+    [BidiGrowVec<T>] == [dyn BidiView<Output = T> + 'b],
+    [BidiSlice<'a, T>] == [dyn BidiView<Output = T> + 'b],
+    [BidiMutSlice<'a, T>] == [dyn BidiView<Output = T> + 'b],
+    [dyn BidiView<Output = T> + 'a] == [BidiVec<T>],
+    [dyn BidiView<Output = T> + 'a] == [BidiArray<T>],
+    [dyn BidiView<Output = T> + 'a] == [BidiGrowVec<T>],
+    [dyn BidiView<Output = T> + 'a] == [BidiSlice<'b, T>],
+    [dyn BidiView<Output = T> + 'a] == [BidiMutSlice<'b, T>],
+    [dyn BidiView<Output = T> + 'a] == [dyn BidiView<Output = T> + 'b],
+);
+
+impl<T: PartialOrd> PartialOrd for BidiVec<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.size().cmp(&other.size()) {
+            Ordering::Equal => self.data.partial_cmp(&other.data),
+            ord => Some(ord),
+        }
+    }
+}
+
+impl<T: Ord> Ord for BidiVec<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.size()
+            .cmp(&other.size())
+            .then_with(|| self.data.cmp(&other.data))
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for BidiArray<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.size().cmp(&other.size()) {
+            Ordering::Equal => self.data.partial_cmp(&other.data),
+            ord => Some(ord),
+        }
+    }
+}
+
+impl<T: Ord> Ord for BidiArray<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.size()
+            .cmp(&other.size())
+            .then_with(|| self.data.cmp(&other.data))
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for BidiGrowVec<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // with the same shape, comparing the rows is the same as
+        // comparing the items in row-major order
+        match self.size().cmp(&other.size()) {
+            Ordering::Equal => self.data.partial_cmp(&other.data),
+            ord => Some(ord),
+        }
+    }
+}
+
+impl<T: Ord> Ord for BidiGrowVec<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.size()
+            .cmp(&other.size())
+            .then_with(|| self.data.cmp(&other.data))
+    }
+}
@@ -1,5 +1,14 @@
 pub(crate) mod bidiarray;
+pub(crate) mod bidiatomicvec;
+pub(crate) mod bidientry;
+pub(crate) mod bidigengrid;
 pub(crate) mod bidigrowvec;
 pub(crate) mod bidimutslice;
+pub(crate) mod bidiobserved;
+pub(crate) mod bidipatch;
 pub(crate) mod bidislice;
+#[cfg(feature = "smallvec")]
+pub(crate) mod bidismallvec;
+pub(crate) mod biditracked;
 pub(crate) mod bidivec;
+pub(crate) mod comparing;
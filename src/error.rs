@@ -1,22 +1,191 @@
+use crate::BidiRect;
+use std::cmp::{max, min};
 use thiserror::Error;
 
 /// The error type for operations on bidimensional data structures
 /// in the crate.
-#[derive(Error, Copy, Clone, Debug, PartialEq)]
+///
+/// Errors carry the context needed to diagnose them (e.g. the requested
+/// coordinates and the size of the data structure), which is also included
+/// in their [`Display`][std::fmt::Display] output. To check what kind of error
+/// occurred regardless of the context, use [`BidiError::kind`].
+///
+/// New kinds of errors may be added as the crate grows (e.g. by optional
+/// features), so matches on this type must include a wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, BidiError, BidiErrorKind};
+///
+/// let mut v = bidivec![0; 3, 2];
+/// let err = v.swap((1, 1), (4, 1)).unwrap_err();
+///
+/// assert_eq!(err.kind(), BidiErrorKind::OutOfBounds);
+/// assert_eq!(err, BidiError::OutOfBounds { x: 4, y: 1, width: 3, height: 2 });
+/// assert_eq!(
+///     err.to_string(),
+///     "coordinates (4, 1) out of bounds of a 3x2 data structure"
+/// );
+/// ```
+#[derive(Error, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum BidiError {
     /// The size of an argument is not compatible with the current
     /// width or height of the data structure.
     ///
     /// For example, this happens when trying to add a row which is
     /// longer than the current width of the data structure.
-    #[error("incompatible argument size")]
-    IncompatibleSize,
+    #[error("incompatible argument size: expected {expected} items, found {found}")]
+    IncompatibleSize {
+        /// The expected size (e.g. the width of the data structure when
+        /// adding a row); when a length must be a multiple of a row size,
+        /// this is the row size
+        expected: usize,
+        /// The size found; when an iterator yields more items than expected,
+        /// the iteration may stop at the first extra item
+        found: usize,
+    },
     /// The argument would access the data structure outside of its
     /// own boundaries.
     ///
     /// For example, this happens when trying to insert a row at an
     /// index which is greater than the current height of the data
     /// structure.
-    #[error("coordinates out of bounds")]
+    #[error("coordinates ({x}, {y}) out of bounds of a {width}x{height} data structure")]
+    OutOfBounds {
+        /// The x coordinate that was requested (for rectangles, the farthest
+        /// one from the origin; for rows, zero)
+        x: isize,
+        /// The y coordinate that was requested (for rectangles, the farthest
+        /// one from the origin; for columns, zero)
+        y: isize,
+        /// The width of the data structure
+        width: usize,
+        /// The height of the data structure
+        height: usize,
+    },
+    /// Signed coordinates were negative where unsigned coordinates are
+    /// required.
+    ///
+    /// For example, this happens when converting a [`BidiRectSigned`][crate::BidiRectSigned]
+    /// with its origin left of or above `(0, 0)` to a [`BidiRect`].
+    #[error("coordinates ({x}, {y}) are negative")]
+    NegativeCoordinates {
+        /// The x coordinate that was requested
+        x: isize,
+        /// The y coordinate that was requested
+        y: isize,
+    },
+    /// An argument has a value that is never valid for the operation.
+    ///
+    /// For example, this happens when the size of a cluster or of a pattern
+    /// is zero.
+    #[error("invalid value for argument '{argument}'")]
+    InvalidArgument {
+        /// The name of the argument
+        argument: &'static str,
+    },
+    /// Data borrowed without copying is not aligned for the type of its
+    /// items.
+    ///
+    /// For example, this happens when creating a [`BidiSlice`][crate::BidiSlice]
+    /// of `u32` items over bytes starting at an odd address.
+    #[error("data is not aligned to {alignment} bytes")]
+    Misaligned {
+        /// The alignment required by the type of the items
+        alignment: usize,
+    },
+    /// The algorithm could not find a solution satisfying all of its
+    /// constraints.
+    ///
+    /// For example, this happens when a synthesis algorithm reaches
+    /// a contradiction in all of its attempts.
+    #[error("no solution satisfies the constraints")]
+    NoSolution,
+}
+
+/// The kind of a [`BidiError`], without its context. As with [`BidiError`],
+/// new kinds may be added in the future.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BidiErrorKind {
+    /// See [`BidiError::IncompatibleSize`].
+    IncompatibleSize,
+    /// See [`BidiError::OutOfBounds`].
     OutOfBounds,
+    /// See [`BidiError::NegativeCoordinates`].
+    NegativeCoordinates,
+    /// See [`BidiError::InvalidArgument`].
+    InvalidArgument,
+    /// See [`BidiError::Misaligned`].
+    Misaligned,
+    /// See [`BidiError::NoSolution`].
+    NoSolution,
+}
+
+impl BidiError {
+    /// Returns the kind of the error, without its context.
+    pub fn kind(&self) -> BidiErrorKind {
+        match self {
+            BidiError::IncompatibleSize { .. } => BidiErrorKind::IncompatibleSize,
+            BidiError::OutOfBounds { .. } => BidiErrorKind::OutOfBounds,
+            BidiError::NegativeCoordinates { .. } => BidiErrorKind::NegativeCoordinates,
+            BidiError::InvalidArgument { .. } => BidiErrorKind::InvalidArgument,
+            BidiError::Misaligned { .. } => BidiErrorKind::Misaligned,
+            BidiError::NoSolution => BidiErrorKind::NoSolution,
+        }
+    }
+
+    pub(crate) fn incompatible_size(expected: usize, found: usize) -> Self {
+        BidiError::IncompatibleSize { expected, found }
+    }
+
+    pub(crate) fn invalid_argument(argument: &'static str) -> Self {
+        BidiError::InvalidArgument { argument }
+    }
+
+    pub(crate) fn out_of_bounds(x: usize, y: usize, size: (usize, usize)) -> Self {
+        BidiError::OutOfBounds {
+            x: min(x, std::isize::MAX as usize) as isize,
+            y: min(y, std::isize::MAX as usize) as isize,
+            width: size.0,
+            height: size.1,
+        }
+    }
+
+    pub(crate) fn rect_out_of_bounds(rect: &BidiRect, size: (usize, usize)) -> Self {
+        Self::out_of_bounds(
+            max(rect.x, rect.x.saturating_add(rect.width).saturating_sub(1)),
+            max(rect.y, rect.y.saturating_add(rect.height).saturating_sub(1)),
+            size,
+        )
+    }
+}
+
+/// The error type for operations reading or writing bidimensional data
+/// structures from and to external formats (e.g. CSV files).
+#[derive(Error, Debug)]
+pub enum BidiIoError {
+    /// An error occurred while reading or writing the underlying
+    /// stream, or the stream is not valid for the format.
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+    /// An item could not be parsed; `x` and `y` are the coordinates
+    /// the item would have had in the data structure.
+    #[error("invalid item at ({x}, {y}): {message}")]
+    InvalidItem {
+        /// The x coordinate of the item
+        x: usize,
+        /// The y coordinate of the item
+        y: usize,
+        /// The error reported by the parser
+        message: String,
+    },
+    /// The data could not be stored in a bidimensional data structure.
+    ///
+    /// For example, this happens when the rows read from a CSV file
+    /// have different lengths ([`BidiError::IncompatibleSize`]).
+    #[error(transparent)]
+    Bidi(#[from] BidiError),
 }
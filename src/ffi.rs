@@ -0,0 +1,123 @@
+//! A module containing the building blocks to hand bidimensional data structures
+//! across an FFI boundary, e.g. to C or C++ code.
+//!
+//! A [`BidiArray`][crate::BidiArray] can be converted to a [`BidiRawParts`]
+//! descriptor with [`BidiArray::into_raw_parts`][crate::BidiArray::into_raw_parts],
+//! handed to native code, and converted back with
+//! [`BidiArray::from_raw_descriptor`][crate::BidiArray::from_raw_descriptor] to be dropped
+//! (or used again) by Rust code. Memory must always be freed by Rust, as it was
+//! allocated by the Rust allocator.
+//!
+//! The [`bidiarray_ffi!`][crate::bidiarray_ffi] macro generates `extern "C"`
+//! functions to create and free arrays of a given type, which can be invoked in
+//! the `cdylib` or `staticlib` crate exposing the API; since they are plain
+//! functions, `cbindgen` can generate the matching C header (remember to enable
+//! macro expansion with `[parse.expand]`, or to list the functions manually).
+//!
+//! # Examples
+//!
+//! ```
+//! use bidivec::{bidiarray, BidiArray};
+//!
+//! let a = bidiarray!{
+//!     [1u8, 2, 3],
+//!     [4, 5, 6],
+//! };
+//!
+//! let parts = a.into_raw_parts();
+//! assert_eq!((parts.len, parts.width, parts.pitch), (6, 3, 3));
+//!
+//! // native code can access the item at (x, y) as ptr[y * pitch + x]
+//! assert_eq!(unsafe { *parts.ptr.add(parts.pitch + 1) }, 5);
+//!
+//! let a = unsafe { BidiArray::from_raw_descriptor(parts) }?;
+//! assert_eq!(a[(1, 1)], 5);
+//! # Ok::<(), bidivec::BidiError>(())
+//! ```
+
+/// A descriptor of the memory of a bidimensional data structure with a
+/// linear layout, with a stable C ABI.
+///
+/// The item at `(x, y)` is at `ptr[y * pitch + x]`, for `x < width` and
+/// `y < len / pitch`. For data structures owned by Rust (see
+/// [`BidiArray::into_raw_parts`][crate::BidiArray::into_raw_parts]), `pitch`
+/// is always equal to `width`, but native code should not rely on it, so
+/// that padded layouts can be described too.
+///
+/// The equivalent C declaration (for a given item type `T`) is:
+///
+/// ```c
+/// typedef struct {
+///     T *ptr;
+///     size_t len;
+///     size_t width;
+///     size_t pitch;
+/// } BidiRawParts_T;
+/// ```
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct BidiRawParts<T> {
+    /// A pointer to the first item
+    pub ptr: *mut T,
+    /// The number of items, including any padding between rows
+    pub len: usize,
+    /// The width, in items
+    pub width: usize,
+    /// The distance between the beginning of two consecutive rows, in items
+    pub pitch: usize,
+}
+
+impl<T> BidiRawParts<T> {
+    /// Returns the height described, in items
+    pub fn height(&self) -> usize {
+        self.len.checked_div(self.pitch).unwrap_or(0)
+    }
+}
+
+impl<T> Clone for BidiRawParts<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BidiRawParts<T> {}
+
+/// Generates `extern "C"` functions to create and free [`BidiArray`][crate::BidiArray]s
+/// of the given item type, through [`BidiRawParts`][crate::ffi::BidiRawParts]
+/// descriptors. See the [`ffi`][crate::ffi] module.
+///
+/// The `new` function takes a width and a height and returns a descriptor of
+/// a new array filled with the default value of the item type; the `free`
+/// function takes a descriptor returned by `new` and frees the memory.
+///
+/// # Examples
+///
+/// ```
+/// bidivec::bidiarray_ffi!(f32, new: heightmap_new, free: heightmap_free);
+///
+/// let parts = heightmap_new(4, 3);
+/// assert_eq!((parts.width, parts.height()), (4, 3));
+/// unsafe { heightmap_free(parts) };
+/// ```
+#[macro_export]
+macro_rules! bidiarray_ffi {
+    ($t:ty, new: $new:ident, free: $free:ident) => {
+        /// Creates a new array of the specified size, filled with default values.
+        /// The array must be freed with the companion free function.
+        #[no_mangle]
+        pub extern "C" fn $new(width: usize, height: usize) -> $crate::ffi::BidiRawParts<$t> {
+            $crate::BidiArray::<$t>::with_size_default(width, height).into_raw_parts()
+        }
+
+        /// Frees an array created by the companion new function.
+        ///
+        /// # Safety
+        ///
+        /// `parts` must have been returned by the companion new function,
+        /// and must not be used after this call.
+        #[no_mangle]
+        pub unsafe extern "C" fn $free(parts: $crate::ffi::BidiRawParts<$t>) {
+            drop($crate::BidiArray::<$t>::from_raw_descriptor(parts));
+        }
+    };
+}
@@ -0,0 +1,303 @@
+use crate::{BidiIoError, BidiVec};
+use std::io;
+
+const MAGIC: &[u8; 4] = b"BIDI";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 24;
+
+/// A trait for the item types that can be stored in the compact binary format
+/// of [`BidiVec::to_binary`] and [`BidiVec::from_binary`].
+///
+/// Each type has a stable tag stored in the header, so that data can't be read
+/// back as a different type; items are stored in little endian byte order. The
+/// trait is implemented for integers (except `usize` and `isize`, whose size
+/// depends on the platform), floats and [`bool`].
+pub trait BidiBinaryItem: Clone + Sized {
+    /// The tag identifying the item type in the header; tags from 0x80 upwards
+    /// are reserved for user types.
+    const ENCODING: u8;
+    /// The size, in bytes, of an encoded item.
+    const SIZE: usize;
+
+    /// Appends the encoding of the item to `out`, writing exactly
+    /// [`SIZE`][BidiBinaryItem::SIZE] bytes.
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decodes an item from exactly [`SIZE`][BidiBinaryItem::SIZE] bytes, returning
+    /// [`None`] if the bytes are not a valid encoding.
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_binary_item {
+    ($($t:ty => $encoding:expr),*) => {
+        $(impl BidiBinaryItem for $t {
+            const ENCODING: u8 = $encoding;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode(bytes: &[u8]) -> Option<Self> {
+                let mut buf = [0u8; std::mem::size_of::<$t>()];
+                buf.copy_from_slice(bytes);
+                Some(<$t>::from_le_bytes(buf))
+            }
+        })*
+    };
+}
+
+impl_binary_item!(
+    u8 => 1, u16 => 2, u32 => 3, u64 => 4, u128 => 5,
+    i8 => 6, i16 => 7, i32 => 8, i64 => 9, i128 => 10
+);
+
+impl BidiBinaryItem for f32 {
+    const ENCODING: u8 = 11;
+    const SIZE: usize = 4;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        u32::decode(bytes).map(f32::from_bits)
+    }
+}
+
+impl BidiBinaryItem for f64 {
+    const ENCODING: u8 = 12;
+    const SIZE: usize = 8;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        u64::decode(bytes).map(f64::from_bits)
+    }
+}
+
+impl BidiBinaryItem for bool {
+    const ENCODING: u8 = 13;
+    const SIZE: usize = 1;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        match bytes[0] {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// The compression applied to the payload of the binary format of
+/// [`BidiVec::to_binary`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BidiCompression {
+    /// Items are stored one after the other, by rows.
+    None,
+    /// Items are stored by rows as runs of identical items (a 32 bits length
+    /// followed by the item); this is very effective on tile maps with large
+    /// uniform areas, but it can double the size of noisy data.
+    Rle,
+}
+
+impl BidiCompression {
+    fn tag(self) -> u8 {
+        match self {
+            BidiCompression::None => 0,
+            BidiCompression::Rle => 1,
+        }
+    }
+}
+
+fn invalid(message: &str) -> BidiIoError {
+    io::Error::new(io::ErrorKind::InvalidData, message).into()
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buf)
+}
+
+impl<T: BidiBinaryItem> BidiVec<T> {
+    /// Serializes the bidivec to a compact binary format, made of a small header
+    /// (a magic number, the format version, the item type, the compression, the
+    /// width and the height) followed by the items, optionally compressed.
+    ///
+    /// The format is stable across platforms and versions of the crate, and it's
+    /// independent of serde, which makes it suitable for save files.
+    ///
+    /// This is not named `to_bytes` to avoid confusion with the `as_bytes` and
+    /// `from_bytes` methods of the `bytemuck` feature, which handle the raw items
+    /// without any header; a `from_bytes` method here would also clash with the latter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiVec};
+    /// use bidivec::formats::BidiCompression;
+    ///
+    /// let v = bidivec![0u16; 100, 100];
+    ///
+    /// let raw = v.to_binary(BidiCompression::None);
+    /// let rle = v.to_binary(BidiCompression::Rle);
+    /// assert!(rle.len() < raw.len());
+    ///
+    /// assert_eq!(BidiVec::<u16>::from_binary(&rle)?, v);
+    /// assert_eq!(BidiVec::<u16>::from_binary(&raw)?, v);
+    ///
+    /// // the item type is checked
+    /// assert!(BidiVec::<i16>::from_binary(&raw).is_err());
+    /// # Ok::<(), bidivec::BidiIoError>(())
+    /// ```
+    pub fn to_binary(&self, compression: BidiCompression) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_SIZE + self.len() * T::SIZE);
+
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[VERSION, T::ENCODING, compression.tag(), 0]);
+        out.extend_from_slice(&(self.width() as u64).to_le_bytes());
+        out.extend_from_slice(&(self.height() as u64).to_le_bytes());
+
+        match compression {
+            BidiCompression::None => {
+                for item in self.iter() {
+                    item.encode(&mut out);
+                }
+            }
+            BidiCompression::Rle => {
+                let mut items = Vec::with_capacity(self.len() * T::SIZE);
+                for item in self.iter() {
+                    item.encode(&mut items);
+                }
+
+                let mut chunks = items.chunks(T::SIZE.max(1)).peekable();
+                while let Some(chunk) = chunks.next() {
+                    let mut run = 1u32;
+                    while run < std::u32::MAX && chunks.peek() == Some(&chunk) {
+                        chunks.next();
+                        run += 1;
+                    }
+
+                    out.extend_from_slice(&run.to_le_bytes());
+                    out.extend_from_slice(chunk);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Deserializes a bidivec from the binary format produced by [`BidiVec::to_binary`].
+    ///
+    /// Data that is not in this format, that has been produced by a newer version of
+    /// the format, that has been produced for a different item type, or that is
+    /// truncated or corrupted, is reported as a [`BidiIoError::Io`] error of kind
+    /// [`InvalidData`][std::io::ErrorKind::InvalidData].
+    ///
+    /// Since a few bytes of [`BidiCompression::Rle`] data can describe a huge bidivec,
+    /// use [`BidiVec::from_binary_limited`] to read data from untrusted sources.
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, BidiIoError> {
+        Self::from_binary_limited(bytes, std::usize::MAX)
+    }
+
+    /// Deserializes a bidivec from the binary format produced by [`BidiVec::to_binary`],
+    /// like [`BidiVec::from_binary`], but reports data describing a bidivec of more than
+    /// `max_items` items as a [`BidiIoError::Io`] error of kind
+    /// [`InvalidData`][std::io::ErrorKind::InvalidData], without allocating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiVec};
+    /// use bidivec::formats::BidiCompression;
+    ///
+    /// let rle = bidivec![0u8; 1000, 1000].to_binary(BidiCompression::Rle);
+    ///
+    /// assert!(BidiVec::<u8>::from_binary_limited(&rle, 4096 * 4096).is_ok());
+    /// assert!(BidiVec::<u8>::from_binary_limited(&rle, 512 * 512).is_err());
+    /// ```
+    pub fn from_binary_limited(bytes: &[u8], max_items: usize) -> Result<Self, BidiIoError> {
+        if bytes.len() < HEADER_SIZE || &bytes[..4] != MAGIC {
+            return Err(invalid("not a bidivec binary snapshot"));
+        }
+
+        let (version, encoding, compression) = (bytes[4], bytes[5], bytes[6]);
+
+        if version != VERSION {
+            return Err(invalid("unsupported binary snapshot version"));
+        }
+        if bytes[7] != 0 {
+            return Err(invalid("invalid binary snapshot header"));
+        }
+        if encoding != T::ENCODING {
+            return Err(invalid("binary snapshot item type mismatch"));
+        }
+
+        let (width, height) = (read_u64(&bytes[8..]), read_u64(&bytes[16..]));
+        let count = width
+            .checked_mul(height)
+            .filter(|n| *n <= std::usize::MAX as u64)
+            .ok_or_else(|| invalid("invalid binary snapshot size"))? as usize;
+
+        if count > max_items {
+            return Err(invalid("binary snapshot too large"));
+        }
+
+        let payload = &bytes[HEADER_SIZE..];
+        let decode = |chunk: &[u8]| T::decode(chunk).ok_or_else(|| invalid("invalid item"));
+        let run_len = |run: &[u8]| u32::from_le_bytes([run[0], run[1], run[2], run[3]]) as usize;
+        let mut data = Vec::new();
+
+        if compression == BidiCompression::None.tag() {
+            if count.checked_mul(T::SIZE) != Some(payload.len()) {
+                return Err(invalid("truncated binary snapshot"));
+            }
+
+            data.reserve(count);
+            for chunk in payload.chunks(T::SIZE.max(1)).take(count) {
+                data.push(decode(chunk)?);
+            }
+        } else if compression == BidiCompression::Rle.tag() {
+            if payload.len() % (4 + T::SIZE) != 0 {
+                return Err(invalid("truncated binary snapshot"));
+            }
+
+            // the runs are validated before allocating anything, as their total
+            // length can be many orders of magnitude larger than the payload
+            let mut total = 0usize;
+            for run in payload.chunks(4 + T::SIZE) {
+                let len = run_len(run);
+                if len == 0 || len > count - total {
+                    return Err(invalid("invalid run in binary snapshot"));
+                }
+                total += len;
+            }
+
+            if total != count {
+                return Err(invalid("truncated binary snapshot"));
+            }
+
+            data.reserve(total);
+            for run in payload.chunks(4 + T::SIZE) {
+                let item = decode(&run[4..])?;
+                data.extend(std::iter::repeat(item).take(run_len(run)));
+            }
+        } else {
+            return Err(invalid("unsupported binary snapshot compression"));
+        }
+
+        if data.len() != count {
+            return Err(invalid("truncated binary snapshot"));
+        }
+
+        Ok(BidiVec::from_vec(data, width as usize)?)
+    }
+}
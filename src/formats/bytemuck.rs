@@ -0,0 +1,213 @@
+use crate::{BidiArray, BidiError, BidiSlice, BidiVec};
+use ::bytemuck::{allocation, Pod, PodCastError};
+use std::mem::{align_of, size_of};
+
+/// The error returned when bytes can't be viewed as items of type `T`.
+fn bytes_error<T: Pod>(len: usize) -> BidiError {
+    let size = size_of::<T>().max(1);
+    BidiError::incompatible_size(len - len % size, len)
+}
+
+/// Converts the error of a failed cast of `len` bytes to items of type `T`.
+fn cast_error<T: Pod>(err: PodCastError, len: usize) -> BidiError {
+    match err {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned => BidiError::Misaligned {
+            alignment: align_of::<T>(),
+        },
+        _ => bytes_error::<T>(len),
+    }
+}
+
+/// Copies bytes into a new vec of `T`, checking that they contain a whole
+/// number of items.
+fn items_from_bytes<T: Pod>(bytes: &[u8]) -> Result<Vec<T>, BidiError> {
+    if size_of::<T>() == 0 || bytes.len() % size_of::<T>() != 0 {
+        return Err(bytes_error::<T>(bytes.len()));
+    }
+
+    Ok(allocation::pod_collect_to_vec(bytes))
+}
+
+/// Reinterprets a vec of `T` as a vec of `U`, without copying if the
+/// alignments of the two types allow it.
+fn cast_items<T: Pod, U: Pod>(data: Vec<T>) -> Result<Vec<U>, BidiError> {
+    if size_of::<T>() != size_of::<U>() {
+        return Err(BidiError::incompatible_size(size_of::<T>(), size_of::<U>()));
+    }
+
+    match allocation::try_cast_vec(data) {
+        Ok(data) => Ok(data),
+        Err((_, data)) => Ok(allocation::pod_collect_to_vec(&data)),
+    }
+}
+
+impl<T: Pod> BidiVec<T> {
+    /// Returns the items of the bidivec as bytes, in the same (row by row)
+    /// order they are stored in. This is useful to upload grids to GPU buffers
+    /// or to hand them to native code.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let v = bidivec!{
+    ///     [1u16, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(v.as_bytes().len(), 8);
+    /// assert_eq!(v.as_bytes()[..2], 1u16.to_ne_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        ::bytemuck::cast_slice(&self.data)
+    }
+
+    /// Creates a bidivec with the specified width from bytes laid out as
+    /// returned by [`as_bytes`][BidiVec::as_bytes]. The bytes are copied, so
+    /// they don't need to be aligned.
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the bytes don't contain a whole
+    /// number of items, or a whole number of rows.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiVec};
+    ///
+    /// let v = bidivec!{
+    ///     [1u32, 2],
+    ///     [3, 4],
+    /// };
+    ///
+    /// assert_eq!(BidiVec::<u32>::from_bytes(v.as_bytes(), 2)?, v);
+    /// assert!(BidiVec::<u32>::from_bytes(&v.as_bytes()[1..], 2).is_err());
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_bytes(bytes: &[u8], width: usize) -> Result<Self, BidiError> {
+        Self::from_vec(items_from_bytes(bytes)?, width)
+    }
+
+    /// Reinterprets the items of the bidivec as items of another type of the
+    /// same size, keeping the width and height. The items are not copied, unless
+    /// `U` requires a stricter alignment than `T`.
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the sizes of the two types
+    /// are different.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let v = bidivec!{
+    ///     [[0xffu8, 0, 0, 0xff], [0, 0xff, 0, 0xff]],
+    /// };
+    ///
+    /// let pixels = v.cast::<u32>()?;
+    /// assert_eq!(pixels[(0, 0)], u32::from_ne_bytes([0xff, 0, 0, 0xff]));
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn cast<U: Pod>(self) -> Result<BidiVec<U>, BidiError> {
+        let width = self.width();
+        BidiVec::from_vec(cast_items(self.data)?, width)
+    }
+}
+
+impl<T: Pod> BidiArray<T> {
+    /// Returns the items of the bidiarray as bytes, in the same (row by row)
+    /// order they are stored in. This is useful to upload grids to GPU buffers
+    /// or to hand them to native code.
+    ///
+    /// Requires the `bytemuck` feature.
+    pub fn as_bytes(&self) -> &[u8] {
+        ::bytemuck::cast_slice(&self.data)
+    }
+
+    /// Creates a bidiarray with the specified width from bytes laid out as
+    /// returned by [`as_bytes`][BidiArray::as_bytes]. The bytes are copied, so
+    /// they don't need to be aligned.
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the bytes don't contain a whole
+    /// number of items, or a whole number of rows.
+    ///
+    /// Requires the `bytemuck` feature.
+    pub fn from_bytes(bytes: &[u8], width: usize) -> Result<Self, BidiError> {
+        Self::from_vec(items_from_bytes(bytes)?, width)
+    }
+
+    /// Reinterprets the items of the bidiarray as items of another type of the
+    /// same size, keeping the width and height. The items are not copied, unless
+    /// `U` requires a stricter alignment than `T`.
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the sizes of the two types
+    /// are different.
+    ///
+    /// Requires the `bytemuck` feature.
+    pub fn cast<U: Pod>(self) -> Result<BidiArray<U>, BidiError> {
+        let width = self.width();
+        BidiArray::from_vec(cast_items(self.data.into_vec())?, width)
+    }
+}
+
+impl<'a, T: Pod> BidiSlice<'a, T> {
+    /// Returns the items of the bidislice as bytes, in the same (row by row)
+    /// order they are stored in.
+    ///
+    /// Requires the `bytemuck` feature.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        ::bytemuck::cast_slice(self.data)
+    }
+
+    /// Creates a bidislice with the specified width over bytes laid out as
+    /// returned by [`as_bytes`][BidiSlice::as_bytes], without copying them.
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the bytes don't contain a whole
+    /// number of items or a whole number of rows, or [`BidiError::Misaligned`]
+    /// if they are not aligned for `T`; use [`BidiVec::from_bytes`] to copy
+    /// bytes of any alignment.
+    ///
+    /// Requires the `bytemuck` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiSlice, BidiView};
+    ///
+    /// let bytes = [1u8, 2, 3, 4, 5, 6];
+    /// let bslice = BidiSlice::<u8>::from_bytes(&bytes, 3)?;
+    ///
+    /// assert_eq!(bslice.size(), (3, 2));
+    /// assert_eq!(bslice[(0, 1)], 4);
+    /// # Ok::<(), bidivec::BidiError>(())
+    /// ```
+    pub fn from_bytes(bytes: &'a [u8], width: usize) -> Result<Self, BidiError> {
+        let data =
+            ::bytemuck::try_cast_slice(bytes).map_err(|e| cast_error::<T>(e, bytes.len()))?;
+        Self::new(data, width)
+    }
+
+    /// Reinterprets the items of the bidislice as items of another type of the
+    /// same size, keeping the width and height, without copying them.
+    ///
+    /// Returns [`BidiError::IncompatibleSize`] if the sizes of the two types are
+    /// different, or [`BidiError::Misaligned`] if the items are not aligned
+    /// for `U`.
+    ///
+    /// Requires the `bytemuck` feature.
+    pub fn cast<U: Pod>(self) -> Result<BidiSlice<'a, U>, BidiError> {
+        if size_of::<T>() != size_of::<U>() {
+            return Err(BidiError::incompatible_size(size_of::<T>(), size_of::<U>()));
+        }
+
+        let data = ::bytemuck::try_cast_slice(self.data)
+            .map_err(|e| cast_error::<U>(e, std::mem::size_of_val(self.data)))?;
+        BidiSlice::new(data, self.row_size)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{BidiError, BidiIoError, BidiVec};
+use std::io::{self, Read, Write};
+
+impl<T> BidiVec<T> {
+    /// Creates a bidivec from CSV data (without headers), where each record is
+    /// a row and each field is an item, converted through the specified parser
+    /// (which also receives the coordinates of the item).
+    ///
+    /// All the records must have the same number of fields, or
+    /// [`BidiError::IncompatibleSize`] is returned (wrapped in [`BidiIoError::Bidi`]);
+    /// items the parser fails on are reported as [`BidiIoError::InvalidItem`].
+    ///
+    /// Requires the `csv` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, bidivec};
+    ///
+    /// let data = "1,2,3\n4,5,6\n";
+    /// let v = BidiVec::from_csv_reader(data.as_bytes(), |s, _, _| s.trim().parse::<i32>())?;
+    ///
+    /// assert_eq!(v, bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// });
+    /// # Ok::<(), bidivec::BidiIoError>(())
+    /// ```
+    pub fn from_csv_reader<R, F, E>(reader: R, mut parser: F) -> Result<Self, BidiIoError>
+    where
+        R: Read,
+        F: FnMut(&str, usize, usize) -> Result<T, E>,
+        E: std::fmt::Display,
+    {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(reader);
+
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut record = ::csv::StringRecord::new();
+        let mut y = 0;
+
+        while reader.read_record(&mut record).map_err(io::Error::from)? {
+            let expected = *width.get_or_insert(record.len());
+            if expected != record.len() {
+                return Err(BidiError::incompatible_size(expected, record.len()).into());
+            }
+
+            for (x, field) in record.iter().enumerate() {
+                let item = parser(field, x, y).map_err(|e| BidiIoError::InvalidItem {
+                    x,
+                    y,
+                    message: e.to_string(),
+                })?;
+                data.push(item);
+            }
+
+            y += 1;
+        }
+
+        Ok(Self::from_vec(data, width.unwrap_or(0))?)
+    }
+
+    /// Writes the bidivec as CSV data (without headers), one record per row,
+    /// converting each item to a field through the specified formatter.
+    ///
+    /// Requires the `csv` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::bidivec;
+    ///
+    /// let v = bidivec!{
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// };
+    ///
+    /// let mut out = Vec::new();
+    /// v.write_csv(&mut out, |n| n.to_string())?;
+    ///
+    /// assert_eq!(String::from_utf8(out).unwrap(), "1,2,3\n4,5,6\n");
+    /// # Ok::<(), bidivec::BidiIoError>(())
+    /// ```
+    pub fn write_csv<W, F>(&self, writer: W, mut formatter: F) -> Result<(), BidiIoError>
+    where
+        W: Write,
+        F: FnMut(&T) -> String,
+    {
+        let mut writer = ::csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(writer);
+
+        for y in 0..self.height() {
+            writer
+                .write_record((0..self.width()).map(|x| formatter(&self[(x, y)])))
+                .map_err(io::Error::from)?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
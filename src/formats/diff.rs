@@ -0,0 +1,196 @@
+use crate::{BidiIoError, BidiPatch};
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+const HEADER: &str = "bidipatch 1";
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape(s: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            unescaped.push(match chars.next()? {
+                '\\' => '\\',
+                't' => '\t',
+                'n' => '\n',
+                'r' => '\r',
+                _ => return None,
+            });
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    Some(unescaped)
+}
+
+fn invalid_line(line: usize, message: &str) -> BidiIoError {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", line, message),
+    )
+    .into()
+}
+
+impl<T> BidiPatch<T> {
+    /// Writes the patch in a line based text format, meant to be stable and easy to
+    /// review (e.g. to keep diffs between versions of a map under version control, or
+    /// as golden files in tests).
+    ///
+    /// The first line is a header (`bidipatch 1`); then every entry is written on its
+    /// own line, in order, as the x and y coordinates, the old value and the new value,
+    /// separated by tabs. Values are written through their [`Display`] implementation,
+    /// escaping backslashes, tabs and line breaks with a backslash.
+    ///
+    /// Requires the `diff` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiPatch};
+    ///
+    /// let old = bidivec!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    /// let new = bidivec!{
+    ///     [1, 20],
+    ///     [3, 40],
+    /// };
+    ///
+    /// let mut out = Vec::new();
+    /// BidiPatch::from_diff(&old, &new)?.write_text(&mut out)?;
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "bidipatch 1\n1\t0\t2\t20\n1\t1\t4\t40\n",
+    /// );
+    /// # Ok::<(), bidivec::BidiIoError>(())
+    /// ```
+    pub fn write_text<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+        T: Display,
+    {
+        writeln!(writer, "{}", HEADER)?;
+
+        for e in self.iter() {
+            writeln!(
+                writer,
+                "{}\t{}\t{}\t{}",
+                e.x,
+                e.y,
+                escape(&e.old.to_string()),
+                escape(&e.new.to_string())
+            )?;
+        }
+
+        writer.flush()
+    }
+
+    /// Reads a patch in the text format produced by [`BidiPatch::write_text`], parsing
+    /// values through their [`FromStr`] implementation. Empty lines and lines starting
+    /// with `#` are ignored.
+    ///
+    /// Values that fail to parse are reported as [`BidiIoError::InvalidItem`], with
+    /// the coordinates of the entry; malformed lines (or a missing header) are reported
+    /// as a [`BidiIoError::Io`] error of kind [`InvalidData`][std::io::ErrorKind::InvalidData].
+    ///
+    /// Requires the `diff` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{bidivec, BidiPatch};
+    ///
+    /// let text = "bidipatch 1\n# a comment\n0\t1\t3\t30\n";
+    /// let patch = BidiPatch::<i32>::from_text_reader(text.as_bytes())?;
+    ///
+    /// let mut v = bidivec!{
+    ///     [1, 2],
+    ///     [3, 4],
+    /// };
+    /// patch.apply(&mut v)?;
+    ///
+    /// assert_eq!(v, bidivec!{
+    ///     [1, 2],
+    ///     [30, 4],
+    /// });
+    /// # Ok::<(), bidivec::BidiIoError>(())
+    /// ```
+    pub fn from_text_reader<R>(reader: R) -> Result<Self, BidiIoError>
+    where
+        R: Read,
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut patch = BidiPatch::new();
+        let mut header_found = false;
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+            let line_number = index + 1;
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if !header_found {
+                if line != HEADER {
+                    return Err(invalid_line(line_number, "missing bidipatch header"));
+                }
+                header_found = true;
+                continue;
+            }
+
+            let fields = line.split('\t').collect::<Vec<&str>>();
+            if fields.len() != 4 {
+                return Err(invalid_line(line_number, "expected 4 tab separated fields"));
+            }
+
+            let (x, y) = match (fields[0].parse::<usize>(), fields[1].parse::<usize>()) {
+                (Ok(x), Ok(y)) => (x, y),
+                _ => return Err(invalid_line(line_number, "invalid coordinates")),
+            };
+
+            let parse = |field: &str| {
+                let value =
+                    unescape(field).ok_or_else(|| invalid_line(line_number, "invalid escape"))?;
+                value.parse::<T>().map_err(|e| BidiIoError::InvalidItem {
+                    x,
+                    y,
+                    message: e.to_string(),
+                })
+            };
+
+            let old = parse(fields[2])?;
+            let new = parse(fields[3])?;
+            patch.push(x, y, old, new);
+        }
+
+        if !header_found {
+            return Err(invalid_line(0, "missing bidipatch header"));
+        }
+
+        Ok(patch)
+    }
+}
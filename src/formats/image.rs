@@ -0,0 +1,66 @@
+use crate::{BidiVec, BidiView};
+use ::image::{ImageBuffer, Pixel};
+use std::ops::Deref;
+
+/// Copies a view of pixels into a new [`ImageBuffer`] of the `image` crate, which
+/// can then be saved to any of the formats it supports (e.g. PNG).
+///
+/// Views of other types can be converted with a transforming view or with
+/// [`BidiVec::from_view_map`] first. Image dimensions are `u32`, so views
+/// larger than `u32::MAX` in either dimension are not supported.
+///
+/// Requires the `image` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, formats, BidiVec};
+/// use image::Luma;
+///
+/// let heights = bidivec!{
+///     [0u8, 100, 200],
+///     [50, 150, 250],
+/// };
+///
+/// let img = formats::to_image_buffer(&BidiVec::from_view_map(&heights, |h| Luma([*h])));
+///
+/// assert_eq!(img.dimensions(), (3, 2));
+/// assert_eq!(img.get_pixel(2, 1), &Luma([250]));
+/// ```
+pub fn to_image_buffer<V, P>(view: &V) -> ImageBuffer<P, Vec<P::Subpixel>>
+where
+    V: BidiView<Output = P> + ?Sized,
+    P: Pixel,
+{
+    ImageBuffer::from_fn(view.width() as u32, view.height() as u32, |x, y| {
+        view[(x as usize, y as usize)]
+    })
+}
+
+impl<P: Pixel> BidiVec<P> {
+    /// Creates a bidivec of pixels from an [`ImageBuffer`] of the `image` crate.
+    ///
+    /// Requires the `image` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bidivec::{BidiVec, BidiView};
+    /// use image::{ImageBuffer, Rgb};
+    ///
+    /// let img = ImageBuffer::from_fn(4, 3, |x, y| Rgb([x as u8, y as u8, 0]));
+    /// let v = BidiVec::from_image_buffer(&img);
+    ///
+    /// assert_eq!(v.size(), (4, 3));
+    /// assert_eq!(v[(3, 1)], Rgb([3, 1, 0]));
+    /// ```
+    pub fn from_image_buffer<C>(image: &ImageBuffer<P, C>) -> Self
+    where
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let width = image.width() as usize;
+        let data = image.pixels().copied().collect::<Vec<_>>();
+
+        Self::from_vec(data, width).unwrap()
+    }
+}
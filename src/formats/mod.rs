@@ -0,0 +1,53 @@
+//! A module containing functions to read and write bidimensional data
+//! structures from and to external formats.
+//!
+//! A compact binary format, meant for stable snapshots of grids (e.g. in save files),
+//! is always available through `BidiVec::to_binary` and `BidiVec::from_binary`.
+//!
+//! All the other formats are enabled by optional features:
+//! - `bytemuck`: byte views and casts of grids of plain old data, through `as_bytes`,
+//!   `from_bytes` and `cast` on `BidiVec`, `BidiArray` and `BidiSlice`.
+//! - `csv`: CSV files, through `BidiVec::from_csv_reader` and `BidiVec::write_csv`.
+//! - `diff`: patches between two versions of a data structure, in a line based text
+//!   format, through `BidiPatch::write_text` and `BidiPatch::from_text_reader`.
+//! - `pnm`: grayscale and color images in the PGM and PPM formats, through
+//!   `write_pgm` and `write_ppm`.
+//! - `image`: conversions from and to the `ImageBuffer` type of the `image` crate,
+//!   through `to_image_buffer` and `BidiVec::from_image_buffer`.
+//! - `nalgebra`: conversions from and to the `DMatrix` type of the `nalgebra` crate,
+//!   through `to_dmatrix` and the [`From`] trait.
+//! - `wasm`: copies and views of the items of `BidiVec` and `BidiArray` as JavaScript typed
+//!   arrays, through `to_js_typed_array`, `as_js_typed_array` and `from_js_typed_array`.
+//! - `tiled`: tile layers of maps in the JSON format of the Tiled map editor, through
+//!   `read_tiled_layers`, `read_tiled_layers_limited` and `read_tiled_layers_with`.
+mod binary;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "diff")]
+mod diff;
+#[cfg(feature = "image")]
+mod image;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "pnm")]
+mod pnm;
+#[cfg(feature = "tiled")]
+mod tiled;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use self::binary::{BidiBinaryItem, BidiCompression};
+#[cfg(feature = "image")]
+pub use self::image::to_image_buffer;
+#[cfg(feature = "nalgebra")]
+pub use self::nalgebra::to_dmatrix;
+#[cfg(feature = "pnm")]
+pub use self::pnm::{write_pgm, write_ppm};
+#[cfg(feature = "tiled")]
+pub use self::tiled::{
+    read_tiled_layers, read_tiled_layers_limited, read_tiled_layers_with, TiledLayer, TiledTile,
+};
+#[cfg(feature = "wasm")]
+pub use self::wasm::{BidiJsArray, BidiJsItem};
@@ -0,0 +1,68 @@
+use crate::{BidiVec, BidiView};
+use ::nalgebra::{DMatrix, Scalar};
+
+/// Copies a view into a new [`DMatrix`] of the `nalgebra` crate, where each row
+/// of the view becomes a row of the matrix (i.e. the item at `(x, y)` becomes the
+/// element at row `y` and column `x`).
+///
+/// Requires the `nalgebra` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, formats};
+///
+/// let v = bidivec!{
+///     [1.0, 2.0, 3.0],
+///     [4.0, 5.0, 6.0],
+/// };
+///
+/// let m = formats::to_dmatrix(&v);
+///
+/// assert_eq!(m.shape(), (2, 3));
+/// assert_eq!(m[(1, 0)], 4.0);
+/// ```
+pub fn to_dmatrix<V, T>(view: &V) -> DMatrix<T>
+where
+    V: BidiView<Output = T> + ?Sized,
+    T: Scalar,
+{
+    DMatrix::from_fn(view.height(), view.width(), |row, col| {
+        view[(col, row)].clone()
+    })
+}
+
+impl<T: Scalar> From<DMatrix<T>> for BidiVec<T> {
+    /// Creates a new instance of [`BidiVec<T>`] from a [`DMatrix`] of the `nalgebra`
+    /// crate, where each row of the matrix becomes a row of the bidivec.
+    ///
+    /// Matrices store their elements by columns, while bidivecs store them by rows,
+    /// so the elements are moved and then transposed in place.
+    ///
+    /// Requires the `nalgebra` feature.
+    fn from(matrix: DMatrix<T>) -> Self {
+        let (rows, cols) = matrix.shape();
+        let data: Vec<T> = matrix.data.into();
+
+        // each column of the matrix is a row of the transposed bidivec
+        let mut bvec = if cols == 0 {
+            BidiVec::new()
+        } else {
+            BidiVec::from_vec(data, rows).unwrap()
+        };
+        bvec.transpose();
+        bvec
+    }
+}
+
+impl<T: Scalar> From<BidiVec<T>> for DMatrix<T> {
+    /// Creates a new [`DMatrix`] of the `nalgebra` crate from a [`BidiVec<T>`],
+    /// where each row of the bidivec becomes a row of the matrix, without
+    /// cloning the elements.
+    ///
+    /// Requires the `nalgebra` feature.
+    fn from(bvec: BidiVec<T>) -> Self {
+        let (width, height) = (bvec.width(), bvec.height());
+        DMatrix::from_row_iterator(height, width, bvec.into_vec())
+    }
+}
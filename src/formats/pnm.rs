@@ -0,0 +1,87 @@
+use crate::BidiView;
+use std::io::{self, Write};
+
+fn write_pnm<V, W, F>(view: &V, mut writer: W, magic: &str, mut pixel: F) -> io::Result<()>
+where
+    V: BidiView + ?Sized,
+    W: Write,
+    F: FnMut(&V::Output, &mut Vec<u8>),
+{
+    let (width, height) = (view.width(), view.height());
+    write!(writer, "{}\n{} {}\n255\n", magic, width, height)?;
+
+    let mut row = Vec::new();
+    for y in 0..height {
+        row.clear();
+        for x in 0..width {
+            pixel(&view[(x, y)], &mut row);
+        }
+        writer.write_all(&row)?;
+    }
+
+    writer.flush()
+}
+
+/// Writes a view of bytes as a grayscale image in the (binary) PGM format,
+/// where each item is the brightness of a pixel (0 is black, 255 is white).
+///
+/// PGM files can be opened by most image viewers and editors, which makes this
+/// a quick way to inspect heightmaps, distance fields and debug masks.
+///
+/// Requires the `pnm` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, formats};
+///
+/// let v = bidivec!{
+///     [0u8, 255],
+///     [128, 64],
+/// };
+///
+/// let mut out = Vec::new();
+/// formats::write_pgm(&v, &mut out)?;
+///
+/// assert_eq!(&out[..11], b"P5\n2 2\n255\n");
+/// assert_eq!(&out[11..], &[0, 255, 128, 64]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_pgm<V, W>(view: &V, writer: W) -> io::Result<()>
+where
+    V: BidiView<Output = u8> + ?Sized,
+    W: Write,
+{
+    write_pnm(view, writer, "P5", |p, out| out.push(*p))
+}
+
+/// Writes a view of `(red, green, blue)` tuples as a color image in the (binary)
+/// PPM format.
+///
+/// Requires the `pnm` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidivec, formats};
+///
+/// let v = bidivec!{
+///     [(255u8, 0u8, 0u8), (0, 0, 255)],
+/// };
+///
+/// let mut out = Vec::new();
+/// formats::write_ppm(&v, &mut out)?;
+///
+/// assert_eq!(&out[..11], b"P6\n2 1\n255\n");
+/// assert_eq!(&out[11..], &[255, 0, 0, 0, 0, 255]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_ppm<V, W>(view: &V, writer: W) -> io::Result<()>
+where
+    V: BidiView<Output = (u8, u8, u8)> + ?Sized,
+    W: Write,
+{
+    write_pnm(view, writer, "P6", |&(r, g, b), out| {
+        out.extend_from_slice(&[r, g, b])
+    })
+}
@@ -0,0 +1,417 @@
+use crate::{BidiIoError, BidiVec};
+use ::base64::Engine;
+use std::io::{self, Read};
+use tinyjson::JsonValue as Value;
+
+const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+const ROTATED_HEXAGONAL_120: u32 = 0x1000_0000;
+const FLAGS: u32 =
+    FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY | ROTATED_HEXAGONAL_120;
+
+/// A tile of a Tiled map, with its global tile id separated from the
+/// flipping flags Tiled stores in the highest bits of each tile.
+///
+/// Requires the `tiled` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::formats::TiledTile;
+///
+/// let tile = TiledTile::from_raw(0x8000_0005);
+///
+/// assert_eq!(tile.gid, 5);
+/// assert!(tile.flipped_horizontally);
+/// assert!(!tile.flipped_vertically);
+/// assert_eq!(tile.to_raw(), 0x8000_0005);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TiledTile {
+    /// The global tile id, or 0 for an empty tile
+    pub gid: u32,
+    /// Whether the tile is flipped horizontally
+    pub flipped_horizontally: bool,
+    /// Whether the tile is flipped vertically
+    pub flipped_vertically: bool,
+    /// Whether the tile is flipped diagonally (i.e. transposed), which combined
+    /// with the other flags expresses rotations by 90 degrees on orthogonal maps
+    pub flipped_diagonally: bool,
+    /// Whether the tile is rotated by 120 degrees (hexagonal maps only)
+    pub rotated_hexagonal_120: bool,
+}
+
+impl TiledTile {
+    /// Decodes a tile as stored by Tiled, with the flipping flags in the
+    /// highest bits.
+    pub fn from_raw(raw: u32) -> Self {
+        Self {
+            gid: raw & !FLAGS,
+            flipped_horizontally: raw & FLIPPED_HORIZONTALLY != 0,
+            flipped_vertically: raw & FLIPPED_VERTICALLY != 0,
+            flipped_diagonally: raw & FLIPPED_DIAGONALLY != 0,
+            rotated_hexagonal_120: raw & ROTATED_HEXAGONAL_120 != 0,
+        }
+    }
+
+    /// Encodes the tile as stored by Tiled, with the flipping flags in the
+    /// highest bits.
+    pub fn to_raw(self) -> u32 {
+        let flag = |set: bool, flag: u32| if set { flag } else { 0 };
+
+        (self.gid & !FLAGS)
+            | flag(self.flipped_horizontally, FLIPPED_HORIZONTALLY)
+            | flag(self.flipped_vertically, FLIPPED_VERTICALLY)
+            | flag(self.flipped_diagonally, FLIPPED_DIAGONALLY)
+            | flag(self.rotated_hexagonal_120, ROTATED_HEXAGONAL_120)
+    }
+
+    /// Returns true if there's no tile (i.e. the global tile id is 0).
+    pub fn is_empty(self) -> bool {
+        self.gid == 0
+    }
+}
+
+/// A tile layer read from a Tiled map.
+///
+/// Requires the `tiled` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TiledLayer<T> {
+    /// The name of the layer
+    pub name: String,
+    /// The map coordinates (in tiles) of the top-left item of `tiles`; this is
+    /// always `(0, 0)` for finite maps, while for infinite maps it's the
+    /// top-left corner of the bounding rect of all the chunks of the layer
+    pub origin: (isize, isize),
+    /// The tiles of the layer
+    pub tiles: BidiVec<T>,
+}
+
+fn invalid(message: String) -> BidiIoError {
+    io::Error::new(io::ErrorKind::InvalidData, message).into()
+}
+
+fn get<'a>(obj: &'a Value, name: &str) -> Option<&'a Value> {
+    match obj {
+        Value::Object(map) => map.get(name),
+        _ => None,
+    }
+}
+
+fn get_str<'a>(obj: &'a Value, name: &str) -> Option<&'a str> {
+    match get(obj, name) {
+        Some(Value::String(s)) => Some(s),
+        _ => None,
+    }
+}
+
+/// Returns the value of a numeric field, if it's an integer in the specified range.
+fn get_integer(obj: &Value, name: &str, min: f64, max: f64) -> Result<f64, BidiIoError> {
+    match get(obj, name) {
+        Some(Value::Number(n)) if n.fract() == 0.0 && *n >= min && *n <= max => Ok(*n),
+        _ => Err(invalid(format!("missing or invalid '{}'", name))),
+    }
+}
+
+fn get_usize(obj: &Value, name: &str) -> Result<usize, BidiIoError> {
+    get_integer(obj, name, 0.0, std::u32::MAX as f64).map(|n| n as usize)
+}
+
+fn get_isize(obj: &Value, name: &str) -> Result<isize, BidiIoError> {
+    get_integer(obj, name, std::i32::MIN as f64, std::i32::MAX as f64).map(|n| n as isize)
+}
+
+/// Decodes the `data` of a layer or chunk, in either the CSV or the
+/// (uncompressed) base64 encoding.
+fn decode_data(layer: &Value, data: &Value, width: usize) -> Result<BidiVec<u32>, BidiIoError> {
+    let tiles = match data {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Number(n) if n.fract() == 0.0 && *n >= 0.0 && *n <= std::u32::MAX as f64 => {
+                    Ok(*n as u32)
+                }
+                _ => Err(invalid(format!("invalid tile '{:?}'", item))),
+            })
+            .collect::<Result<Vec<u32>, BidiIoError>>()?,
+        Value::String(s) => {
+            let compression = get_str(layer, "compression").unwrap_or("");
+
+            if !compression.is_empty() {
+                return Err(invalid(format!(
+                    "unsupported compression '{}'",
+                    compression
+                )));
+            }
+
+            let bytes = ::base64::engine::general_purpose::STANDARD
+                .decode(s.trim())
+                .map_err(|e| invalid(e.to_string()))?;
+
+            if bytes.len() % 4 != 0 {
+                return Err(invalid("truncated base64 layer data".to_string()));
+            }
+
+            bytes
+                .chunks_exact(4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect()
+        }
+        _ => return Err(invalid("missing or invalid 'data'".to_string())),
+    };
+
+    Ok(BidiVec::from_vec(tiles, width)?)
+}
+
+fn read_tile_layer(layer: &Value, max_tiles: usize) -> Result<TiledLayer<u32>, BidiIoError> {
+    let name = get_str(layer, "name").unwrap_or("").to_string();
+
+    let chunks = match get(layer, "chunks") {
+        None => {
+            let (width, height) = (get_usize(layer, "width")?, get_usize(layer, "height")?);
+            let tiles = decode_data(layer, get(layer, "data").unwrap_or(&Value::Null), width)?;
+
+            if tiles.height() != height {
+                return Err(crate::BidiError::incompatible_size(height, tiles.height()).into());
+            }
+
+            return Ok(TiledLayer {
+                name,
+                origin: (0, 0),
+                tiles,
+            });
+        }
+        Some(Value::Array(chunks)) => chunks,
+        Some(_) => return Err(invalid("invalid 'chunks'".to_string())),
+    };
+
+    let mut decoded = Vec::with_capacity(chunks.len());
+    for chunk in chunks.iter() {
+        let (x, y) = (get_isize(chunk, "x")?, get_isize(chunk, "y")?);
+        let (width, height) = (get_usize(chunk, "width")?, get_usize(chunk, "height")?);
+        let tiles = decode_data(layer, get(chunk, "data").unwrap_or(&Value::Null), width)?;
+
+        if tiles.height() != height {
+            return Err(crate::BidiError::incompatible_size(height, tiles.height()).into());
+        }
+
+        decoded.push(((x, y), tiles));
+    }
+
+    let min_x = decoded.iter().map(|c| (c.0).0).min().unwrap_or(0);
+    let min_y = decoded.iter().map(|c| (c.0).1).min().unwrap_or(0);
+    let max_x = decoded
+        .iter()
+        .map(|c| (c.0).0 + c.1.width() as isize)
+        .max()
+        .unwrap_or(0);
+    let max_y = decoded
+        .iter()
+        .map(|c| (c.0).1 + c.1.height() as isize)
+        .max()
+        .unwrap_or(0);
+
+    // the chunks can be placed far apart, so the size of the merged layer is
+    // checked before allocating it
+    let (width, height) = ((max_x - min_x) as usize, (max_y - min_y) as usize);
+    if width
+        .checked_mul(height)
+        .filter(|n| *n <= max_tiles)
+        .is_none()
+    {
+        return Err(invalid(format!("layer '{}' too large", name)));
+    }
+
+    let mut tiles = BidiVec::with_elem(0, width, height);
+
+    for ((x, y), chunk) in decoded.iter() {
+        let (dx, dy) = ((x - min_x) as usize, (y - min_y) as usize);
+
+        for cy in 0..chunk.height() {
+            for cx in 0..chunk.width() {
+                tiles[(dx + cx, dy + cy)] = chunk[(cx, cy)];
+            }
+        }
+    }
+
+    Ok(TiledLayer {
+        name,
+        origin: (min_x, min_y),
+        tiles,
+    })
+}
+
+fn read_layers(
+    layers: &Value,
+    max_tiles: usize,
+    result: &mut Vec<TiledLayer<u32>>,
+) -> Result<(), BidiIoError> {
+    let layers = match layers {
+        Value::Array(layers) => layers,
+        _ => return Err(invalid("missing or invalid 'layers'".to_string())),
+    };
+
+    for layer in layers.iter() {
+        match get_str(layer, "type") {
+            Some("tilelayer") => result.push(read_tile_layer(layer, max_tiles)?),
+            Some("group") => read_layers(
+                get(layer, "layers").unwrap_or(&Value::Null),
+                max_tiles,
+                result,
+            )?,
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the tile layers of a map in the JSON format of the [Tiled](https://www.mapeditor.org/)
+/// map editor, in the order they appear in the map (layers inside groups are included, in
+/// place of the group). Other kinds of layers (e.g. object and image layers) are ignored.
+///
+/// Each tile is the raw value stored by Tiled: a global tile id, with the flipping flags in the
+/// highest bits; use [`TiledTile::from_raw`] to decode them, or see [`read_tiled_layers_with`].
+///
+/// Both finite and infinite maps are supported: the chunks of the layers of infinite maps are
+/// merged into a single data structure, filling the gaps with empty tiles (see
+/// [`TiledLayer::origin`]). Layer data can be either in the CSV or in the base64 encoding;
+/// compressed layer data is not supported, and is reported as a [`BidiIoError::Io`] error,
+/// like invalid JSON documents and maps.
+///
+/// Since a few chunks placed far apart can describe a huge layer, use
+/// [`read_tiled_layers_limited`] to read maps from untrusted sources.
+///
+/// Requires the `tiled` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::bidivec;
+/// use bidivec::formats::read_tiled_layers;
+///
+/// let map = r#"{
+///     "width": 3, "height": 2, "infinite": false,
+///     "layers": [{
+///         "type": "tilelayer", "name": "ground", "width": 3, "height": 2,
+///         "data": [1, 2, 1, 0, 3, 2147483651]
+///     }]
+/// }"#;
+///
+/// let layers = read_tiled_layers(map.as_bytes())?;
+///
+/// assert_eq!(layers.len(), 1);
+/// assert_eq!(layers[0].name, "ground");
+/// assert_eq!(layers[0].tiles, bidivec!{
+///     [1, 2, 1],
+///     [0, 3, 0x8000_0003],
+/// });
+/// # Ok::<(), bidivec::BidiIoError>(())
+/// ```
+pub fn read_tiled_layers<R: Read>(reader: R) -> Result<Vec<TiledLayer<u32>>, BidiIoError> {
+    read_tiled_layers_limited(reader, std::usize::MAX)
+}
+
+/// Reads the tile layers of a map in the JSON format of the [Tiled](https://www.mapeditor.org/)
+/// map editor like [`read_tiled_layers`], but reports layers of infinite maps whose chunks
+/// span more than `max_tiles` tiles as a [`BidiIoError::Io`] error, without allocating them.
+///
+/// Requires the `tiled` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::formats::read_tiled_layers_limited;
+///
+/// // two tiny chunks, two billion tiles apart
+/// let map = r#"{
+///     "infinite": true,
+///     "layers": [{
+///         "type": "tilelayer", "name": "bomb",
+///         "chunks": [
+///             { "x": -1000000000, "y": 0, "width": 1, "height": 1, "data": [1] },
+///             { "x": 1000000000, "y": 0, "width": 1, "height": 1, "data": [1] }
+///         ]
+///     }]
+/// }"#;
+///
+/// assert!(read_tiled_layers_limited(map.as_bytes(), 4096 * 4096).is_err());
+/// ```
+pub fn read_tiled_layers_limited<R: Read>(
+    mut reader: R,
+    max_tiles: usize,
+) -> Result<Vec<TiledLayer<u32>>, BidiIoError> {
+    let mut json = String::new();
+    reader.read_to_string(&mut json)?;
+
+    let map = json.parse::<Value>().map_err(|e| invalid(e.to_string()))?;
+    let mut layers = Vec::new();
+
+    read_layers(
+        get(&map, "layers").unwrap_or(&Value::Null),
+        max_tiles,
+        &mut layers,
+    )?;
+    Ok(layers)
+}
+
+/// Reads the tile layers of a map in the JSON format of the [Tiled](https://www.mapeditor.org/)
+/// map editor like [`read_tiled_layers`], converting each tile (with the flipping flags
+/// decoded) through the specified function, which also receives the coordinates of the tile
+/// in the layer.
+///
+/// Requires the `tiled` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::BidiView;
+/// use bidivec::formats::read_tiled_layers_with;
+///
+/// // an infinite map, with two chunks
+/// let map = r#"{
+///     "infinite": true,
+///     "layers": [{
+///         "type": "tilelayer", "name": "walls",
+///         "chunks": [
+///             { "x": -2, "y": 0, "width": 2, "height": 1, "data": [1, 0] },
+///             { "x": 0, "y": 1, "width": 1, "height": 1, "data": [1073741826] }
+///         ]
+///     }]
+/// }"#;
+///
+/// let layers = read_tiled_layers_with(map.as_bytes(), |tile, _, _| {
+///     (tile.gid, tile.flipped_vertically)
+/// })?;
+///
+/// let walls = &layers[0];
+/// assert_eq!(walls.origin, (-2, 0));
+/// assert_eq!(walls.tiles.size(), (3, 2));
+/// assert_eq!(walls.tiles[(0, 0)], (1, false));
+/// assert_eq!(walls.tiles[(2, 1)], (2, true));
+/// assert_eq!(walls.tiles[(0, 1)], (0, false));
+/// # Ok::<(), bidivec::BidiIoError>(())
+/// ```
+pub fn read_tiled_layers_with<R, T, F>(
+    reader: R,
+    mut func: F,
+) -> Result<Vec<TiledLayer<T>>, BidiIoError>
+where
+    R: Read,
+    F: FnMut(TiledTile, usize, usize) -> T,
+{
+    Ok(read_tiled_layers(reader)?
+        .into_iter()
+        .map(|layer| {
+            let raw = layer.tiles;
+            TiledLayer {
+                name: layer.name,
+                origin: layer.origin,
+                tiles: BidiVec::with_size_func_xy(raw.width(), raw.height(), |x, y| {
+                    func(TiledTile::from_raw(raw[(x, y)]), x, y)
+                }),
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,137 @@
+use crate::{BidiArray, BidiError, BidiVec};
+use js_sys::{
+    Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, Uint16Array, Uint32Array,
+    Uint8Array,
+};
+
+/// A trait for the item types that have a matching JavaScript typed array type
+/// (e.g. [`u8`] and `Uint8Array`, or [`f32`] and `Float32Array`).
+///
+/// Requires the `wasm` feature.
+pub trait BidiJsItem: Copy {
+    /// The matching JavaScript typed array type.
+    type TypedArray;
+
+    /// Copies the items to a new typed array.
+    fn to_typed_array(items: &[Self]) -> Self::TypedArray;
+
+    /// Creates a typed array viewing the items directly in the WebAssembly memory.
+    ///
+    /// # Safety
+    ///
+    /// See [`BidiVec::as_js_typed_array`].
+    unsafe fn view_typed_array(items: &[Self]) -> Self::TypedArray;
+
+    /// Copies the items of a typed array to a new vector.
+    fn from_typed_array(array: &Self::TypedArray) -> Vec<Self>;
+}
+
+macro_rules! impl_js_item {
+    ($($t:ty => $array:ty),*) => {
+        $(impl BidiJsItem for $t {
+            type TypedArray = $array;
+
+            fn to_typed_array(items: &[Self]) -> Self::TypedArray {
+                <$array>::from(items)
+            }
+
+            unsafe fn view_typed_array(items: &[Self]) -> Self::TypedArray {
+                <$array>::view(items)
+            }
+
+            fn from_typed_array(array: &Self::TypedArray) -> Vec<Self> {
+                array.to_vec()
+            }
+        })*
+    };
+}
+
+impl_js_item!(
+    u8 => Uint8Array, u16 => Uint16Array, u32 => Uint32Array,
+    i8 => Int8Array, i16 => Int16Array, i32 => Int32Array,
+    f32 => Float32Array, f64 => Float64Array
+);
+
+/// A JavaScript typed array containing the items of a bidimensional data structure
+/// by rows, together with its size, ready to be passed to JavaScript code (e.g. to
+/// fill an `ImageData` or upload a WebGL texture).
+///
+/// Requires the `wasm` feature.
+#[derive(Clone, Debug)]
+pub struct BidiJsArray<A> {
+    /// The typed array containing the items, by rows
+    pub array: A,
+    /// The width of the data structure
+    pub width: usize,
+    /// The height of the data structure
+    pub height: usize,
+}
+
+macro_rules! impl_js_arrays {
+    ($($container:ident),*) => {
+        $(impl<T: BidiJsItem> $container<T> {
+            /// Copies the items (by rows) to a new JavaScript typed array, in a
+            /// single operation.
+            ///
+            /// Requires the `wasm` feature.
+            ///
+            /// # Examples
+            ///
+            /// ```no_run
+            /// use bidivec::BidiVec;
+            ///
+            /// let v = BidiVec::<u8>::with_size_default(320, 200);
+            /// let js = v.to_js_typed_array();
+            ///
+            /// // js.array is a Uint8Array with 320 * 200 items
+            /// assert_eq!(js.array.length(), 320 * 200);
+            /// assert_eq!((js.width, js.height), (320, 200));
+            /// ```
+            pub fn to_js_typed_array(&self) -> BidiJsArray<T::TypedArray> {
+                BidiJsArray {
+                    array: T::to_typed_array(&self.data),
+                    width: self.width(),
+                    height: self.height(),
+                }
+            }
+
+            /// Returns a JavaScript typed array viewing the items (by rows) directly
+            /// in the WebAssembly memory, without copying them.
+            ///
+            /// Requires the `wasm` feature.
+            ///
+            /// # Safety
+            ///
+            /// The typed array is only valid as long as the data structure is not
+            /// modified or dropped, and no memory is allocated: if the WebAssembly
+            /// memory grows, its buffer is detached and the view becomes invalid.
+            /// The typed array should be consumed (or copied) by JavaScript code
+            /// right away.
+            pub unsafe fn as_js_typed_array(&self) -> BidiJsArray<T::TypedArray> {
+                BidiJsArray {
+                    array: T::view_typed_array(&self.data),
+                    width: self.width(),
+                    height: self.height(),
+                }
+            }
+
+            /// Creates a new data structure copying the items of a JavaScript typed
+            /// array, laid out by rows with the specified width.
+            ///
+            /// Returns [`BidiError::IncompatibleSize`] if the length of the typed array
+            /// is not a multiple of the width.
+            ///
+            /// Requires the `wasm` feature.
+            pub fn from_js_typed_array(
+                array: &T::TypedArray,
+                width: usize,
+            ) -> Result<Self, BidiError> {
+                let data = T::from_typed_array(array);
+                let width = if data.is_empty() { 0 } else { width };
+                Ok(BidiVec::from_vec(data, width)?.into())
+            }
+        })*
+    };
+}
+
+impl_js_arrays!(BidiVec, BidiArray);
@@ -0,0 +1,33 @@
+use crate::{BidiArray, BidiGrowVec, BidiVec};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for BidiVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<T>()?;
+        let width = if len == 0 {
+            0
+        } else {
+            u.int_in_range(1..=len)?
+        };
+        let height = len.checked_div(width).unwrap_or(0);
+
+        let mut data = Vec::with_capacity(width * height);
+        for _ in 0..width * height {
+            data.push(T::arbitrary(u)?);
+        }
+
+        Ok(BidiVec::from_vec(data, width).unwrap())
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for BidiArray<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(BidiVec::arbitrary(u)?.into_bidiarray())
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for BidiGrowVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(BidiVec::arbitrary(u)?.into_bidigrowvec())
+    }
+}
@@ -0,0 +1,17 @@
+//! A module containing support for property testing and fuzzing of code using
+//! bidimensional data structures, generating random data structures that always
+//! respect their invariants (e.g. all the rows having the same length).
+//!
+//! All the functionalities are enabled by optional features:
+//! - `arbitrary`: implementations of the `Arbitrary` trait of the `arbitrary` crate
+//!   (used by `cargo fuzz`) for [`BidiVec`][crate::BidiVec], [`BidiArray`][crate::BidiArray]
+//!   and [`BidiGrowVec`][crate::BidiGrowVec].
+//! - `proptest`: strategies for the `proptest` crate, through `bidivec_strategy`,
+//!   `bidiarray_strategy` and `bidigrowvec_strategy`.
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "proptest")]
+mod proptest;
+
+#[cfg(feature = "proptest")]
+pub use self::proptest::{bidiarray_strategy, bidigrowvec_strategy, bidivec_strategy};
@@ -0,0 +1,80 @@
+use crate::{BidiArray, BidiGrowVec, BidiVec};
+use ::proptest::collection::{vec, SizeRange};
+use ::proptest::strategy::Strategy;
+
+/// Returns a `proptest` strategy generating [`BidiVec`]s with a width in `width`,
+/// a height in `height`, and items generated by `item`. Shrinking reduces the
+/// size of the data structure first, and then its items.
+///
+/// Since a data structure with no columns has no rows either (and vice versa), if
+/// the generated width or height is zero the result is an empty data structure.
+///
+/// Requires the `proptest` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::fuzzing::bidivec_strategy;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     // in a test module, this would be marked with #[test]
+///     fn transposing_twice_is_identity(v in bidivec_strategy(1..10, 1..10, any::<u8>())) {
+///         let mut t = v.clone();
+///         t.transpose();
+///         t.transpose();
+///         prop_assert_eq!(t, v);
+///     }
+/// }
+/// transposing_twice_is_identity();
+/// ```
+pub fn bidivec_strategy<S>(
+    width: impl Into<SizeRange>,
+    height: impl Into<SizeRange>,
+    item: S,
+) -> impl Strategy<Value = BidiVec<S::Value>>
+where
+    S: Strategy + Clone,
+{
+    let (width, height) = (width.into(), height.into());
+
+    (
+        width.start()..width.end_excl(),
+        height.start()..height.end_excl(),
+    )
+        .prop_flat_map(move |(width, height)| {
+            let len = if width == 0 { 0 } else { width * height };
+            vec(item.clone(), len).prop_map(move |data| {
+                let width = if data.is_empty() { 0 } else { width };
+                BidiVec::from_vec(data, width).unwrap()
+            })
+        })
+}
+
+/// Returns a `proptest` strategy generating [`BidiArray`]s; see [`bidivec_strategy`].
+///
+/// Requires the `proptest` feature.
+pub fn bidiarray_strategy<S>(
+    width: impl Into<SizeRange>,
+    height: impl Into<SizeRange>,
+    item: S,
+) -> impl Strategy<Value = BidiArray<S::Value>>
+where
+    S: Strategy + Clone,
+{
+    bidivec_strategy(width, height, item).prop_map(BidiVec::into_bidiarray)
+}
+
+/// Returns a `proptest` strategy generating [`BidiGrowVec`]s; see [`bidivec_strategy`].
+///
+/// Requires the `proptest` feature.
+pub fn bidigrowvec_strategy<S>(
+    width: impl Into<SizeRange>,
+    height: impl Into<SizeRange>,
+    item: S,
+) -> impl Strategy<Value = BidiGrowVec<S::Value>>
+where
+    S: Strategy + Clone,
+{
+    bidivec_strategy(width, height, item).prop_map(BidiVec::into_bidigrowvec)
+}
@@ -68,6 +68,50 @@
 //!   functionality to support an externally provided data store, including in-place transformations.
 //! - [`BidiSlice`]: a bidimensional wrapper over a `&[T]` slice, with the same caveats as before,
 //!   but immutable.
+//! - [`BidiCellSlice`]: a [`BidiSlice`] over a slice of [`Cell`][std::cell::Cell]s, whose items can
+//!   be modified through shared references.
+//! - `BidiSmallVec`: a bidimensional wrapper over a `SmallVec` (from the `smallvec` crate), storing
+//!   small grids inline without allocating; requires the `smallvec` feature.
+//! - [`BidiAtomicVec`]: a fixed size grid of atomic integers, which can be updated concurrently
+//!   by many threads without locking.
+//! - [`BidiGenGrid`]: a grid of marks (e.g. the visited locations of a search) that can be
+//!   cleared in constant time, to be reused by many searches.
+//! - [`BidiPatch`]: a journal of changes that can be recorded while editing any of the above and
+//!   later applied or reverted, to support undo/redo.
+//! - [`BidiTracked`]: a wrapper over any of the above keeping track of the region modified
+//!   through it, e.g. to redraw only the changed part of a grid.
+//! - [`BidiObserved`]: a wrapper over any of the above calling a callback for every item changed
+//!   through it, e.g. to validate the changes as they happen.
+//!
+//! Data structures of different types can be compared with each other (and with any
+//! `dyn BidiView`): they are equal if they have the same width, height and items. If
+//! their items can, they also implement [`Eq`] and [`Hash`][std::hash::Hash], so that
+//! they can be used as keys of a [`HashMap`][std::collections::HashMap], and [`Ord`]
+//! (comparing the width, the height and then the items in row-major order), so that
+//! they can be sorted or stored in a [`BTreeSet`][std::collections::BTreeSet].
+//!
+//! ```
+//! use bidivec::{bidivec, bidigrowvec, BidiView};
+//! use std::collections::HashSet;
+//!
+//! let v = bidivec!{
+//!     [1, 2],
+//!     [3, 4],
+//! };
+//! let g = bidigrowvec!{
+//!     [1, 2],
+//!     [3, 4],
+//! };
+//! let view: &dyn BidiView<Output = i32> = &g;
+//!
+//! assert_eq!(v, g);
+//! assert!(v == *view);
+//!
+//! let mut seen = HashSet::new();
+//! seen.insert(v);
+//! assert!(seen.contains(&bidivec!{[1, 2], [3, 4]}));
+//! assert!(!seen.contains(&bidivec!{[1, 2, 3, 4]}));
+//! ```
 //!
 //! ## Other functionalities:
 //!
@@ -88,6 +132,41 @@
 //!   original coordinates together with items.
 //! - [Pathfinding][pathfinding] algorithms for 2D tiled maps, doing Djikstra algorithm on single source, multiple destinations and
 //!   either Djikstra or A* for singe-source, single-destination.
+//! - [Pretty printing][display] of data structures one row per line, with configurable cell formatting.
+//! - [Raw parts][ffi] with a stable C ABI, to hand bidiarrays across an FFI boundary.
+//! - A compact, versioned [binary format][formats] for grid snapshots, with optional RLE compression.
+//! - [Test helpers][testing], like [`assert_bidi_eq!`], which shows the differences between two views
+//!   as highlighted grids.
+//!
+//! ## Optional features:
+//!
+//! - `rand`: enables filling views with random values and shuffling their items, in the
+//!   [editing] module.
+//! - `noise`: enables filling views with (fractal) noise produced by a user-provided noise
+//!   function, in the [editing] module and through `BidiVec::from_noise`.
+//! - `bytemuck`: enables byte views and casts of data structures of plain old data types
+//!   (through `as_bytes`, `from_bytes` and `cast`).
+//! - `csv`: enables reading and writing CSV files through `BidiVec::from_csv_reader` and
+//!   `BidiVec::write_csv`.
+//! - `diff`: enables reading and writing [patches][BidiPatch] in a line based text format, through
+//!   `BidiPatch::write_text` and `BidiPatch::from_text_reader`.
+//! - `pnm`: enables writing views as PGM and PPM images, in the [formats] module.
+//! - `image`: enables conversions from and to the `ImageBuffer` type of the `image` crate,
+//!   in the [formats] module and through `BidiVec::from_image_buffer`.
+//! - `nalgebra`: enables conversions from and to the `DMatrix` type of the `nalgebra` crate,
+//!   in the [formats] module and through the [`From`] trait.
+//! - `smallvec`: enables `BidiSmallVec`, a linear data structure storing small grids (e.g. kernels)
+//!   inline, without heap allocations.
+//! - `synthesis`: enables the `synthesis` module, to generate new data structures from examples
+//!   using the wave function collapse algorithm (implies `rand`).
+//! - `tiled`: enables reading the tile layers of maps made with the Tiled map editor (in the JSON
+//!   format), in the [formats] module.
+//! - `wasm`: enables passing `BidiVec` and `BidiArray` to JavaScript as typed arrays (e.g. for
+//!   canvas or WebGL rendering), in the [formats] module.
+//! - `arbitrary`: enables generating data structures for fuzzing through the `Arbitrary` trait of
+//!   the `arbitrary` crate, see the [fuzzing] module.
+//! - `proptest`: enables generating data structures in property tests through `proptest` strategies,
+//!   in the [fuzzing] module.
 
 mod algorithms;
 mod areas;
@@ -95,7 +174,11 @@ pub mod bidiiter;
 mod bidiview;
 mod collections;
 mod error;
+pub mod ffi;
+pub mod formats;
+pub mod fuzzing;
 mod macros;
+pub mod testing;
 
 #[cfg(test)]
 mod tests;
@@ -103,22 +186,39 @@ mod tests;
 // areas
 pub use crate::areas::bidirect::BidiRect;
 pub use crate::areas::bidirect_signed::BidiRectSigned;
+pub use crate::areas::direction::BidiDirection;
+pub use crate::areas::hex::{BidiHex, BidiHexLayout};
+pub use crate::areas::line::BidiLine;
 pub use crate::areas::neighbours::BidiNeighbours;
+pub use crate::areas::point::{BidiPoint, BidiVector};
 
 // data structures
 pub use crate::collections::bidiarray::BidiArray;
+pub use crate::collections::bidiatomicvec::{BidiAtomic, BidiAtomicVec};
+pub use crate::collections::bidientry::BidiEntry;
+pub use crate::collections::bidigengrid::BidiGenGrid;
 pub use crate::collections::bidigrowvec::BidiGrowVec;
 pub use crate::collections::bidimutslice::BidiMutSlice;
-pub use crate::collections::bidislice::BidiSlice;
+pub use crate::collections::bidiobserved::BidiObserved;
+pub use crate::collections::bidipatch::{BidiPatch, BidiPatchEntry, BidiPatchRecorder};
+pub use crate::collections::bidislice::{BidiCellSlice, BidiSlice};
+#[cfg(feature = "smallvec")]
+pub use crate::collections::bidismallvec::BidiSmallVec;
+pub use crate::collections::biditracked::BidiTracked;
 pub use crate::collections::bidivec::BidiVec;
 
 // errors
-pub use crate::error::BidiError;
+pub use crate::error::{BidiError, BidiErrorKind, BidiIoError};
 
 // views
+pub use crate::bidiview::display;
 pub use crate::bidiview::transforming;
-pub use crate::bidiview::{BidiFrom, BidiView, BidiViewMut, BidiViewMutIterable};
+pub use crate::bidiview::{
+    BidiBuild, BidiConstruct, BidiFrom, BidiView, BidiViewMut, BidiViewMutIterable,
+};
 
 // algorithms
 pub use algorithms::editing;
 pub use algorithms::pathfinding;
+#[cfg(feature = "synthesis")]
+pub use algorithms::synthesis;
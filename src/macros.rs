@@ -6,10 +6,11 @@ use crate::*;
 /// `bidivec!` allows a [`BidiVec`] to be defined with a syntax somewhat
 /// similar to array expressions and [`vec!`] macro invocations.
 ///
-/// There are three forms of this macro:
+/// There are four forms of this macro:
 ///
 /// - Create a [`BidiVec`][crate::BidiVec] from a series of comma
-///   separated rows (from Rust 1.53 onward):
+///   separated rows (from Rust 1.53 onward), which can also be written as a
+///   nested array, like `bidivec![[1, 2], [3, 4]]`:
 ///
 /// ```
 /// # use bidivec::bidivec;
@@ -26,6 +27,16 @@ use crate::*;
 /// assert_eq!(v[(1, 1)], 4);
 /// ```
 ///
+///   Rows of different lengths are rejected at compile time:
+///
+/// ```compile_fail
+/// # use bidivec::bidivec;
+/// let v = bidivec!{
+///     [1, 2],
+///     [3],
+/// };
+/// ```
+///
 /// - Create a [`BidiVec`][crate::BidiVec] containing a given list of elements, given a width:
 ///
 /// ```
@@ -55,6 +66,19 @@ use crate::*;
 /// assert_eq!(v[(1, 1)], 1);
 /// ```
 ///
+/// - Create a [`BidiVec`][crate::BidiVec] from a closure producing the item at each
+///   coordinates, given a width and a height (see [`BidiVec::with_size_func_xy`][crate::BidiVec::with_size_func_xy]):
+///
+/// ```
+/// # use bidivec::bidivec;
+/// let v = bidivec![|x, y| x + y * 10; 3, 2];
+///
+/// assert_eq!(v.width(), 3);
+/// assert_eq!(v.height(), 2);
+/// assert_eq!(v[(2, 0)], 2);
+/// assert_eq!(v[(1, 1)], 11);
+/// ```
+///
 /// Note that unlike array expressions this syntax supports all elements
 /// which implement [`Clone`] and the width and height don't have to be
 /// constants.
@@ -69,13 +93,19 @@ macro_rules! bidivec {
     () => (
         $crate::BidiVec::new()
     );
-    ($elem:expr; $w:expr, $h:expr) => (
+    (|$x:pat, $y:pat| $f:expr; $w:expr, $h:expr $(,)?) => (
+        $crate::BidiVec::with_size_func_xy($w, $h, |$x, $y| $f)
+    );
+    ($elem:expr; $w:expr, $h:expr $(,)?) => (
         $crate::BidiVec::with_elem($elem, $w, $h)
     );
-    ($($x:expr),+ $(,)?; $w:expr) => (
+    ($($x:expr),+ $(,)?; $w:expr $(,)?) => (
         $crate::BidiVec::from_vec(vec![$($x),+], $w).unwrap()
     );
     ($([$($x:expr),+ $(,)?]),+ $(,)?) => ({
+        // rows of different lengths are arrays of different types, so they
+        // don't compile
+        let _ = [$([$(stringify!($x)),+]),+];
         let mut bv = $crate::BidiVec::new();
         $(
             bv.push_row(vec![$($x),+]).unwrap();
@@ -89,10 +119,11 @@ macro_rules! bidivec {
 /// `bidigrowvec!` allows a [`BidiGrowVec`] to be defined with a syntax somewhat
 /// similar to array expressions and [`vec!`] macro invocations.
 ///
-/// There are three forms of this macro:
+/// There are four forms of this macro:
 ///
 /// - Create a [`BidiGrowVec`][crate::BidiGrowVec] from a series of comma
-///   separated rows (from Rust 1.53 onward):
+///   separated rows (from Rust 1.53 onward), which can also be written as a
+///   nested array, like `bidigrowvec![[1, 2], [3, 4]]`:
 ///
 /// ```
 /// # use bidivec::bidigrowvec;
@@ -109,6 +140,16 @@ macro_rules! bidivec {
 /// assert_eq!(v[(1, 1)], 4);
 /// ```
 ///
+///   Rows of different lengths are rejected at compile time:
+///
+/// ```compile_fail
+/// # use bidivec::bidigrowvec;
+/// let v = bidigrowvec!{
+///     [1, 2],
+///     [3],
+/// };
+/// ```
+///
 /// - Create a [`BidiGrowVec`][crate::BidiGrowVec] containing a given list of elements, given a width:
 ///
 /// ```
@@ -138,6 +179,19 @@ macro_rules! bidivec {
 /// assert_eq!(v[(1, 1)], 1);
 /// ```
 ///
+/// - Create a [`BidiGrowVec`][crate::BidiGrowVec] from a closure producing the item at each
+///   coordinates, given a width and a height (see [`BidiGrowVec::with_size_func_xy`][crate::BidiGrowVec::with_size_func_xy]):
+///
+/// ```
+/// # use bidivec::bidigrowvec;
+/// let v = bidigrowvec![|x, y| x + y * 10; 3, 2];
+///
+/// assert_eq!(v.width(), 3);
+/// assert_eq!(v.height(), 2);
+/// assert_eq!(v[(2, 0)], 2);
+/// assert_eq!(v[(1, 1)], 11);
+/// ```
+///
 /// Note that unlike array expressions this syntax supports all elements
 /// which implement [`Clone`] and the width and height don't have to be
 /// constants.
@@ -152,13 +206,19 @@ macro_rules! bidigrowvec {
     () => (
         $crate::BidiGrowVec::new()
     );
-    ($elem:expr; $w:expr, $h:expr) => (
+    (|$x:pat, $y:pat| $f:expr; $w:expr, $h:expr $(,)?) => (
+        $crate::BidiGrowVec::with_size_func_xy($w, $h, |$x, $y| $f)
+    );
+    ($elem:expr; $w:expr, $h:expr $(,)?) => (
         $crate::BidiGrowVec::with_elem($elem, $w, $h)
     );
-    ($($x:expr),+ $(,)?; $w:expr) => (
+    ($($x:expr),+ $(,)?; $w:expr $(,)?) => (
         $crate::BidiGrowVec::from_vec(vec![$($x),+], $w).unwrap()
     );
     ($([$($x:expr),+ $(,)?]),+ $(,)?) => ({
+        // rows of different lengths are arrays of different types, so they
+        // don't compile
+        let _ = [$([$(stringify!($x)),+]),+];
         let mut bv = $crate::BidiGrowVec::new();
         $(
             bv.push_row(vec![$($x),+]).unwrap();
@@ -172,10 +232,11 @@ macro_rules! bidigrowvec {
 /// `bidiarray!` allows a [`BidiArray`] to be defined with a syntax somewhat
 /// similar to array expressions and [`vec!`] macro invocations.
 ///
-/// There are three forms of this macro:
+/// There are four forms of this macro:
 ///
 /// - Create a [`BidiArray`][crate::BidiArray] from a series of comma
-///   separated rows (from Rust 1.53 onward):
+///   separated rows (from Rust 1.53 onward), which can also be written as a
+///   nested array, like `bidiarray![[1, 2], [3, 4]]`:
 ///
 /// ```
 /// # use bidivec::bidiarray;
@@ -192,6 +253,16 @@ macro_rules! bidigrowvec {
 /// assert_eq!(v[(1, 1)], 4);
 /// ```
 ///
+///   Rows of different lengths are rejected at compile time:
+///
+/// ```compile_fail
+/// # use bidivec::bidiarray;
+/// let v = bidiarray!{
+///     [1, 2],
+///     [3],
+/// };
+/// ```
+///
 /// - Create a [`BidiArray`][crate::BidiArray] containing a given list of elements, given a width:
 ///
 /// ```
@@ -221,6 +292,19 @@ macro_rules! bidigrowvec {
 /// assert_eq!(v[(1, 1)], 1);
 /// ```
 ///
+/// - Create a [`BidiArray`][crate::BidiArray] from a closure producing the item at each
+///   coordinates, given a width and a height (see [`BidiArray::with_size_func_xy`][crate::BidiArray::with_size_func_xy]):
+///
+/// ```
+/// # use bidivec::bidiarray;
+/// let v = bidiarray![|x, y| x + y * 10; 3, 2];
+///
+/// assert_eq!(v.width(), 3);
+/// assert_eq!(v.height(), 2);
+/// assert_eq!(v[(2, 0)], 2);
+/// assert_eq!(v[(1, 1)], 11);
+/// ```
+///
 /// Note that unlike array expressions this syntax supports all elements
 /// which implement [`Clone`] and the width and height don't have to be
 /// constants.
@@ -235,13 +319,19 @@ macro_rules! bidiarray {
     () => (
         $crate::BidiArray::new()
     );
-    ($elem:expr; $w:expr, $h:expr) => (
+    (|$x:pat, $y:pat| $f:expr; $w:expr, $h:expr $(,)?) => (
+        $crate::BidiArray::with_size_func_xy($w, $h, |$x, $y| $f)
+    );
+    ($elem:expr; $w:expr, $h:expr $(,)?) => (
         $crate::BidiArray::with_elem($elem, $w, $h)
     );
-    ($($x:expr),+ $(,)?; $w:expr) => (
+    ($($x:expr),+ $(,)?; $w:expr $(,)?) => (
         $crate::BidiArray::from_vec(vec![$($x),+], $w).unwrap()
     );
     ($([$($x:expr),+ $(,)?]),+ $(,)?) => ({
+        // rows of different lengths are arrays of different types, so they
+        // don't compile
+        let _ = [$([$(stringify!($x)),+]),+];
         let mut bv = $crate::BidiVec::new();
         $(
             bv.push_row(vec![$($x),+]).unwrap();
@@ -249,3 +339,53 @@ macro_rules! bidiarray {
         bv.into_bidiarray()
     });
 }
+
+/// Asserts that two bidimensional views are equal (that is, they have the same
+/// size and items), comparing the items through [`PartialEq`].
+///
+/// On failure, the panic message contains both views as grids, with the items
+/// which differ highlighted (see [`diff_views`][crate::testing::diff_views]),
+/// which is much easier to read than the diff of two flat lists of items. The
+/// views can be of different types, as long as their items can be compared.
+///
+/// Like [`assert_eq!`], a custom message can be passed after the two views.
+///
+/// # Examples
+///
+/// ```
+/// # use bidivec::{assert_bidi_eq, bidiarray, bidivec, BidiView};
+/// let v = bidivec!{
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// };
+///
+/// assert_bidi_eq!(v.clone().to_transposed(), bidiarray!{
+///     [1, 4],
+///     [2, 5],
+///     [3, 6],
+/// });
+/// assert_bidi_eq!(v, bidivec![1, 2, 3, 4, 5, 6; 3], "rows of {}", 3);
+/// ```
+///
+/// ```should_panic
+/// # use bidivec::{assert_bidi_eq, bidivec};
+/// assert_bidi_eq!(bidivec![1, 2; 2], bidivec![1, 3; 2]);
+/// ```
+#[macro_export]
+macro_rules! assert_bidi_eq {
+    ($found:expr, $expected:expr $(,)?) => {
+        if let Some(diff) = $crate::testing::diff_views(&$found, &$expected) {
+            panic!(
+                "assertion failed: `{} == {}`\n{}",
+                stringify!($found),
+                stringify!($expected),
+                diff
+            );
+        }
+    };
+    ($found:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(diff) = $crate::testing::diff_views(&$found, &$expected) {
+            panic!("assertion failed: {}\n{}", format_args!($($arg)+), diff);
+        }
+    };
+}
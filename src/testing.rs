@@ -0,0 +1,121 @@
+//! A module containing helpers to test code working on bidimensional data
+//! structures, like the [`assert_bidi_eq!`][crate::assert_bidi_eq!] macro.
+
+use crate::BidiView;
+use std::cmp::min;
+use std::env;
+use std::fmt::{Debug, Write};
+
+const COLOR_RESTORE: &str = "\x1b[0m";
+const COLOR_ALERT: &str = "\x1b[1;31m";
+const COLOR_OK: &str = "\x1b[1;32m";
+
+struct Highlighter {
+    colors: bool,
+}
+
+impl Highlighter {
+    fn new() -> Self {
+        // see https://no-color.org
+        Self {
+            colors: env::var_os("NO_COLOR").map_or(true, |v| v.is_empty()),
+        }
+    }
+
+    fn write<D: Debug + ?Sized>(&self, out: &mut String, item: &D, color: Option<&str>) {
+        match color {
+            Some(color) if self.colors => {
+                write!(out, "{}{:?}{}", color, item, COLOR_RESTORE).unwrap()
+            }
+            Some(_) => write!(out, "*{:?}*", item).unwrap(),
+            None => write!(out, "{:?}", item).unwrap(),
+        }
+    }
+
+    fn write_size(&self, out: &mut String, label: &str, found: usize, expected: usize) {
+        let differs = found != expected;
+        out.push_str(label);
+        out.push_str(": ");
+        self.write(out, &found, Some(COLOR_ALERT).filter(|_| differs));
+        out.push_str(" found, ");
+        self.write(out, &expected, Some(COLOR_OK).filter(|_| differs));
+        out.push_str(" expected\n");
+    }
+
+    fn write_items<V>(&self, out: &mut String, view: &V, diff: &[bool], stride: usize, color: &str)
+    where
+        V: BidiView + ?Sized,
+        V::Output: Debug,
+    {
+        for y in 0..view.height() {
+            out.push('\t');
+            for x in 0..view.width() {
+                let differs = x < stride && diff.get(y * stride + x) == Some(&true);
+                self.write(out, &view[(x, y)], if differs { Some(color) } else { None });
+                out.push_str(", ");
+            }
+            out.push('\n');
+        }
+    }
+}
+
+/// Compares two views item by item, returning [`None`] if they have the same
+/// size and items, or a description of their differences otherwise.
+///
+/// The description contains the sizes and all the items of both views, with the
+/// differences highlighted through ANSI colors (or between asterisks, if the
+/// `NO_COLOR` environment variable is set). This is the comparison performed by
+/// [`assert_bidi_eq!`][crate::assert_bidi_eq!].
+///
+/// # Examples
+///
+/// ```
+/// use bidivec::{bidiarray, bidivec};
+/// use bidivec::testing::diff_views;
+///
+/// let v = bidivec!{
+///     [1, 2],
+///     [3, 4],
+/// };
+///
+/// assert!(diff_views(&v, &bidiarray![1, 2, 3, 4; 2]).is_none());
+/// assert!(diff_views(&v, &bidivec![1, 2, 3, 5; 2]).is_some());
+/// ```
+pub fn diff_views<V1, V2>(found: &V1, expected: &V2) -> Option<String>
+where
+    V1: BidiView + ?Sized,
+    V2: BidiView + ?Sized,
+    V1::Output: PartialEq<V2::Output> + Debug,
+    V2::Output: Debug,
+{
+    let width_differs = found.width() != expected.width();
+    let height_differs = found.height() != expected.height();
+
+    let stride = min(found.width(), expected.width());
+    let mut diff = vec![false; stride * min(found.height(), expected.height())];
+    let mut any_diff = false;
+
+    for y in 0..min(found.height(), expected.height()) {
+        for x in 0..stride {
+            let differs = found[(x, y)] != expected[(x, y)];
+            diff[y * stride + x] = differs;
+            any_diff |= differs;
+        }
+    }
+
+    if !any_diff && !width_differs && !height_differs {
+        return None;
+    }
+
+    let hl = Highlighter::new();
+    let mut out = String::new();
+
+    hl.write_size(&mut out, "Width", found.width(), expected.width());
+    hl.write_size(&mut out, "Height", found.height(), expected.height());
+    out.push_str("Found items:\n");
+    hl.write_items(&mut out, found, &diff, stride, COLOR_ALERT);
+    out.push_str("Expected items:\n");
+    hl.write_items(&mut out, expected, &diff, stride, COLOR_OK);
+
+    Some(out)
+}
@@ -0,0 +1,569 @@
+use super::*;
+
+/// Checks, with exact integer arithmetic, if the segment between the centers of
+/// `a` and `b` touches the (closed) square of the location `cell`.
+fn segment_touches_cell(a: (usize, usize), b: (usize, usize), cell: (usize, usize)) -> bool {
+    // coordinates are doubled, so that the edges of the cells are integers
+    let p = (2 * a.0 as i64, 2 * a.1 as i64);
+    let d = (2 * b.0 as i64 - p.0, 2 * b.1 as i64 - p.1);
+    let c = (2 * cell.0 as i64, 2 * cell.1 as i64);
+
+    // the interval of t (in [0, 1]) inside the cell, as fractions (num, den) with den > 0
+    let (mut t_min, mut t_max) = ((0i64, 1i64), (1i64, 1i64));
+
+    for &(p, d, c) in [(p.0, d.0, c.0), (p.1, d.1, c.1)].iter() {
+        let (lo, hi) = (c - 1, c + 1);
+
+        if d == 0 {
+            if p < lo || p > hi {
+                return false;
+            }
+            continue;
+        }
+
+        let (mut t0, mut t1) = ((lo - p, d), (hi - p, d));
+        if d < 0 {
+            t0 = (p - lo, -d);
+            t1 = (p - hi, -d);
+            std::mem::swap(&mut t0, &mut t1);
+        }
+
+        if t0.0 * t_min.1 > t_min.0 * t0.1 {
+            t_min = t0;
+        }
+        if t1.0 * t_max.1 < t_max.0 * t1.1 {
+            t_max = t1;
+        }
+    }
+
+    t_min.0 * t_max.1 <= t_max.0 * t_min.1
+}
+
+#[test]
+fn line_covers_exactly_the_touched_cells() {
+    for ax in 0..5 {
+        for ay in 0..5 {
+            for bx in 0..5 {
+                for by in 0..5 {
+                    let (a, b) = ((ax, ay), (bx, by));
+                    let line = BidiLine::new(a, b).collect::<Vec<_>>();
+
+                    assert_eq!(line.first(), Some(&a));
+                    assert_eq!(line.last(), Some(&b));
+
+                    let mut expected = Vec::new();
+                    for y in 0..5 {
+                        for x in 0..5 {
+                            if segment_touches_cell(a, b, (x, y)) {
+                                expected.push((x, y));
+                            }
+                        }
+                    }
+
+                    let mut sorted = line.clone();
+                    sorted.sort_by_key(|p| (p.1, p.0));
+                    sorted.dedup();
+                    assert_eq!(sorted.len(), line.len(), "{:?} -> {:?}", a, b);
+                    assert_eq!(sorted, expected, "{:?} -> {:?}", a, b);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn line_handles_large_coordinates() {
+    let max = usize::MAX;
+    let line = BidiLine::new((max, max - 2), (max - 2, max)).collect::<Vec<_>>();
+
+    assert_eq!(line.len(), 7);
+    assert_eq!(line.first(), Some(&(max, max - 2)));
+    assert_eq!(line.last(), Some(&(max - 2, max)));
+}
+
+#[test]
+fn rect_set_algebra_matches_points() {
+    let rects = [
+        BidiRect::new(0, 0, 3, 3),
+        BidiRect::new(1, 2, 4, 1),
+        BidiRect::new(3, 0, 2, 5),
+        BidiRect::new(2, 2, 0, 3),
+        BidiRect::new(0, 4, 6, 2),
+        BidiRect::new(1, 1, 1, 1),
+    ];
+
+    for a in rects.iter() {
+        for b in rects.iter() {
+            let intersection = a.intersection(b);
+            let union = a.union_bounds(b);
+            let mut common = 0;
+
+            for y in 0..8 {
+                for x in 0..8 {
+                    let (in_a, in_b) = (a.contains(x, y), b.contains(x, y));
+                    assert_eq!(
+                        intersection.as_ref().map_or(false, |r| r.contains(x, y)),
+                        in_a && in_b
+                    );
+                    if in_a || in_b {
+                        assert!(union.contains_point((x, y)));
+                    }
+                    if in_a && in_b {
+                        common += 1;
+                    }
+                }
+            }
+
+            assert_eq!(a.overlaps(b), common > 0);
+            assert_eq!(intersection.is_some(), common > 0);
+            assert!(union.contains_rect(a) && union.contains_rect(b));
+            assert_eq!(
+                a.contains_rect(b),
+                intersection.as_ref() == Some(b) || b.is_empty()
+            );
+
+            // the signed versions behave the same
+            let (sa, sb) = (a.offset(-3, -3), b.offset(-3, -3));
+            assert_eq!(sa.intersection(&sb), intersection.map(|r| r.offset(-3, -3)));
+            assert_eq!(sa.overlaps(&sb), a.overlaps(b));
+            assert_eq!(sa.contains_rect(&sb), a.contains_rect(b));
+            assert_eq!(sa.union_bounds(&sb), union.offset(-3, -3));
+        }
+    }
+}
+
+#[test]
+fn rect_translate() {
+    let r = BidiRect::new(2, 3, 4, 5);
+
+    assert_eq!(r.translate(1, -3), Some(BidiRect::new(3, 0, 4, 5)));
+    assert_eq!(r.translate(-3, 0), None);
+    assert_eq!(
+        r.translate(std::isize::MAX, 0)
+            .and_then(|r| r.translate(std::isize::MAX, 0)),
+        None
+    );
+    assert_eq!(
+        r.offset(-3, 0).translate(3, 1),
+        BidiRectSigned::new(2, 4, 4, 5)
+    );
+    assert!(BidiRect::new(5, 5, 0, 2).is_empty());
+    assert!(!BidiRect::new(5, 5, 0, 2).overlaps(&BidiRect::new(0, 0, 10, 10)));
+}
+
+#[test]
+fn rect_construction_helpers() {
+    use std::convert::TryFrom;
+
+    for &(a, b) in [((0, 0), (0, 0)), ((3, 1), (1, 4)), ((5, 5), (2, 2))].iter() {
+        let r = BidiRect::from_points(a, b);
+        assert!(r.contains_point(a) && r.contains_point(b));
+        let s =
+            BidiRectSigned::from_points((a.0 as isize, a.1 as isize), (b.0 as isize, b.1 as isize));
+        assert_eq!(BidiRect::try_from(s), Ok(r));
+    }
+
+    let r = BidiRect::new(2, 3, 4, 5);
+    assert_eq!(r.inflate(0), r);
+    assert_eq!(r.deflate(0), r);
+    assert_eq!(r.inflate(2).deflate(2), r);
+    assert_eq!(r.inflate(3), BidiRect::new(0, 0, 9, 11));
+    assert_eq!(r.deflate(2), BidiRect::new(4, 5, 0, 1));
+    assert_eq!(r.deflate(std::usize::MAX).width, 0);
+    assert_eq!(
+        r.inflate(std::usize::MAX),
+        BidiRect::new(0, 0, std::usize::MAX, std::usize::MAX)
+    );
+    assert_eq!(
+        BidiRect::new(std::usize::MAX - 3, 1, 2, 2).inflate(2),
+        BidiRect::new(std::usize::MAX - 5, 0, 5, 5)
+    );
+
+    let s = BidiRectSigned::new(-2, 1, 3, 3);
+    assert_eq!(s.inflate(1).deflate(1), s);
+    assert_eq!(
+        BidiRectSigned::new(std::isize::MIN + 1, 0, 2, 2).inflate(3),
+        BidiRectSigned::new(std::isize::MIN, -3, 6, 8)
+    );
+    assert_eq!(
+        BidiRectSigned::new(0, 0, std::usize::MAX - 1, 1).inflate(1),
+        BidiRectSigned::new(-1, -1, std::usize::MAX, 3)
+    );
+    assert_eq!(
+        s.translate(std::isize::MIN, std::isize::MAX),
+        BidiRectSigned::new(std::isize::MIN, std::isize::MAX, 3, 3)
+    );
+    assert_eq!(s.translate(3, -1), BidiRectSigned::new(1, 0, 3, 3));
+    assert_eq!(
+        BidiRect::try_from(s.clone()),
+        Err(BidiError::NegativeCoordinates { x: -2, y: 1 })
+    );
+    assert_eq!(
+        BidiRect::try_from(s.clone()).unwrap_err().to_string(),
+        "coordinates (-2, 1) are negative"
+    );
+    assert_eq!(BidiRectSigned::try_from(r.clone()), Ok(r.offset(0, 0)));
+    assert_eq!(
+        BidiRectSigned::try_from(BidiRect::new(std::usize::MAX - 1, 0, 1, 1)),
+        Err(BidiError::OutOfBounds {
+            x: std::isize::MAX,
+            y: 0,
+            width: std::isize::MAX as usize,
+            height: std::isize::MAX as usize,
+        })
+    );
+
+    let v = bidivec![0; 4, 3];
+    assert_eq!(s.clip_to(&v), Some(BidiRect::new(0, 1, 1, 2)));
+    assert_eq!(BidiRectSigned::new(-5, 0, 2, 2).clip_to(&v), None);
+    assert_eq!(
+        BidiRect::new(3, 2, 5, 5).clip_to(&v),
+        Some(BidiRect::new(3, 2, 1, 1))
+    );
+    assert_eq!(BidiRect::new(4, 0, 5, 5).clip_to(&v), None);
+}
+
+#[test]
+fn points_index_all_containers() {
+    let p = BidiPoint::new(1, 2);
+
+    let mut v = bidivec![0; 3, 4];
+    let mut a = bidiarray![0; 3, 4];
+    let mut g = bidigrowvec![0; 3, 4];
+    let mut data = vec![0; 12];
+    v[p] = 1;
+    a[p] = 2;
+    g[p] = 3;
+    {
+        let mut ms = BidiMutSlice::new(&mut data, 3).unwrap();
+        ms[p] = 4;
+        assert_eq!(ms[(1, 2)], 4);
+    }
+    let s = BidiSlice::new(&data, 3).unwrap();
+
+    assert_eq!((v[(1, 2)], a[(1, 2)], g[(1, 2)], s[p]), (1, 2, 3, 4));
+}
+
+#[test]
+fn points_and_vectors_arithmetic() {
+    let p = BidiPoint::from((3, 4));
+    let q = BidiPoint::new(5, 1);
+
+    assert_eq!(<(usize, usize)>::from(p), (3, 4));
+    assert_eq!(p.to_tuple(), (3, 4));
+    assert_eq!(q - p, BidiVector::new(2, -3));
+    assert_eq!(p.checked_add(q - p), Some(q));
+    assert_eq!(q.checked_add(p - q), Some(p));
+    assert_eq!(p.checked_add(BidiVector::new(0, -5)), None);
+    assert_eq!(
+        BidiPoint::new(std::usize::MAX, 0).checked_add(BidiVector::new(1, 0)),
+        None
+    );
+    assert_eq!(p.to_vector(), BidiVector::new(3, 4));
+
+    let mut v = BidiVector::from((1, 2));
+    v += BidiVector::new(1, 1);
+    v -= BidiVector::new(0, 5);
+    assert_eq!(v.to_tuple(), (2, -2));
+    assert_eq!(-v * 2, BidiVector::new(-4, 4));
+
+    for &n in [BidiNeighbours::Adjacent, BidiNeighbours::Bordering].iter() {
+        let mut generated = Vec::new();
+        n.generate_points_on(&mut generated, (1, 1), 3, 3);
+        let from_offsets = n
+            .offsets()
+            .iter()
+            .map(|o| BidiPoint::new(1, 1).checked_add(*o).unwrap().to_tuple())
+            .collect::<Vec<_>>();
+        assert_eq!(generated, from_offsets);
+    }
+}
+
+#[test]
+fn directions_rotate_and_step() {
+    for (i, &d) in BidiDirection::ALL.iter().enumerate() {
+        assert_eq!(d.rotate_cw_45(), BidiDirection::ALL[(i + 1) % 8]);
+        assert_eq!(d.rotate_cw_45().rotate_ccw_45(), d);
+        assert_eq!(d.rotate_cw().rotate_ccw(), d);
+        assert_eq!(d.rotate_cw().rotate_cw(), d.opposite());
+        assert_eq!(d.opposite().offset(), -d.offset());
+        assert_eq!(BidiDirection::from_offset(d.offset()), Some(d));
+        assert_eq!(d.is_diagonal(), d.offset().x != 0 && d.offset().y != 0);
+
+        // a clockwise rotation on screen (y growing downwards)
+        let (o, r) = (d.offset(), d.rotate_cw().offset());
+        assert_eq!(r, BidiVector::new(-o.y, o.x));
+    }
+
+    assert_eq!(BidiDirection::from_offset(BidiVector::new(0, 0)), None);
+    assert!(BidiDirection::all(BidiNeighbours::Adjacent)
+        .iter()
+        .all(|d| !d.is_diagonal()));
+
+    for &n in [BidiNeighbours::Adjacent, BidiNeighbours::Bordering].iter() {
+        let mut offsets = n.offsets().to_vec();
+        let mut from_dirs = BidiDirection::all(n)
+            .iter()
+            .map(|d| d.offset())
+            .collect::<Vec<_>>();
+        offsets.sort();
+        from_dirs.sort();
+        assert_eq!(offsets, from_dirs);
+    }
+
+    let v = bidivec![0; 3, 2];
+    let mut steps = BidiDirection::ALL
+        .iter()
+        .filter_map(|d| v.step(0, 1, *d))
+        .collect::<Vec<_>>();
+    steps.sort();
+    assert_eq!(steps, vec![(0, 0), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn hex_offset_roundtrip() {
+    for &layout in [BidiHexLayout::OddRows, BidiHexLayout::EvenRows].iter() {
+        for y in 0..6 {
+            for x in 0..6 {
+                let hex = BidiHex::from_offset((x, y), layout);
+                assert_eq!(hex.to_offset(layout), Some((x, y)));
+                assert_eq!(hex.r, y as isize);
+
+                // the neighbours of the axial coordinates are the hex neighbours
+                let mut axial = hex
+                    .neighbours()
+                    .iter()
+                    .filter_map(|h| h.to_offset(layout))
+                    .filter(|&(x, y)| x < 6 && y < 6)
+                    .collect::<Vec<_>>();
+                let mut offset = Vec::new();
+                BidiNeighbours::Hex(layout).generate_points_on(&mut offset, (x, y), 6, 6);
+                axial.sort();
+                offset.sort();
+                assert_eq!(axial, offset);
+
+                for n in hex.neighbours().iter() {
+                    assert_eq!(hex.distance(*n), 1);
+                }
+            }
+        }
+    }
+
+    assert_eq!(BidiHex::new(0, -1).to_offset(BidiHexLayout::OddRows), None);
+    assert_eq!(BidiHex::new(-1, 1).to_offset(BidiHexLayout::OddRows), None);
+    assert_eq!(
+        BidiHex::new(-1, 1).to_offset(BidiHexLayout::EvenRows),
+        Some((0, 1))
+    );
+}
+
+#[test]
+fn hex_distance_matches_pathfinding() {
+    for &layout in [BidiHexLayout::OddRows, BidiHexLayout::EvenRows].iter() {
+        let map = bidivec![1u32; 9, 7];
+        let start = (4, 3);
+
+        let res = pathfinding::pathfind_to_whole(
+            &map,
+            start,
+            BidiNeighbours::Hex(layout),
+            |_, _, to, _| Some(*to),
+        )
+        .unwrap();
+
+        for (x, y, tile) in res.tiles.iter().with_coords() {
+            assert_eq!(tile.cost, Some(layout.distance(start, (x, y)) as u32));
+        }
+
+        let res = pathfinding::pathfind_to_dest_heuristic(
+            &map,
+            (0, 0),
+            (8, 6),
+            BidiNeighbours::Hex(layout),
+            |_, _, to, _| Some(*to),
+            pathfinding::heuristics::hex(layout, 1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.path().unwrap().cost(),
+            layout.distance((0, 0), (8, 6)) as u32
+        );
+    }
+}
+
+#[test]
+fn rect_iter_coords() {
+    let rect = BidiRect::new(2, 1, 3, 4);
+    let view = bidivec![0; 6, 6];
+
+    let by_rows = rect.iter().collect::<Vec<_>>();
+    let expected = view
+        .iter()
+        .with_coords()
+        .on_rect(&rect)
+        .map(|(x, y, _)| (x, y))
+        .collect::<Vec<_>>();
+    assert_eq!(by_rows, expected);
+
+    let by_cols = rect.iter().by_column().collect::<Vec<_>>();
+    let expected = view
+        .iter()
+        .with_coords()
+        .by_column()
+        .on_rect(&rect)
+        .map(|(x, y, _)| (x, y))
+        .collect::<Vec<_>>();
+    assert_eq!(by_cols, expected);
+
+    let mut iter = rect.iter().by_column();
+    assert_eq!(iter.len(), 12);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 10);
+
+    let border = rect.iter_border().collect::<Vec<_>>();
+    let expected = view
+        .iter()
+        .with_coords()
+        .on_border(&rect.offset(0, 0))
+        .map(|(x, y, _)| (x, y))
+        .collect::<Vec<_>>();
+    assert_eq!(border, expected);
+    assert_eq!(border.len(), 10);
+
+    let signed = BidiRectSigned::new(-3, -2, 3, 4);
+    assert_eq!(
+        signed.iter().collect::<Vec<_>>(),
+        rect.iter()
+            .map(|(x, y)| (x as isize - 5, y as isize - 3))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        signed.iter_border().collect::<Vec<_>>(),
+        border
+            .iter()
+            .map(|&(x, y)| (x as isize - 5, y as isize - 3))
+            .collect::<Vec<_>>()
+    );
+
+    for &(w, h, count) in [
+        (0, 3, 0),
+        (3, 0, 0),
+        (1, 1, 1),
+        (1, 3, 3),
+        (3, 1, 3),
+        (2, 2, 4),
+    ]
+    .iter()
+    {
+        let rect = BidiRect::new(1, 1, w, h);
+        assert_eq!(rect.iter().count(), w * h);
+        assert_eq!(rect.iter_border().count(), count);
+    }
+}
+
+#[test]
+#[should_panic]
+fn rect_iter_by_column_after_start_panics() {
+    let mut iter = BidiRect::new(0, 0, 2, 2).iter();
+    iter.next();
+    let _ = iter.by_column();
+}
+
+#[test]
+fn rect_split_and_subdivide() {
+    let rect = BidiRect::new(2, 3, 7, 5);
+
+    assert_eq!(
+        rect.split_horizontal(0),
+        Some((BidiRect::new(2, 3, 7, 0), rect.clone()))
+    );
+    assert_eq!(
+        rect.split_vertical(7),
+        Some((rect.clone(), BidiRect::new(9, 3, 0, 5)))
+    );
+    assert_eq!(rect.split_horizontal(6), None);
+    assert_eq!(rect.split_vertical(8), None);
+
+    for &(cols, rows) in [(1, 1), (2, 3), (3, 2), (7, 5), (10, 1)].iter() {
+        let cells = rect.subdivide(cols, rows);
+        assert_eq!(cells.len(), cols * rows);
+
+        // every coordinate is in exactly one cell
+        for pos in rect.iter() {
+            assert_eq!(cells.iter().filter(|c| c.contains_point(pos)).count(), 1);
+        }
+        assert!(cells.iter().all(|c| rect.contains_rect(c) || c.is_empty()));
+
+        let widths = cells.iter().map(|c| c.width).collect::<Vec<_>>();
+        assert!(widths.iter().max().unwrap() - widths.iter().min().unwrap() <= 1);
+    }
+
+    assert!(rect.subdivide(0, 3).is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn rect_subdivide_random() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let rect = BidiRect::new(3, 1, 50, 37);
+
+    for seed in 0..20 {
+        let parts = rect.subdivide_random(4, 3, &mut StdRng::seed_from_u64(seed));
+
+        for pos in rect.iter() {
+            assert_eq!(parts.iter().filter(|c| c.contains_point(pos)).count(), 1);
+        }
+        for p in parts.iter() {
+            assert!(p.width >= 4 && p.height >= 3);
+            assert!(p.width < 8 && p.height < 6);
+        }
+    }
+
+    let small = BidiRect::new(0, 0, 3, 3);
+    assert_eq!(
+        small.subdivide_random(2, 2, &mut StdRng::seed_from_u64(1)),
+        vec![small]
+    );
+}
+
+#[test]
+fn signed_rect_intersect_view() {
+    let source = bidivec! {
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+    };
+    let view = bidivec![0; 6, 5];
+
+    for y in -4..7 {
+        for x in -5..8 {
+            let rect = BidiRectSigned::new(x, y, source.width(), source.height());
+
+            // blitting through the clipped rect and offset equals a per-item clipped copy
+            let mut clipped = view.clone();
+            if let Some((dest, (ox, oy))) = rect.intersect_view(&view) {
+                assert!(!dest.is_empty());
+                for (dx, dy) in dest.iter() {
+                    clipped[(dx, dy)] = source[(dx - dest.x + ox, dy - dest.y + oy)];
+                }
+            }
+
+            let mut expected = view.clone();
+            for (sx, sy) in rect.iter() {
+                if sx >= 0 && sy >= 0 && (sx as usize) < 6 && (sy as usize) < 5 {
+                    expected[(sx as usize, sy as usize)] =
+                        source[((sx - x) as usize, (sy - y) as usize)];
+                }
+            }
+
+            assert_eq!(clipped, expected);
+            assert_eq!(
+                rect.intersect_view(&view).is_some(),
+                rect.clip_to(&view).is_some()
+            );
+        }
+    }
+}
@@ -1330,3 +1330,72 @@ fn iterator_neighbours_adjacent_mut_clipped<T: Testable>() {
         vec![11, 12, 13, 14, 21, 22, 23, 24, 31, 32, 33, 34, 41, 42, 43, -44, 51, 52, -53, 54,]
     );
 }
+
+#[test]
+fn raw_descriptor_roundtrip() {
+    let a = helper_build_5x3::<i32>();
+    let parts = a.clone().into_raw_parts();
+
+    assert_eq!((parts.len, parts.width, parts.pitch), (15, 5, 5));
+    assert_eq!(parts.height(), 3);
+
+    let b = unsafe { BidiArray::from_raw_descriptor(parts) }.unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn raw_descriptor_empty() {
+    let parts = BidiArray::<String>::with_size_default(0, 0).into_raw_parts();
+    assert_eq!(parts.height(), 0);
+
+    let b = unsafe { BidiArray::from_raw_descriptor(parts) }.unwrap();
+    assert!(b.is_empty());
+}
+
+#[test]
+fn raw_descriptor_invalid() {
+    let mut parts = helper_build_5x3::<i32>().into_raw_parts();
+
+    parts.pitch = 6;
+    assert_err(BidiErrorKind::IncompatibleSize, unsafe {
+        BidiArray::from_raw_descriptor(parts)
+    });
+
+    parts.pitch = 4;
+    parts.width = 4;
+    assert_err(BidiErrorKind::IncompatibleSize, unsafe {
+        BidiArray::from_raw_descriptor(parts)
+    });
+
+    // reshaping is allowed
+    parts.pitch = 3;
+    parts.width = 3;
+    let b = unsafe { BidiArray::from_raw_descriptor(parts) }.unwrap();
+    assert_eq!(b.size(), (3, 5));
+}
+
+run_test_on_types!(map_moves_items on all);
+fn map_moves_items<T: Testable>() {
+    let v = helper_build_3x3::<T>().map(|t| T::new(t.id() * 2));
+    assert_layout::<T>(v, 3, 3, vec![0, 2, 4, 6, 8, 10, 12, 14, 16]);
+}
+
+#[test]
+fn from_fn_and_map() {
+    let v = BidiArray::from_fn(3, 2, |x, y| (x, y));
+    assert_eq!(v.size(), (3, 2));
+    assert_eq!(v[(2, 1)], (2, 1));
+
+    let v = v.map(|(x, y)| x * 10 + y);
+    assert_eq!(
+        v,
+        bidiarray! {
+            [0, 10, 20],
+            [1, 11, 21],
+        }
+    );
+
+    let empty = BidiArray::<i32>::from_fn(0, 0, |_, _| unreachable!()).map(|n| n + 1);
+    assert!(empty.is_empty());
+    assert_eq!(empty.validate(), Ok(()));
+}
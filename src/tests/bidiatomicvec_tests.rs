@@ -0,0 +1,78 @@
+use super::*;
+use std::sync::atomic::{AtomicI16, AtomicU32, AtomicU8, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn atomic_accessors() {
+    let v = BidiAtomicVec::<AtomicU8>::with_size_func_xy(3, 2, |x, y| (x + y * 3) as u8);
+    assert_eq!(v.size(), (3, 2));
+    assert_eq!(v.len(), 6);
+
+    assert_eq!(v.load(2, 1, Ordering::Relaxed), Ok(5));
+    v.store(2, 1, 50, Ordering::Release).unwrap();
+    assert_eq!(v.swap(2, 1, 60, Ordering::AcqRel), Ok(50));
+    assert_eq!(v.fetch_add(2, 1, 1, Ordering::AcqRel), Ok(60));
+    assert_eq!(v.fetch_sub(0, 0, 1, Ordering::AcqRel), Ok(0));
+    assert_eq!(v[(0, 0)].load(Ordering::Relaxed), 255);
+
+    assert_err(BidiErrorKind::OutOfBounds, v.load(3, 0, Ordering::Relaxed));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.store(0, 2, 1, Ordering::Relaxed),
+    );
+    assert!(v.get(0, 2).is_none());
+    assert!(!v.valid_coords(3, 1));
+
+    assert_eq!(
+        v.into_bidiarray(),
+        bidiarray! {
+            [255, 1, 2],
+            [3, 4, 61],
+        }
+    );
+}
+
+#[test]
+fn atomic_construction() {
+    let v = BidiAtomicVec::<AtomicI16>::from_view(&bidivec![-1, 2, 3, -4; 2]);
+    assert_eq!(v.snapshot(Ordering::Relaxed), bidiarray![-1, 2, 3, -4; 2]);
+    assert_eq!(v.row_slice(1).map(|row| row.len()), Some(2));
+    assert!(v.row_slice(2).is_none());
+
+    let v = BidiAtomicVec::<AtomicUsize>::with_elem(7, 2, 3);
+    assert!(v.snapshot(Ordering::Relaxed).iter().all(|&n| n == 7));
+
+    for (w, h) in [(0, 0), (3, 0), (0, 3)].iter() {
+        let v = BidiAtomicVec::<AtomicU32>::with_size_default(*w, *h);
+        assert!(v.is_empty());
+        assert_eq!(v.size(), (0, 0));
+        assert!(v.snapshot(Ordering::Relaxed).is_empty());
+    }
+}
+
+#[test]
+fn atomic_concurrent_updates() {
+    let v = Arc::new(BidiAtomicVec::<AtomicU32>::with_size_default(8, 8));
+
+    let threads = (0..4)
+        .map(|_| {
+            let v = Arc::clone(&v);
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    for y in 0..8 {
+                        for x in 0..8 {
+                            v.fetch_add(x, y, 1, Ordering::Relaxed).unwrap();
+                        }
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for t in threads {
+        t.join().unwrap();
+    }
+
+    assert!(v.snapshot(Ordering::Acquire).iter().all(|&n| n == 400));
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn mark_and_clear() {
+    let mut grid = BidiGenGrid::new(4, 3);
+    assert_eq!(grid.size(), (4, 3));
+    assert!(grid.iter().all(|&m| !m));
+
+    assert!(grid.mark(1, 2));
+    assert!(grid.mark(3, 0));
+    assert!(!grid.mark(3, 0));
+    assert!(grid[(1, 2)]);
+    assert!(grid[BidiPoint::new(3, 0)]);
+    assert_eq!(grid.get(4, 0), None);
+    assert_eq!(grid.iter().filter(|&&m| m).count(), 2);
+
+    assert!(grid.unmark(1, 2));
+    assert!(!grid.unmark(1, 2));
+    assert!(!grid.is_marked(1, 2));
+
+    grid.clear_all();
+    assert!(!grid.is_marked(3, 0));
+    assert!(grid.mark(3, 0));
+
+    grid.resize(2, 5);
+    assert_eq!(grid.size(), (2, 5));
+    assert!(grid.iter().all(|&m| !m));
+
+    let empty = BidiGenGrid::new(5, 0);
+    assert_eq!(empty.size(), (0, 0));
+}
+
+#[test]
+fn sized_to_a_view() {
+    let v = BidiVec::with_elem(0u8, 7, 2);
+    let grid = BidiGenGrid::for_view(&v);
+    assert_eq!(grid.size(), v.size());
+}
+
+#[test]
+#[should_panic]
+fn mark_out_of_bounds() {
+    BidiGenGrid::new(2, 2).mark(0, 2);
+}
@@ -155,7 +155,7 @@ run_test_on_types!(push_row_short_rollback on all);
 fn push_row_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_row([T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -164,14 +164,14 @@ fn push_row_short_rollback<T: Testable>() {
 run_test_on_types!(push_row_shortest_rollback on all);
 fn push_row_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_row([T::new(9)]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_row([T::new(9)]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(push_row_shortestest_rollback on all);
 fn push_row_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_row([]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_row([]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -179,7 +179,7 @@ run_test_on_types!(push_row_longer_rollback on all);
 fn push_row_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_row([T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -189,7 +189,7 @@ run_test_on_types!(push_row_double_rollback on all);
 fn push_row_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_row([
             T::new(9),
             T::new(10),
@@ -230,7 +230,7 @@ run_test_on_types!(push_col_short_rollback on all);
 fn push_col_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_col([T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -239,14 +239,14 @@ fn push_col_short_rollback<T: Testable>() {
 run_test_on_types!(push_col_shortest_rollback on all);
 fn push_col_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_col([T::new(9)]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_col([T::new(9)]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(push_col_shortestest_rollback on all);
 fn push_col_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_col([]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_col([]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -254,7 +254,7 @@ run_test_on_types!(push_col_longer_rollback on all);
 fn push_col_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_col([T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -264,7 +264,7 @@ run_test_on_types!(push_col_double_rollback on all);
 fn push_col_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_col([
             T::new(9),
             T::new(10),
@@ -316,7 +316,7 @@ run_test_on_types!(insert_row_short_rollback on all);
 fn insert_row_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_row(1, [T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -325,14 +325,17 @@ fn insert_row_short_rollback<T: Testable>() {
 run_test_on_types!(insert_row_shortest_rollback on all);
 fn insert_row_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_row(1, [T::new(9)]));
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        v.insert_row(1, [T::new(9)]),
+    );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(insert_row_shortestest_rollback on all);
 fn insert_row_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_row(1, []));
+    assert_err(BidiErrorKind::IncompatibleSize, v.insert_row(1, []));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -340,7 +343,7 @@ run_test_on_types!(insert_row_longer_rollback on all);
 fn insert_row_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_row(1, [T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -350,7 +353,7 @@ run_test_on_types!(insert_row_double_rollback on all);
 fn insert_row_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_row(
             1,
             [
@@ -370,7 +373,7 @@ run_test_on_types!(insert_row_outofbounds on all);
 fn insert_row_outofbounds<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::OutOfBounds,
+        BidiErrorKind::OutOfBounds,
         v.insert_row(4, [T::new(9), T::new(10), T::new(11)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -415,7 +418,7 @@ run_test_on_types!(insert_col_short_rollback on all);
 fn insert_col_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_col(1, [T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -424,14 +427,17 @@ fn insert_col_short_rollback<T: Testable>() {
 run_test_on_types!(insert_col_shortest_rollback on all);
 fn insert_col_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_col(1, [T::new(9)]));
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        v.insert_col(1, [T::new(9)]),
+    );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(insert_col_shortestest_rollback on all);
 fn insert_col_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_col(1, []));
+    assert_err(BidiErrorKind::IncompatibleSize, v.insert_col(1, []));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -439,7 +445,7 @@ run_test_on_types!(insert_col_longer_rollback on all);
 fn insert_col_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_col(1, [T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -449,7 +455,7 @@ run_test_on_types!(insert_col_double_rollback on all);
 fn insert_col_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_col(
             1,
             [
@@ -469,7 +475,7 @@ run_test_on_types!(insert_col_outofbounds on all);
 fn insert_col_outofbounds<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::OutOfBounds,
+        BidiErrorKind::OutOfBounds,
         v.insert_col(4, [T::new(9), T::new(10), T::new(11)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -611,19 +617,19 @@ fn truncate_0x3<T: Testable>() {
 run_test_on_types!(truncate_4x3 on all);
 fn truncate_4x3<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::OutOfBounds, v.truncate(4, 3))
+    assert_err(BidiErrorKind::OutOfBounds, v.truncate(4, 3))
 }
 
 run_test_on_types!(truncate_3x4 on all);
 fn truncate_3x4<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::OutOfBounds, v.truncate(3, 4))
+    assert_err(BidiErrorKind::OutOfBounds, v.truncate(3, 4))
 }
 
 run_test_on_types!(truncate_4x0 on all);
 fn truncate_4x0<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::OutOfBounds, v.truncate(4, 0))
+    assert_err(BidiErrorKind::OutOfBounds, v.truncate(4, 0))
 }
 
 run_test_on_types!(truncate_90x18 on all);
@@ -1095,6 +1101,28 @@ fn macro_invocation_bidistyle<T: Testable>() {
     assert_layout::<T>(v, 3, 2, vec![1, 2, 3, 4, 5, 6]);
 }
 
+run_test_on_types!(macro_invocation_generator on all);
+fn macro_invocation_generator<T: Testable>() {
+    let v = bidigrowvec![|x, y| T::new((x + y * 3) as i32); 3, 2];
+    assert_layout::<T>(v, 3, 2, vec![0, 1, 2, 3, 4, 5]);
+}
+
+run_test_on_types!(macro_invocation_nested_arrays on all);
+fn macro_invocation_nested_arrays<T: Testable>() {
+    let v = bidigrowvec![
+        [T::new(1), T::new(2), T::new(3),],
+        [T::new(4), T::new(5), T::new(6),],
+    ];
+    assert_layout::<T>(v, 3, 2, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn macro_invocation_trailing_commas() {
+    assert_eq!(bidigrowvec![7; 2, 1,], bidigrowvec![7, 7; 2]);
+    assert_eq!(bidigrowvec![1, 2, 3, 4; 2,], bidigrowvec![[1, 2], [3, 4]]);
+    assert_eq!(bidigrowvec![|x, _| x; 2, 2,], bidigrowvec![0, 1, 0, 1,; 2]);
+}
+
 // ==================================================
 // Tests for transpose
 // ==================================================
@@ -1478,19 +1506,28 @@ fn crop_row_9<T: Testable>() {
 run_test_on_types!(crop_err_1 on all);
 fn crop_err_1<T: Testable>() {
     let mut v = helper_build_10x10::<T>();
-    assert_err(BidiError::OutOfBounds, v.crop(&BidiRect::new(0, 10, 1, 1)));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.crop(&BidiRect::new(0, 10, 1, 1)),
+    );
 }
 
 run_test_on_types!(crop_err_2 on all);
 fn crop_err_2<T: Testable>() {
     let mut v = helper_build_10x10::<T>();
-    assert_err(BidiError::OutOfBounds, v.crop(&BidiRect::new(0, 0, 11, 1)));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.crop(&BidiRect::new(0, 0, 11, 1)),
+    );
 }
 
 run_test_on_types!(crop_err_3 on all);
 fn crop_err_3<T: Testable>() {
     let mut v = helper_build_10x10::<T>();
-    assert_err(BidiError::OutOfBounds, v.crop(&BidiRect::new(0, 5, 5, 6)));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.crop(&BidiRect::new(0, 5, 5, 6)),
+    );
 }
 
 // ==================================================
@@ -2365,3 +2402,123 @@ fn iterator_neighbours_adjacent_mut_clipped<T: Testable>() {
         vec![11, 12, 13, 14, 21, 22, 23, 24, 31, 32, 33, 34, 41, 42, 43, -44, 51, 52, -53, 54,]
     );
 }
+
+run_test_on_types!(row_pool_reuses_deleted_rows on all);
+fn row_pool_reuses_deleted_rows<T: Testable>() {
+    let mut b = helper_build_4x5::<T>();
+    b.enable_row_pool();
+
+    b.delete_row(1).unwrap();
+    b.delete_last_row();
+    assert_eq!(b.pooled_rows(), 2);
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        b.push_row(vec![T::new(1), T::new(2)]),
+    );
+    assert_eq!(b.pooled_rows(), 2);
+
+    b.insert_row(0, (1..=4).map(T::new)).unwrap();
+    b.push_row((5..=8).map(T::new)).unwrap();
+    assert_eq!(b.pooled_rows(), 0);
+
+    let v = b.iter().map(|t| t.id()).collect::<Vec<i32>>();
+    assert_eq!(
+        v,
+        vec![1, 2, 3, 4, 11, 12, 13, 14, 31, 32, 33, 34, 41, 42, 43, 44, 5, 6, 7, 8]
+    );
+
+    b.crop(&BidiRect::new(1, 1, 2, 2)).unwrap();
+    assert_eq!(b.pooled_rows(), 3);
+
+    b.clear();
+    assert_eq!(b.pooled_rows(), 5);
+
+    b.shrink_row_pool();
+    assert_eq!(b.pooled_rows(), 0);
+    assert!(b.has_row_pool());
+}
+
+#[test]
+fn row_pool_is_not_part_of_the_value() {
+    let mut a = bidigrowvec![1; 3, 3];
+    let b = a.clone();
+
+    a.enable_row_pool();
+    a.push_row(vec![2; 3]).unwrap();
+    a.delete_last_row();
+
+    assert_eq!(a.pooled_rows(), 1);
+    assert_eq!(a, b);
+    assert_eq!(format!("{:?}", a), format!("{:?}", b));
+
+    let c = a.clone();
+    assert!(c.has_row_pool());
+    assert_eq!(c.pooled_rows(), 0);
+
+    a.disable_row_pool();
+    a.delete_last_row();
+    assert_eq!(a.pooled_rows(), 0);
+    assert!(!a.has_row_pool());
+}
+
+#[test]
+fn validate_detects_ragged_rows() {
+    let mut v = bidigrowvec![0; 3, 2];
+    assert_eq!(v.validate(), Ok(()));
+
+    v.data[1].pop();
+    assert_eq!(
+        v.validate(),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 2
+        })
+    );
+
+    v.data = vec![Vec::new()];
+    assert_err(BidiErrorKind::IncompatibleSize, v.validate());
+}
+
+#[test]
+fn from_rows_and_cols() {
+    let v = BidiGrowVec::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        v,
+        bidigrowvec! {
+            [1, 2, 3],
+            [4, 5, 6],
+        }
+    );
+
+    let v = BidiGrowVec::from_cols(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        v,
+        bidigrowvec! {
+            [1, 4],
+            [2, 5],
+            [3, 6],
+        }
+    );
+
+    assert_eq!(
+        BidiGrowVec::from_rows(vec![vec![1, 2, 3], vec![4, 5]]),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiGrowVec::from_rows(vec![vec![], vec![1]]),
+    );
+
+    let v = BidiGrowVec::<i32>::from_rows(vec![vec![], vec![]]).unwrap();
+    assert!(v.is_empty());
+    assert_eq!(v.validate(), Ok(()));
+
+    let mut v = BidiGrowVec::<i32>::new();
+    v.push_row(vec![]).unwrap();
+    assert!(v.is_empty());
+    assert_eq!(v.validate(), Ok(()));
+}
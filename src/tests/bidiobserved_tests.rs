@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn observe_set_and_index_mut() {
+    let mut v = BidiVec::with_elem(0, 3, 3);
+    let mut changes = Vec::new();
+
+    {
+        let mut observed = BidiObserved::new(&mut v, |x, y, old: &i32, new: &i32| {
+            changes.push((x, y, *old, *new))
+        });
+
+        observed.set(1, 1, 3).unwrap();
+        // unchanged items are not notified
+        observed.set(2, 2, 0).unwrap();
+        assert_err(BidiErrorKind::OutOfBounds, observed.set(3, 0, 1));
+
+        observed[(0, 2)] = 4;
+        observed[(0, 2)] += 1;
+        observed[(2, 0)] = 0;
+        // the pending change of (0, 2) is notified before the new one
+        observed.set(0, 2, 6).unwrap();
+
+        for item in observed.iter_mut().on_row(0) {
+            *item = 7;
+        }
+        observed.flush();
+        observed.flush();
+    }
+
+    assert_eq!(
+        changes,
+        vec![
+            (1, 1, 0, 3),
+            (0, 2, 0, 5),
+            (0, 2, 5, 6),
+            (0, 0, 0, 7),
+            (1, 0, 0, 7),
+            (2, 0, 0, 7),
+        ]
+    );
+}
+
+#[test]
+fn observe_editing_functions() {
+    let mut v = bidivec! {
+        [1, 1, 2],
+        [1, 2, 2],
+    };
+    let mut count = 0;
+
+    let mut observed = BidiObserved::new(&mut v, |_, _, old: &i32, new: &i32| {
+        assert_eq!((*old, *new), (1, 9));
+        count += 1;
+    });
+    editing::flood_fill(
+        &mut observed,
+        (0, 0),
+        BidiNeighbours::Adjacent,
+        |_, a, b| a == b,
+        |item, _| *item = 9,
+    )
+    .unwrap();
+    drop(observed);
+
+    assert_eq!(count, 3);
+    assert_eq!(
+        v,
+        bidivec! {
+            [9, 9, 2],
+            [9, 2, 2],
+        }
+    );
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+#[test]
+fn record_through_iter_mut() {
+    let mut v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+        [7, 8, 9],
+    };
+
+    let mut recorder = BidiPatch::record(&mut v);
+    for item in recorder.iter_mut().on_column(1) {
+        *item *= 10;
+    }
+    // accessed but not changed, must not be recorded
+    recorder[(0, 0)] = 1;
+    let patch = recorder.finish();
+
+    assert_eq!(patch.len(), 3);
+    assert_eq!(patch.bounding_rect(), Some(BidiRect::new(1, 0, 1, 3)));
+    assert_eq!(
+        v,
+        bidivec! {
+            [1, 20, 3],
+            [4, 50, 6],
+            [7, 80, 9],
+        }
+    );
+
+    patch.revert(&mut v).unwrap();
+    assert_eq!(
+        v,
+        bidivec! {
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9],
+        }
+    );
+}
+
+#[test]
+fn apply_out_of_bounds_is_atomic() {
+    let mut patch = BidiPatch::new();
+    patch.push(0, 0, 1, 10);
+    patch.push(5, 5, 1, 10);
+
+    let mut v = bidivec![1; 2, 2];
+
+    assert_err(BidiErrorKind::OutOfBounds, patch.apply(&mut v));
+    assert_err(BidiErrorKind::OutOfBounds, patch.revert(&mut v));
+    assert_eq!(v, bidivec![1; 2, 2]);
+}
+
+#[test]
+fn diff_and_invert() {
+    let a = bidiarray! {
+        [1, 2],
+        [3, 4],
+    };
+    let b = bidigrowvec! {
+        [0, 2],
+        [3, 0],
+    };
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiPatch::from_diff(&a, &bidivec![0; 3, 2]),
+    );
+
+    let patch = BidiPatch::from_diff(&a, &b).unwrap();
+    assert_eq!(patch.len(), 2);
+
+    let mut v = a.clone();
+    patch.apply(&mut v).unwrap();
+    assert!(v.equivalent(&b));
+
+    patch.into_inverted().apply(&mut v).unwrap();
+    assert_eq!(v, a);
+}
@@ -929,3 +929,58 @@ fn iterator_neighbours_adjacent_clipped_2<T: Testable>() {
 
     assert_eq!(v, vec![44, 53]);
 }
+
+#[test]
+fn cell_slices_mutate_through_shared_references() {
+    let mut v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+
+    {
+        let cells = v.as_cell_slice();
+        assert_eq!(cells.size(), (3, 2));
+
+        let increment_neighbours = |x: usize, y: usize| {
+            for (dx, dy) in [(0, 1), (1, 0)].iter() {
+                if let Some(cell) = cells.get(x + dx, y + dy) {
+                    cell.set(cell.get() + 10);
+                }
+            }
+        };
+        increment_neighbours(0, 0);
+        increment_neighbours(2, 0);
+
+        assert_eq!(cells.replace(1, 0, 20).unwrap(), 12);
+        cells.set(0, 0, 0).unwrap();
+        assert_err(BidiErrorKind::OutOfBounds, cells.set(3, 0, 0));
+        assert_err(BidiErrorKind::OutOfBounds, cells.replace(0, 2, 0));
+    }
+
+    assert_eq!(
+        v,
+        bidivec! {
+            [0, 20, 3],
+            [14, 5, 16],
+        }
+    );
+
+    let mut a = v.clone().into_bidiarray();
+    a.as_cell_slice().set(1, 1, 50).unwrap();
+    assert_eq!(a[(1, 1)], 50);
+
+    let mut data = v.into_vec();
+    let mut slice = BidiMutSlice::new(&mut data, 2).unwrap();
+    slice.as_cell_slice().set(1, 2, 60).unwrap();
+    assert_eq!(data[5], 60);
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiCellSlice::from_mut_slice(&mut [1, 2, 3], 2),
+    );
+
+    let mut empty = BidiVec::<i32>::new();
+    let cells = empty.as_cell_slice();
+    assert!(cells.is_empty());
+    assert_eq!(cells.size(), (0, 0));
+}
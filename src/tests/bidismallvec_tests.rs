@@ -0,0 +1,136 @@
+use super::test_types::*;
+use super::*;
+use crate::run_test_on_types;
+
+fn helper_build_3x3<T: Testable>() -> BidiSmallVec<[T; 9]> {
+    BidiSmallVec::from_iterator((0..9).map(T::new), 3).unwrap()
+}
+
+fn helper_build_4x5<T: Testable>() -> BidiSmallVec<[T; 9]> {
+    BidiSmallVec::with_size_func_xy(4, 5, |x, y| T::new((y as i32 + 1) * 10 + x as i32 + 1))
+}
+
+run_test_on_types!(small_grids_are_inline on all);
+fn small_grids_are_inline<T: Testable>() {
+    let b = helper_build_3x3::<T>();
+
+    assert!(!b.spilled());
+    assert_eq!(b.width(), 3);
+    assert_eq!(b.height(), 3);
+    assert_eq!(b[(2, 1)].id(), 5);
+    assert!(b.get(3, 0).is_none());
+    assert!(b.get(0, 3).is_none());
+
+    let v = b.iter().map(|t| t.id()).collect::<Vec<i32>>();
+    assert_eq!(v, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+run_test_on_types!(large_grids_spill on all);
+fn large_grids_spill<T: Testable>() {
+    let mut b = helper_build_4x5::<T>();
+
+    assert!(b.spilled());
+    assert_eq!(b.width(), 4);
+    assert_eq!(b.height(), 5);
+    assert_eq!(b[(3, 4)].id(), 54);
+
+    b.transpose();
+    assert_eq!(b.width(), 5);
+    assert_eq!(b.height(), 4);
+    assert_eq!(b[(4, 3)].id(), 54);
+    assert_eq!(b[(1, 0)].id(), 21);
+}
+
+run_test_on_types!(conversions_move_back_inline on all);
+fn conversions_move_back_inline<T: Testable>() {
+    let v = bidivec! {
+        [T::new(1), T::new(2)],
+        [T::new(3), T::new(4)],
+    };
+
+    let b = BidiSmallVec::<[T; 9]>::from(v);
+    assert!(!b.spilled());
+    assert_eq!(b[(1, 1)].id(), 4);
+
+    let v = b.into_bidivec();
+    assert_eq!(v.width(), 2);
+    let ids = v.iter().map(|t| t.id()).collect::<Vec<i32>>();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+
+    let big = BidiSmallVec::<[T; 2]>::from_vec((0..6).map(T::new).collect(), 3).unwrap();
+    assert!(big.spilled());
+    assert_eq!(big.height(), 2);
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiSmallVec::<[T; 9]>::from_vec(vec![T::new(1), T::new(2), T::new(3)], 2),
+    );
+}
+
+run_test_on_types!(rotations_and_reversals on all);
+fn rotations_and_reversals<T: Testable>() {
+    let mut b = helper_build_3x3::<T>();
+
+    b.rotate90ccw();
+    let v = b.iter().map(|t| t.id()).collect::<Vec<i32>>();
+    assert_eq!(v, vec![2, 5, 8, 1, 4, 7, 0, 3, 6]);
+
+    b.rotate270ccw();
+    b.rotate180();
+    let v = b.iter().map(|t| t.id()).collect::<Vec<i32>>();
+    assert_eq!(v, vec![8, 7, 6, 5, 4, 3, 2, 1, 0]);
+
+    b.reverse_rows();
+    b.reverse_columns();
+    let v = b.iter().map(|t| t.id()).collect::<Vec<i32>>();
+    assert_eq!(v, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn editing_on_smallvec() {
+    let src = BidiSmallVec::<[i32; 9]>::from_iterator(0..9, 3).unwrap();
+    let mut dest = BidiSmallVec::<[i32; 16]>::with_elem(-1, 4, 4);
+
+    editing::copy(&src, &mut dest, &BidiRect::new(0, 0, 3, 3), (1, 1)).unwrap();
+
+    assert!(!dest.spilled());
+    assert_eq!(dest[(0, 0)], -1);
+    assert_eq!(dest[(1, 1)], 0);
+    assert_eq!(dest[(3, 3)], 8);
+    assert_eq!(
+        editing::count_in_rect(&dest, &BidiRect::new(1, 1, 3, 3), &-1),
+        0
+    );
+
+    for item in dest.iter_mut() {
+        *item *= 2;
+    }
+    assert_eq!(dest[(3, 3)], 16);
+    assert_eq!(editing::count(&dest, &-2), 7);
+}
+
+#[test]
+fn rows_of_smallvec() {
+    let v = BidiSmallVec::<[i32; 8]>::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    assert_eq!(v.as_rows(), vec![&[1, 2], &[3, 4]]);
+    assert_eq!(v.into_rows(), vec![vec![1, 2], vec![3, 4]]);
+}
+
+#[test]
+fn for_each_row_and_col_mut() {
+    let mut b = BidiSmallVec::<[usize; 12]>::with_size_func_xy(4, 3, |x, y| x + y * 4);
+
+    b.for_each_row_mut(|y, row| {
+        assert_eq!(row, &[y * 4, y * 4 + 1, y * 4 + 2, y * 4 + 3]);
+        row.reverse();
+    });
+    b.for_each_col_mut(|x, col| {
+        assert_eq!(col, &[3 - x, 7 - x, 11 - x]);
+        col.iter_mut().for_each(|item| *item *= 10);
+    });
+
+    let expected = (0..3)
+        .flat_map(|y| (0..4).map(move |x| (3 - x + y * 4) * 10))
+        .collect::<Vec<_>>();
+    assert_eq!(b.into_vec(), expected);
+}
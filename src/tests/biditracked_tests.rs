@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn track_through_index_and_iter_mut() {
+    let mut tracked = BidiTracked::new(BidiVec::with_elem(0, 8, 6));
+    assert!(!tracked.is_dirty());
+
+    tracked[(3, 2)] = 1;
+    assert_eq!(tracked.dirty(), Some(&BidiRect::new(3, 2, 1, 1)));
+
+    for item in tracked.iter_mut().on_row(4) {
+        *item = 2;
+    }
+    assert_eq!(tracked.take_dirty(), Some(BidiRect::new(0, 2, 8, 3)));
+    assert_eq!(tracked.take_dirty(), None);
+
+    // out of bounds accesses must not change the dirty region
+    assert!(tracked.get_mut(8, 0).is_none());
+    assert!(tracked.get_mut(0, 6).is_none());
+    assert!(!tracked.is_dirty());
+
+    let v = tracked.into_inner();
+    assert_eq!(v[(3, 2)], 1);
+    assert_eq!(v[(5, 4)], 2);
+}
+
+#[test]
+fn track_editing_functions() {
+    let src = BidiArray::with_elem(7, 2, 2);
+    let mut tracked = BidiTracked::new(BidiVec::with_elem(0, 10, 10));
+
+    editing::copy(&src, &mut tracked, &BidiRect::new(0, 0, 2, 2), (4, 6)).unwrap();
+    assert_eq!(tracked.take_dirty(), Some(BidiRect::new(4, 6, 2, 2)));
+
+    tracked.mark_dirty(&BidiRect::new(8, 8, 5, 5));
+    assert_eq!(tracked.take_dirty(), Some(BidiRect::new(8, 8, 2, 2)));
+
+    tracked.mark_dirty(&BidiRect::new(10, 0, 1, 1));
+    assert_eq!(tracked.take_dirty(), None);
+
+    assert_eq!(tracked.inner()[(5, 7)], 7);
+}
+
+#[test]
+fn track_mut_slices() {
+    let mut data = vec![0; 12];
+    let mut tracked = BidiTracked::new(BidiMutSlice::new(&mut data, 4).unwrap());
+
+    tracked[(0, 0)] = 1;
+    tracked[(3, 2)] = 1;
+    assert_eq!(tracked.take_dirty(), Some(BidiRect::new(0, 0, 4, 3)));
+
+    assert_eq!(data[11], 1);
+}
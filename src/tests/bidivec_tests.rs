@@ -155,7 +155,7 @@ run_test_on_types!(push_row_short_rollback on all);
 fn push_row_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_row([T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -164,14 +164,14 @@ fn push_row_short_rollback<T: Testable>() {
 run_test_on_types!(push_row_shortest_rollback on all);
 fn push_row_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_row([T::new(9)]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_row([T::new(9)]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(push_row_shortestest_rollback on all);
 fn push_row_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_row([]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_row([]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -179,7 +179,7 @@ run_test_on_types!(push_row_longer_rollback on all);
 fn push_row_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_row([T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -189,7 +189,7 @@ run_test_on_types!(push_row_double_rollback on all);
 fn push_row_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_row([
             T::new(9),
             T::new(10),
@@ -230,7 +230,7 @@ run_test_on_types!(push_col_short_rollback on all);
 fn push_col_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_col([T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -239,14 +239,14 @@ fn push_col_short_rollback<T: Testable>() {
 run_test_on_types!(push_col_shortest_rollback on all);
 fn push_col_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_col([T::new(9)]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_col([T::new(9)]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(push_col_shortestest_rollback on all);
 fn push_col_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.push_col([]));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_col([]));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -254,17 +254,59 @@ run_test_on_types!(push_col_longer_rollback on all);
 fn push_col_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_col([T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn push_and_insert_col_error_context() {
+    let mut v = BidiVec::with_elem(0, 3, 3);
+
+    assert_eq!(
+        v.push_col(vec![]),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 0
+        })
+    );
+    assert_eq!(
+        v.push_col(vec![1, 2]),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        v.push_col(vec![1, 2, 3, 4, 5]),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 4
+        })
+    );
+    assert_eq!(
+        v.insert_col(1, vec![1]),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 1
+        })
+    );
+    assert_eq!(
+        v.insert_col(1, 0..),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 4
+        })
+    );
+    assert_eq!(v, BidiVec::with_elem(0, 3, 3));
+}
+
 run_test_on_types!(push_col_double_rollback on all);
 fn push_col_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.push_col([
             T::new(9),
             T::new(10),
@@ -316,7 +358,7 @@ run_test_on_types!(insert_row_short_rollback on all);
 fn insert_row_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_row(1, [T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -325,14 +367,17 @@ fn insert_row_short_rollback<T: Testable>() {
 run_test_on_types!(insert_row_shortest_rollback on all);
 fn insert_row_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_row(1, [T::new(9)]));
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        v.insert_row(1, [T::new(9)]),
+    );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(insert_row_shortestest_rollback on all);
 fn insert_row_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_row(1, []));
+    assert_err(BidiErrorKind::IncompatibleSize, v.insert_row(1, []));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -340,7 +385,7 @@ run_test_on_types!(insert_row_longer_rollback on all);
 fn insert_row_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_row(1, [T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -350,7 +395,7 @@ run_test_on_types!(insert_row_double_rollback on all);
 fn insert_row_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_row(
             1,
             [
@@ -370,7 +415,7 @@ run_test_on_types!(insert_row_outofbounds on all);
 fn insert_row_outofbounds<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::OutOfBounds,
+        BidiErrorKind::OutOfBounds,
         v.insert_row(4, [T::new(9), T::new(10), T::new(11)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -415,7 +460,7 @@ run_test_on_types!(insert_col_short_rollback on all);
 fn insert_col_short_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_col(1, [T::new(9), T::new(10)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -424,14 +469,17 @@ fn insert_col_short_rollback<T: Testable>() {
 run_test_on_types!(insert_col_shortest_rollback on all);
 fn insert_col_shortest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_col(1, [T::new(9)]));
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        v.insert_col(1, [T::new(9)]),
+    );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
 run_test_on_types!(insert_col_shortestest_rollback on all);
 fn insert_col_shortestest_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::IncompatibleSize, v.insert_col(1, []));
+    assert_err(BidiErrorKind::IncompatibleSize, v.insert_col(1, []));
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
@@ -439,7 +487,7 @@ run_test_on_types!(insert_col_longer_rollback on all);
 fn insert_col_longer_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_col(1, [T::new(9), T::new(10), T::new(11), T::new(12)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -449,7 +497,7 @@ run_test_on_types!(insert_col_double_rollback on all);
 fn insert_col_double_rollback<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::IncompatibleSize,
+        BidiErrorKind::IncompatibleSize,
         v.insert_col(
             1,
             [
@@ -469,12 +517,44 @@ run_test_on_types!(insert_col_outofbounds on all);
 fn insert_col_outofbounds<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
     assert_err(
-        BidiError::OutOfBounds,
+        BidiErrorKind::OutOfBounds,
         v.insert_col(4, [T::new(9), T::new(10), T::new(11)]),
     );
     assert_layout::<T>(v, 3, 3, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn insert_and_push_cols_wide() {
+    let mut v = BidiVec::with_size_func_xy(40, 6, |x, y| (x * 2, y));
+
+    v.insert_col(0, (0..6).map(|y| (1, y))).unwrap();
+    v.insert_col(20, (0..6).map(|y| (39, y))).unwrap();
+    v.push_col((0..6).map(|y| (100, y))).unwrap();
+
+    // wrong sizes leave the bidivec untouched
+    let before = v.clone();
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        v.insert_col(3, (0..5).map(|y| (0, y))),
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        v.push_col((0..).map(|y| (0, y))),
+    );
+    assert_eq!(v, before);
+
+    let mut expected_cols = (0..40).map(|x| x * 2).collect::<Vec<_>>();
+    expected_cols.insert(0, 1);
+    expected_cols.insert(20, 39);
+    expected_cols.push(100);
+
+    assert_eq!(v.width(), 43);
+    assert_eq!(v.height(), 6);
+    for (x, y, item) in v.iter().with_coords() {
+        assert_eq!(*item, (expected_cols[x], y));
+    }
+}
+
 // ==================================================
 // Tests for delete_last_row
 // ==================================================
@@ -611,19 +691,19 @@ fn truncate_0x3<T: Testable>() {
 run_test_on_types!(truncate_4x3 on all);
 fn truncate_4x3<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::OutOfBounds, v.truncate(4, 3))
+    assert_err(BidiErrorKind::OutOfBounds, v.truncate(4, 3))
 }
 
 run_test_on_types!(truncate_3x4 on all);
 fn truncate_3x4<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::OutOfBounds, v.truncate(3, 4))
+    assert_err(BidiErrorKind::OutOfBounds, v.truncate(3, 4))
 }
 
 run_test_on_types!(truncate_4x0 on all);
 fn truncate_4x0<T: Testable>() {
     let mut v = helper_build_3x3::<T>();
-    assert_err(BidiError::OutOfBounds, v.truncate(4, 0))
+    assert_err(BidiErrorKind::OutOfBounds, v.truncate(4, 0))
 }
 
 run_test_on_types!(truncate_90x18 on all);
@@ -1080,6 +1160,30 @@ fn remove_col_middle_last<T: Testable>() {
     assert_layout::<T>(v, 1, 3, vec![0, 3, 6]);
 }
 
+#[test]
+fn remove_and_delete_cols_wide() {
+    let mut v = BidiVec::with_size_func_xy(50, 7, |x, y| (x, y));
+
+    assert_eq!(
+        v.remove_col(10).unwrap(),
+        (0..7).map(|y| (10, y)).collect::<Vec<_>>()
+    );
+    v.delete_col(0).unwrap();
+    assert_eq!(
+        v.pop_col().unwrap(),
+        (0..7).map(|y| (49, y)).collect::<Vec<_>>()
+    );
+    v.delete_last_col();
+    v.truncate(40, 5).unwrap();
+
+    let expected_cols = (1..49).filter(|x| *x != 10).take(40).collect::<Vec<_>>();
+    assert_eq!(v.width(), 40);
+    assert_eq!(v.height(), 5);
+    for (x, y, item) in v.iter().with_coords() {
+        assert_eq!(*item, (expected_cols[x], y));
+    }
+}
+
 // ==================================================
 // Tests for bidivec!
 // ==================================================
@@ -1111,6 +1215,28 @@ fn macro_invocation_bidistyle<T: Testable>() {
     assert_layout::<T>(v, 3, 2, vec![1, 2, 3, 4, 5, 6]);
 }
 
+run_test_on_types!(macro_invocation_generator on all);
+fn macro_invocation_generator<T: Testable>() {
+    let v = bidivec![|x, y| T::new((x + y * 3) as i32); 3, 2];
+    assert_layout::<T>(v, 3, 2, vec![0, 1, 2, 3, 4, 5]);
+}
+
+run_test_on_types!(macro_invocation_nested_arrays on all);
+fn macro_invocation_nested_arrays<T: Testable>() {
+    let v = bidivec![
+        [T::new(1), T::new(2), T::new(3),],
+        [T::new(4), T::new(5), T::new(6),],
+    ];
+    assert_layout::<T>(v, 3, 2, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn macro_invocation_trailing_commas() {
+    assert_eq!(bidivec![7; 2, 1,], bidivec![7, 7; 2]);
+    assert_eq!(bidivec![1, 2, 3, 4; 2,], bidivec![[1, 2], [3, 4]]);
+    assert_eq!(bidivec![|x, _| x; 2, 2,], bidivec![0, 1, 0, 1,; 2]);
+}
+
 // ==================================================
 // Tests for transpose
 // ==================================================
@@ -1162,6 +1288,46 @@ fn transpose_1x5<T: Testable>() {
     assert_layout::<T>(v, 5, 1, vec![11, 21, 31, 41, 51]);
 }
 
+#[test]
+fn transpose_square_blocked() {
+    for &size in &[1, 2, 5, 17, 100] {
+        for &block_size in &[0, 1, 2, 3, 7, 64, 1000] {
+            let mut v = BidiVec::with_size_func_xy(size, size, |x, y| (x, y));
+            v.transpose_with_block_size(block_size);
+
+            for (x, y, item) in v.iter().with_coords() {
+                assert_eq!(*item, (y, x));
+            }
+        }
+    }
+
+    let mut v = BidiVec::with_size_func_xy(300, 300, |x, y| (x, y));
+    v.rotate90ccw();
+    for (x, y, item) in v.iter().with_coords() {
+        assert_eq!(*item, (299 - y, x));
+    }
+    v.rotate270ccw();
+    assert_eq!(v, BidiVec::with_size_func_xy(300, 300, |x, y| (x, y)));
+}
+
+#[test]
+fn transpose_large_non_square() {
+    // sizes chosen so that the permutation has many cycles of different lengths
+    for &(width, height) in &[(2, 64), (70, 130), (129, 65), (97, 3)] {
+        let mut v = BidiVec::with_size_func_xy(width, height, |x, y| (x, y));
+        v.transpose();
+
+        assert_eq!(v.width(), height);
+        assert_eq!(v.height(), width);
+        for (x, y, item) in v.iter().with_coords() {
+            assert_eq!(*item, (y, x));
+        }
+
+        v.transpose();
+        assert_eq!(v, BidiVec::with_size_func_xy(width, height, |x, y| (x, y)));
+    }
+}
+
 // ==================================================
 // Tests for rotate90ccw
 // ==================================================
@@ -1494,19 +1660,50 @@ fn crop_row_9<T: Testable>() {
 run_test_on_types!(crop_err_1 on all);
 fn crop_err_1<T: Testable>() {
     let mut v = helper_build_10x10::<T>();
-    assert_err(BidiError::OutOfBounds, v.crop(&BidiRect::new(0, 10, 1, 1)));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.crop(&BidiRect::new(0, 10, 1, 1)),
+    );
 }
 
 run_test_on_types!(crop_err_2 on all);
 fn crop_err_2<T: Testable>() {
     let mut v = helper_build_10x10::<T>();
-    assert_err(BidiError::OutOfBounds, v.crop(&BidiRect::new(0, 0, 11, 1)));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.crop(&BidiRect::new(0, 0, 11, 1)),
+    );
 }
 
 run_test_on_types!(crop_err_3 on all);
 fn crop_err_3<T: Testable>() {
     let mut v = helper_build_10x10::<T>();
-    assert_err(BidiError::OutOfBounds, v.crop(&BidiRect::new(0, 5, 5, 6)));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.crop(&BidiRect::new(0, 5, 5, 6)),
+    );
+}
+
+run_test_on_types!(crop_whole_10x10 on all);
+fn crop_whole_10x10<T: Testable>() {
+    let mut v = helper_build_10x10::<T>();
+    v.crop(&BidiRect::new(0, 0, 10, 10)).expect("Crop failed");
+    assert_layout::<T>(v, 10, 10, (0..100).collect());
+}
+
+#[test]
+fn crop_keeps_allocation() {
+    let mut v = BidiVec::with_size_func_xy(300, 200, |x, y| (x, y));
+    let capacity = v.capacity();
+
+    v.crop(&BidiRect::new(120, 30, 50, 70)).unwrap();
+
+    assert_eq!(v.capacity(), capacity);
+    assert_eq!(v.width(), 50);
+    assert_eq!(v.height(), 70);
+    for (x, y, item) in v.iter().with_coords() {
+        assert_eq!(*item, (x + 120, y + 30));
+    }
 }
 
 // ==================================================
@@ -1576,6 +1773,101 @@ fn iterator_basic<T: Testable>() {
     );
 }
 
+#[test]
+fn iterators_on_contiguous_rows_match_views() {
+    let v = BidiVec::with_size_func_xy(7, 5, |x, y| (x, y));
+    let rect = BidiRect::new(2, 1, 4, 3);
+    let in_rect = |&&(x, y): &&(usize, usize)| rect.contains(x, y);
+
+    // the transposed twice view doesn't have contiguous rows
+    let all = v.as_slice(..).to_vec();
+    let transposed = v.clone().to_transposed().to_transposed();
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), all);
+    assert_eq!(transposed.iter().copied().collect::<Vec<_>>(), all);
+    assert_eq!(
+        BidiGrowVec::from(v.clone())
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        all
+    );
+
+    let expected = v
+        .as_slice(..)
+        .iter()
+        .filter(in_rect)
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        v.iter().on_rect(&rect).copied().collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        v.iter().on_row(2).copied().collect::<Vec<_>>(),
+        (0..7).map(|x| (x, 2)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        v.iter()
+            .on_rect(&rect)
+            .by_column()
+            .copied()
+            .collect::<Vec<_>>(),
+        (2..6)
+            .flat_map(|x| (1..4).map(move |y| (x, y)))
+            .collect::<Vec<_>>()
+    );
+
+    let mut g = BidiGrowVec::from(v.clone());
+    for item in g.iter_mut().on_rect(&rect) {
+        item.0 += 100;
+    }
+    let mut v = v;
+    for item in v.iter_mut() {
+        if rect.contains(item.0, item.1) {
+            item.0 += 100;
+        }
+    }
+    assert!(g.equivalent(&v));
+    assert_eq!(v.iter_mut().on_row(4).count(), 7);
+}
+
+#[test]
+fn unchecked_access_matches_checked_access() {
+    let v = BidiVec::with_size_func_xy(7, 5, |x, y| (x, y));
+    let mut g = BidiGrowVec::from(v.clone());
+    let mut a = BidiArray::from(v.clone());
+    let transposed = v.clone().to_transposed();
+
+    for (x, y, item) in v.iter().with_coords() {
+        assert_eq!(*item, (x, y));
+        unsafe {
+            assert_eq!(v.get_unchecked(x, y), item);
+            assert_eq!(g.get_unchecked(x, y), item);
+            assert_eq!(a.get_unchecked_mut(x, y), item);
+            assert_eq!(transposed.get_unchecked(y, x), item);
+        }
+    }
+
+    let by_column = (0..7)
+        .flat_map(|x| (0..5).map(move |y| (x, y)))
+        .collect::<Vec<_>>();
+    assert_eq!(g.iter().by_column().copied().collect::<Vec<_>>(), by_column);
+    assert_eq!(transposed.iter().copied().collect::<Vec<_>>(), by_column);
+    assert!(g
+        .iter()
+        .on_rect(&BidiRect::new(2, 1, 10, 10))
+        .with_coords()
+        .all(|(x, y, item)| *item == (x, y)));
+
+    for (x, y, item) in g.iter_mut().by_column().with_coords() {
+        *item = (y, x);
+    }
+    for item in a.iter_mut().by_column() {
+        *item = (item.1, item.0);
+    }
+    assert!(g.equivalent(&a));
+}
+
 run_test_on_types!(iterator_basic_by_col on all);
 fn iterator_basic_by_col<T: Testable>() {
     let b = helper_build_4x5::<T>();
@@ -2381,3 +2673,254 @@ fn iterator_neighbours_adjacent_mut_clipped<T: Testable>() {
         vec![11, 12, 13, 14, 21, 22, 23, 24, 31, 32, 33, 34, 41, 42, 43, -44, 51, 52, -53, 54,]
     );
 }
+
+#[test]
+fn display_formats_rows() {
+    let v = bidivec! {
+        [1, -2, 3],
+        [40, 5, 6],
+    };
+
+    assert_eq!(v.to_string(), " 1 -2  3\n40  5  6");
+    assert_eq!(
+        v.display().column_sep(",").row_sep(";").pad(false).render(),
+        "1,-2,3;40,5,6"
+    );
+    assert_eq!(
+        v.display()
+            .with_cell(|n: &i32| "*".repeat(n.abs() as usize % 3))
+            .column_sep("|")
+            .render(),
+        " *|**|  \n *|**|  "
+    );
+
+    let a = BidiArray::from(v.clone());
+    assert_eq!(a.to_string(), v.to_string());
+    assert_eq!(
+        BidiSlice::new(&[1, 2, 3, 4][..], 2).unwrap().to_string(),
+        "1 2\n3 4"
+    );
+
+    let dynamic: &dyn BidiView<Output = i32> = &v;
+    assert_eq!(
+        display::BidiDisplay::new(dynamic, |n| n.to_string()).render(),
+        " 1-2 3\n40 5 6"
+    );
+
+    assert_eq!(BidiVec::<i32>::new().to_string(), "");
+}
+
+#[test]
+fn from_str_grid_parses_rows() {
+    use std::convert::TryFrom;
+
+    let v = BidiVec::<char>::try_from("ab\r\ncd\nef").unwrap();
+    assert_eq!(
+        v,
+        bidivec! {
+            ['a', 'b'],
+            ['c', 'd'],
+            ['e', 'f'],
+        }
+    );
+
+    let coords = BidiVec::from_str_grid("\n\n..\n..\n\n", |_, x, y| (x, y)).unwrap();
+    assert_eq!(coords.size(), (2, 2));
+    assert!(coords.iter().with_coords().all(|(x, y, c)| *c == (x, y)));
+
+    // widths are counted in characters, not bytes
+    assert_eq!(BidiVec::<char>::try_from("é.\n.é").unwrap().size(), (2, 2));
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::<char>::try_from("...\n..\n..."),
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::<char>::try_from("..\n\n.."),
+    );
+    assert!(BidiVec::<char>::try_from("").unwrap().is_empty());
+    assert!(BidiVec::<char>::try_from("\n\n").unwrap().is_empty());
+}
+
+#[test]
+fn errors_carry_context() {
+    let mut v = bidivec![0; 3, 2];
+
+    let err = v.push_row(vec![1, 2]).unwrap_err();
+    assert_eq!(
+        err,
+        BidiError::IncompatibleSize {
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "incompatible argument size: expected 3 items, found 2"
+    );
+
+    let err = v.crop(&BidiRect::new(1, 0, 2, 5)).unwrap_err();
+    assert_eq!(
+        err,
+        BidiError::OutOfBounds {
+            x: 2,
+            y: 4,
+            width: 3,
+            height: 2
+        }
+    );
+
+    // cuts of non square views are checked against the right sides
+    let wide = bidivec![0; 4, 1];
+    let cut = BidiVec::<i32>::from_view_cut(
+        &wide as &dyn BidiView<Output = i32>,
+        &BidiRect::new(0, 0, 3, 1),
+    );
+    assert_eq!(cut.unwrap().size(), (3, 1));
+
+    let boxed: Box<dyn std::error::Error> = Box::new(BidiError::NoSolution);
+    assert_eq!(boxed.to_string(), "no solution satisfies the constraints");
+    assert_eq!(BidiError::NoSolution.kind(), BidiErrorKind::NoSolution);
+}
+
+#[test]
+fn validate_detects_inconsistent_state() {
+    let mut v = bidivec![0; 3, 2];
+    assert_eq!(v.validate(), Ok(()));
+    assert_eq!(BidiVec::<i32>::new().validate(), Ok(()));
+
+    // empty vecs are empty bidivecs, regardless of the row size
+    let empty = BidiVec::<i32>::from_vec(Vec::new(), 3).unwrap();
+    assert_eq!(empty.validate(), Ok(()));
+    assert_eq!(empty.size(), (0, 0));
+
+    v.data.push(0);
+    assert_eq!(
+        v.validate(),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 7
+        })
+    );
+
+    let mut a = bidiarray![0; 3, 2];
+    assert_eq!(a.validate(), Ok(()));
+    a.row_size = 4;
+    assert_err(BidiErrorKind::IncompatibleSize, a.validate());
+}
+
+#[test]
+fn entry_reads_modifies_and_grows() {
+    let mut v = bidivec! {
+        [1, 2],
+        [3, 4],
+    };
+
+    assert!(v.entry(1, 1).is_occupied());
+    assert_eq!(v.entry(1, 1).get(), Some(&4));
+    assert_eq!(v.entry(2, 3).get(), None);
+    assert_eq!(v.entry(2, 3).coords(), (2, 3));
+
+    v.entry(0, 0).and_modify(|i| *i *= 10).or_insert(0);
+    v.entry(5, 5).and_modify(|i| *i *= 10);
+    assert_eq!(v.size(), (2, 2));
+    assert_eq!(v[(0, 0)], 10);
+
+    *v.entry(2, 2).or_default() = 9;
+    assert_eq!(
+        v,
+        bidivec! {
+            [10, 2, 0],
+            [3, 4, 0],
+            [0, 0, 9],
+        }
+    );
+
+    let mut g = BidiGrowVec::<i32>::new();
+    *g.entry(1, 0).or_insert_with(|| 7) += 1;
+    assert_eq!(g, bidigrowvec! {[7, 8]});
+
+    assert_eq!(v.get_or(2, 2, &-1), &9);
+    assert_eq!(g.get_or(2, 2, &-1), &-1);
+}
+
+#[test]
+fn coords_of_items_from_iteration() {
+    let v = BidiVec::with_size_func_xy(4, 3, |x, y| (x, y));
+
+    for item in v.iter() {
+        assert_eq!(v.coords_of(item), Some(*item));
+    }
+
+    let other = v.clone();
+    assert_eq!(v.coords_of(&other[(1, 1)]), None);
+    assert_eq!(v.coords_of(&(1, 1)), None);
+
+    let a = v.clone().into_bidiarray();
+    assert_eq!(a.coords_of(&a[(3, 2)]), Some((3, 2)));
+    let slice = BidiSlice::new(v.as_slice(4..), 4).unwrap();
+    assert_eq!(slice.coords_of(&v[(1, 2)]), Some((1, 1)));
+    assert_eq!(slice.coords_of(&v[(1, 0)]), None);
+
+    let units = bidivec![(); 2, 2];
+    assert_eq!(units.coords_of(&units[(1, 1)]), None);
+}
+
+#[test]
+fn from_rows_and_cols() {
+    let v = BidiVec::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        v,
+        bidivec! {
+            [1, 2, 3],
+            [4, 5, 6],
+        }
+    );
+
+    let v = BidiVec::from_cols(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(
+        v,
+        bidivec! {
+            [1, 4],
+            [2, 5],
+            [3, 6],
+        }
+    );
+
+    assert_eq!(
+        BidiVec::from_rows(vec![vec![1, 2, 3], vec![4, 5]]),
+        Err(BidiError::IncompatibleSize {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::from_cols(vec![vec![1], vec![2, 3]]),
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::from_rows(vec![vec![], vec![1]]),
+    );
+
+    let v = BidiVec::<i32>::from_rows(Vec::<Vec<i32>>::new()).unwrap();
+    assert!(v.is_empty());
+    assert_eq!(v.validate(), Ok(()));
+
+    let v = BidiVec::<i32>::from_cols(vec![vec![], vec![]]).unwrap();
+    assert!(v.is_empty());
+    assert_eq!(v.validate(), Ok(()));
+}
+
+#[test]
+fn push_empty_into_empty_is_noop() {
+    let mut v = BidiVec::<i32>::new();
+    v.push_row(vec![]).unwrap();
+    v.push_col(vec![]).unwrap();
+    assert!(v.is_empty());
+    assert_eq!(v.validate(), Ok(()));
+
+    v.push_col(vec![1, 2]).unwrap();
+    assert_eq!(v.size(), (1, 2));
+}
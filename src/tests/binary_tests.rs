@@ -0,0 +1,146 @@
+use super::*;
+use crate::formats::BidiCompression;
+
+fn assert_invalid_data<T: std::fmt::Debug>(r: Result<T, BidiIoError>) {
+    match r {
+        Err(BidiIoError::Io(e)) if e.kind() == std::io::ErrorKind::InvalidData => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
+#[test]
+fn binary_roundtrip() {
+    let v = bidivec! {
+        [1i64, 1, 1, -5],
+        [7, 7, 1, 1],
+        [std::i64::MIN, std::i64::MAX, 0, 0],
+    };
+
+    for compression in [BidiCompression::None, BidiCompression::Rle].iter() {
+        let bytes = v.to_binary(*compression);
+        assert_eq!(BidiVec::<i64>::from_binary(&bytes).unwrap(), v);
+
+        let empty = BidiVec::<bool>::new().to_binary(*compression);
+        assert!(BidiVec::<bool>::from_binary(&empty).unwrap().is_empty());
+    }
+
+    let f = bidivec![std::f32::NAN; 3, 3].to_binary(BidiCompression::Rle);
+    assert!(BidiVec::<f32>::from_binary(&f)
+        .unwrap()
+        .iter()
+        .all(|n| n.is_nan()));
+}
+
+#[test]
+fn binary_layout_is_stable() {
+    let v = bidivec! {
+        [1u8, 1, 1],
+        [1, 2, 2],
+    };
+
+    let bytes = v.to_binary(BidiCompression::Rle);
+
+    assert_eq!(
+        bytes,
+        vec![
+            b'B', b'I', b'D', b'I', 1, 1, 1, 0, //
+            3, 0, 0, 0, 0, 0, 0, 0, //
+            2, 0, 0, 0, 0, 0, 0, 0, //
+            4, 0, 0, 0, 1, //
+            2, 0, 0, 0, 2, //
+        ]
+    );
+}
+
+#[test]
+fn binary_reports_errors() {
+    let v = bidivec![true; 4, 4];
+    let raw = v.to_binary(BidiCompression::None);
+    let rle = v.to_binary(BidiCompression::Rle);
+
+    assert_invalid_data(BidiVec::<bool>::from_binary(&raw[..raw.len() - 1]));
+    assert_invalid_data(BidiVec::<bool>::from_binary(&rle[..rle.len() - 1]));
+    assert_invalid_data(BidiVec::<bool>::from_binary(&raw[..10]));
+    assert_invalid_data(BidiVec::<u8>::from_binary(&raw));
+
+    let mut corrupted = raw.clone();
+    corrupted[30] = 2;
+    assert_invalid_data(BidiVec::<bool>::from_binary(&corrupted));
+
+    let mut corrupted = raw.clone();
+    corrupted[4] = 99;
+    assert_invalid_data(BidiVec::<bool>::from_binary(&corrupted));
+
+    // the reserved header byte must be zero
+    let mut corrupted = raw.clone();
+    corrupted[7] = 1;
+    assert_invalid_data(BidiVec::<bool>::from_binary(&corrupted));
+
+    let mut corrupted = rle.clone();
+    corrupted[24] = 17;
+    assert_invalid_data(BidiVec::<bool>::from_binary(&corrupted));
+}
+
+#[test]
+fn binary_rle_bombs_are_rejected() {
+    // a 2^32 x 2^16 header followed by runs of u32::MAX items
+    let mut bomb = vec![b'B', b'I', b'D', b'I', 1, 1, 1, 0];
+    bomb.extend_from_slice(&(1u64 << 32).to_le_bytes());
+    bomb.extend_from_slice(&(1u64 << 16).to_le_bytes());
+    for _ in 0..4 {
+        bomb.extend_from_slice(&std::u32::MAX.to_le_bytes());
+        bomb.push(7);
+    }
+
+    assert_invalid_data(BidiVec::<u8>::from_binary_limited(&bomb, 1 << 24));
+    // the runs don't add up to the declared size, which is detected before allocating
+    assert_invalid_data(BidiVec::<u8>::from_binary(&bomb));
+
+    let v = bidivec![3u8; 64, 64];
+    let rle = v.to_binary(BidiCompression::Rle);
+    assert_eq!(
+        BidiVec::<u8>::from_binary_limited(&rle, 64 * 64).unwrap(),
+        v
+    );
+    assert_invalid_data(BidiVec::<u8>::from_binary_limited(&rle, 64 * 64 - 1));
+    assert_invalid_data(BidiVec::<u8>::from_binary_limited(
+        &v.to_binary(BidiCompression::None),
+        100,
+    ));
+}
+
+#[test]
+fn binary_rle_decodes_each_run_once() {
+    use crate::formats::BidiBinaryItem;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DECODED: AtomicUsize = AtomicUsize::new(0);
+
+    // an item type whose decoding isn't deterministic
+    #[derive(Clone, Debug, PartialEq)]
+    struct Counted(u8, usize);
+
+    impl BidiBinaryItem for Counted {
+        const ENCODING: u8 = 0x80;
+        const SIZE: usize = 1;
+
+        fn encode(&self, out: &mut Vec<u8>) {
+            out.push(self.0);
+        }
+
+        fn decode(bytes: &[u8]) -> Option<Self> {
+            Some(Counted(bytes[0], DECODED.fetch_add(1, Ordering::SeqCst)))
+        }
+    }
+
+    let v = BidiVec::with_size_func_xy(10, 3, |_, y| Counted(y as u8, 0));
+    let decoded = BidiVec::<Counted>::from_binary(&v.to_binary(BidiCompression::Rle)).unwrap();
+
+    assert_eq!(DECODED.load(Ordering::SeqCst), 3);
+    for y in 0..3 {
+        assert!(decoded
+            .iter()
+            .on_row(y)
+            .all(|item| *item == Counted(y as u8, y)));
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+#[test]
+fn bytes_roundtrip() {
+    let v = bidivec! {
+        [1u32, 2, 3],
+        [4, 5, 6],
+    };
+
+    let bytes = v.as_bytes().to_vec();
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(bytes[12..16], 4u32.to_ne_bytes());
+
+    assert_eq!(BidiVec::<u32>::from_bytes(&bytes, 3).unwrap(), v);
+    let a = BidiArray::<u32>::from_bytes(&bytes, 3).unwrap();
+    assert_eq!(a, BidiArray::from(v.clone()));
+    assert_eq!(a.as_bytes(), &bytes[..]);
+
+    // unaligned bytes are copied
+    let mut shifted = vec![0u8];
+    shifted.extend_from_slice(&bytes);
+    assert_eq!(
+        BidiVec::<u32>::from_bytes(&shifted[1..], 2).unwrap().size(),
+        (2, 3)
+    );
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::<u32>::from_bytes(&bytes[..23], 3),
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::<u32>::from_bytes(&bytes, 4),
+    );
+    assert!(BidiVec::<u32>::from_bytes(&[], 0).unwrap().is_empty());
+}
+
+#[test]
+fn cast_keeps_shape() {
+    let v = bidivec! {
+        [1u32, 2],
+        [3, 4],
+        [5, 6],
+    };
+
+    let floats = v.clone().cast::<f32>().unwrap();
+    assert_eq!(floats.size(), (2, 3));
+    assert_eq!(floats[(1, 2)].to_bits(), 6);
+
+    let back = floats.cast::<u32>().unwrap();
+    assert_eq!(back, v);
+
+    // stricter alignment
+    let bytes = bidivec![[1u8, 0, 0, 0]; 2, 2];
+    assert_eq!(
+        bytes.cast::<u32>().unwrap(),
+        bidivec![u32::from_ne_bytes([1, 0, 0, 0]); 2, 2]
+    );
+
+    assert_err(BidiErrorKind::IncompatibleSize, v.clone().cast::<u16>());
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiArray::from(v.clone()).cast::<u64>(),
+    );
+
+    let data = [1u16, 2, 3, 4];
+    let slice = BidiSlice::new(&data, 2).unwrap();
+    let signed = slice.cast::<i16>().unwrap();
+    assert_eq!(signed.size(), (2, 2));
+    assert_eq!(signed[(1, 1)], 4);
+    assert_eq!(signed.as_bytes().len(), 8);
+
+    let bytes = signed.as_bytes();
+    assert_eq!(BidiSlice::<u16>::from_bytes(bytes, 2).unwrap()[(0, 1)], 3);
+    assert_err(
+        BidiErrorKind::Misaligned,
+        BidiSlice::<u16>::from_bytes(&bytes[1..7], 1),
+    );
+}
+
+#[test]
+fn misaligned_bytes_are_reported() {
+    let data = [0u32; 3];
+    let bytes = BidiSlice::new(&data, 3).unwrap().as_bytes();
+    let misaligned = &bytes[1..9];
+
+    assert_eq!(
+        BidiSlice::<u32>::from_bytes(misaligned, 1).unwrap_err(),
+        BidiError::Misaligned { alignment: 4 }
+    );
+    assert_eq!(
+        BidiVec::<u32>::from_bytes(misaligned, 1).unwrap().size(),
+        (1, 2)
+    );
+
+    let arrays = BidiSlice::<[u8; 4]>::from_bytes(misaligned, 2).unwrap();
+    assert_eq!(
+        arrays.cast::<u32>().unwrap_err(),
+        BidiError::Misaligned { alignment: 4 }
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiSlice::<u32>::from_bytes(&bytes[..7], 1),
+    );
+}
@@ -470,3 +470,948 @@ fn transformations_crop<T: Testable>() {
         }
     }
 }
+
+run_test_on_types!(cross_container_equality on
+    option: Option<i32>,
+    plain_int: i32,
+    string: String,
+    vec: Vec<i32>
+);
+fn cross_container_equality<T: Testable + PartialEq + Clone>() {
+    let v = helper_bidivec::<T>();
+    let g = helper_bidigrowvec::<T>();
+    let a = helper_bidiarray::<T>();
+    let mut data = helper_bidivec::<T>().into_vec();
+    let mut mdata = helper_bidivec::<T>().into_vec();
+    let s = BidiSlice::new(&data, v.width()).unwrap();
+    let m = BidiMutSlice::new(&mut mdata, v.width()).unwrap();
+    let d: &dyn BidiView<Output = T> = &g;
+
+    assert!(v == g && v == a && v == s && v == m && v == *d);
+    assert!(g == v && g == a && g == s && g == m && g == *d);
+    assert!(a == v && a == g && a == s && a == m && a == *d);
+    assert!(s == v && s == g && s == a && s == m && s == *d);
+    assert!(m == v && m == g && m == a && m == s && m == *d);
+    assert!(*d == v && *d == g && *d == a && *d == s && *d == m);
+    assert!(*d == *(&a as &dyn BidiView<Output = T>));
+
+    let mut t = helper_bidivec::<T>();
+    t.transpose();
+    assert!(v != t.clone().into_bidigrowvec() && v != t.clone().into_bidiarray());
+
+    data.swap(0, 1);
+    let s = BidiSlice::new(&data, v.width()).unwrap();
+    assert!(v != s && g != s && a != s && *d != s);
+}
+
+#[test]
+fn empty_containers_equality() {
+    let v = BidiVec::<i32>::new();
+    let a = BidiArray::<i32>::new();
+    let g = BidiGrowVec::<i32>::new();
+
+    assert_eq!(a.size(), (0, 0));
+    assert!(v == a && a == g && g == v);
+    assert!(v != bidiarray![0; 1, 1]);
+}
+
+#[test]
+fn containers_as_hash_keys() {
+    use std::collections::HashMap;
+
+    let mut memo = HashMap::new();
+    memo.insert(bidivec! {[1, 2], [3, 4]}, "square");
+    memo.insert(bidivec! {[1, 2, 3, 4]}, "row");
+
+    assert_eq!(memo[&bidivec! {[1, 2], [3, 4]}], "square");
+    assert_eq!(memo[&bidivec! {[1, 2, 3, 4]}], "row");
+
+    let mut grow = HashMap::new();
+    grow.insert(bidigrowvec! {[1, 2], [3, 4]}, 1);
+    grow.insert(bidigrowvec! {[1, 2, 3, 4]}, 2);
+    assert_eq!(grow.len(), 2);
+}
+
+#[test]
+fn containers_ordering() {
+    use std::cmp::Ordering;
+    use std::collections::BTreeSet;
+
+    let small = bidivec! {[9, 9]};
+    let tall = bidivec! {[0], [0], [0]};
+    let a = bidivec! {[1, 2], [3, 4]};
+    let b = bidivec! {[1, 2], [3, 5]};
+    let c = bidivec! {[2, 0], [0, 0]};
+
+    // shape first: width, then height
+    assert!(tall < small);
+    assert!(small < a);
+    assert!(a < b && b < c);
+    assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+
+    let set = vec![c.clone(), a.clone(), tall.clone(), b.clone(), small.clone()]
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        set.into_iter().collect::<Vec<_>>(),
+        vec![tall, small, a, b, c]
+    );
+
+    let ga = bidigrowvec! {[1, 2], [3, 4]};
+    let gb = bidigrowvec! {[1, 2], [3, 5]};
+    assert!(ga < gb && bidigrowvec! {[5], [5]} < ga);
+    assert!(bidiarray! {[1, 2], [3, 4]} < bidiarray! {[1, 3], [0, 0]});
+    assert!(BidiArray::<i32>::new() < bidiarray! {[0]});
+
+    let nan = bidivec! {[std::f64::NAN]};
+    assert_eq!(nan.partial_cmp(&nan), None);
+    assert!(nan < bidivec! {[0.0, 0.0]});
+}
+
+#[test]
+fn normalized_exports() {
+    let v = bidivec! {
+        [0.0f32, 0.5, 1.0],
+        [2.0, std::f32::NAN, -1.0],
+    };
+
+    assert_eq!(
+        v.normalize_to_u8(0.0, 1.0),
+        bidivec! {
+            [0, 128, 255],
+            [255, 0, 0],
+        }
+    );
+    assert_eq!(v.normalize_to_u8(1.0, 1.0), bidivec![0u8; 3, 2]);
+
+    let f = v.to_f32_normalized();
+    assert_eq!(f[(2, 1)], 0.0);
+    assert_eq!(f[(0, 1)], 1.0);
+    assert_eq!(f[(1, 1)], 0.0);
+    assert!((f[(1, 0)] - 0.5).abs() < 1e-6);
+
+    let rect = BidiRect::new(1, 0, 2, 1);
+    assert_eq!(
+        v.normalize_rect_to_u8(&rect, 0.5, 1.0).unwrap(),
+        bidivec! {[0, 255]}
+    );
+    assert_eq!(
+        v.to_f32_normalized_rect(&rect).unwrap(),
+        bidivec! {[0.0, 1.0]}
+    );
+    assert_eq!(
+        bidivec![7u8; 2, 2]
+            .to_f32_normalized_rect(&BidiRect::new(0, 0, 2, 2))
+            .unwrap(),
+        bidivec![0.0; 2, 2]
+    );
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.normalize_rect_to_u8(&BidiRect::new(2, 0, 2, 1), 0.0, 1.0),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        v.to_f32_normalized_rect(&BidiRect::new(0, 1, 1, 2)),
+    );
+    assert!(BidiVec::<u8>::new().to_f32_normalized().is_empty());
+}
+
+#[test]
+fn try_index_reports_out_of_bounds() {
+    let v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+
+    fn sum_rect<V: BidiView<Output = i32>>(view: &V, rect: &BidiRect) -> Result<i32, BidiError> {
+        let cropped = view.try_index_rect(rect)?;
+        Ok(cropped.iter().sum())
+    }
+
+    assert_eq!(v.try_index((2, 1)), Ok(&6));
+    assert_err(BidiErrorKind::OutOfBounds, v.try_index((3, 0)));
+    assert_eq!(sum_rect(&v, &BidiRect::new(1, 0, 2, 2)), Ok(16));
+    assert_eq!(
+        sum_rect(&v.clone().to_transposed(), &BidiRect::new(0, 1, 2, 2)),
+        Ok(16)
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        sum_rect(&v, &BidiRect::new(0, 1, 3, 2)),
+    );
+
+    // cropped views don't reach outside of their rect
+    let cropped = v.try_index_rect(&BidiRect::new(0, 0, 2, 1)).unwrap();
+    assert_eq!(cropped.try_index((1, 0)), Ok(&2));
+    assert_err(BidiErrorKind::OutOfBounds, cropped.try_index((2, 0)));
+    assert_err(BidiErrorKind::OutOfBounds, cropped.try_index((0, 1)));
+    assert!(v.to_reversed_columns().get(3, 0).is_none());
+}
+
+#[test]
+fn clamped_and_wrapped_access() {
+    let v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+
+    assert_eq!(v.get_clamped(2, 1), Some(&6));
+    assert_eq!(v.get_clamped(-5, -5), Some(&1));
+    assert_eq!(v.get_clamped(std::isize::MAX, 0), Some(&3));
+    assert_eq!(v.get_clamped(1, std::isize::MIN), Some(&2));
+
+    assert_eq!(v.get_wrapped(2, 1), Some(&6));
+    assert_eq!(v.get_wrapped(-3, -2), Some(&1));
+    assert_eq!(v.get_wrapped(-4, 3), Some(&6));
+    assert_eq!(v.get_wrapped(std::isize::MIN, 0), v.get(1, 0));
+
+    let empty = BidiVec::<i32>::new();
+    assert_eq!(empty.get_clamped(0, 0), None);
+    assert_eq!(empty.get_wrapped(0, 0), None);
+}
+
+#[test]
+fn signed_access() {
+    let mut v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+
+    let around = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        .iter()
+        .filter_map(|&(dx, dy)| v.get_signed(dx, 1 + dy))
+        .copied()
+        .collect::<Vec<i32>>();
+    assert_eq!(around, vec![5, 1]);
+
+    assert_eq!(v.get_signed(std::isize::MIN, 0), None);
+    assert_eq!(v.get_signed(std::isize::MAX, std::isize::MAX), None);
+
+    *v.get_mut_signed(2, 1).unwrap() = 60;
+    assert!(v.get_mut_signed(-1, -1).is_none());
+    assert_eq!(v[(2, 1)], 60);
+}
+
+#[test]
+fn bounds_of_views() {
+    let v = bidivec![0; 3, 2];
+    assert_eq!(v.bounding_rect(), BidiRect::new(0, 0, 3, 2));
+    assert!(v.is_in_bounds(2, 1));
+    assert!(!v.is_in_bounds(3, 0));
+    assert!(!v.is_in_bounds(0, 2));
+
+    let t = v.to_transposed();
+    assert_eq!(t.bounding_rect(), BidiRect::new(0, 0, 2, 3));
+    assert!(t.is_in_bounds(1, 2));
+    assert!(!t.is_in_bounds(2, 1));
+    assert!(!BidiVec::<i32>::new().is_in_bounds(0, 0));
+}
+
+#[test]
+fn rows_of_containers() {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let slices = vec![&[1, 2, 3][..], &[4, 5, 6][..]];
+
+    let v = BidiVec::from_rows(rows.clone()).unwrap();
+    assert_eq!(v.as_rows(), slices);
+    assert_eq!(v.clone().into_rows(), rows);
+
+    let a = v.clone().into_bidiarray();
+    assert_eq!(a.as_rows(), slices);
+    assert_eq!(a.into_rows(), rows);
+
+    let g = v.clone().into_bidigrowvec();
+    assert_eq!(g.as_rows(), slices);
+    assert_eq!(g.into_rows(), rows);
+
+    let data = v.clone().into_vec();
+    assert_eq!(BidiSlice::new(&data, 3).unwrap().as_rows(), slices);
+
+    let mut data = data;
+    assert_eq!(BidiMutSlice::new(&mut data, 3).unwrap().as_rows(), slices);
+
+    let empty = BidiVec::<i32>::new();
+    assert!(empty.as_rows().is_empty());
+    assert!(empty.into_rows().is_empty());
+    assert!(BidiArray::<i32>::new().as_rows().is_empty());
+}
+
+fn construct_generic<C>() -> C
+where
+    C: BidiConstruct + BidiView<Output = (usize, usize)>,
+{
+    C::with_size_func_xy(3, 2, |x, y| (x, y))
+}
+
+fn assert_constructs<C>()
+where
+    C: BidiConstruct + BidiView<Output = (usize, usize)>,
+{
+    let v = construct_generic::<C>();
+    assert_eq!(v.size(), (3, 2));
+    assert_eq!(v[(2, 1)], (2, 1));
+
+    let v = C::with_elem((1, 1), 2, 2);
+    assert!(v.iter().all(|&item| item == (1, 1)));
+    let v = C::with_size_default(4, 1);
+    assert_eq!(v.size(), (4, 1));
+    let v = C::with_size_func(0, 0, || unreachable!());
+    assert_eq!(v.size(), (0, 0));
+    assert_eq!(C::default().size(), (0, 0));
+}
+
+#[test]
+fn construct_all_owned_containers() {
+    assert_constructs::<BidiVec<_>>();
+    assert_constructs::<BidiGrowVec<_>>();
+    assert_constructs::<BidiArray<_>>();
+    #[cfg(feature = "smallvec")]
+    assert_constructs::<BidiSmallVec<[_; 4]>>();
+}
+
+#[test]
+fn default_without_default_items() {
+    struct NoDefault;
+
+    assert!(BidiVec::<NoDefault>::default().is_empty());
+    assert!(BidiGrowVec::<NoDefault>::default().is_empty());
+    assert!(BidiArray::<NoDefault>::default().is_empty());
+}
+
+fn build_generic<C>() -> Result<C, BidiError>
+where
+    C: BidiBuild + BidiView<Output = i32>,
+{
+    let mut v = C::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    v.push_row(vec![5, 6])?;
+    Ok(v)
+}
+
+fn assert_builds<C>()
+where
+    C: BidiBuild + BidiView<Output = i32>,
+{
+    let v = build_generic::<C>().unwrap();
+    assert!(v.equivalent(&bidiarray![1, 2, 3, 4, 5, 6; 2]));
+
+    let mut v = C::from_iterator(0..6, 3).unwrap();
+    assert_eq!(v.size(), (3, 2));
+    assert_err(BidiErrorKind::IncompatibleSize, v.push_row(vec![1]));
+    assert_err(BidiErrorKind::IncompatibleSize, C::from_iterator(0..5, 3));
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        C::from_rows(vec![vec![1], vec![]]),
+    );
+}
+
+#[test]
+fn build_growable_containers() {
+    assert_builds::<BidiVec<_>>();
+    assert_builds::<BidiGrowVec<_>>();
+}
+
+fn swap_replace_take<V>(v: &mut V)
+where
+    V: BidiViewMut<Output = String>,
+{
+    BidiViewMut::swap(v, (0, 0), (1, 1)).unwrap();
+    BidiViewMut::swap(v, (1, 0), (1, 0)).unwrap();
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        BidiViewMut::swap(v, (0, 0), (0, 9)),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        BidiViewMut::swap(v, (9, 0), (0, 0)),
+    );
+
+    assert_eq!(v.replace(0, 1, "x".to_string()).unwrap(), "c");
+    assert_eq!(v.take(1, 0).unwrap(), "b");
+    assert_err(BidiErrorKind::OutOfBounds, v.replace(2, 0, String::new()));
+    assert_err(BidiErrorKind::OutOfBounds, v.take(0, 2));
+}
+
+#[test]
+fn swap_replace_take_through_traits() {
+    let items = || {
+        bidivec! {
+            ["a".to_string(), "b".to_string()],
+            ["c".to_string(), "d".to_string()],
+        }
+    };
+    let expected = bidivec! {
+        ["d".to_string(), "".to_string()],
+        ["x".to_string(), "a".to_string()],
+    };
+
+    let mut v = items();
+    swap_replace_take(&mut v);
+    assert_eq!(v, expected);
+
+    let mut g = items().into_bidigrowvec();
+    swap_replace_take(&mut g);
+    assert!(g.equivalent(&expected));
+
+    let mut t = items().to_transposed().to_transposed();
+    swap_replace_take(&mut t);
+    assert!(t.equivalent(&expected));
+}
+
+macro_rules! assert_dihedral_transforms {
+    ($source:expr) => {{
+        let source = $source;
+        let views = bidivec! {
+            [1, 2, 3],
+            [4, 5, 6],
+        };
+
+        assert!(source
+            .clone()
+            .into_transposed()
+            .equivalent(&views.clone().to_transposed()));
+        assert!(source
+            .clone()
+            .into_anti_transposed()
+            .equivalent(&views.clone().to_anti_transposed()));
+        assert!(source
+            .clone()
+            .into_rotated90ccw()
+            .equivalent(&views.clone().to_rotated90ccw()));
+        assert!(source
+            .clone()
+            .into_rotated180()
+            .equivalent(&views.clone().to_rotated180()));
+        assert!(source
+            .clone()
+            .into_rotated270ccw()
+            .equivalent(&views.clone().to_rotated270ccw()));
+        assert!(source
+            .clone()
+            .into_reversed_columns()
+            .equivalent(&views.clone().to_reversed_columns()));
+        assert!(source
+            .into_reversed_rows()
+            .equivalent(&views.to_reversed_rows()));
+    }};
+}
+
+#[test]
+fn dihedral_transforms_of_owned_containers() {
+    let v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+
+    assert_dihedral_transforms!(v.clone());
+    assert_dihedral_transforms!(v.clone().into_bidiarray());
+    assert_dihedral_transforms!(v.clone().into_bidigrowvec());
+    #[cfg(feature = "smallvec")]
+    assert_dihedral_transforms!(BidiSmallVec::<[i32; 4]>::from(v));
+}
+
+#[test]
+fn anti_transposed_views_and_slices() {
+    let v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+    let expected = bidivec! {
+        [6, 3],
+        [5, 2],
+        [4, 1],
+    };
+
+    let mut view = v.clone().to_anti_transposed();
+    assert!(view.equivalent(&expected));
+    assert!(view.get(2, 0).is_none());
+    view[(1, 2)] = 10;
+    assert!(view.to_anti_transposed().equivalent(&bidivec! {
+        [10, 2, 3],
+        [4, 5, 6],
+    }));
+
+    let mut data = v.into_vec();
+    let mut slice = BidiMutSlice::new(&mut data, 3).unwrap();
+    slice.anti_transpose();
+    assert!(slice.equivalent(&expected));
+
+    let mut empty = BidiVec::<i32>::new();
+    empty.anti_transpose();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn owned_views_share_the_data() {
+    use crate::transforming::OwnedBidiView;
+    use std::rc::Rc;
+
+    let data = Rc::new(bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    });
+    let owned = OwnedBidiView::from(Rc::clone(&data));
+    assert_eq!(Rc::strong_count(&data), 2);
+
+    let cropped = owned
+        .clone()
+        .to_transposed()
+        .to_cropped(&BidiRect::new(0, 1, 2, 2))
+        .unwrap();
+    let copy = cropped.clone();
+    drop(cropped);
+    assert_eq!(Rc::strong_count(&data), 3);
+
+    assert!(copy.equivalent(&bidivec! {
+        [2, 5],
+        [3, 6],
+    }));
+    assert_eq!(owned.size(), (3, 2));
+    assert_eq!(owned.row_slice(1), Some(&[4, 5, 6][..]));
+    assert!(Rc::ptr_eq(owned.source(), &data));
+
+    drop(copy);
+    drop(owned);
+    assert_eq!(Rc::strong_count(&data), 1);
+}
+
+#[test]
+fn iterate_trait_objects() {
+    use crate::bidiiter::iter_dyn;
+
+    let v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+    let views: Vec<Box<dyn BidiView<Output = i32>>> = vec![
+        Box::new(v.clone()),
+        Box::new(v.clone().into_bidigrowvec()),
+        Box::new(v.clone().to_transposed().to_transposed()),
+    ];
+
+    for view in views.iter() {
+        let view = view.as_ref();
+        assert_eq!(
+            iter_dyn(view).copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            iter_dyn(view).by_column().copied().collect::<Vec<_>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        assert_eq!(
+            iter_dyn(view)
+                .on_rect(&BidiRect::new(1, 0, 2, 2))
+                .with_coords()
+                .map(|(x, y, n)| (x, y, *n))
+                .collect::<Vec<_>>(),
+            vec![(1, 0, 2), (2, 0, 3), (1, 1, 5), (2, 1, 6)]
+        );
+        assert_eq!(
+            iter_dyn(view)
+                .on_border(&BidiRectSigned::new(0, 0, 3, 2))
+                .count(),
+            6
+        );
+    }
+}
+
+#[test]
+fn strided_views() {
+    let v = BidiVec::with_size_func_xy(7, 4, |x, y| (x, y));
+
+    let s = v.clone().to_strided(3, 2);
+    assert_eq!(s.size(), (3, 2));
+    assert_eq!(s[(2, 1)], (6, 2));
+    assert!(s.get(3, 0).is_none());
+    assert!(s.get(0, 2).is_none());
+
+    let s = v.clone().to_strided(1, 1);
+    assert!(s.equivalent(&v));
+
+    let s = v.clone().to_strided(10, 10);
+    assert_eq!(s.size(), (1, 1));
+
+    let s = v
+        .clone()
+        .to_cropped(&BidiRect::new(1, 1, 6, 3))
+        .unwrap()
+        .to_strided(2, 2)
+        .to_transposed();
+    assert!(s.equivalent(&bidivec! {
+        [(1, 1), (1, 3)],
+        [(3, 1), (3, 3)],
+        [(5, 1), (5, 3)],
+    }));
+
+    let mut s = v.to_strided(4, 4);
+    s[(1, 0)] = (40, 40);
+    assert!(s.equivalent(&bidivec![(0, 0), (40, 40); 2]));
+
+    assert_eq!(BidiVec::<i32>::new().to_strided(2, 3).size(), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn strided_views_reject_zero_steps() {
+    bidivec![1, 2, 3; 3].to_strided(0, 1);
+}
+
+#[test]
+fn constant_and_repeating_views() {
+    use crate::transforming::{constant, repeat};
+
+    let c = constant('x', 3, 2);
+    assert_eq!(c.size(), (3, 2));
+    assert_eq!(c[(2, 1)], 'x');
+    assert!(c.get(3, 0).is_none());
+    assert!(c.iter().all(|&ch| ch == 'x'));
+
+    let r = repeat(bidivec![1, 2, 3, 4; 2], 5, 3);
+    assert!(r.equivalent(&bidivec! {
+        [1, 2, 1, 2, 1],
+        [3, 4, 3, 4, 3],
+        [1, 2, 1, 2, 1],
+    }));
+    assert!(r.get(5, 0).is_none());
+
+    let mut r = repeat(bidivec![0; 2, 2], 4, 4);
+    r[(3, 3)] = 9;
+    assert_eq!(r[(1, 1)], 9);
+
+    let empty = repeat(BidiVec::<i32>::new(), 4, 4);
+    assert_eq!(empty.size(), (0, 0));
+    assert!(empty.get(0, 0).is_none());
+
+    let mut dest = bidivec![0; 4, 2];
+    editing::blend(
+        &repeat(constant(1, 1, 1), 4, 2),
+        &mut dest,
+        &BidiRect::new(0, 0, 4, 2),
+        (0, 0),
+        |s, d| *d += *s,
+    )
+    .unwrap();
+    assert_eq!(dest, bidivec![1; 4, 2]);
+}
+
+#[test]
+#[should_panic]
+fn constant_views_panic_out_of_bounds() {
+    let _ = crate::transforming::constant(1, 2, 2)[(2, 0)];
+}
+
+#[test]
+fn column_major_import_and_export() {
+    let expected = bidivec! {
+        [0, 3, 6, 9],
+        [1, 4, 7, 10],
+        [2, 5, 8, 11],
+    };
+    let by_cols = (0..12).collect::<Vec<_>>();
+
+    let bvec = BidiVec::from_iterator_by_cols(by_cols.iter().copied(), 3).unwrap();
+    assert_eq!(bvec, expected);
+    let barr = BidiArray::from_iterator_by_cols(by_cols.iter().copied(), 3).unwrap();
+    assert_eq!(barr, expected);
+    let bgrow = BidiGrowVec::from_iterator_by_cols(by_cols.iter().copied(), 3).unwrap();
+    assert_eq!(bgrow, expected);
+
+    assert_eq!(bvec.into_vec_by_cols(), by_cols);
+    assert_eq!(barr.into_vec_by_cols(), by_cols);
+    assert_eq!(bgrow.into_vec_by_cols(), by_cols);
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiVec::from_iterator_by_cols(0..10, 3),
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        BidiGrowVec::from_iterator_by_cols(0..10, 3),
+    );
+}
+
+#[test]
+fn for_each_row_and_col_mut_on_all_layouts() {
+    let source = BidiVec::with_size_func_xy(4, 3, |x, y| x + y * 4);
+    let rows = |y: usize, row: &mut [usize]| {
+        assert_eq!(row, &[y * 4, y * 4 + 1, y * 4 + 2, y * 4 + 3]);
+        row.reverse();
+    };
+    let cols = |x: usize, col: &mut [usize]| {
+        assert_eq!(col, &[3 - x, 7 - x, 11 - x]);
+        for item in col.iter_mut() {
+            *item *= 10;
+        }
+    };
+    let expected = BidiVec::with_size_func_xy(4, 3, |x, y| (3 - x + y * 4) * 10);
+
+    let mut bvec = source.clone();
+    bvec.for_each_row_mut(rows);
+    bvec.for_each_col_mut(cols);
+    assert_eq!(bvec, expected);
+
+    let mut barr = BidiArray::from(source.clone());
+    barr.for_each_row_mut(rows);
+    barr.for_each_col_mut(cols);
+    assert_eq!(barr, expected);
+
+    let mut bgrow = BidiGrowVec::from(source.clone());
+    bgrow.for_each_row_mut(rows);
+    bgrow.for_each_col_mut(cols);
+    assert_eq!(bgrow, expected);
+
+    let mut data = source.clone().into_vec();
+    let mut bslice = BidiMutSlice::new(&mut data, 4).unwrap();
+    bslice.for_each_row_mut(rows);
+    bslice.for_each_col_mut(cols);
+    assert_eq!(bslice.size(), (4, 3));
+    assert_eq!(data, expected.clone().into_vec());
+
+    let mut calls = 0;
+    let mut empty = BidiVec::<usize>::new();
+    empty.for_each_row_mut(|_, _| calls += 1);
+    empty.for_each_col_mut(|_, _| calls += 1);
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn for_each_col_mut_survives_panics() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let source = BidiVec::with_size_func_xy(3, 2, |x, y| format!("{}{}", x, y));
+    let mut expected = source.clone();
+    expected[(0, 0)] = "changed".to_string();
+
+    let mut bvec = source.clone();
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        bvec.for_each_col_mut(|x, col| {
+            assert_eq!(col.len(), 2);
+            col[0] = "changed".to_string();
+            if x == 0 {
+                panic!("closure panicked");
+            }
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(bvec, expected);
+
+    let mut bgrow = BidiGrowVec::from(source);
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        bgrow.for_each_col_mut(|x, col| {
+            col[0] = "changed".to_string();
+            if x == 0 {
+                panic!("closure panicked");
+            }
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(bgrow, expected);
+}
+
+#[test]
+fn offset_coords_never_overflow() {
+    let v = BidiVec::with_elem(0, 4, 3);
+    let view: &dyn BidiView<Output = i32> = &v;
+
+    assert_eq!(view.offset_coords(1, 1, 2, 1), Some((3, 2)));
+    assert_eq!(view.offset_coords(1, 1, -1, -1), Some((0, 0)));
+    assert_eq!(view.offset_coords(1, 1, -2, 0), None);
+    assert_eq!(view.offset_coords(1, 1, 0, 2), None);
+    assert_eq!(view.offset_coords(usize::MAX, 0, isize::MAX, 0), None);
+    assert_eq!(view.offset_coords(0, 0, isize::MIN, 0), None);
+    assert_eq!(view.offset_coords(usize::MAX, 2, isize::MIN, 0), None);
+
+    assert_eq!(view.wrapping_offset_coords(3, 2, 1, 1), Some((0, 0)));
+    assert_eq!(view.wrapping_offset_coords(0, 0, -9, -7), Some((3, 2)));
+    assert_eq!(view.wrapping_offset_coords(6, 5, 0, 0), Some((2, 2)));
+    assert_eq!(
+        view.wrapping_offset_coords(usize::MAX, 0, isize::MIN, isize::MAX),
+        Some((3, (isize::MAX % 3) as usize))
+    );
+
+    let empty = BidiVec::<i32>::new();
+    assert_eq!(empty.offset_coords(0, 0, 0, 0), None);
+    assert_eq!(empty.wrapping_offset_coords(0, 0, 1, 1), None);
+}
+
+#[test]
+fn insert_views_into_containers() {
+    let pattern = bidivec! {
+        [1, 2],
+        [3, 4],
+    };
+    let transposed = pattern.clone().to_transposed();
+
+    let mut bvec = BidiVec::with_elem(0, 3, 3);
+    bvec.insert_view(1, 0, &pattern).unwrap();
+    bvec.insert_view(0, 1, &transposed).unwrap();
+    assert_eq!(
+        bvec,
+        bidivec! {
+            [0, 1, 2],
+            [1, 3, 4],
+            [2, 4, 0],
+        }
+    );
+    assert_err(BidiErrorKind::OutOfBounds, bvec.insert_view(2, 0, &pattern));
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bvec.insert_view(0, usize::MAX, &pattern),
+    );
+
+    let mut bgrow = BidiGrowVec::with_elem(0, 3, 3);
+    assert_eq!(
+        bgrow.insert_view_clipped(2, 2, &pattern),
+        Some(BidiRect::new(2, 2, 1, 1))
+    );
+    assert_eq!(
+        bgrow.insert_view_clipped(0, 1, &transposed),
+        Some(BidiRect::new(0, 1, 2, 2))
+    );
+    assert_eq!(bgrow.insert_view_clipped(3, 0, &pattern), None);
+    assert_eq!(
+        bgrow,
+        bidivec! {
+            [0, 0, 0],
+            [1, 3, 0],
+            [2, 4, 1],
+        }
+    );
+
+    let mut data = vec![String::new(); 4];
+    let mut slice = BidiMutSlice::new(&mut data, 2).unwrap();
+    let strings = BidiArray::with_elem(String::from("a"), 1, 2);
+    slice.insert_view(1, 0, &strings).unwrap();
+    assert_eq!(data, vec!["", "a", "", "a"]);
+}
+
+#[test]
+fn cut_rects_out_of_views() {
+    let mut bvec = bidivec! {
+        [String::from("a"), String::from("b"), String::from("c")],
+        [String::from("d"), String::from("e"), String::from("f")],
+    };
+
+    let cut = bvec.cut(&BidiRect::new(0, 1, 2, 1), String::new()).unwrap();
+    assert_eq!(cut, bidivec![String::from("d"), String::from("e"); 2]);
+    assert_eq!(bvec.as_rows()[1], &["", "", "f"]);
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bvec.cut(&BidiRect::new(2, 0, 2, 1), String::new()),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bvec.cut(&BidiRect::new(usize::MAX, 0, 2, 1), String::new()),
+    );
+
+    // cutting out of a slice
+    let mut data = vec![1, 2, 3, 4];
+    let mut slice = BidiMutSlice::new(&mut data, 2).unwrap();
+    assert_eq!(
+        slice.cut(&BidiRect::new(1, 0, 1, 2), 0).unwrap(),
+        bidivec![2, 4; 1]
+    );
+    assert_eq!(data, vec![1, 0, 3, 0]);
+
+    let empty = bvec.cut(&BidiRect::new(1, 1, 0, 0), String::new()).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn move_rects_within_views() {
+    let original = BidiVec::with_size_func_xy(5, 4, |x, y| Some(x + y * 5));
+
+    for (from, to) in [
+        (BidiRect::new(0, 0, 3, 2), (2, 2)),
+        (BidiRect::new(1, 1, 3, 3), (0, 0)),
+        (BidiRect::new(0, 1, 4, 2), (1, 2)),
+        (BidiRect::new(2, 0, 2, 4), (2, 0)),
+        (BidiRect::new(1, 1, 0, 0), (4, 3)),
+    ]
+    .iter()
+    {
+        let mut bvec = original.clone();
+        bvec.move_rect(from, *to, None).unwrap();
+
+        for (x, y, item) in bvec.iter().with_coords() {
+            let expected =
+                if x >= to.0 && x < to.0 + from.width && y >= to.1 && y < to.1 + from.height {
+                    original[(x - to.0 + from.x, y - to.1 + from.y)]
+                } else if from.contains(x, y) {
+                    None
+                } else {
+                    original[(x, y)]
+                };
+            assert_eq!(
+                *item, expected,
+                "({}, {}) moving {:?} to {:?}",
+                x, y, from, to
+            );
+        }
+    }
+
+    let mut bgrow = BidiGrowVec::from(original.clone());
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bgrow.move_rect(&BidiRect::new(0, 0, 2, 2), (4, 0), None),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bgrow.move_rect(&BidiRect::new(4, 0, 2, 2), (0, 0), None),
+    );
+    assert_eq!(bgrow, original);
+}
+
+#[test]
+fn flip_rects_in_place() {
+    let original = BidiVec::with_size_func_xy(6, 5, |x, y| x + y * 6);
+    let rects = [
+        BidiRect::new(0, 0, 6, 5),
+        BidiRect::new(1, 1, 3, 4),
+        BidiRect::new(2, 0, 4, 1),
+        BidiRect::new(5, 4, 1, 1),
+        BidiRect::new(3, 3, 0, 0),
+    ];
+
+    for rect in rects.iter() {
+        let map = |f: &dyn Fn(usize, usize) -> (usize, usize)| {
+            BidiVec::with_size_func_xy(6, 5, |x, y| {
+                if rect.contains(x, y) {
+                    let (sx, sy) = f(x - rect.x, y - rect.y);
+                    original[(rect.x + sx, rect.y + sy)]
+                } else {
+                    original[(x, y)]
+                }
+            })
+        };
+
+        let mut bgrow = BidiGrowVec::from(original.clone());
+        bgrow.reverse_columns_in_rect(rect).unwrap();
+        assert_eq!(bgrow, map(&|x, y| (x, rect.height - y - 1)));
+
+        let mut bvec = original.clone();
+        bvec.reverse_rows_in_rect(rect).unwrap();
+        assert_eq!(bvec, map(&|x, y| (rect.width - x - 1, y)));
+
+        let mut data = original.clone().into_vec();
+        let mut slice = BidiMutSlice::new(&mut data, 6).unwrap();
+        slice.rotate180_in_rect(rect).unwrap();
+        assert_eq!(
+            slice,
+            map(&|x, y| (rect.width - x - 1, rect.height - y - 1))
+        );
+    }
+
+    let mut bvec = original.clone();
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bvec.rotate180_in_rect(&BidiRect::new(4, 0, 3, 1)),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        bvec.reverse_rows_in_rect(&BidiRect::new(0, 4, 1, 2)),
+    );
+    assert_eq!(bvec, original);
+}
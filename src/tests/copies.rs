@@ -107,6 +107,50 @@ fn copy_example() {
     assert_eq!(v2[(0, 2)], -1);
 }
 
+#[test]
+fn copy_between_linear_and_transformed_views() {
+    let source = BidiVec::with_size_func_xy(12, 9, |x, y| (x * 100 + y) as u32);
+    let from = BidiRect::new(3, 2, 7, 5);
+    let to = (4, 1);
+
+    let mut expected = BidiVec::with_size_default(13, 10);
+    editing::blend(&source, &mut expected, &from, to, |s, d| *d = *s).unwrap();
+
+    // rows are contiguous in both views
+    let mut v = BidiVec::with_size_default(13, 10);
+    editing::copy(&source, &mut v, &from, to).unwrap();
+    assert_eq!(v, expected);
+
+    let mut g = BidiGrowVec::with_size_default(13, 10);
+    editing::copy(&BidiArray::from(source.clone()), &mut g, &from, to).unwrap();
+    assert!(g.equivalent(&expected));
+
+    // rows are contiguous only in the destination
+    let transposed = source.clone().to_transposed().to_transposed();
+    let mut v = BidiVec::with_size_default(13, 10);
+    editing::copy(&transposed, &mut v, &from, to).unwrap();
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn row_slices() {
+    let v = BidiVec::with_size_func_xy(3, 2, |x, y| x + y * 3);
+    let mut g = BidiGrowVec::from(v.clone());
+
+    assert_eq!(v.row_slice(1), Some(&[3, 4, 5][..]));
+    assert_eq!(v.row_slice(2), None);
+    let data = [0, 1, 2, 3, 4, 5];
+    let s = BidiSlice::new(&data, 3).unwrap();
+    assert_eq!(s.row_slice(0), Some(&[0, 1, 2][..]));
+
+    g.row_slice_mut(0).unwrap().copy_from_slice(&[7, 8, 9]);
+    assert_eq!(g.row_slice(0), Some(&[7, 8, 9][..]));
+    assert!(g.row_slice_mut(2).is_none());
+
+    assert_eq!(BidiVec::<i32>::new().row_slice(0), None);
+    assert_eq!(BidiArray::<i32>::from(BidiVec::new()).row_slice(0), None);
+}
+
 #[test]
 fn clone_over_example() {
     #[derive(Clone)]
@@ -156,3 +200,94 @@ fn blend_example() {
     assert_eq!(v2[(2, 0)], 100);
     assert_eq!(v2[(0, 2)], 100);
 }
+
+#[test]
+fn nine_slice_tile_and_clip() {
+    use crate::editing::NineSliceMode;
+
+    let src = bidivec! {
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [9, 10, 11, 12],
+    };
+    let mut dest = bidivec![0; 7, 4];
+
+    editing::copy_nine_slice(
+        &src,
+        &mut dest,
+        &BidiRect::new(1, 1, 2, 1),
+        &BidiRect::new(0, 0, 7, 4),
+        NineSliceMode::Tile,
+    )
+    .unwrap();
+
+    assert_eq!(
+        dest,
+        bidivec! {
+            [1, 2, 3, 2, 3, 2, 4],
+            [5, 6, 7, 6, 7, 6, 8],
+            [5, 6, 7, 6, 7, 6, 8],
+            [9, 10, 11, 10, 11, 10, 12],
+        }
+    );
+
+    let mut small = bidivec![0; 2, 1];
+    editing::copy_nine_slice(
+        &src,
+        &mut small,
+        &BidiRect::new(1, 1, 2, 1),
+        &BidiRect::new(0, 0, 2, 1),
+        NineSliceMode::Stretch,
+    )
+    .unwrap();
+    assert_eq!(small, bidivec![1, 4; 2]);
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        editing::copy_nine_slice(
+            &src,
+            &mut small,
+            &BidiRect::new(1, 1, 2, 1),
+            &BidiRect::new(0, 0, 3, 1),
+            NineSliceMode::Stretch,
+        ),
+    );
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        editing::copy_nine_slice(
+            &src,
+            &mut dest,
+            &BidiRect::new(1, 1, 0, 1),
+            &BidiRect::new(0, 0, 7, 4),
+            NineSliceMode::Tile,
+        ),
+    );
+}
+
+#[test]
+fn tile_fill_offset_and_clip() {
+    let pattern = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+    let mut v = bidivec![0; 5, 4];
+
+    editing::tile_fill(&mut v, &BidiRect::new(2, 1, 8, 8), &pattern, (1, 1)).unwrap();
+
+    assert_eq!(
+        v,
+        bidivec! {
+            [0, 0, 0, 0, 0],
+            [0, 0, 1, 2, 3],
+            [0, 0, 4, 5, 6],
+            [0, 0, 1, 2, 3],
+        }
+    );
+
+    assert_eq!(
+        editing::tile_fill(&mut v, &BidiRect::new(0, 0, 1, 1), &BidiVec::new(), (0, 0)),
+        Err(BidiError::InvalidArgument {
+            argument: "pattern"
+        })
+    );
+}
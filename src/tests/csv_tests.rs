@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn csv_roundtrip() {
+    let v = bidivec! {
+        ["a".to_string(), "b,c".to_string()],
+        ["\"quoted\"".to_string(), "".to_string()],
+        ["line\nbreak".to_string(), " f ".to_string()],
+    };
+
+    let mut out = Vec::new();
+    v.write_csv(&mut out, |s| s.clone()).unwrap();
+
+    let read = BidiVec::from_csv_reader(&out[..], |s, _, _| {
+        Ok::<_, std::convert::Infallible>(s.to_string())
+    })
+    .unwrap();
+    assert_eq!(read, v);
+
+    let mut out = Vec::new();
+    BidiVec::<i32>::new()
+        .write_csv(&mut out, |n| n.to_string())
+        .unwrap();
+    assert!(out.is_empty());
+
+    let read = BidiVec::from_csv_reader(&b""[..], |s, _, _| s.parse::<i32>()).unwrap();
+    assert!(read.is_empty());
+}
+
+#[test]
+fn csv_reports_errors() {
+    match BidiVec::from_csv_reader(&b"1,2\n3\n"[..], |s, _, _| s.parse::<i32>()) {
+        Err(BidiIoError::Bidi(BidiError::IncompatibleSize {
+            expected: 2,
+            found: 1,
+        })) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    match BidiVec::from_csv_reader(&b"1,2\n3,x\n"[..], |s, _, _| s.parse::<i32>()) {
+        Err(BidiIoError::InvalidItem { x: 1, y: 1, .. }) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    let coords = BidiVec::from_csv_reader(&b"a,b\nc,d"[..], |_, x, y| {
+        Ok::<_, std::convert::Infallible>((x, y))
+    })
+    .unwrap();
+    assert!(coords.iter().with_coords().all(|(x, y, c)| *c == (x, y)));
+
+    match BidiVec::from_csv_reader(&[b'a', 0xff, b'\n'][..], |s, _, _| {
+        Ok::<_, std::convert::Infallible>(s.to_string())
+    }) {
+        Err(BidiIoError::Io(_)) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn diff_text_roundtrip() {
+    let old = bidivec! {
+        ["a".to_string(), "b".to_string()],
+        ["c".to_string(), "d".to_string()],
+    };
+    let new = bidivec! {
+        ["a".to_string(), "tab\there".to_string()],
+        ["back\\slash\nline".to_string(), "".to_string()],
+    };
+
+    let patch = BidiPatch::from_diff(&old, &new).unwrap();
+    let mut out = Vec::new();
+    patch.write_text(&mut out).unwrap();
+
+    let text = String::from_utf8(out.clone()).unwrap();
+    assert_eq!(text.lines().count(), 4);
+
+    let read = BidiPatch::<String>::from_text_reader(&out[..]).unwrap();
+    assert_eq!(read, patch);
+
+    let mut v = old.clone();
+    read.apply(&mut v).unwrap();
+    assert_eq!(v, new);
+
+    let empty = BidiPatch::<String>::from_text_reader(&b"bidipatch 1\r\n"[..]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn diff_text_reports_errors() {
+    let invalid_data = |text: &str| match BidiPatch::<i32>::from_text_reader(text.as_bytes()) {
+        Err(BidiIoError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::InvalidData),
+        r => panic!("unexpected result {:?}", r),
+    };
+
+    invalid_data("");
+    invalid_data("1\t1\t2\t3\n");
+    invalid_data("bidipatch 2\n");
+    invalid_data("bidipatch 1\n1\t1\t2\n");
+    invalid_data("bidipatch 1\n-1\t1\t2\t3\n");
+    invalid_data("bidipatch 1\n1\t1\t\\x\t3\n");
+
+    match BidiPatch::<i32>::from_text_reader(&b"bidipatch 1\n4\t5\t2\tthree\n"[..]) {
+        Err(BidiIoError::InvalidItem { x: 4, y: 5, .. }) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+}
@@ -0,0 +1,479 @@
+use super::*;
+
+#[test]
+fn outline_bordering_and_edges() {
+    let v = bidivec! {
+        [1, 1, 0, 0],
+        [1, 1, 1, 0],
+        [1, 1, 1, 0],
+        [0, 0, 0, 0],
+    };
+
+    let adjacent = editing::outline(&v, BidiNeighbours::Adjacent, |n| *n == 1);
+    assert_eq!(
+        adjacent,
+        vec![(0, 0), (1, 0), (0, 1), (2, 1), (0, 2), (1, 2), (2, 2)]
+    );
+
+    let bordering = editing::outline(&v, BidiNeighbours::Bordering, |n| *n == 1);
+    assert_eq!(
+        bordering,
+        vec![
+            (0, 0),
+            (1, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2)
+        ]
+    );
+
+    assert!(editing::outline(&v, BidiNeighbours::Adjacent, |n| *n == 2).is_empty());
+}
+
+#[test]
+fn marching_squares_holes_and_saddles() {
+    let v = bidivec! {
+        [1, 1, 1],
+        [1, 0, 1],
+        [1, 1, 1],
+    };
+
+    assert_eq!(
+        editing::marching_squares(&v, |n| *n == 1),
+        vec![
+            vec![(0, 0), (3, 0), (3, 3), (0, 3)],
+            vec![(1, 1), (1, 2), (2, 2), (2, 1)],
+        ]
+    );
+
+    let v = bidivec! {
+        [1, 0],
+        [0, 1],
+    };
+
+    assert_eq!(
+        editing::marching_squares(&v, |n| *n == 1),
+        vec![
+            vec![(0, 0), (1, 0), (1, 1), (0, 1)],
+            vec![(1, 1), (2, 1), (2, 2), (1, 2)],
+        ]
+    );
+
+    assert!(editing::marching_squares(&v, |n| *n == 2).is_empty());
+}
+
+#[test]
+fn replace_clips_to_view() {
+    let mut v = bidivec![5; 3, 3];
+
+    let count = editing::replace(&mut v, &BidiRect::new(1, 1, 10, 10), |n| *n == 5, |_| 0);
+
+    assert_eq!(count, 4);
+    assert_eq!(
+        v,
+        bidivec! {
+            [5, 5, 5],
+            [5, 0, 0],
+            [5, 0, 0],
+        }
+    );
+
+    let count = editing::replace(&mut v, &BidiRect::new(4, 4, 2, 2), |_| true, |_| 1);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn replace_value_matches_replace_on_all_layouts() {
+    let rect = BidiRect::new(1, 1, 30, 3);
+    let source = BidiVec::with_size_func_xy(6, 5, |x, y| (x * 3 + y) as u8 % 4);
+
+    let mut expected = source.clone();
+    let count = editing::replace(&mut expected, &rect, |n| *n == 2, |_| 9);
+    let in_rect = source
+        .iter()
+        .with_coords()
+        .filter(|(x, y, n)| **n == 2 && *x >= 1 && *y >= 1 && *y < 4)
+        .count();
+    assert!(count > 0);
+    assert_eq!(count, in_rect);
+
+    let mut v = source.clone();
+    assert_eq!(editing::replace_value(&mut v, &rect, 2, 9), count);
+    assert_eq!(v, expected);
+
+    let mut g = BidiGrowVec::from(source.clone());
+    assert_eq!(editing::replace_value(&mut g, &rect, 2, 9), count);
+    assert_eq!(g, expected);
+
+    // a view without row slices
+    let mut tracked = BidiTracked::new(source);
+    assert_eq!(editing::replace_value(&mut tracked, &rect, 2, 9), count);
+    assert_eq!(tracked.take_dirty(), Some(BidiRect::new(1, 1, 5, 3)));
+    assert_eq!(tracked.into_inner(), expected);
+
+    assert_eq!(editing::replace_value(&mut v, &rect, 2, 9), 0);
+}
+
+#[test]
+fn histogram_on_transformed_and_clipped() {
+    let v = bidivec! {
+        [1, 2, 2],
+        [3, 3, 3],
+    };
+
+    let histogram = editing::histogram(&v.clone().to_transposed());
+    assert_eq!(histogram.values().sum::<usize>(), 6);
+    assert_eq!(histogram[&3], 3);
+
+    let histogram = editing::histogram_in_rect(&v, &BidiRect::new(2, 1, 5, 5));
+    assert_eq!(histogram.len(), 1);
+    assert_eq!(histogram[&3], 1);
+
+    let empty = BidiVec::<u8>::new();
+    assert!(editing::histogram(&empty).is_empty());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_fill_and_shuffle_are_deterministic() {
+    use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+
+    let mut a = bidivec![0i32; 6, 5];
+    let mut b = bidigrowvec![0i32; 6, 5];
+
+    let rect = BidiRect::new(1, 1, 10, 3);
+    editing::fill_random(
+        &mut a,
+        &rect,
+        &mut StdRng::seed_from_u64(42),
+        Uniform::new(1, 1000),
+    );
+    editing::fill_random(
+        &mut b,
+        &rect,
+        &mut StdRng::seed_from_u64(42),
+        Uniform::new(1, 1000),
+    );
+
+    assert_view_eq_views(&a, &b);
+    assert!(a.iter().on_row(0).all(|n| *n == 0));
+    assert!(a.iter().on_row(4).all(|n| *n == 0));
+    assert!(a.iter().on_column(0).all(|n| *n == 0));
+    assert!(a.iter().on_row(2).skip(1).all(|n| *n != 0));
+
+    let mut sorted = a.clone().into_vec();
+    editing::shuffle(&mut a, &mut StdRng::seed_from_u64(7));
+    editing::shuffle(&mut b, &mut StdRng::seed_from_u64(7));
+    assert_view_eq_views(&a, &b);
+
+    let mut shuffled = a.clone().into_vec();
+    sorted.sort_unstable();
+    shuffled.sort_unstable();
+    assert_eq!(sorted, shuffled);
+
+    let mut rows = BidiVec::from_iterator((0..30i32).map(|n| n / 6), 6).unwrap();
+    editing::shuffle_rows(&mut rows, &mut StdRng::seed_from_u64(3));
+    for y in 0..5 {
+        assert!(rows.iter().on_row(y).all(|n| *n == y as i32));
+    }
+}
+
+#[cfg(feature = "noise")]
+#[test]
+fn fill_noise_octaves() {
+    use editing::NoiseParams;
+
+    let params = NoiseParams {
+        scale: 1.0,
+        offset: (0.0, 0.0),
+        octaves: 2,
+        persistence: 1.0,
+        lacunarity: 2.0,
+    };
+
+    // (f(x, y) + f(2x, 2y)) / 2, with f(x, y) = x
+    assert_eq!(params.sample(&|x, _| x, 1, 0), 1.5);
+
+    let mut v = bidivec![-1.0; 3, 2];
+    editing::fill_noise(&mut v, &BidiRect::new(1, 0, 5, 1), &params, |x, _| x);
+
+    assert_eq!(v[(0, 0)], -1.0);
+    assert_eq!(v[(2, 0)], 3.0);
+    assert_eq!(v[(2, 1)], -1.0);
+
+    let zero_octaves = NoiseParams {
+        octaves: 0,
+        ..params
+    };
+    assert_eq!(zero_octaves.sample(&|x, _| x, 1, 0), 1.0);
+
+    let from_noise = BidiVec::from_noise(3, 2, &params, |x, _| x);
+    assert_eq!(from_noise[(2, 1)], 3.0);
+}
+
+#[test]
+fn flood_blend_skips_outside_source() {
+    let mut v = bidivec![1; 4, 3];
+    let source = bidivec![10; 2, 2];
+
+    let count = editing::flood_blend(
+        &source,
+        &mut v,
+        (3, 2),
+        BidiNeighbours::Bordering,
+        |_, a, b| a == b,
+        |s, d| *d += s,
+    )
+    .unwrap();
+
+    assert_eq!(count, 4);
+    assert_eq!(
+        v,
+        bidivec! {
+            [11, 11, 1, 1],
+            [11, 11, 1, 1],
+            [1, 1, 1, 1],
+        }
+    );
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        editing::flood_blend(
+            &source,
+            &mut v,
+            (4, 0),
+            BidiNeighbours::Adjacent,
+            |_, a, b| a == b,
+            |s, d| *d += s,
+        ),
+    );
+}
+
+#[test]
+fn fill_and_count_match_on_all_layouts() {
+    let rect = BidiRect::new(2, 1, 30, 3);
+
+    let mut v = BidiVec::with_size_func_xy(6, 5, |x, y| (x + y) as u8 % 3);
+    let mut g = BidiGrowVec::from(v.clone());
+    let mut t = v.clone().to_transposed();
+
+    editing::fill(&mut v, &rect, 9);
+    editing::fill(&mut g, &rect, 9);
+    editing::fill(&mut t, &BidiRect::new(1, 2, 3, 30), 9);
+
+    let t = t.to_transposed();
+    assert_eq!(v, g);
+    assert!(v.equivalent(&t));
+    assert_eq!(editing::count(&v, &9), 12);
+    assert_eq!(editing::count(&g, &9), 12);
+    assert_eq!(editing::count(&v.clone().to_transposed(), &9), 12);
+
+    for value in 0..3 {
+        let expected = v.iter().filter(|n| **n == value).count();
+        assert_eq!(editing::count(&g, &value), expected);
+        assert_eq!(editing::count(&t, &value), expected);
+    }
+
+    assert_eq!(editing::count_in_rect(&v, &rect, &9), 12);
+    assert_eq!(
+        editing::count_in_rect(&v, &BidiRect::new(0, 0, 2, 5), &9),
+        0
+    );
+}
+
+#[test]
+fn equality_across_layouts() {
+    let v = BidiVec::with_size_func_xy(5, 4, |x, y| x * 10 + y);
+    let g = BidiGrowVec::from(v.clone());
+    let a = BidiArray::from(v.clone());
+
+    assert_eq!(v, g);
+    assert_eq!(a, g);
+    assert!(v.equivalent(&g));
+    assert!(v.equivalent(&v.clone().to_transposed().to_transposed()));
+
+    let mut other = g.clone();
+    other[(4, 3)] = 0;
+    assert_ne!(v, other);
+    assert!(!v.equivalent(&other));
+    assert!(!v.clone().to_transposed().to_transposed().equivalent(&other));
+}
+
+#[test]
+fn stamp_symmetric_copies() {
+    use editing::Symmetry;
+
+    let source = bidivec! {
+        [1, 2],
+        [3, 4],
+        [5, 6],
+    };
+
+    let mut v = BidiVec::with_elem(0, 5, 5);
+    editing::stamp_symmetric(&mut v, &source, 0, 1, Symmetry::Horizontal).unwrap();
+    assert_eq!(v.as_rows()[1], &[1, 2, 0, 2, 1]);
+    assert_eq!(v.as_rows()[3], &[5, 6, 0, 6, 5]);
+    assert_eq!(v.as_rows()[0], &[0, 0, 0, 0, 0]);
+
+    let mut v = BidiVec::with_elem(0, 4, 5);
+    editing::stamp_symmetric(&mut v, &source, 1, 1, Symmetry::Vertical).unwrap();
+    // the copies overlap on the middle row, where the source wins
+    assert_eq!(
+        v,
+        bidivec! {
+            [0, 0, 0, 0],
+            [0, 1, 2, 0],
+            [0, 3, 4, 0],
+            [0, 5, 6, 0],
+            [0, 0, 0, 0],
+        }
+    );
+
+    let mut v = BidiVec::with_elem(0, 7, 7);
+    editing::stamp_symmetric(&mut v, &source, 2, 0, Symmetry::EightFold).unwrap();
+    assert_eq!(v[(2, 0)], 1);
+    assert_eq!(v[(0, 2)], 1);
+    assert_eq!(v[(4, 6)], 1);
+    assert_eq!(v.iter().filter(|&&n| n == 1).count(), 8);
+    // items on the diagonals have only 4 distinct copies
+    assert_eq!(v.iter().filter(|&&n| n == 5).count(), 4);
+    let mut flipped = v.clone();
+    flipped.reverse_rows();
+    assert_eq!(flipped, v);
+    flipped.reverse_columns();
+    assert_eq!(flipped, v);
+    flipped.transpose();
+    assert_eq!(flipped, v);
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        editing::stamp_symmetric(
+            &mut BidiVec::with_elem(0, 7, 6),
+            &source,
+            0,
+            0,
+            Symmetry::EightFold,
+        ),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        editing::stamp_symmetric(&mut v, &source, 6, 0, Symmetry::FourFold),
+    );
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        editing::stamp_symmetric(&mut v, &source, 0, usize::MAX, Symmetry::FourFold),
+    );
+}
+
+#[test]
+fn view_borders_match_draw_border() {
+    for &(width, height) in [(6, 5), (1, 7), (4, 1), (3, 3), (0, 0)].iter() {
+        for thickness in 0..5 {
+            let mut drawn = BidiVec::with_elem(false, width, height);
+            editing::draw_border(&mut drawn, thickness, true);
+
+            let expected = BidiVec::with_size_func_xy(width, height, |x, y| {
+                x < thickness || y < thickness || x + thickness >= width || y + thickness >= height
+            });
+            assert_eq!(drawn, expected, "{}x{} by {}", width, height, thickness);
+
+            let frame = expected
+                .iter()
+                .with_coords()
+                .filter(|(_, _, b)| **b)
+                .map(|(x, y, _)| (x, y))
+                .collect::<Vec<_>>();
+            let iterated = drawn
+                .iter()
+                .on_view_border(thickness)
+                .with_coords()
+                .map(|(x, y, _)| (x, y))
+                .collect::<Vec<_>>();
+            assert_eq!(iterated, frame);
+            assert!(drawn.iter().on_view_border(thickness).all(|b| *b));
+
+            let mut cleared = drawn.clone();
+            for item in cleared.iter_mut().on_view_border(thickness) {
+                *item = false;
+            }
+            assert!(cleared.iter().all(|b| !*b));
+
+            let mut counted = 0;
+            for (x, y, item) in cleared.iter_mut().with_coords().on_view_border(thickness) {
+                assert!(expected[(x, y)]);
+                *item = true;
+                counted += 1;
+            }
+            assert_eq!(counted, frame.len());
+            assert_eq!(cleared, expected);
+        }
+    }
+}
+
+#[test]
+fn checkerboards_and_stripes_match_tiled_patterns() {
+    use editing::StripeDirection;
+
+    for cell_size in 1..4 {
+        let mut pattern = BidiVec::with_elem(0, cell_size * 2, cell_size * 2);
+        editing::fill(&mut pattern, &BidiRect::new(0, 0, cell_size, cell_size), 1);
+        editing::fill(
+            &mut pattern,
+            &BidiRect::new(cell_size, cell_size, cell_size, cell_size),
+            1,
+        );
+
+        let mut expected = BidiVec::with_elem(9, 11, 7);
+        editing::tile_fill(
+            &mut expected,
+            &BidiRect::new(0, 0, 20, 20),
+            &pattern,
+            (0, 0),
+        )
+        .unwrap();
+
+        // filled in two halves, which must line up
+        let mut v = BidiVec::with_elem(9, 11, 7);
+        editing::fill_checkerboard(&mut v, &BidiRect::new(0, 0, 5, 7), 1, 0, cell_size).unwrap();
+        editing::fill_checkerboard(&mut v, &BidiRect::new(5, 0, 50, 50), 1, 0, cell_size).unwrap();
+        assert_eq!(v, expected);
+
+        let rect = v.bounding_rect();
+        editing::fill_stripes(&mut v, &rect, 1, 0, cell_size, StripeDirection::Horizontal).unwrap();
+        assert!(v
+            .iter()
+            .with_coords()
+            .all(|(_, y, n)| *n == if (y / cell_size) % 2 == 0 { 1 } else { 0 }));
+
+        editing::fill_stripes(&mut v, &rect, 1, 0, cell_size, StripeDirection::Vertical).unwrap();
+        assert!(v
+            .iter()
+            .with_coords()
+            .all(|(x, _, n)| *n == if (x / cell_size) % 2 == 0 { 1 } else { 0 }));
+
+        editing::fill_stripes(&mut v, &rect, 1, 0, cell_size, StripeDirection::Diagonal).unwrap();
+        assert!(v
+            .iter()
+            .with_coords()
+            .all(|(x, y, n)| *n == if ((x + y) / cell_size) % 2 == 0 { 1 } else { 0 }));
+    }
+
+    let mut v = BidiVec::with_elem(9, 3, 3);
+    let rect = v.bounding_rect();
+    assert_eq!(
+        editing::fill_checkerboard(&mut v, &rect, 1, 0, 0),
+        Err(BidiError::InvalidArgument {
+            argument: "cell_size"
+        })
+    );
+    assert_eq!(
+        editing::fill_stripes(&mut v, &rect, 1, 0, 0, StripeDirection::Diagonal),
+        Err(BidiError::InvalidArgument {
+            argument: "stripe_width"
+        })
+    );
+    assert!(v.iter().all(|n| *n == 9));
+}
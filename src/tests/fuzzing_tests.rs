@@ -0,0 +1,50 @@
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_respects_invariants() {
+    use crate::*;
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    let bytes = (0..=255u8).cycle().take(4096).collect::<Vec<u8>>();
+
+    for start in 0..64 {
+        let mut u = Unstructured::new(&bytes[start * 13..]);
+        let v = BidiVec::<u16>::arbitrary(&mut u).unwrap();
+        assert_eq!(v.len(), v.width() * v.height());
+        assert_eq!(v.is_empty(), v.width() == 0);
+
+        let a = BidiArray::<u8>::arbitrary(&mut u).unwrap();
+        assert_eq!(a.len(), a.width() * a.height());
+
+        let g = BidiGrowVec::<bool>::arbitrary(&mut u).unwrap();
+        assert_eq!(g.len(), g.width() * g.height());
+    }
+
+    let mut u = Unstructured::new(&[]);
+    assert!(BidiVec::<u32>::arbitrary(&mut u).unwrap().is_empty());
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_strategies {
+    use crate::fuzzing::{bidiarray_strategy, bidigrowvec_strategy, bidivec_strategy};
+    use ::proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bidivec_strategy_respects_ranges(v in bidivec_strategy(2..5, 3..=4, any::<i32>())) {
+            prop_assert!((2..5).contains(&v.width()));
+            prop_assert!((3..=4).contains(&v.height()));
+            prop_assert_eq!(v.len(), v.width() * v.height());
+        }
+
+        #[test]
+        fn bidiarray_strategy_respects_ranges(a in bidiarray_strategy(1..3, 1..3, 0..10u8)) {
+            prop_assert!((1..3).contains(&a.width()));
+            prop_assert!(a.iter().all(|n| *n < 10));
+        }
+
+        #[test]
+        fn bidigrowvec_strategy_may_be_empty(g in bidigrowvec_strategy(0..3, 0..3, any::<bool>())) {
+            prop_assert_eq!(g.is_empty(), g.width() == 0 || g.height() == 0);
+        }
+    }
+}
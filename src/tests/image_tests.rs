@@ -0,0 +1,24 @@
+use super::*;
+use image::{ImageBuffer, Rgb};
+
+#[test]
+fn image_buffer_roundtrip() {
+    let img = ImageBuffer::from_fn(5, 3, |x, y| Rgb([x as u8, y as u8, (x * y) as u8]));
+
+    let v = BidiVec::from_image_buffer(&img);
+    assert_eq!(v.size(), (5, 3));
+    assert!(v
+        .iter()
+        .with_coords()
+        .all(|(x, y, p)| *p == Rgb([x as u8, y as u8, (x * y) as u8])));
+
+    let back = formats::to_image_buffer(&v);
+    assert_eq!(back, img);
+
+    let rotated = formats::to_image_buffer(&v.clone().to_rotated90ccw());
+    assert_eq!(rotated.dimensions(), (3, 5));
+    assert_eq!(rotated.get_pixel(0, 0), img.get_pixel(4, 0));
+
+    let empty: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(0, 0);
+    assert!(BidiVec::from_image_buffer(&empty).is_empty());
+}
@@ -0,0 +1,37 @@
+use super::*;
+use nalgebra::DMatrix;
+
+#[test]
+fn dmatrix_conversions_keep_rows() {
+    let v = bidivec! {
+        [1, 2, 3],
+        [4, 5, 6],
+    };
+
+    let m = DMatrix::from(v.clone());
+    assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+    assert_eq!(formats::to_dmatrix(&v), m);
+    assert_eq!(BidiVec::from(m.clone()), v);
+
+    // through a transforming view
+    assert_eq!(
+        formats::to_dmatrix(&v.clone().to_transposed()),
+        m.transpose()
+    );
+
+    let column = DMatrix::from_column_slice(3, 1, &[7, 8, 9]);
+    assert_eq!(
+        BidiVec::from(column),
+        bidivec! {
+            [7],
+            [8],
+            [9],
+        }
+    );
+
+    for &(rows, cols) in [(0, 0), (0, 3), (3, 0)].iter() {
+        let empty = DMatrix::<i32>::zeros(rows, cols);
+        assert!(BidiVec::from(empty).is_empty());
+    }
+    assert_eq!(DMatrix::from(BidiVec::<i32>::new()).shape(), (0, 0));
+}
@@ -0,0 +1,940 @@
+use super::*;
+use pathfinding::heuristics;
+
+fn path_cost<C: pathfinding::PathFindCost>(data: &pathfinding::PathFindData<C>) -> Option<C> {
+    match data.result {
+        pathfinding::PathFindDataResult::ShortestPathFound(c) => Some(c),
+        _ => None,
+    }
+}
+
+#[test]
+fn heuristics_values() {
+    let (from, to) = ((1, 7), (4, 3));
+
+    assert_eq!(heuristics::manhattan(2u32)(from, to), 14);
+    assert_eq!(heuristics::chebyshev(2u32)(from, to), 8);
+    assert_eq!(heuristics::octile(10u32, 14u32)(from, to), 52);
+    assert_eq!(heuristics::euclidean(1.0f64)(from, to), 5.0);
+    assert_eq!(heuristics::euclidean(3u32)(from, to), 15);
+    assert_eq!(
+        heuristics::weighted(heuristics::manhattan(1u32), 1.5)(from, to),
+        10
+    );
+}
+
+#[test]
+fn heuristics_find_same_cost_as_djikstra() {
+    let map = bidivec! {
+        [1, 1, 1, 1, 1, 1],
+        [1, 9, 9, 9, 9, 1],
+        [1, 1, 1, 1, 9, 1],
+        [9, 9, 9, 1, 9, 1],
+        [1, 1, 1, 1, 1, 1],
+    };
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+    let (start, dest) = ((0, 2), (5, 4));
+
+    let djikstra =
+        pathfinding::pathfind_to_dest(&map, start, dest, BidiNeighbours::Bordering, cost).unwrap();
+    let octile = pathfinding::pathfind_to_dest_heuristic(
+        &map,
+        start,
+        dest,
+        BidiNeighbours::Bordering,
+        cost,
+        heuristics::octile(1, 1),
+    )
+    .unwrap();
+
+    assert!(path_cost(&djikstra).is_some());
+    assert_eq!(path_cost(&djikstra), path_cost(&octile));
+
+    assert!(heuristics::find_inadmissible(
+        &map,
+        dest,
+        BidiNeighbours::Bordering,
+        cost,
+        heuristics::octile(1, 1)
+    )
+    .unwrap()
+    .is_empty());
+
+    let inadmissible = heuristics::find_inadmissible(
+        &map,
+        dest,
+        BidiNeighbours::Bordering,
+        cost,
+        heuristics::octile(2, 2),
+    )
+    .unwrap();
+    assert!(inadmissible.contains(&(5, 3)));
+    assert!(!inadmissible.contains(&(5, 4)));
+}
+
+#[test]
+fn custom_neighbours_match_grid_and_ignore_out_of_bounds() {
+    let map = bidivec! {
+        [1u32, 1, 1, 1],
+        [1, 5, 5, 1],
+        [1, 1, 9, 1],
+    };
+
+    let grid =
+        pathfinding::pathfind_to_whole(&map, (0, 0), BidiNeighbours::Adjacent, |_, _, to, _| {
+            Some(*to)
+        })
+        .unwrap();
+
+    let custom = pathfinding::pathfind_to_whole_custom(&map, (0, 0), |(x, y)| {
+        let mut moves = vec![((x + 100, y), 1)];
+        let (xs, ys) = (x as isize, y as isize);
+        for p in [(xs + 1, ys), (xs, ys + 1), (xs - 1, ys), (xs, ys - 1)].iter() {
+            if let Some(cost) = map.get_signed(p.0, p.1) {
+                moves.push(((p.0 as usize, p.1 as usize), *cost));
+            }
+        }
+        moves
+    })
+    .unwrap();
+
+    for (x, y, tile) in grid.tiles.iter().with_coords() {
+        assert_eq!(tile.cost, custom.tiles[(x, y)].cost);
+    }
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        pathfinding::pathfind_to_dest_custom(&map, (0, 0), (4, 0), |_| Vec::new(), |_, _| 0u32),
+    );
+}
+
+#[test]
+fn costs_of_any_type() {
+    let map = bidivec! {
+        [0.5, 0.25, 2.0],
+        [1.0, 8.0, 0.25],
+    };
+
+    let res = pathfinding::pathfind_to_dest(
+        &map,
+        (0, 0),
+        (2, 1),
+        BidiNeighbours::Adjacent,
+        |_, _, to, _| Some(*to),
+    )
+    .unwrap();
+    assert!(matches!(
+        res.result,
+        pathfinding::PathFindDataResult::ShortestPathFound(c) if c == 2.5
+    ));
+
+    // overflowing paths are impossible rather than panicking
+    let map = bidivec![100u8; 4, 1];
+    let res =
+        pathfinding::pathfind_to_whole(&map, (0, 0), BidiNeighbours::Adjacent, |_, _, to, _| {
+            Some(*to)
+        })
+        .unwrap();
+    assert_eq!(res.tiles[(2, 0)].cost, Some(200));
+    assert_eq!(res.tiles[(3, 0)].cost, None);
+
+    // negative costs are impossible movements
+    let map = bidivec![[1i32, -1, 1]];
+    let res =
+        pathfinding::pathfind_to_whole(&map, (0, 0), BidiNeighbours::Adjacent, |_, _, to, _| {
+            Some(*to)
+        })
+        .unwrap();
+    assert_eq!(res.tiles[(1, 0)].cost, None);
+    assert_eq!(res.tiles[(2, 0)].cost, None);
+}
+
+#[test]
+fn flow_field_follows_asymmetric_costs() {
+    // moving right is cheap, moving left is expensive
+    let map = bidivec![0u32; 5, 1];
+    let cost = |_: &u32, from: (usize, usize), _: &u32, to: (usize, usize)| {
+        Some(if to.0 > from.0 { 1u32 } else { 10 })
+    };
+
+    let field = pathfinding::flow_field(&map, &[(2, 0)], BidiNeighbours::Adjacent, cost).unwrap();
+
+    assert_eq!(field[(0, 0)].distance, Some(2));
+    assert_eq!(field[(4, 0)].distance, Some(20));
+    assert_eq!(field[(4, 0)].direction, (-1, 0));
+    assert_eq!(field[(0, 0)].direction, (1, 0));
+
+    // the flow field agrees with a regular search from any location
+    for x in 0..5 {
+        let res =
+            pathfinding::pathfind_to_dest(&map, (x, 0), (2, 0), BidiNeighbours::Adjacent, cost)
+                .unwrap();
+        assert!(matches!(
+            res.result,
+            pathfinding::PathFindDataResult::ShortestPathFound(c) if Some(c) == field[(x, 0)].distance
+        ));
+    }
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        pathfinding::flow_field(&map, &[(0, 0), (0, 1)], BidiNeighbours::Adjacent, cost),
+    );
+}
+
+#[test]
+fn dstar_lite_matches_full_searches() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(11);
+
+    for &neighbouring in [BidiNeighbours::Adjacent, BidiNeighbours::Bordering].iter() {
+        let mut map = BidiVec::with_size_func(12, 9, || {
+            if rng.gen_range(0..4) == 0 {
+                None
+            } else {
+                Some(rng.gen_range(1..5u32))
+            }
+        });
+        let (mut start, goal) = ((0, 0), (11, 8));
+        map[start] = Some(1);
+        map[goal] = Some(1);
+
+        let mut search = pathfinding::DStarLite::new(
+            &map,
+            start,
+            goal,
+            neighbouring,
+            |c, _| *c,
+            heuristics::chebyshev(1),
+        )
+        .unwrap();
+
+        for step in 0..60 {
+            let full =
+                pathfinding::pathfind_to_dest(&map, start, goal, neighbouring, |_, _, to, _| *to)
+                    .unwrap();
+            let expected = path_cost(&full);
+
+            assert_eq!(search.cost(), expected);
+
+            if let Some(path) = search.path() {
+                assert_eq!(path.start(), Some(start));
+                assert_eq!(path.end(), Some(goal));
+                let cost: u32 = path.iter_coords().skip(1).map(|p| map[p].unwrap()).sum();
+                assert_eq!(Some(cost), expected);
+                assert_eq!(Some(path.cost()), expected);
+
+                if step % 3 == 0 && path.len() > 1 {
+                    start = path.coords()[1];
+                    search.set_start(start).unwrap();
+                }
+            }
+
+            let (x, y) = (rng.gen_range(0..12), rng.gen_range(0..9));
+            if (x, y) != start && (x, y) != goal {
+                let cost = if rng.gen_range(0..3) == 0 {
+                    None
+                } else {
+                    Some(rng.gen_range(1..5u32))
+                };
+                map[(x, y)] = cost;
+                search.update_cell(x, y, cost).unwrap();
+            }
+        }
+    }
+}
+
+#[test]
+fn hierarchical_paths_are_valid_and_complete() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(5);
+    let map = BidiVec::with_size_func(37, 29, || {
+        if rng.gen_range(0..4) == 0 {
+            None
+        } else {
+            Some(rng.gen_range(1..4u32))
+        }
+    });
+
+    let pathfinder =
+        pathfinding::HierarchicalPathfinder::new(&map, BidiNeighbours::Adjacent, 6, |c, _| *c)
+            .unwrap();
+    assert!(pathfinder.abstract_nodes() > 0);
+
+    for _ in 0..40 {
+        let start = (rng.gen_range(0..37), rng.gen_range(0..29));
+        let goal = (rng.gen_range(0..37), rng.gen_range(0..29));
+
+        let full = pathfinding::pathfind_to_dest(
+            &map,
+            start,
+            goal,
+            BidiNeighbours::Adjacent,
+            |_, _, to, _| *to,
+        )
+        .unwrap();
+        let optimal = path_cost(&full);
+
+        match pathfinder.find_path(start, goal).unwrap() {
+            None => assert_eq!(optimal, None),
+            Some(path) => {
+                assert!(optimal.unwrap() <= path.cost());
+                assert_eq!(path.start(), Some(start));
+                assert_eq!(path.end(), Some(goal));
+                assert!(path
+                    .iter_directions()
+                    .all(|(dx, dy)| dx.abs() + dy.abs() == 1));
+
+                let total: u32 = path.iter_coords().skip(1).map(|p| map[p].unwrap()).sum();
+                assert_eq!(total, path.cost());
+            }
+        }
+    }
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        pathfinder.find_path((0, 0), (37, 0)),
+    );
+    assert_eq!(
+        pathfinding::HierarchicalPathfinder::new(&map, BidiNeighbours::Adjacent, 0, |c, _| *c)
+            .err(),
+        Some(BidiError::InvalidArgument {
+            argument: "cluster_size"
+        })
+    );
+
+    // clusters are only connected across their straight edges
+    let diagonal = BidiVec::with_size_func_xy(4, 4, |x, y| x == y && (x == 1 || x == 2));
+    let direct = pathfinding::pathfind_to_dest(
+        &diagonal,
+        (1, 1),
+        (2, 2),
+        BidiNeighbours::Bordering,
+        |_, _, open, _| if *open { Some(1u32) } else { None },
+    )
+    .unwrap();
+    assert_eq!(path_cost(&direct), Some(1));
+    let pathfinder = pathfinding::HierarchicalPathfinder::new(
+        &diagonal,
+        BidiNeighbours::Bordering,
+        2,
+        |open, _| {
+            if *open {
+                Some(1u32)
+            } else {
+                None
+            }
+        },
+    )
+    .unwrap();
+    assert_eq!(pathfinder.find_path((1, 1), (2, 2)).unwrap(), None);
+}
+
+#[test]
+fn nearest_matches_independent_searches() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(21);
+    let map = BidiVec::with_size_func(15, 15, || rng.gen_range(1..6u32));
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+    let points = (0..5)
+        .map(|_| (rng.gen_range(0..15), rng.gen_range(0..15)))
+        .collect::<Vec<_>>();
+    let center = (7, 7);
+
+    let best_to = points
+        .iter()
+        .map(|p| {
+            path_cost(
+                &pathfinding::pathfind_to_dest(&map, center, *p, BidiNeighbours::Bordering, cost)
+                    .unwrap(),
+            )
+            .unwrap()
+        })
+        .min();
+    let nearest =
+        pathfinding::pathfind_to_nearest(&map, center, &points, BidiNeighbours::Bordering, cost)
+            .unwrap();
+    assert_eq!(path_cost(&nearest), best_to);
+    assert!(points.contains(&nearest.destination.unwrap()));
+
+    let best_from = points
+        .iter()
+        .map(|p| {
+            path_cost(
+                &pathfinding::pathfind_to_dest(&map, *p, center, BidiNeighbours::Bordering, cost)
+                    .unwrap(),
+            )
+            .unwrap()
+        })
+        .min();
+    let nearest =
+        pathfinding::pathfind_from_nearest(&map, &points, center, BidiNeighbours::Bordering, cost)
+            .unwrap();
+    assert_eq!(path_cost(&nearest), best_from);
+    assert_eq!(nearest.destination, Some(center));
+
+    let none = pathfinding::pathfind_to_nearest(&map, center, &[], BidiNeighbours::Bordering, cost)
+        .unwrap();
+    assert_eq!(path_cost(&none), None);
+    assert_eq!(none.destination, None);
+}
+
+#[test]
+fn smooth_edge_cases() {
+    assert!(pathfinding::smooth(&[], |_, _| true).is_empty());
+    assert_eq!(pathfinding::smooth(&[(1, 1)], |_, _| true), vec![(1, 1)]);
+    assert_eq!(
+        pathfinding::smooth(&[(1, 1), (1, 2)], |_, _| false),
+        vec![(1, 1), (1, 2)]
+    );
+
+    // without any line of sight, the path is kept as is
+    let path = [(0, 0), (1, 0), (1, 1), (2, 1)];
+    assert_eq!(pathfinding::smooth(&path, |_, _| false), path.to_vec());
+
+    // line of sight is checked at most once per location
+    let calls = std::cell::Cell::new(0);
+    pathfinding::smooth(&path, |_, _| {
+        calls.set(calls.get() + 1);
+        true
+    });
+    assert!(calls.get() <= path.len());
+}
+
+#[test]
+fn path_from_data() {
+    let map = bidivec![1u32; 5, 4];
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+
+    let data = pathfinding::pathfind_to_dest(&map, (4, 0), (1, 3), BidiNeighbours::Bordering, cost)
+        .unwrap();
+    let mut path = data.path().unwrap();
+
+    assert_eq!(path.cost(), 3);
+    assert_eq!(path.start(), Some((4, 0)));
+    assert_eq!(path.end(), Some((1, 3)));
+    assert!(path.iter_directions().all(|d| d == (-1, 1)));
+
+    path.reverse();
+    assert_eq!(path.start(), Some((1, 3)));
+    assert!(path.iter_directions().all(|d| d == (1, -1)));
+
+    let whole =
+        pathfinding::pathfind_to_whole(&map, (0, 0), BidiNeighbours::Adjacent, cost).unwrap();
+    assert!(whole.path().is_none());
+    assert!(whole.path_to((9, 9)).is_none());
+
+    let path = whole.path_to((3, 2)).unwrap();
+    assert_eq!(path.cost(), 5);
+    assert_eq!(path.len(), 6);
+    assert_eq!(path.start(), Some((0, 0)));
+
+    let single = whole.path_to((0, 0)).unwrap();
+    assert_eq!(single.coords(), &[(0, 0)]);
+    assert_eq!(single.iter_directions().count(), 0);
+
+    let mut small = bidivec![0; 2, 2];
+    assert_err(BidiErrorKind::OutOfBounds, path.stamp(&mut small, 1));
+    assert_eq!(small, bidivec![0; 2, 2]);
+}
+
+#[test]
+fn limits_restrict_exploration() {
+    let map = bidivec! {
+        [1, 1, 1, 1, 1],
+        [1, 9, 9, 9, 1],
+        [1, 1, 1, 9, 1],
+    };
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+
+    let unlimited =
+        pathfinding::pathfind_to_whole(&map, (0, 0), BidiNeighbours::Adjacent, cost).unwrap();
+    let limited = pathfinding::pathfind_to_whole_limited(
+        &map,
+        (0, 0),
+        BidiNeighbours::Adjacent,
+        cost,
+        &mut pathfinding::PathFindLimits::new().with_max_cost(4),
+    )
+    .unwrap();
+
+    for (x, y, tile) in limited.tiles.iter().with_coords() {
+        let expected = unlimited.tiles[(x, y)].cost.filter(|c| *c <= 4);
+        assert_eq!(tile.cost, expected);
+    }
+
+    // visits happen in order of increasing cost, and stop when requested
+    let mut visits = Vec::new();
+    pathfinding::pathfind_to_whole_limited(
+        &map,
+        (0, 0),
+        BidiNeighbours::Adjacent,
+        cost,
+        &mut pathfinding::PathFindLimits::new().with_on_visit(|pos, c| {
+            visits.push((pos, c));
+            c < 3
+        }),
+    )
+    .unwrap();
+
+    assert!(visits.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert_eq!(visits.last().unwrap().1, 3);
+    assert_eq!(visits.iter().filter(|v| v.1 == 3).count(), 1);
+
+    // the destination is found within the exact budget, but not below it
+    for &(budget, found) in [(6, true), (5, false)].iter() {
+        let res = pathfinding::pathfind_to_dest_limited(
+            &map,
+            (0, 0),
+            (4, 2),
+            BidiNeighbours::Adjacent,
+            cost,
+            heuristics::manhattan(1),
+            &mut pathfinding::PathFindLimits::new().with_max_cost(budget),
+        )
+        .unwrap();
+        assert_eq!(path_cost(&res).is_some(), found);
+    }
+}
+
+#[test]
+fn invalid_budgets_only_reach_the_start() {
+    let map = BidiVec::with_elem(1, 5, 4);
+
+    let range = pathfinding::reachable(
+        &map,
+        (2, 1),
+        -1i32,
+        BidiNeighbours::Adjacent,
+        |_, _, to, _| Some(*to),
+    )
+    .unwrap();
+    assert_eq!(range[(2, 1)], Some(0));
+    assert_eq!(range.iter().filter(|c| c.is_some()).count(), 1);
+
+    // free movements don't get past a negative budget either
+    let range = pathfinding::reachable(
+        &map,
+        (2, 1),
+        -0.5f32,
+        BidiNeighbours::Adjacent,
+        |_, _, _, _| Some(0.0),
+    )
+    .unwrap();
+    assert_eq!(range.iter().filter(|c| c.is_some()).count(), 1);
+
+    let range = pathfinding::reachable(
+        &map,
+        (0, 0),
+        f64::NAN,
+        BidiNeighbours::Adjacent,
+        |_, _, _, _| Some(1.0),
+    )
+    .unwrap();
+    assert_eq!(range[(0, 0)], Some(0.0));
+    assert_eq!(range.iter().filter(|c| c.is_some()).count(), 1);
+
+    // while an infinite budget is no limit at all
+    let range = pathfinding::reachable(
+        &map,
+        (0, 0),
+        f64::INFINITY,
+        BidiNeighbours::Adjacent,
+        |_, _, _, _| Some(1.0),
+    )
+    .unwrap();
+    assert!(range.iter().all(|c| c.is_some()));
+}
+
+#[test]
+fn reachable_matches_whole_search() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(13);
+    let map = BidiVec::with_size_func(16, 11, || rng.gen_range(1..4u32));
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+
+    let whole =
+        pathfinding::pathfind_to_whole(&map, (5, 5), BidiNeighbours::Bordering, cost).unwrap();
+
+    for &budget in [0, 1, 4, 9, 100].iter() {
+        let range =
+            pathfinding::reachable(&map, (5, 5), budget, BidiNeighbours::Bordering, cost).unwrap();
+
+        assert_eq!(range.size(), map.size());
+        for (x, y, c) in range.iter().with_coords() {
+            assert_eq!(*c, whole.tiles[(x, y)].cost.filter(|c| *c <= budget));
+        }
+    }
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        pathfinding::reachable(&map, (16, 0), 3, BidiNeighbours::Bordering, cost),
+    );
+}
+
+#[test]
+fn fov_open_map_is_a_circle() {
+    let map = bidivec![0; 21, 15];
+
+    for &radius in [0, 1, 3, 6, 100].iter() {
+        let visible = pathfinding::fov(&map, (10, 7), radius, |_, _| false).unwrap();
+
+        for (x, y, v) in visible.iter().with_coords() {
+            let (dx, dy) = (x as isize - 10, y as isize - 7);
+            let r = radius.min(100) as isize;
+            assert_eq!(
+                *v,
+                dx * dx + dy * dy <= r * r,
+                "({}, {}) r={}",
+                x,
+                y,
+                radius
+            );
+        }
+    }
+}
+
+#[test]
+fn fov_walls_hide_outside_of_room() {
+    let map = BidiVec::with_size_func_xy(12, 10, |x, y| {
+        let wall = x == 2 || x == 8 || y == 1 || y == 7;
+        let inside = (2..=8).contains(&x) && (1..=7).contains(&y);
+        wall && inside
+    });
+
+    let visible = pathfinding::fov(&map, (4, 4), 50, |wall, _| *wall).unwrap();
+
+    for (x, y, v) in visible.iter().with_coords() {
+        let inside = (2..=8).contains(&x) && (1..=7).contains(&y);
+        assert_eq!(*v, inside, "({}, {})", x, y);
+    }
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        pathfinding::fov(&map, (12, 0), 5, |wall, _| *wall),
+    );
+}
+
+#[test]
+fn line_of_sight_is_symmetric_and_ignores_endpoints() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(8);
+    let map = BidiVec::with_size_func(9, 7, || rng.gen_range(0..5) == 0);
+    let blocking = |wall: &bool, _| *wall;
+
+    let coords = map
+        .iter()
+        .with_coords()
+        .map(|(x, y, _)| (x, y))
+        .collect::<Vec<_>>();
+
+    for &a in coords.iter() {
+        for &b in coords.iter() {
+            let los = pathfinding::line_of_sight(&map, a, b, blocking);
+            assert_eq!(los, pathfinding::line_of_sight(&map, b, a, blocking));
+
+            let expected = BidiLine::new(a, b).all(|p| p == a || p == b || !map[p]);
+            assert_eq!(los, expected);
+        }
+    }
+
+    assert!(!pathfinding::line_of_sight(&map, (0, 0), (9, 0), blocking));
+}
+
+#[test]
+fn bidirectional_finds_shortest_paths() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(34);
+
+    for round in 0..30 {
+        // some rounds use zero cost movements
+        let min_cost = if round % 3 == 0 { 0 } else { 1 };
+        let map = BidiVec::with_size_func(17, 13, || {
+            if rng.gen_range(0..4) == 0 {
+                None
+            } else {
+                Some(rng.gen_range(min_cost..4u32))
+            }
+        });
+        let cost = |_: &Option<u32>, _, to: &Option<u32>, _| *to;
+        let start = (rng.gen_range(0..17), rng.gen_range(0..13));
+        let dest = (rng.gen_range(0..17), rng.gen_range(0..13));
+        let neighbouring = if round % 2 == 0 {
+            BidiNeighbours::Adjacent
+        } else {
+            BidiNeighbours::Bordering
+        };
+        let heuristic = move |a, b| {
+            if min_cost == 0 {
+                0
+            } else {
+                heuristics::chebyshev(1u32)(a, b)
+            }
+        };
+
+        let expected =
+            pathfinding::pathfind_to_dest(&map, start, dest, neighbouring, cost).unwrap();
+        let res = pathfinding::pathfind_to_dest_bidirectional(
+            &map,
+            start,
+            dest,
+            neighbouring,
+            cost,
+            heuristic,
+        )
+        .unwrap();
+
+        assert_eq!(path_cost(&res), path_cost(&expected), "round {}", round);
+
+        if let Some(path) = res.path() {
+            assert_eq!(path.start(), Some(start));
+            assert_eq!(path.end(), Some(dest));
+            let total: u32 = path.iter_coords().skip(1).map(|p| map[p].unwrap()).sum();
+            assert_eq!(total, path.cost());
+            assert_eq!(
+                res.tiles.iter().filter(|t| t.in_shortest_path).count(),
+                path.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn any_angle_paths_are_valid_and_short() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(55);
+    let blocking = |wall: &bool, _| *wall;
+    let length = |a: (usize, usize), b: (usize, usize)| {
+        let (dx, dy) = (a.0 as f64 - b.0 as f64, a.1 as f64 - b.1 as f64);
+        (dx * dx + dy * dy).sqrt()
+    };
+
+    for _ in 0..20 {
+        let map = BidiVec::with_size_func(20, 15, || rng.gen_range(0..5) == 0);
+        let start = (rng.gen_range(0..20), rng.gen_range(0..15));
+        let dest = (rng.gen_range(0..20), rng.gen_range(0..15));
+
+        // the shortest grid path, with the same rules about corners
+        let grid = pathfinding::pathfind_to_dest(
+            &map,
+            start,
+            dest,
+            BidiNeighbours::Bordering,
+            |_, from_pos, to, to_pos| {
+                if !*to && pathfinding::line_of_sight(&map, from_pos, to_pos, blocking) {
+                    Some(length(from_pos, to_pos))
+                } else {
+                    None
+                }
+            },
+        )
+        .unwrap();
+
+        let res = pathfinding::pathfind_to_dest_any_angle(&map, start, dest, blocking).unwrap();
+
+        match (res, path_cost(&grid)) {
+            (None, grid_cost) => assert!(grid_cost.is_none() || map[dest]),
+            (Some(_), None) => panic!("path found where the grid search found none"),
+            (Some(path), Some(grid_cost)) => {
+                assert_eq!(path.start(), Some(start));
+                assert_eq!(path.end(), Some(dest));
+
+                let mut total = 0.0;
+                for step in path.coords().windows(2) {
+                    assert!(pathfinding::line_of_sight(&map, step[0], step[1], blocking));
+                    total += length(step[0], step[1]);
+                }
+
+                assert!((total - path.cost()).abs() < 1e-9);
+                assert!(path.cost() + 1e-9 >= length(start, dest));
+                assert!(path.cost() <= grid_cost + 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn wrapping_paths_cross_the_edges() {
+    use pathfinding::PathFindWrap;
+
+    let map = bidivec![1u32; 7, 5];
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+
+    for &(wrap, expected) in [
+        (PathFindWrap::Horizontal, 2 + 3),
+        (PathFindWrap::Vertical, 5 + 2),
+        (PathFindWrap::Both, 2 + 2),
+    ]
+    .iter()
+    {
+        let neighbours =
+            pathfinding::wrapping_neighbours(&map, BidiNeighbours::Adjacent, wrap, cost);
+        let heuristic = heuristics::wrapping(heuristics::manhattan(1), map.size(), wrap);
+
+        let res =
+            pathfinding::pathfind_to_dest_custom(&map, (0, 0), (5, 3), neighbours, &heuristic)
+                .unwrap();
+        assert_eq!(path_cost(&res), Some(expected), "{:?}", wrap);
+        assert!(heuristic((0, 0), (5, 3)) <= expected);
+
+        // the heuristic stays admissible on the whole map
+        let whole = pathfinding::pathfind_to_whole_custom(
+            &map,
+            (5, 3),
+            pathfinding::wrapping_neighbours(&map, BidiNeighbours::Adjacent, wrap, cost),
+        )
+        .unwrap();
+        for (x, y, tile) in whole.tiles.iter().with_coords() {
+            assert_eq!(
+                tile.cost.map(|c| heuristic((x, y), (5, 3)) == c),
+                Some(true)
+            );
+        }
+    }
+
+    // maps too small to have distinct neighbours across the edges
+    let narrow = bidivec![1u32; 1, 2];
+    let neighbours = pathfinding::wrapping_neighbours(
+        &narrow,
+        BidiNeighbours::Bordering,
+        PathFindWrap::Both,
+        cost,
+    );
+    assert_eq!(neighbours((0, 0)), vec![((0, 1), 1)]);
+}
+
+#[test]
+fn stepping_search_matches_whole_search() {
+    use pathfinding::{PathFindLimits, PathFindSearch, PathFindStatus};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(89);
+    let map = BidiVec::with_size_func(25, 18, || rng.gen_range(1..5u32));
+    let cost = |_: &u32, _, to: &u32, _| Some(*to);
+
+    let expected =
+        pathfinding::pathfind_to_dest(&map, (2, 3), (21, 15), BidiNeighbours::Bordering, cost)
+            .unwrap();
+
+    let mut search = PathFindSearch::to_dest(
+        &map,
+        (2, 3),
+        (21, 15),
+        BidiNeighbours::Bordering,
+        cost,
+        |_, _| 0,
+    )
+    .unwrap();
+
+    let mut steps = 0;
+    while search.step(7) == PathFindStatus::Searching {
+        assert!(!search.is_finished());
+        assert!(
+            search
+                .data()
+                .tiles
+                .iter()
+                .filter(|t| t.cost.is_some())
+                .count()
+                <= 7 * (steps + 1)
+        );
+        steps += 1;
+    }
+
+    assert!(search.is_finished());
+    assert_eq!(search.step(7), PathFindStatus::Finished);
+    assert!(steps > 10);
+
+    let data = search.into_data();
+    assert_eq!(path_cost(&data), path_cost(&expected));
+    assert_eq!(data.path(), expected.path());
+
+    // a whole search with limits, stopped early
+    let mut search = PathFindSearch::to_whole(&map, (0, 0), BidiNeighbours::Adjacent, cost)
+        .unwrap()
+        .with_limits(PathFindLimits::new().with_max_cost(6));
+    assert_eq!(search.step(usize::MAX), PathFindStatus::Finished);
+    assert!(search
+        .into_data()
+        .tiles
+        .iter()
+        .all(|t| t.cost.map_or(true, |c| c <= 6)));
+
+    // a search cancelled before reaching the destination
+    let mut search = PathFindSearch::to_dest(
+        &map,
+        (0, 0),
+        (24, 17),
+        BidiNeighbours::Adjacent,
+        cost,
+        |_, _| 0,
+    )
+    .unwrap();
+    assert_eq!(search.step(3), PathFindStatus::Searching);
+    assert!(path_cost(&search.into_data()).is_none());
+
+    assert_err(
+        BidiErrorKind::OutOfBounds,
+        PathFindSearch::to_whole(&map, (25, 0), BidiNeighbours::Adjacent, cost),
+    );
+}
+
+#[test]
+fn cost_grid_search_matches_whole_search() {
+    use pathfinding::BidiCostGrid;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(97);
+    let map = BidiVec::with_size_func(23, 19, || rng.gen_range(1..5u32));
+
+    let expected = pathfinding::pathfind_to_whole(
+        &map,
+        (4, 7),
+        BidiNeighbours::Bordering,
+        |_, _, to: &u32, _| Some(*to),
+    )
+    .unwrap();
+
+    let mut grid = BidiCostGrid::for_view(&map);
+    assert!(grid.relax(4, 7, 0));
+    assert!(!grid.relax(4, 7, 0));
+    assert!(!grid.relax(23, 0, 0));
+
+    let mut last_cost = 0;
+    while let Some(((x, y), cost)) = grid.pop() {
+        assert!(cost >= last_cost);
+        last_cost = cost;
+
+        for offset in BidiNeighbours::Bordering.offsets() {
+            let nx = (x as isize + offset.x) as usize;
+            let ny = (y as isize + offset.y) as usize;
+            if let Some(to) = map.get(nx, ny) {
+                grid.relax_from(nx, ny, cost + to, (x, y));
+            }
+        }
+    }
+
+    assert!(grid.is_exhausted());
+    assert_eq!(grid.finalized().count(), map.len());
+    assert!(!grid.relax(0, 0, 0));
+
+    for (x, y, tile) in expected.tiles.iter().with_coords() {
+        assert!(grid.is_finalized(x, y));
+        assert_eq!(grid.cost(x, y), tile.cost);
+        assert_eq!(
+            grid.path_to((x, y)).map(|p| p.cost()),
+            expected.path_to((x, y)).map(|p| p.cost())
+        );
+    }
+    assert_eq!(grid.origin(4, 7), None);
+
+    grid.clear();
+    assert_eq!(grid.cost(4, 7), None);
+    assert!(grid.pop().is_none());
+}
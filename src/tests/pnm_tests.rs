@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn pgm_and_ppm_layout() {
+    let v = bidivec! {
+        [1u8, 2, 3],
+        [4, 5, 6],
+    };
+
+    // through a transforming view, to check that items are written in view order
+    let mut out = Vec::new();
+    formats::write_pgm(&v.clone().to_transposed(), &mut out).unwrap();
+    assert_eq!(out, b"P5\n2 3\n255\n\x01\x04\x02\x05\x03\x06".to_vec());
+
+    let colors = BidiVec::from_view_map(&v, |n| (*n, n * 10, n * 20));
+    let mut out = Vec::new();
+    formats::write_ppm(&colors, &mut out).unwrap();
+    assert_eq!(&out[..11], b"P6\n3 2\n255\n");
+    assert_eq!(out.len(), 11 + 3 * 6);
+    assert_eq!(&out[11 + 3 * 4..11 + 3 * 5], &[5, 50, 100]);
+
+    let mut out = Vec::new();
+    formats::write_pgm(&BidiVec::<u8>::new(), &mut out).unwrap();
+    assert_eq!(out, b"P5\n0 0\n255\n".to_vec());
+}
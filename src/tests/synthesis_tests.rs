@@ -0,0 +1,104 @@
+use super::*;
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::HashSet;
+
+fn windows(view: &BidiVec<char>, n: usize, periodic: bool) -> HashSet<Vec<char>> {
+    let (width, height) = view.size();
+    let (max_x, max_y) = if periodic {
+        (width, height)
+    } else {
+        (width - n + 1, height - n + 1)
+    };
+
+    let mut result = HashSet::new();
+    for y in 0..max_y {
+        for x in 0..max_x {
+            result.insert(
+                (0..n * n)
+                    .map(|i| view[((x + i % n) % width, (y + i / n) % height)])
+                    .collect(),
+            );
+        }
+    }
+    result
+}
+
+#[test]
+fn overlapping_model_only_uses_example_patterns() {
+    let example = bidivec! {
+        ['.', '.', '.', '.', '.'],
+        ['.', '#', '#', '#', '.'],
+        ['.', '#', '~', '#', '.'],
+        ['.', '#', '#', '#', '.'],
+        ['.', '.', '.', '.', '.'],
+    };
+
+    for periodic_output in [false, true].iter().copied() {
+        let options = synthesis::OverlappingOptions {
+            periodic_output,
+            max_attempts: 50,
+            ..synthesis::OverlappingOptions::default()
+        };
+
+        let output =
+            synthesis::overlapping_model(&example, 10, 10, &options, &mut StdRng::seed_from_u64(1))
+                .unwrap();
+
+        assert_eq!(output.size(), (10, 10));
+
+        let allowed = windows(&example, 3, true);
+        assert!(windows(&output, 3, periodic_output).is_subset(&allowed));
+    }
+}
+
+#[test]
+fn overlapping_model_errors() {
+    let example = bidivec!['a'; 2, 2];
+    let mut rng = StdRng::seed_from_u64(1);
+    let options = synthesis::OverlappingOptions::default();
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        synthesis::overlapping_model(&example, 5, 5, &options, &mut rng),
+    );
+
+    let options = synthesis::OverlappingOptions {
+        pattern_size: 2,
+        ..options
+    };
+
+    assert_err(
+        BidiErrorKind::IncompatibleSize,
+        synthesis::overlapping_model(&example, 1, 5, &options, &mut rng),
+    );
+
+    let output = synthesis::overlapping_model(&example, 0, 5, &options, &mut rng).unwrap();
+    assert!(output.is_empty());
+
+    let empty = synthesis::OverlappingOptions {
+        pattern_size: 0,
+        ..options
+    };
+    assert_err(
+        BidiErrorKind::InvalidArgument,
+        synthesis::overlapping_model(&example, 5, 5, &empty, &mut rng),
+    );
+
+    // a non-periodic example whose rows can't repeat: the output is
+    // too tall for any valid arrangement
+    let example = bidivec! {
+        ['a', 'a'],
+        ['b', 'b'],
+    };
+    let options = synthesis::OverlappingOptions {
+        pattern_size: 2,
+        periodic_input: false,
+        max_attempts: 3,
+        ..options
+    };
+
+    assert_err(
+        BidiErrorKind::NoSolution,
+        synthesis::overlapping_model(&example, 2, 3, &options, &mut rng),
+    );
+}
@@ -0,0 +1,41 @@
+use crate::testing::diff_views;
+use crate::*;
+
+#[test]
+fn diff_views_describes_differences() {
+    let v = bidivec! {
+        [1, 2],
+        [3, 4],
+    };
+
+    assert_eq!(diff_views(&v, &v.clone().into_bidigrowvec()), None);
+    assert_eq!(diff_views(&BidiVec::<i32>::new(), &BidiArray::new()), None);
+
+    let diff = diff_views(&v, &bidivec![1, 2, 3, 5; 2]).unwrap();
+    let lines = diff.lines().collect::<Vec<&str>>();
+    assert_eq!(lines[0], "Width: 2 found, 2 expected");
+    assert_eq!(lines[1], "Height: 2 found, 2 expected");
+    assert_eq!(lines[2], "Found items:");
+    assert_eq!(lines[3], "\t1, 2, ");
+    assert!(lines[4].starts_with("\t3, ") && lines[4] != "\t3, 4, ");
+    assert_eq!(lines[5], "Expected items:");
+    assert_eq!(lines.len(), 8);
+
+    let diff = diff_views(&v, &bidivec![1, 2, 3; 3]).unwrap();
+    assert!(diff.lines().all(|l| !l.starts_with("Width: 2 found, 3")));
+    assert_eq!(diff.lines().count(), 7);
+}
+
+#[test]
+fn assert_bidi_eq_compares_views_of_different_types() {
+    let v = bidivec![1, 2, 3, 4; 2];
+    assert_bidi_eq!(v, bidiarray![1, 2, 3, 4; 2]);
+    assert_bidi_eq!(v.clone().to_rotated180(), bidivec![4, 3, 2, 1; 2],);
+    assert_bidi_eq!(v, v.clone(), "comparing {} with itself", "v");
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: rows differ")]
+fn assert_bidi_eq_panics_with_message() {
+    assert_bidi_eq!(bidivec![1, 2; 2], bidivec![1; 2, 1], "rows differ");
+}
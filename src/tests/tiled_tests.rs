@@ -0,0 +1,135 @@
+use super::*;
+use crate::formats::{
+    read_tiled_layers, read_tiled_layers_limited, read_tiled_layers_with, TiledTile,
+};
+
+#[test]
+fn tiled_reads_base64_and_groups() {
+    // 2x2 layer, tiles 1, 2, 3 and 0x20000004 (flipped diagonally), little endian
+    let map = r#"{
+        "layers": [
+            { "type": "objectgroup", "name": "objects", "objects": [] },
+            {
+                "type": "group", "name": "group",
+                "layers": [{
+                    "type": "tilelayer", "name": "inner", "width": 2, "height": 2,
+                    "encoding": "base64", "data": "AQAAAAIAAAADAAAABAAAIA=="
+                }]
+            },
+            { "type": "tilelayer", "name": "outer", "width": 1, "height": 1, "data": [7] }
+        ]
+    }"#;
+
+    let layers = read_tiled_layers_with(map.as_bytes(), |t, _, _| t).unwrap();
+
+    assert_eq!(layers.len(), 2);
+    assert_eq!(layers[0].name, "inner");
+    assert_eq!(layers[0].tiles.size(), (2, 2));
+    assert_eq!(layers[0].tiles[(0, 1)].gid, 3);
+    assert_eq!(
+        layers[0].tiles[(1, 1)],
+        TiledTile {
+            gid: 4,
+            flipped_diagonally: true,
+            ..TiledTile::default()
+        }
+    );
+    assert_eq!(layers[1].name, "outer");
+    assert_eq!(layers[1].tiles[(0, 0)].gid, 7);
+}
+
+#[test]
+fn tiled_merges_chunks() {
+    let map = r#"{
+        "infinite": true,
+        "layers": [{
+            "type": "tilelayer", "name": "l",
+            "chunks": [
+                { "x": 16, "y": -16, "width": 2, "height": 2, "data": [1, 2, 3, 4] },
+                { "x": 14, "y": -13, "width": 1, "height": 1, "data": [5] }
+            ]
+        }]
+    }"#;
+
+    let layers = read_tiled_layers(map.as_bytes()).unwrap();
+
+    assert_eq!(layers[0].origin, (14, -16));
+    assert_eq!(
+        layers[0].tiles,
+        bidivec! {
+            [0, 0, 1, 2],
+            [0, 0, 3, 4],
+            [0, 0, 0, 0],
+            [5, 0, 0, 0],
+        }
+    );
+}
+
+#[test]
+fn tiled_limits_merged_chunks() {
+    let map = |x: i64, y: i64| {
+        format!(
+            r#"{{
+                "infinite": true,
+                "layers": [{{
+                    "type": "tilelayer", "name": "l",
+                    "chunks": [
+                        {{ "x": 0, "y": 0, "width": 1, "height": 1, "data": [1] }},
+                        {{ "x": {}, "y": {}, "width": 1, "height": 1, "data": [2] }}
+                    ]
+                }}]
+            }}"#,
+            x, y
+        )
+    };
+
+    let near = map(15, 3);
+    assert_eq!(
+        read_tiled_layers_limited(near.as_bytes(), 64).unwrap()[0]
+            .tiles
+            .size(),
+        (16, 4)
+    );
+    assert!(read_tiled_layers_limited(near.as_bytes(), 63).is_err());
+
+    // rejected without allocating the merged layer
+    let far = map(std::i32::MIN as i64, std::i32::MAX as i64);
+    assert!(read_tiled_layers_limited(far.as_bytes(), 1 << 20).is_err());
+}
+
+#[test]
+fn tiled_flags_roundtrip() {
+    for raw in [0u32, 1, 0xF000_0001, 0x9000_0010, 0x0FFF_FFFF].iter() {
+        assert_eq!(TiledTile::from_raw(*raw).to_raw(), *raw);
+    }
+
+    assert!(TiledTile::from_raw(0xF000_0000).is_empty());
+}
+
+#[test]
+fn tiled_reports_errors() {
+    let bad_size = r#"{ "layers": [
+        { "type": "tilelayer", "width": 2, "height": 2, "data": [1, 2, 3] }
+    ] }"#;
+    match read_tiled_layers(bad_size.as_bytes()) {
+        Err(BidiIoError::Bidi(BidiError::IncompatibleSize {
+            expected: 2,
+            found: 3,
+        })) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    let compressed = r#"{ "layers": [
+        { "type": "tilelayer", "width": 1, "height": 1,
+          "encoding": "base64", "compression": "zlib", "data": "eJxjZAAAAAQAAg==" }
+    ] }"#;
+    match read_tiled_layers(compressed.as_bytes()) {
+        Err(BidiIoError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::InvalidData),
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    match read_tiled_layers(&b"{ not json"[..]) {
+        Err(BidiIoError::Io(_)) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+}